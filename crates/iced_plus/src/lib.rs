@@ -30,6 +30,7 @@
 //! - `layouts` (default): Layout primitives (stacks, shells, responsive)
 //! - `components` (default): Pre-built UI components
 //! - `platform`: Desktop platform APIs (opt-in)
+//! - `dark-light`: Detect the OS light/dark preference (opt-in)
 //! - `full`: All features including platform APIs
 //!
 //! # Crate Organization
@@ -74,18 +75,24 @@ pub mod prelude {
     // Tokens
     #[cfg(feature = "tokens")]
     pub use iced_plus_tokens::{
-        Color, ColorPalette, ColorScale, Elevation, ElevationLevel, Motion, RadiusScale,
-        RadiusSize, SemanticColors, Shade, SpacingScale, SpacingSize, TextStyle, ThemePreset,
-        ThemeTokens, TypographyScale,
+        Color, ColorPalette, ColorScale, Elevation, ElevationLevel, Layer, Motion, RadiusScale,
+        RadiusSize, SemanticColors, Shade, SpacingScale, SpacingSize, TextStyle, TextTransform,
+        ThemePreset, ThemeTokens, TypographyScale,
     };
 
+    #[cfg(feature = "tokens")]
+    pub use iced_plus_tokens::{set_reduce_motion, with_reduce_motion};
+
     // Theme
     #[cfg(feature = "theme")]
     pub use iced_plus_theme::{
         AppTheme, ButtonVariant, ComponentSize, Destructive, ExtraLarge, ExtraSmall, Ghost, Large,
-        Medium, Outline, Primary, Secondary, Small, Status,
+        Medium, Outline, Primary, Secondary, Small, Status, ThemeMode, Tonal,
     };
 
+    #[cfg(feature = "theme")]
+    pub use iced_plus_theme::{set_ui_scale, with_ui_scale};
+
     // Layouts
     #[cfg(feature = "layouts")]
     pub use iced_plus_layouts::{Direction, HStack, Horizontal, VStack, Vertical};
@@ -94,10 +101,13 @@ pub mod prelude {
     pub use iced_plus_layouts::AppShell;
 
     #[cfg(feature = "layouts")]
-    pub use iced_plus_layouts::{modal, Modal};
+    pub use iced_plus_layouts::{close_task, modal, Modal};
 
     #[cfg(feature = "layouts")]
-    pub use iced_plus_layouts::{BreakpointTier, Breakpoints, Responsive, ShowOn};
+    pub use iced_plus_layouts::{
+        set_app_breakpoints, with_breakpoints, BreakpointTier, Breakpoints, Responsive,
+        ResponsiveContainer, ShowOn,
+    };
 
     // Components
     #[cfg(feature = "components")]
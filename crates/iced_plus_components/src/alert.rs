@@ -2,11 +2,16 @@
 
 use std::borrow::Cow;
 
-use iced::widget::{container, row, text};
-use iced::{Background, Border, Color, Element, Length};
+use iced::widget::{button, container, row, text};
+use iced::{Background, Border, Color, Element, Font, Length};
+
+use crate::toast::{Toast, ToastVariant};
+
+const SHOW_DETAILS: &str = "Show details";
+const HIDE_DETAILS: &str = "Hide details";
 
 /// Alert severity/type.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AlertType {
     /// Informational alert.
     #[default]
@@ -20,6 +25,17 @@ pub enum AlertType {
 }
 
 impl AlertType {
+    /// The toast variant with the same severity.
+    #[must_use]
+    pub const fn toast_variant(self) -> ToastVariant {
+        match self {
+            Self::Info => ToastVariant::Info,
+            Self::Success => ToastVariant::Success,
+            Self::Warning => ToastVariant::Warning,
+            Self::Error => ToastVariant::Error,
+        }
+    }
+
     fn colors(self) -> (Color, Color, Color) {
         // (background, border, text)
         match self {
@@ -56,23 +72,68 @@ impl AlertType {
     }
 }
 
+/// How wide an [`Alert`] is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlertWidth {
+    /// Shrink to the content, e.g. for an error next to a form field.
+    Inline,
+    /// Fill the available width, with rounded corners.
+    #[default]
+    Block,
+    /// Fill the available width with square corners, for page-level
+    /// banners placed edge to edge.
+    Banner,
+}
+
+impl AlertWidth {
+    /// Width of the alert.
+    #[must_use]
+    pub const fn length(self) -> Length {
+        match self {
+            Self::Inline => Length::Shrink,
+            Self::Block | Self::Banner => Length::Fill,
+        }
+    }
+
+    /// Corner radius of the alert.
+    #[must_use]
+    pub const fn radius(self) -> f32 {
+        match self {
+            Self::Inline | Self::Block => 6.0,
+            Self::Banner => 0.0,
+        }
+    }
+}
+
 /// An alert banner for contextual feedback.
 ///
+/// Alerts fill the available width by default; use
+/// [`inline`](Self::inline) for a compact alert that shrinks to its content,
+/// or [`banner`](Self::banner) for a full-bleed one.
+///
 /// # Example
 ///
 /// ```rust,ignore
 /// Alert::info("This is an informational message.")
 /// Alert::error("Something went wrong!")
 ///     .title("Error")
+///     .details(backtrace)
+///     .details_expanded(self.show_details)
+///     .on_toggle_details(Message::ToggleDetails)
 /// ```
-pub struct Alert<'a> {
+pub struct Alert<'a, Message = ()> {
     message: Cow<'a, str>,
     title: Option<Cow<'a, str>>,
     alert_type: AlertType,
     show_icon: bool,
+    details: Option<Cow<'a, str>>,
+    details_expanded: bool,
+    on_toggle_details: Option<Message>,
+    on_dismiss: Option<Message>,
+    width: AlertWidth,
 }
 
-impl<'a> Alert<'a> {
+impl<'a, Message> Alert<'a, Message> {
     /// Create a new alert.
     #[must_use]
     pub fn new(message: impl Into<Cow<'a, str>>) -> Self {
@@ -81,6 +142,11 @@ impl<'a> Alert<'a> {
             title: None,
             alert_type: AlertType::default(),
             show_icon: true,
+            details: None,
+            details_expanded: false,
+            on_toggle_details: None,
+            on_dismiss: None,
+            width: AlertWidth::default(),
         }
     }
 
@@ -128,12 +194,108 @@ impl<'a> Alert<'a> {
         self.show_icon = false;
         self
     }
+
+    /// Attach collapsible details (e.g. a stack trace).
+    ///
+    /// The details are rendered in a monospace font behind a
+    /// "Show details" toggle.
+    #[must_use]
+    pub fn details(mut self, details: impl Into<Cow<'a, str>>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Set whether the details are expanded.
+    ///
+    /// The expanded state is owned by the application.
+    #[must_use]
+    pub const fn details_expanded(mut self, expanded: bool) -> Self {
+        self.details_expanded = expanded;
+        self
+    }
+
+    /// Set the message to emit when the details toggle is pressed.
+    #[must_use]
+    pub fn on_toggle_details(mut self, message: Message) -> Self {
+        self.on_toggle_details = Some(message);
+        self
+    }
+
+    /// Show a close button that emits `message`.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Shrink the alert to its content.
+    #[must_use]
+    pub const fn inline(mut self) -> Self {
+        self.width = AlertWidth::Inline;
+        self
+    }
+
+    /// Draw the alert as a full-width banner with square corners.
+    ///
+    /// A banner can only fill its parent; place it outside any padded
+    /// container for it to span the window edge to edge.
+    #[must_use]
+    pub const fn banner(mut self) -> Self {
+        self.width = AlertWidth::Banner;
+        self
+    }
+
+    /// How wide the alert is drawn.
+    #[must_use]
+    pub const fn width(&self) -> AlertWidth {
+        self.width
+    }
+
+    /// Label of the details toggle, if the alert has details.
+    #[must_use]
+    pub fn details_toggle(&self) -> Option<&'static str> {
+        self.details.as_ref().map(|_| {
+            if self.details_expanded {
+                HIDE_DETAILS
+            } else {
+                SHOW_DETAILS
+            }
+        })
+    }
+
+    /// The details text currently shown, if expanded.
+    #[must_use]
+    pub fn visible_details(&self) -> Option<&str> {
+        self.details.as_deref().filter(|_| self.details_expanded)
+    }
+
+    /// Turn the alert into a toast that floats in a toast container and
+    /// auto-dismisses after the default toast duration.
+    ///
+    /// The title, message and severity carry over; details are dropped.
+    ///
+    /// ```rust,ignore
+    /// self.toasts.push(Alert::warning("Connection lost").floating());
+    /// ```
+    #[must_use]
+    pub fn floating(self) -> Toast<'a> {
+        let toast = Toast::new(self.message).variant(self.alert_type.toast_variant());
+
+        match self.title {
+            Some(title) => toast.title(title),
+            None => toast,
+        }
+    }
 }
 
-impl<'a, Message: 'a> From<Alert<'a>> for Element<'a, Message, iced::Theme> {
-    fn from(alert: Alert<'a>) -> Self {
+impl<'a, Message: Clone + 'a> From<Alert<'a, Message>> for Element<'a, Message, iced::Theme> {
+    fn from(alert: Alert<'a, Message>) -> Self {
         let (bg_color, border_color, text_color) = alert.alert_type.colors();
+        let toggle_label = alert.details_toggle();
+        let visible_details = alert.visible_details().map(str::to_owned);
         let message: String = alert.message.into_owned();
+        let width = alert.width.length();
+        let radius = alert.width.radius();
 
         let mut content_row = row![].spacing(8);
 
@@ -142,7 +304,7 @@ impl<'a, Message: 'a> From<Alert<'a>> for Element<'a, Message, iced::Theme> {
                 content_row.push(text(alert.alert_type.icon()).size(16).color(border_color));
         }
 
-        let text_content = if let Some(title) = alert.title {
+        let mut text_content = if let Some(title) = alert.title {
             let title_str: String = title.into_owned();
             iced::widget::column![
                 text(title_str).size(14).color(text_color),
@@ -153,15 +315,57 @@ impl<'a, Message: 'a> From<Alert<'a>> for Element<'a, Message, iced::Theme> {
             iced::widget::column![text(message).size(14).color(text_color)]
         };
 
-        content_row = content_row.push(text_content);
+        if let Some(label) = toggle_label {
+            text_content = text_content.push(
+                button(text(label).size(12).color(border_color))
+                    .padding(0)
+                    .style(button::text)
+                    .on_press_maybe(alert.on_toggle_details),
+            );
+        }
+
+        if let Some(details) = visible_details {
+            text_content = text_content.push(
+                container(
+                    text(details)
+                        .size(12)
+                        .font(Font::MONOSPACE)
+                        .color(text_color),
+                )
+                .padding(8)
+                .width(width)
+                .style(move |_theme: &iced::Theme| container::Style {
+                    background: Some(Background::Color(Color {
+                        a: 0.08,
+                        ..border_color
+                    })),
+                    border: Border {
+                        radius: 4.0.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+            );
+        }
+
+        content_row = content_row.push(text_content.width(width));
+
+        if let Some(on_dismiss) = alert.on_dismiss {
+            content_row = content_row.push(
+                button(text("✕").size(12).color(text_color))
+                    .padding([2, 6])
+                    .style(button::text)
+                    .on_press(on_dismiss),
+            );
+        }
 
         container(content_row)
             .padding(12)
-            .width(Length::Fill)
+            .width(width)
             .style(move |_theme: &iced::Theme| container::Style {
                 background: Some(Background::Color(bg_color)),
                 border: Border {
-                    radius: 6.0.into(),
+                    radius: radius.into(),
                     width: 1.0,
                     color: border_color,
                 },
@@ -170,3 +374,41 @@ impl<'a, Message: 'a> From<Alert<'a>> for Element<'a, Message, iced::Theme> {
             .into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn details_render_toggle() {
+        let alert: Alert<'_, ()> = Alert::error("Failed").details("trace");
+        assert_eq!(alert.details_toggle(), Some(SHOW_DETAILS));
+        assert_eq!(Alert::<()>::error("Failed").details_toggle(), None);
+    }
+
+    #[test]
+    fn details_visible_only_when_expanded() {
+        let collapsed: Alert<'_, ()> = Alert::error("Failed").details("trace");
+        assert_eq!(collapsed.visible_details(), None);
+
+        let expanded = collapsed.details_expanded(true);
+        assert_eq!(expanded.visible_details(), Some("trace"));
+        assert_eq!(expanded.details_toggle(), Some(HIDE_DETAILS));
+    }
+
+    #[test]
+    fn width_modes() {
+        let inline = Alert::<()>::error("Required").inline().width();
+        let block = Alert::<()>::error("Required").width();
+        let banner = Alert::<()>::error("Required").banner().width();
+
+        assert_eq!(inline.length(), Length::Shrink);
+        assert_eq!(banner.length(), Length::Fill);
+        assert!(banner.radius().abs() < f32::EPSILON);
+
+        // The default fills like a banner but keeps rounded corners.
+        assert_eq!(block, AlertWidth::Block);
+        assert_eq!(block.length(), Length::Fill);
+        assert!(block.radius() > banner.radius());
+    }
+}
@@ -1,9 +1,15 @@
 //! Avatar component for user/entity representation.
 
+use std::future::Future;
+
+use iced::advanced::graphics::image::{image_rs, load};
+use iced::advanced::image as adv_image;
 use iced::advanced::layout::{Layout, Limits, Node};
 use iced::advanced::renderer;
-use iced::advanced::widget::{tree, Tree, Widget};
-use iced::{mouse, Color, Element, Length, Point, Rectangle, Size};
+use iced::advanced::widget::{Tree, Widget};
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Size, Task};
+
+use crate::image::ImageSource;
 
 /// Avatar size preset.
 #[derive(Debug, Clone, Copy, Default)]
@@ -48,6 +54,63 @@ pub enum AvatarShape {
     Square,
 }
 
+impl AvatarShape {
+    /// Corner radius for an avatar of `side` pixels.
+    fn radius(self, side: f32) -> f32 {
+        match self {
+            Self::Circle => side / 2.0,
+            Self::Rounded => 8.0,
+            Self::Square => 0.0,
+        }
+    }
+}
+
+/// Loading state of an avatar image, owned by the application.
+///
+/// Starts in [`Loading`](Self::Loading) and moves on as
+/// [`AvatarLoadEvent`]s from [`Avatar::load_task`] are fed to
+/// [`update`](Self::update).
+#[derive(Debug, Clone, Default)]
+pub enum AvatarImage {
+    /// The image is being loaded; a skeleton is shown.
+    #[default]
+    Loading,
+    /// The image loaded and is shown.
+    Loaded(adv_image::Handle),
+    /// The image failed to load; initials are shown instead.
+    Failed,
+}
+
+impl AvatarImage {
+    /// Apply the outcome of a load.
+    pub fn update(&mut self, event: AvatarLoadEvent) {
+        *self = match event {
+            AvatarLoadEvent::Loaded(handle) => Self::Loaded(handle),
+            AvatarLoadEvent::Failed(_) => Self::Failed,
+        };
+    }
+}
+
+/// Outcome of loading an avatar image.
+#[derive(Debug, Clone)]
+pub enum AvatarLoadEvent {
+    /// The image loaded, decoded to RGBA and clipped to the avatar's shape.
+    Loaded(adv_image::Handle),
+    /// The image failed to load, with the reason.
+    Failed(String),
+}
+
+/// What an [`Avatar`] currently displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarContent<'a> {
+    /// A loading skeleton.
+    Skeleton,
+    /// The avatar image.
+    Image(&'a adv_image::Handle),
+    /// The initials.
+    Initials(&'a str),
+}
+
 /// An avatar component showing initials or placeholder.
 ///
 /// # Example
@@ -56,9 +119,13 @@ pub enum AvatarShape {
 /// Avatar::new("John Doe")
 ///     .size(AvatarSize::Large)
 ///     .color(Color::from_rgb(0.2, 0.5, 0.8))
+///
+/// // With an image: skeleton while loading, initials if it fails
+/// Avatar::from_image("John Doe", &self.avatar_image)
 /// ```
 pub struct Avatar {
     initials: String,
+    image: Option<AvatarImage>,
     size: AvatarSize,
     shape: AvatarShape,
     background_color: Color,
@@ -89,6 +156,7 @@ impl Avatar {
 
         Self {
             initials,
+            image: None,
             size: AvatarSize::default(),
             shape: AvatarShape::default(),
             background_color,
@@ -101,6 +169,7 @@ impl Avatar {
     pub fn initials(initials: impl Into<String>) -> Self {
         Self {
             initials: initials.into().to_uppercase(),
+            image: None,
             size: AvatarSize::default(),
             shape: AvatarShape::default(),
             background_color: Color::from_rgb(0.5, 0.5, 0.5),
@@ -108,6 +177,75 @@ impl Avatar {
         }
     }
 
+    /// Create an avatar showing an image, falling back to the initials of
+    /// `name` if it fails to load.
+    #[must_use]
+    pub fn from_image(name: &str, image: &AvatarImage) -> Self {
+        Self {
+            image: Some(image.clone()),
+            ..Self::new(name)
+        }
+    }
+
+    /// Load an avatar image from a file or bytes.
+    ///
+    /// The image is decoded and clipped to `shape` at `size` in the task,
+    /// off the UI thread, so show it with the same shape and size. Feed the
+    /// resulting event to [`AvatarImage::update`].
+    pub fn load_task(
+        source: impl Into<ImageSource>,
+        shape: AvatarShape,
+        size: AvatarSize,
+    ) -> Task<AvatarLoadEvent> {
+        match source.into() {
+            ImageSource::Path(path) => Self::load_with(
+                async move {
+                    async_fs::read(&path)
+                        .await
+                        .map_err(|error| format!("{}: {error}", path.display()))
+                },
+                shape,
+                size,
+            ),
+            ImageSource::Bytes(bytes) => Self::load_with(async move { Ok(bytes) }, shape, size),
+            ImageSource::Handle(handle) => Task::perform(
+                async move { prepare(&handle, shape, size) },
+                std::convert::identity,
+            ),
+        }
+    }
+
+    /// Load an avatar image with a custom fetcher, e.g. an HTTP request for
+    /// a URL using the app's own client.
+    ///
+    /// As with [`load_task`](Self::load_task), the image is decoded and
+    /// clipped to `shape` at `size` in the task. Feed the resulting event to
+    /// [`AvatarImage::update`].
+    pub fn load_with<F>(fetch: F, shape: AvatarShape, size: AvatarSize) -> Task<AvatarLoadEvent>
+    where
+        F: Future<Output = Result<Vec<u8>, String>> + Send + 'static,
+    {
+        Task::perform(
+            async move {
+                match fetch.await {
+                    Ok(bytes) => prepare(&adv_image::Handle::from_bytes(bytes), shape, size),
+                    Err(error) => AvatarLoadEvent::Failed(error),
+                }
+            },
+            std::convert::identity,
+        )
+    }
+
+    /// What the avatar currently displays.
+    #[must_use]
+    pub fn content(&self) -> AvatarContent<'_> {
+        match &self.image {
+            Some(AvatarImage::Loading) => AvatarContent::Skeleton,
+            Some(AvatarImage::Loaded(handle)) => AvatarContent::Image(handle),
+            Some(AvatarImage::Failed) | None => AvatarContent::Initials(&self.initials),
+        }
+    }
+
     /// Set the size.
     #[must_use]
     pub fn size(mut self, size: AvatarSize) -> Self {
@@ -161,21 +299,15 @@ fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Avatar
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Renderer: renderer::Renderer
+        + iced::advanced::text::Renderer<Font = iced::Font>
+        + adv_image::Renderer<Handle = adv_image::Handle>,
 {
     fn size(&self) -> Size<Length> {
         let px = self.size.pixels();
         Size::new(Length::Fixed(px), Length::Fixed(px))
     }
 
-    fn tag(&self) -> tree::Tag {
-        tree::Tag::stateless()
-    }
-
-    fn state(&self) -> tree::State {
-        tree::State::None
-    }
-
     fn children(&self) -> Vec<Tree> {
         Vec::new()
     }
@@ -209,10 +341,28 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let radius = match self.shape {
-            AvatarShape::Circle => bounds.width / 2.0,
-            AvatarShape::Rounded => 8.0,
-            AvatarShape::Square => 0.0,
+        let radius = self.shape.radius(bounds.width);
+
+        let initials = match self.content() {
+            AvatarContent::Skeleton => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: iced::Border {
+                            radius: radius.into(),
+                            ..Default::default()
+                        },
+                        shadow: iced::Shadow::default(),
+                    },
+                    Color::from_rgba(0.0, 0.0, 0.0, 0.08),
+                );
+                return;
+            }
+            AvatarContent::Image(handle) => {
+                renderer.draw_image(adv_image::Image::new(handle.clone()), bounds);
+                return;
+            }
+            AvatarContent::Initials(initials) => initials,
         };
 
         // Draw background
@@ -232,7 +382,7 @@ where
         let font_size = bounds.width * 0.4;
         renderer.fill_text(
             iced::advanced::text::Text {
-                content: self.initials.clone(),
+                content: initials.to_owned(),
                 bounds: Size::new(bounds.width, bounds.height),
                 size: iced::Pixels(font_size),
                 line_height: iced::advanced::text::LineHeight::default(),
@@ -249,11 +399,140 @@ where
     }
 }
 
+/// Decode `handle` and clip it to an avatar of `shape` and `size`.
+///
+/// Runs inside the load task, so drawing the avatar never decodes.
+fn prepare(handle: &adv_image::Handle, shape: AvatarShape, size: AvatarSize) -> AvatarLoadEvent {
+    let px = size.pixels();
+
+    match clip_corners(handle, shape.radius(px) / px) {
+        Ok(clipped) => AvatarLoadEvent::Loaded(clipped),
+        Err(error) => AvatarLoadEvent::Failed(error.to_string()),
+    }
+}
+
+/// Decode `handle` and fade out the pixels outside a rounded square with
+/// corners of `radius`, relative to its side, anti-aliased over one pixel.
+///
+/// iced 0.13 cannot clip images to rounded shapes, so the mask is baked into
+/// the pixels. The image is stretched to the avatar's square bounds, so the
+/// mask is computed in the same stretched coordinates.
+// Image dimensions and alpha values are far below where the casts lose
+// precision or truncate.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn clip_corners(
+    handle: &adv_image::Handle,
+    radius: f32,
+) -> image_rs::ImageResult<adv_image::Handle> {
+    let image = load(handle)?;
+    let (width, height) = image.dimensions();
+    let pixel = 1.0 / width.min(height).max(1) as f32;
+    let mut pixels = image.into_raw().to_vec();
+
+    for (index, rgba) in pixels.chunks_exact_mut(4).enumerate() {
+        let x = (index % width as usize) as f32 + 0.5;
+        let y = (index / width as usize) as f32 + 0.5;
+        let (u, v) = (x / width as f32, y / height as f32);
+
+        // Signed distance to the rounded square's edge, negative inside.
+        let qx = (u - 0.5).abs() - (0.5 - radius);
+        let qy = (v - 0.5).abs() - (0.5 - radius);
+        let distance = qx.max(0.0).hypot(qy.max(0.0)) + qx.max(qy).min(0.0) - radius;
+        let coverage = (0.5 - distance / pixel).clamp(0.0, 1.0);
+
+        rgba[3] = (f32::from(rgba[3]) * coverage).round() as u8;
+    }
+
+    Ok(adv_image::Handle::from_rgba(width, height, pixels))
+}
+
 impl<'a, Message: 'a, Theme: 'a, Renderer> From<Avatar> for Element<'a, Message, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+    Renderer: renderer::Renderer
+        + iced::advanced::text::Renderer<Font = iced::Font>
+        + adv_image::Renderer<Handle = adv_image::Handle>
+        + 'a,
 {
     fn from(avatar: Avatar) -> Self {
         Element::new(avatar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loading_then_loaded_shows_image() {
+        let mut image = AvatarImage::default();
+        assert_eq!(
+            Avatar::from_image("Jane Doe", &image).content(),
+            AvatarContent::Skeleton
+        );
+
+        let handle = adv_image::Handle::from_bytes(vec![0_u8; 4]);
+        image.update(AvatarLoadEvent::Loaded(handle.clone()));
+        assert_eq!(
+            Avatar::from_image("Jane Doe", &image).content(),
+            AvatarContent::Image(&handle)
+        );
+    }
+
+    #[test]
+    fn loading_then_error_falls_back_to_initials() {
+        let mut image = AvatarImage::Loading;
+        image.update(AvatarLoadEvent::Failed(String::from("404")));
+
+        assert!(matches!(image, AvatarImage::Failed));
+        assert_eq!(
+            Avatar::from_image("Jane Doe", &image).content(),
+            AvatarContent::Initials("JD")
+        );
+    }
+
+    fn alpha(handle: &adv_image::Handle, x: u32, y: u32) -> u8 {
+        load(handle).expect("rgba image").get_pixel(x, y).0[3]
+    }
+
+    fn loaded(event: AvatarLoadEvent) -> adv_image::Handle {
+        match event {
+            AvatarLoadEvent::Loaded(handle) => handle,
+            AvatarLoadEvent::Failed(error) => panic!("load failed: {error}"),
+        }
+    }
+
+    #[test]
+    fn circle_images_are_clipped_to_the_circle() {
+        let opaque = adv_image::Handle::from_rgba(32, 32, vec![255_u8; 32 * 32 * 4]);
+        let clipped = loaded(prepare(&opaque, AvatarShape::Circle, AvatarSize::Medium));
+
+        assert!(matches!(clipped, adv_image::Handle::Rgba { .. }));
+        assert_eq!(alpha(&clipped, 0, 0), 0);
+        assert_eq!(alpha(&clipped, 31, 31), 0);
+        assert_eq!(alpha(&clipped, 16, 16), 255);
+        assert_eq!(alpha(&clipped, 16, 1), 255);
+    }
+
+    #[test]
+    fn square_images_are_decoded_unclipped() {
+        let opaque = adv_image::Handle::from_rgba(8, 8, vec![255_u8; 8 * 8 * 4]);
+        let decoded = loaded(prepare(&opaque, AvatarShape::Square, AvatarSize::Medium));
+
+        assert_eq!(alpha(&decoded, 0, 0), 255);
+        assert_eq!(alpha(&decoded, 7, 7), 255);
+    }
+
+    #[test]
+    fn undecodable_images_fail_to_load() {
+        let garbage = adv_image::Handle::from_bytes(vec![0_u8; 4]);
+
+        assert!(matches!(
+            prepare(&garbage, AvatarShape::Circle, AvatarSize::Medium),
+            AvatarLoadEvent::Failed(_)
+        ));
+    }
+}
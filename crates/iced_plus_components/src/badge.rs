@@ -2,8 +2,15 @@
 
 use std::borrow::Cow;
 
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
 use iced::widget::{container, text};
-use iced::{Background, Border, Color, Element};
+use iced::{
+    event, mouse, Background, Border, Color, Element, Event, Length, Point, Rectangle, Size, Theme,
+    Vector,
+};
 
 /// Badge variant for different semantic meanings.
 #[derive(Debug, Clone, Copy, Default)]
@@ -119,3 +126,322 @@ impl<'a, Message: 'a> From<Badge<'a>> for Element<'a, Message, iced::Theme> {
             .into()
     }
 }
+
+/// Corner of the content a badge is anchored to by [`with_badge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgePosition {
+    /// Top-left corner.
+    TopLeft,
+    /// Top-right corner, e.g. a count on a bell icon.
+    #[default]
+    TopRight,
+    /// Bottom-left corner.
+    BottomLeft,
+    /// Bottom-right corner, e.g. a status dot on an avatar.
+    BottomRight,
+}
+
+impl BadgePosition {
+    /// The corner of `bounds` this position refers to.
+    #[must_use]
+    pub fn corner(self, bounds: Rectangle) -> Point {
+        let (right, bottom) = match self {
+            Self::TopLeft => (false, false),
+            Self::TopRight => (true, false),
+            Self::BottomLeft => (false, true),
+            Self::BottomRight => (true, true),
+        };
+
+        Point::new(
+            if right {
+                bounds.x + bounds.width
+            } else {
+                bounds.x
+            },
+            if bottom {
+                bounds.y + bounds.height
+            } else {
+                bounds.y
+            },
+        )
+    }
+}
+
+/// Anchor `badge` centered on a corner of `content`.
+///
+/// The badge overhangs the content without taking any space: the result
+/// measures exactly like `content`. The badge is drawn on top and gets
+/// events first, so an interactive badge still works over a button.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// with_badge(
+///     icon(IconName::Bell),
+///     Badge::count(self.unread).error(),
+///     BadgePosition::TopRight,
+/// )
+/// ```
+pub fn with_badge<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    badge: impl Into<Element<'a, Message, Theme, Renderer>>,
+    position: BadgePosition,
+) -> BadgeOverlay<'a, Message, Renderer> {
+    BadgeOverlay {
+        content: content.into(),
+        badge: badge.into(),
+        position,
+    }
+}
+
+/// An element with a badge on one of its corners; see [`with_badge`].
+pub struct BadgeOverlay<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    badge: Element<'a, Message, Theme, Renderer>,
+    position: BadgePosition,
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for BadgeOverlay<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.badge)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.badge]);
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let badge = self.badge.as_widget().layout(
+            &mut tree.children[1],
+            renderer,
+            &Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        let corner = self.position.corner(Rectangle::with_size(content.size()));
+        let badge_size = badge.size();
+        let badge = badge.move_to(Point::new(
+            corner.x - badge_size.width / 2.0,
+            corner.y - badge_size.height / 2.0,
+        ));
+
+        Node::with_children(content.size(), vec![content, badge])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        for ((child, state), layout) in [&self.content, &self.badge]
+            .into_iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .operate(state, layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content_layout = children.next().expect("badge content layout");
+        let badge_layout = children.next().expect("badge layout");
+
+        let status = self.badge.as_widget_mut().on_event(
+            &mut tree.children[1],
+            event.clone(),
+            badge_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let content_layout = children.next().expect("badge content layout");
+        let badge_layout = children.next().expect("badge layout");
+
+        let badge = self.badge.as_widget().mouse_interaction(
+            &tree.children[1],
+            badge_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if badge == mouse::Interaction::default() {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content_layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else {
+            badge
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let content_layout = children.next().expect("badge content layout");
+        let badge_layout = children.next().expect("badge layout");
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        // The badge overhangs the content, so it must not be culled by a
+        // viewport check against the content bounds.
+        let badge_viewport = viewport.union(&badge_layout.bounds());
+        self.badge.as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            badge_layout,
+            cursor,
+            &badge_viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<BadgeOverlay<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(overlay: BadgeOverlay<'a, Message, Renderer>) -> Self {
+        Element::new(overlay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::Space;
+
+    fn layout(position: BadgePosition) -> Node {
+        let overlay = with_badge::<(), ()>(Space::new(40.0, 30.0), Space::new(10.0, 8.0), position);
+        let mut tree = Tree::new(&overlay as &dyn Widget<(), Theme, ()>);
+
+        overlay.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        )
+    }
+
+    #[test]
+    fn badge_is_centered_on_requested_corner() {
+        let cases = [
+            (BadgePosition::TopLeft, Point::new(-5.0, -4.0)),
+            (BadgePosition::TopRight, Point::new(35.0, -4.0)),
+            (BadgePosition::BottomLeft, Point::new(-5.0, 26.0)),
+            (BadgePosition::BottomRight, Point::new(35.0, 26.0)),
+        ];
+
+        for (position, expected) in cases {
+            let node = layout(position);
+            let badge = Layout::new(&node).children().nth(1).unwrap().bounds();
+
+            assert_eq!(badge.position(), expected, "{position:?}");
+            assert_eq!(badge.size(), Size::new(10.0, 8.0));
+        }
+    }
+
+    #[test]
+    fn badge_does_not_change_measured_size() {
+        for position in [BadgePosition::TopLeft, BadgePosition::BottomRight] {
+            let node = layout(position);
+            let content = Layout::new(&node).children().next().unwrap().bounds();
+
+            assert_eq!(node.size(), Size::new(40.0, 30.0));
+            assert_eq!(content, Rectangle::with_size(Size::new(40.0, 30.0)));
+        }
+    }
+}
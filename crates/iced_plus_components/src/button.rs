@@ -6,11 +6,25 @@
 use std::borrow::Cow;
 use std::marker::PhantomData;
 
-use iced::widget::button;
-use iced::{Element, Length};
-use iced_plus_theme::{AppTheme, ButtonClass};
+use iced::advanced::graphics::geometry;
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::advanced::{renderer, text};
+use iced::time::Instant;
+use iced::widget::{button, container, tooltip};
+use iced::{
+    event, mouse, window, Border, Color, Element, Event, Length, Padding, Point, Rectangle, Size,
+    Transformation, Vector,
+};
+use iced_plus_theme::{scaled, AppTheme, ButtonClass, ContainerClass};
+use iced_plus_tokens::{reduce_motion, Motion, MotionScale};
 
+use crate::disabled::disable;
+use crate::focus::keyboard_focusable;
+use crate::icons::{IconName, InheritedIcon};
 use crate::private::Sealed;
+use crate::tooltip::{bubble_style, TooltipPosition};
 
 // ============================================================================
 // Variant marker types
@@ -41,6 +55,11 @@ impl Sealed for Destructive {}
 pub struct Outline;
 impl Sealed for Outline {}
 
+/// Tonal button variant - low-emphasis primary container fill.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tonal;
+impl Sealed for Tonal {}
+
 /// Trait for button variants.
 pub trait ButtonVariant: Sealed + Copy + Default {
     /// Get the corresponding ButtonClass for iced styling.
@@ -77,6 +96,12 @@ impl ButtonVariant for Outline {
     }
 }
 
+impl ButtonVariant for Tonal {
+    fn button_class() -> ButtonClass {
+        ButtonClass::Tonal
+    }
+}
+
 // ============================================================================
 // Size marker types
 // ============================================================================
@@ -109,8 +134,29 @@ pub trait ButtonSize: Sealed + Copy + Default {
     const PADDING_V: f32;
     /// Font size in pixels.
     const FONT_SIZE: f32;
+
+    /// Font size scaled by the current [UI scale](iced_plus_theme::set_ui_scale).
+    #[must_use]
+    fn font_size() -> f32 {
+        scaled(Self::FONT_SIZE)
+    }
+
+    /// Padding scaled by the current UI scale.
+    #[must_use]
+    fn padding() -> Padding {
+        Padding::from([scaled(Self::PADDING_V), scaled(Self::PADDING_H)])
+    }
+
+    /// Height of a single-line button at the current UI scale.
+    #[must_use]
+    fn height() -> f32 {
+        scaled(Self::FONT_SIZE.mul_add(LINE_HEIGHT, Self::PADDING_V * 2.0))
+    }
 }
 
+/// Label line height relative to the font size (iced's default).
+pub(crate) const LINE_HEIGHT: f32 = 1.3;
+
 impl ButtonSize for ExtraSmall {
     const PADDING_H: f32 = 8.0;
     const PADDING_V: f32 = 4.0;
@@ -143,7 +189,7 @@ impl ButtonSize for Large {
 ///
 /// # Type Parameters
 ///
-/// - `V`: The variant type (Primary, Secondary, Ghost, Destructive, Outline)
+/// - `V`: The variant type (Primary, Secondary, Ghost, Destructive, Outline, Tonal)
 /// - `S`: The size type (ExtraSmall, Small, Medium, Large)
 /// - `Message`: The message type for button press events
 ///
@@ -160,6 +206,12 @@ impl ButtonSize for Large {
 /// let delete = Button::destructive("Delete")
 ///     .small()
 ///     .on_press(Message::Delete);
+///
+/// // A square toolbar button showing just an icon.
+/// let toggle = Button::<Ghost, _, _>::icon_only(IconName::Moon)
+///     .small()
+///     .tooltip("Dark mode")
+///     .on_press(Message::ToggleTheme);
 /// ```
 pub struct Button<'a, V, S, Message>
 where
@@ -167,8 +219,12 @@ where
     S: ButtonSize,
 {
     label: Cow<'a, str>,
+    icon: Option<IconName>,
     on_press: Option<Message>,
     width: Length,
+    effect: PressEffect,
+    disabled: bool,
+    tooltip: Option<Cow<'a, str>>,
     _variant: PhantomData<V>,
     _size: PhantomData<S>,
 }
@@ -214,6 +270,32 @@ impl<'a, Message> Button<'a, Outline, Medium, Message> {
     }
 }
 
+impl<'a, Message> Button<'a, Tonal, Medium, Message> {
+    /// Create a tonal button.
+    #[must_use]
+    pub fn tonal(label: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(label)
+    }
+}
+
+impl<V, Message> Button<'_, V, Medium, Message>
+where
+    V: ButtonVariant,
+{
+    /// Create a square button showing only `icon`, centered.
+    ///
+    /// The button is as tall as a text button of the same size, and as
+    /// wide as it is tall. The icon takes the button's text color; add a
+    /// [`tooltip`](Self::tooltip) to say what it does.
+    #[must_use]
+    pub fn icon_only(icon: IconName) -> Self {
+        Self {
+            icon: Some(icon),
+            ..Self::new("")
+        }
+    }
+}
+
 impl<'a, V, S, Message> Button<'a, V, S, Message>
 where
     V: ButtonVariant,
@@ -222,8 +304,12 @@ where
     fn new(label: impl Into<Cow<'a, str>>) -> Self {
         Self {
             label: label.into(),
+            icon: None,
             on_press: None,
             width: Length::Shrink,
+            effect: PressEffect::None,
+            disabled: false,
+            tooltip: None,
             _variant: PhantomData,
             _size: PhantomData,
         }
@@ -257,6 +343,52 @@ where
         self
     }
 
+    /// Stretch the button to its parent's width, as in forms and mobile
+    /// layouts. Same as [`fill_width`](Self::fill_width).
+    #[must_use]
+    pub fn full_width(self) -> Self {
+        self.fill_width()
+    }
+
+    /// Disable the button: it ignores input and is dimmed.
+    ///
+    /// Unlike leaving out [`on_press`](Self::on_press), this keeps the
+    /// message, so toggling the flag doesn't require rebuilding it.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Show `text` in a tooltip below the button on hover.
+    ///
+    /// The tooltip keeps working while the button is
+    /// [`disabled`](Self::disabled), which makes it the place to explain
+    /// why it can't be pressed.
+    #[must_use]
+    pub fn tooltip(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Spread a material-style ripple from the cursor when pressed.
+    ///
+    /// Skipped if the button is built while [`reduce_motion`] is on.
+    #[must_use]
+    pub const fn ripple(mut self) -> Self {
+        self.effect = PressEffect::Ripple;
+        self
+    }
+
+    /// Briefly shrink the button when pressed.
+    ///
+    /// Skipped if the button is built while [`reduce_motion`] is on.
+    #[must_use]
+    pub const fn press_scale(mut self) -> Self {
+        self.effect = PressEffect::Scale;
+        self
+    }
+
     // Size changers - these change the type parameter S
 
     /// Change to extra small size.
@@ -264,8 +396,12 @@ where
     pub fn extra_small(self) -> Button<'a, V, ExtraSmall, Message> {
         Button {
             label: self.label,
+            icon: self.icon,
             on_press: self.on_press,
             width: self.width,
+            effect: self.effect,
+            disabled: self.disabled,
+            tooltip: self.tooltip,
             _variant: PhantomData,
             _size: PhantomData,
         }
@@ -276,8 +412,12 @@ where
     pub fn small(self) -> Button<'a, V, Small, Message> {
         Button {
             label: self.label,
+            icon: self.icon,
             on_press: self.on_press,
             width: self.width,
+            effect: self.effect,
+            disabled: self.disabled,
+            tooltip: self.tooltip,
             _variant: PhantomData,
             _size: PhantomData,
         }
@@ -288,56 +428,644 @@ where
     pub fn large(self) -> Button<'a, V, Large, Message> {
         Button {
             label: self.label,
+            icon: self.icon,
             on_press: self.on_press,
             width: self.width,
+            effect: self.effect,
+            disabled: self.disabled,
+            tooltip: self.tooltip,
             _variant: PhantomData,
             _size: PhantomData,
         }
     }
 }
 
-impl<'a, V, S, Message> From<Button<'a, V, S, Message>> for Element<'a, Message, AppTheme<'a>>
+impl<'a, V, S, Message> Button<'a, V, S, Message>
+where
+    V: ButtonVariant,
+    S: ButtonSize,
+{
+    /// The label or icon, and the padding around it.
+    ///
+    /// Icons are a label line tall with even padding, so icon-only buttons
+    /// come out square at the height of a text button.
+    fn content<Theme, Renderer>(&self) -> (Element<'a, Message, Theme, Renderer>, Padding)
+    where
+        Message: 'a,
+        Theme: iced::widget::text::Catalog + 'a,
+        Renderer: text::Renderer + geometry::Renderer + 'a,
+    {
+        self.icon.map_or_else(
+            || {
+                let label = iced::widget::text(self.label.clone().into_owned());
+                (label.size(S::font_size()).into(), S::padding())
+            },
+            |icon| {
+                let icon = InheritedIcon::new(icon, S::font_size() * LINE_HEIGHT);
+                (icon.into(), Padding::new(scaled(S::PADDING_V)))
+            },
+        )
+    }
+}
+
+impl<'a, V, S, Message, Renderer> From<Button<'a, V, S, Message>>
+    for Element<'a, Message, AppTheme<'a>, Renderer>
 where
     V: ButtonVariant + 'a,
     S: ButtonSize + 'a,
     Message: Clone + 'a,
+    Renderer: text::Renderer + geometry::Renderer + 'a,
 {
     fn from(btn: Button<'a, V, S, Message>) -> Self {
-        let label: String = btn.label.into_owned();
-        let content = iced::widget::text(label).size(S::FONT_SIZE);
+        let (content, padding) = btn.content();
 
         let mut button = button(content)
-            .padding([S::PADDING_V, S::PADDING_H])
+            .padding(padding)
             .width(btn.width)
             .class(V::button_class());
 
+        let enabled = btn.on_press.is_some() && !btn.disabled;
+        let effect = if btn.on_press.is_some() {
+            btn.effect
+        } else {
+            PressEffect::None
+        };
+
         if let Some(msg) = btn.on_press {
             button = button.on_press(msg);
         }
 
-        button.into()
+        // The tooltip wraps the disabled button, so it still sees the hover.
+        let button = effect.wrap(button);
+        let button = disable(
+            if enabled {
+                keyboard_focusable(button)
+            } else {
+                button
+            },
+            btn.disabled,
+        );
+        match btn.tooltip {
+            Some(tip) => tooltip(
+                button,
+                container(iced::widget::text(tip.into_owned()).size(12)).padding([4, 8]),
+                TooltipPosition::default().into(),
+            )
+            .class(ContainerClass::Card)
+            .into(),
+            None => button,
+        }
     }
 }
 
 // Also implement for default iced::Theme for flexibility
-impl<'a, V, S, Message> From<Button<'a, V, S, Message>> for Element<'a, Message, iced::Theme>
+impl<'a, V, S, Message, Renderer> From<Button<'a, V, S, Message>>
+    for Element<'a, Message, iced::Theme, Renderer>
 where
     V: ButtonVariant + 'a,
     S: ButtonSize + 'a,
     Message: Clone + 'a,
+    Renderer: text::Renderer + geometry::Renderer + 'a,
 {
     fn from(btn: Button<'a, V, S, Message>) -> Self {
-        let label: String = btn.label.into_owned();
-        let content = iced::widget::text(label).size(S::FONT_SIZE);
+        let (content, padding) = btn.content();
 
-        let mut button = button(content)
-            .padding([S::PADDING_V, S::PADDING_H])
-            .width(btn.width);
+        let mut button = button(content).padding(padding).width(btn.width);
+
+        let enabled = btn.on_press.is_some() && !btn.disabled;
+        let effect = if btn.on_press.is_some() {
+            btn.effect
+        } else {
+            PressEffect::None
+        };
 
         if let Some(msg) = btn.on_press {
             button = button.on_press(msg);
         }
 
-        button.into()
+        let button = effect.wrap(button);
+        let button = disable(
+            if enabled {
+                keyboard_focusable(button)
+            } else {
+                button
+            },
+            btn.disabled,
+        );
+        match btn.tooltip {
+            Some(tip) => tooltip(
+                button,
+                container(iced::widget::text(tip.into_owned()).size(12)).padding([4, 8]),
+                TooltipPosition::default().into(),
+            )
+            .style(bubble_style)
+            .into(),
+            None => button,
+        }
+    }
+}
+
+// ============================================================================
+// Press feedback
+// ============================================================================
+
+/// Visual feedback played when a button is pressed.
+///
+/// Effects are drawn over or around the button without changing its layout,
+/// and only play on enabled buttons.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum PressEffect {
+    /// No feedback beyond the pressed style.
+    #[default]
+    None,
+    /// A circle expanding from the press point, clipped to the button.
+    Ripple,
+    /// The button shrinks slightly and springs back.
+    Scale,
+}
+
+impl PressEffect {
+    /// How far [`PressEffect::Scale`] shrinks the button at its deepest.
+    const SCALE_DEPTH: f32 = 0.04;
+
+    /// Peak opacity of the ripple.
+    const RIPPLE_ALPHA: f32 = 0.25;
+
+    /// Timing of the effect.
+    fn motion(self) -> Motion {
+        let motion = MotionScale::default();
+
+        match self {
+            Self::None => motion.instant,
+            Self::Ripple => motion.slow,
+            Self::Scale => motion.normal,
+        }
+    }
+
+    fn wrap<'a, Message, Theme, Renderer>(
+        self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: renderer::Renderer + 'a,
+    {
+        match self {
+            Self::None => content.into(),
+            effect => Element::new(Pressable::new(content, effect)),
+        }
+    }
+}
+
+/// Progress of a press animation.
+#[derive(Debug, Default)]
+struct Press {
+    /// Press point relative to the button.
+    origin: Point,
+    active: bool,
+    started: Option<Instant>,
+    progress: f32,
+}
+
+impl Press {
+    /// Advance the animation to `now`; the first frame after a press starts it.
+    fn advance(&mut self, now: Instant, motion: Motion) {
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.duration_since(started).as_secs_f32();
+
+        self.progress = if motion.duration_ms == 0 {
+            1.0
+        } else {
+            (elapsed / motion.duration_seconds()).min(1.0)
+        };
+        self.active = self.progress < 1.0;
+    }
+
+    /// Ripple radius within `bounds`, growing until it covers the farthest corner.
+    fn ripple_radius(&self, bounds: Size, motion: Motion) -> f32 {
+        let reach_x = self.origin.x.max(bounds.width - self.origin.x);
+        let reach_y = self.origin.y.max(bounds.height - self.origin.y);
+
+        reach_x.hypot(reach_y) * motion.easing.apply(self.progress)
+    }
+
+    /// Scale factor, dipping to `1 - SCALE_DEPTH` halfway through.
+    fn scale(&self) -> f32 {
+        let dip = (self.progress * std::f32::consts::PI).sin();
+        PressEffect::SCALE_DEPTH.mul_add(-dip, 1.0)
+    }
+}
+
+/// Plays a [`PressEffect`] over a button.
+struct Pressable<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    effect: PressEffect,
+    /// Timing of the effect; instant if built while reduced motion was on.
+    motion: Motion,
+}
+
+impl<'a, Message, Theme, Renderer> Pressable<'a, Message, Theme, Renderer> {
+    fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, effect: PressEffect) -> Self {
+        Self {
+            content: content.into(),
+            effect,
+            motion: effect.motion().reduced(reduce_motion()),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Pressable<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Press>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Press::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<Press>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                // An instant effect has nothing to play.
+                let animated = self.motion.duration_ms > 0;
+
+                if let Some(position) = cursor.position_in(layout.bounds()).filter(|_| animated) {
+                    *state = Press {
+                        origin: position,
+                        active: true,
+                        ..Press::default()
+                    };
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) if state.active => {
+                state.advance(now, self.motion);
+                if state.active {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<Press>();
+        let bounds = layout.bounds();
+        let draw_content = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        };
+
+        if !state.active {
+            draw_content(renderer);
+            return;
+        }
+
+        match self.effect {
+            PressEffect::None => draw_content(renderer),
+            PressEffect::Ripple => {
+                draw_content(renderer);
+
+                let radius = state.ripple_radius(bounds.size(), self.motion);
+                let center = bounds.position() + Vector::new(state.origin.x, state.origin.y);
+
+                renderer.with_layer(bounds, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x - radius,
+                                y: center.y - radius,
+                                width: radius * 2.0,
+                                height: radius * 2.0,
+                            },
+                            border: Border::default().rounded(radius),
+                            ..renderer::Quad::default()
+                        },
+                        Color {
+                            a: PressEffect::RIPPLE_ALPHA * (1.0 - state.progress),
+                            ..style.text_color
+                        },
+                    );
+                });
+            }
+            PressEffect::Scale => {
+                let center = bounds.center();
+                let transformation = Transformation::translate(center.x, center.y)
+                    * Transformation::scale(state.scale())
+                    * Transformation::translate(-center.x, -center.y);
+
+                renderer.with_transformation(transformation, draw_content);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+    use iced_plus_theme::set_ui_scale;
+
+    /// Lay out a medium primary button with text shaped by a real font.
+    fn lay_out_button(label: &str) -> Size {
+        let renderer = iced::Renderer::Secondary(iced_tiny_skia::Renderer::new(
+            iced::Font::default(),
+            iced::Pixels(16.0),
+        ));
+        let button: Element<'_, (), iced::Theme, iced::Renderer> =
+            Button::primary(label).on_press(()).into();
+        let mut tree = Tree::new(&button);
+
+        button
+            .as_widget()
+            .layout(
+                &mut tree,
+                &renderer,
+                &Limits::new(Size::ZERO, Size::new(800.0, 800.0)),
+            )
+            .size()
+    }
+
+    #[test]
+    fn ui_scale_enlarges_laid_out_buttons() {
+        let size = lay_out_button("Save changes");
+
+        set_ui_scale(1.25);
+        let scaled = lay_out_button("Save changes");
+        let scaled_height = Medium::height();
+        set_ui_scale(1.0);
+
+        assert!((size.height - Medium::height()).abs() < 1e-3);
+        assert!((scaled.height - scaled_height).abs() < 1e-3);
+        assert!(size.height.mul_add(-1.25, scaled.height).abs() < 1e-3);
+        assert!(size.width.mul_add(-1.25, scaled.width).abs() < 1.0);
+    }
+
+    fn press_and_frames(effect: PressEffect, frames: &[u64]) -> Vec<(bool, f32)> {
+        let mut pressable: Pressable<'_, (), iced::Theme, ()> =
+            Pressable::new(iced::widget::Space::new(100.0, 40.0), effect);
+        let mut tree = Tree::new(&pressable as &dyn Widget<(), iced::Theme, ()>);
+        let node = pressable.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut send = |tree: &mut Tree, event: Event| {
+            let mut messages = Vec::new();
+            let _ = pressable.on_event(
+                tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(20.0, 20.0)),
+                &(),
+                &mut iced::advanced::clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(200.0, 200.0)),
+            );
+        };
+
+        send(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        );
+
+        let start = Instant::now();
+        frames
+            .iter()
+            .map(|&ms| {
+                send(
+                    &mut tree,
+                    Event::Window(window::Event::RedrawRequested(
+                        start + std::time::Duration::from_millis(ms),
+                    )),
+                );
+                let state = tree.state.downcast_ref::<Press>();
+                (
+                    state.active,
+                    state.ripple_radius(node.size(), effect.motion()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pressed_ripple_expands_over_time() {
+        let frames = press_and_frames(PressEffect::Ripple, &[0, 60, 150, 1_000]);
+
+        assert_eq!(frames[0], (true, 0.0));
+        assert!(frames[1].0 && frames[1].1 > 0.0);
+        assert!(frames[2].0 && frames[2].1 > frames[1].1);
+
+        // Finished once the ripple covers the farthest corner (80, 20) away.
+        assert!(!frames[3].0);
+        assert!((frames[3].1 - 80.0_f32.hypot(20.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn reduced_motion_skips_press_effects() {
+        iced_plus_tokens::set_reduce_motion(true);
+        let frames = [PressEffect::Ripple, PressEffect::Scale]
+            .map(|effect| press_and_frames(effect, &[0, 60]));
+        iced_plus_tokens::set_reduce_motion(false);
+
+        assert!(frames.iter().flatten().all(|&(active, _)| !active));
+    }
+
+    #[test]
+    fn press_scale_dips_and_recovers() {
+        let press = |progress| Press {
+            progress,
+            ..Press::default()
+        };
+
+        assert!((press(0.0).scale() - 1.0).abs() < f32::EPSILON);
+        assert!((press(0.5).scale() - (1.0 - PressEffect::SCALE_DEPTH)).abs() < 1e-6);
+        assert!((press(1.0).scale() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn disabled_button_ignores_clicks_and_is_dimmed() {
+        let button = |disabled| Button::primary("Save").on_press(()).disabled(disabled);
+        let theme = AppTheme::light();
+
+        assert_eq!(interact::<_, AppTheme<'_>>(button(false).into()), [()]);
+        assert!(interact::<_, AppTheme<'_>>(button(true).into()).is_empty());
+        assert_eq!(
+            last_quad(&button(true).into(), &theme),
+            Some(theme.disabled_veil())
+        );
+    }
+
+    #[test]
+    fn full_width_button_fills_its_parent() {
+        let button: Element<'_, (), iced::Theme, ()> =
+            Button::primary("Sign in").full_width().into();
+
+        assert_eq!(button.as_widget().size().width, Length::Fill);
+    }
+
+    #[test]
+    fn icon_only_small_button_is_square() {
+        let button: Element<'_, (), iced::Theme, ()> =
+            Button::<Ghost, _, _>::icon_only(IconName::Moon)
+                .small()
+                .into();
+        let mut tree = Tree::new(&button);
+        let size = button
+            .as_widget()
+            .layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+            )
+            .size();
+
+        assert!((size.width - Small::height()).abs() < 1e-4);
+        assert!((size.height - Small::height()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn disabled_button_still_shows_its_tooltip() {
+        let mut button: Element<'_, (), iced::Theme, ()> = Button::primary("Save")
+            .on_press(())
+            .disabled(true)
+            .tooltip("Add a title first")
+            .into();
+        let mut tree = Tree::new(&button);
+        let node = button.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+        let cursor = mouse::Cursor::Available(node.bounds().center());
+
+        let mut messages = Vec::new();
+        let _ = button.as_widget_mut().on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: node.bounds().center(),
+            }),
+            Layout::new(&node),
+            cursor,
+            &(),
+            &mut iced::advanced::clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(400.0, 100.0)),
+        );
+
+        assert!(button
+            .as_widget_mut()
+            .overlay(&mut tree, Layout::new(&node), &(), Vector::ZERO)
+            .is_some());
     }
 }
@@ -1,7 +1,11 @@
 //! Card component for elevated content containers.
 
-use iced::widget::container;
-use iced::{Background, Border, Color, Element, Length, Shadow, Vector};
+use iced::advanced::text;
+use iced::widget::{button, container, stack, text as label};
+use iced::{alignment, Background, Border, Color, Element, Length, Shadow, Vector};
+
+/// Diameter of the check shown on a selected card.
+const CHECK_SIZE: f32 = 20.0;
 
 /// Elevation level for cards.
 #[derive(Debug, Clone, Copy, Default)]
@@ -19,7 +23,7 @@ pub enum Elevation {
 
 impl Elevation {
     /// Get shadow configuration for this elevation.
-    fn shadow(self) -> Shadow {
+    pub(crate) fn shadow(self) -> Shadow {
         match self {
             Self::Flat => Shadow::default(),
             Self::Low => Shadow {
@@ -51,18 +55,30 @@ impl Elevation {
 ///     .padding(16.0)
 ///     .width(Length::Fixed(300.0))
 /// ```
-pub struct Card<'a, Message> {
-    content: Element<'a, Message, iced::Theme>,
+///
+/// Selectable cards make a choice group, e.g. a plan picker, where the app
+/// tracks which card is selected:
+///
+/// ```rust,ignore
+/// row(Plan::ALL.iter().map(|plan| {
+///     Card::new(plan_summary(plan))
+///         .selectable(self.plan == *plan, Message::PlanPicked(*plan))
+///         .into()
+/// }))
+/// ```
+pub struct Card<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, iced::Theme, Renderer>,
     elevation: Elevation,
     padding: f32,
     width: Length,
     height: Length,
     radius: f32,
+    selection: Option<(bool, Message)>,
 }
 
-impl<'a, Message: 'a> Card<'a, Message> {
+impl<'a, Message: 'a, Renderer> Card<'a, Message, Renderer> {
     /// Create a new card with content.
-    pub fn new(content: impl Into<Element<'a, Message, iced::Theme>>) -> Self {
+    pub fn new(content: impl Into<Element<'a, Message, iced::Theme, Renderer>>) -> Self {
         Self {
             content: content.into(),
             elevation: Elevation::default(),
@@ -70,6 +86,7 @@ impl<'a, Message: 'a> Card<'a, Message> {
             width: Length::Shrink,
             height: Length::Shrink,
             radius: 8.0,
+            selection: None,
         }
     }
 
@@ -113,13 +130,107 @@ impl<'a, Message: 'a> Card<'a, Message> {
     pub fn fill_width(self) -> Self {
         self.width(Length::Fill)
     }
+
+    /// Make the card pressable, emitting `on_select` when pressed.
+    ///
+    /// A selected card gets a primary-colored border and a check in its
+    /// top-right corner.
+    #[must_use]
+    pub fn selectable(mut self, selected: bool, on_select: Message) -> Self {
+        self.selection = Some((selected, on_select));
+        self
+    }
+}
+
+/// Style of a selectable card: primary border when selected, a stronger
+/// border on hover.
+fn selectable_style(
+    theme: &iced::Theme,
+    status: button::Status,
+    selected: bool,
+    radius: f32,
+    elevation: Elevation,
+) -> button::Style {
+    let palette = theme.extended_palette();
+    let (color, width) = match status {
+        _ if selected => (palette.primary.base.color, 2.0),
+        button::Status::Hovered | button::Status::Pressed => (palette.background.strong.color, 1.0),
+        button::Status::Active | button::Status::Disabled => (palette.background.weak.color, 1.0),
+    };
+
+    button::Style {
+        background: Some(Background::Color(palette.background.base.color)),
+        text_color: palette.background.base.text,
+        border: Border {
+            radius: radius.into(),
+            width,
+            color,
+        },
+        shadow: elevation.shadow(),
+    }
+}
+
+/// The round check in the corner of a selected card.
+fn check<'a, Message, Renderer>() -> Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    container(
+        label("✓")
+            .size(12)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+            .color(Color::WHITE),
+    )
+    .width(CHECK_SIZE)
+    .height(CHECK_SIZE)
+    .style(|theme: &iced::Theme| container::Style {
+        background: Some(Background::Color(
+            theme.extended_palette().primary.base.color,
+        )),
+        border: Border::default().rounded(CHECK_SIZE / 2.0),
+        ..container::Style::default()
+    })
+    .into()
 }
 
-impl<'a, Message: 'a> From<Card<'a, Message>> for Element<'a, Message, iced::Theme> {
-    fn from(card: Card<'a, Message>) -> Self {
+impl<'a, Message, Renderer> From<Card<'a, Message, Renderer>>
+    for Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(card: Card<'a, Message, Renderer>) -> Self {
         let elevation = card.elevation;
         let radius = card.radius;
 
+        if let Some((selected, on_select)) = card.selection {
+            let content: Element<'a, Message, iced::Theme, Renderer> = if selected {
+                stack![
+                    card.content,
+                    container(check())
+                        .width(Length::Fill)
+                        .align_x(alignment::Horizontal::Right),
+                ]
+                .into()
+            } else {
+                card.content
+            };
+
+            return button(content)
+                .padding(card.padding)
+                .width(card.width)
+                .height(card.height)
+                .on_press(on_select)
+                .style(move |theme: &iced::Theme, status| {
+                    selectable_style(theme, status, selected, radius, elevation)
+                })
+                .into();
+        }
+
         container(card.content)
             .padding(card.padding)
             .width(card.width)
@@ -142,3 +253,67 @@ impl<'a, Message: 'a> From<Card<'a, Message>> for Element<'a, Message, iced::The
 }
 
 // Note: For AppTheme support, use the iced::Theme version and configure theming at the app level
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::layout::{Layout, Limits};
+    use iced::advanced::widget::Tree;
+    use iced::advanced::{clipboard, Shell};
+    use iced::widget::Space;
+    use iced::{mouse, Event, Point, Rectangle, Size};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Plan {
+        Pro,
+    }
+
+    #[test]
+    fn selected_card_has_primary_border() {
+        let theme = iced::Theme::Light;
+        let style = |status, selected| {
+            selectable_style(&theme, status, selected, 8.0, Elevation::Low).border
+        };
+        let primary = theme.extended_palette().primary.base.color;
+
+        assert_eq!(style(button::Status::Active, true).color, primary);
+        assert_eq!(style(button::Status::Hovered, true).color, primary);
+        assert_ne!(style(button::Status::Active, false).color, primary);
+        assert!(
+            style(button::Status::Active, true).width > style(button::Status::Active, false).width
+        );
+    }
+
+    #[test]
+    fn pressing_a_selectable_card_emits_select() {
+        let mut card: Element<'_, Plan, iced::Theme, ()> = Card::new(Space::new(200.0, 80.0))
+            .selectable(false, Plan::Pro)
+            .into();
+        let mut tree = Tree::new(&card);
+        let node = card.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ] {
+            let _ = card.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(100.0, 40.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(400.0, 400.0)),
+            );
+        }
+
+        assert_eq!(messages, [Plan::Pro]);
+    }
+}
@@ -1,7 +1,16 @@
 //! Styled checkbox component.
 
-use iced::widget::checkbox;
-use iced::{Element, Length};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use iced::advanced::text;
+use iced::widget::{checkbox, text as label};
+use iced::{Element, Font, Length};
+use iced_plus_theme::scaled;
+
+use crate::disabled::disable;
+use crate::focus::keyboard_focusable;
+use crate::labeled::{LabelPosition, Labeled};
 
 /// A styled checkbox component.
 ///
@@ -11,13 +20,29 @@ use iced::{Element, Length};
 /// Checkbox::new("Accept terms", is_checked, Message::ToggleTerms)
 ///     .size(20.0)
 /// ```
+///
+/// The default box size and label spacing follow the
+/// [UI scale](iced_plus_theme::set_ui_scale).
+///
+/// Clicking the label toggles the checkbox too, unless
+/// [`label_clickable(false)`](Self::label_clickable) is set.
+///
+/// A "select all" checkbox over a [`CheckGroup`] shows a dash while only
+/// some of the group is checked:
+///
+/// ```rust,ignore
+/// Checkbox::tri_state("Select all", self.files.state(), Message::SelectAll)
+/// ```
 pub struct Checkbox<'a, Message> {
     label: &'a str,
-    is_checked: bool,
+    state: CheckState,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     size: f32,
     spacing: f32,
     width: Length,
+    label_position: LabelPosition,
+    label_clickable: bool,
+    disabled: bool,
 }
 
 impl<'a, Message> Checkbox<'a, Message>
@@ -31,11 +56,33 @@ where
     {
         Self {
             label,
-            is_checked,
+            state: if is_checked {
+                CheckState::Checked
+            } else {
+                CheckState::Unchecked
+            },
             on_toggle: Box::new(on_toggle),
-            size: 20.0,
-            spacing: 10.0,
+            size: scaled(20.0),
+            spacing: scaled(10.0),
             width: Length::Shrink,
+            label_position: LabelPosition::default(),
+            label_clickable: true,
+            disabled: false,
+        }
+    }
+
+    /// Create a checkbox showing a [`CheckState`].
+    ///
+    /// An indeterminate checkbox shows a dash instead of the checkmark, for
+    /// a parent whose children are only partly checked. Toggling it emits
+    /// `true`, to check the rest.
+    pub fn tri_state<F>(label: &'a str, state: CheckState, on_toggle: F) -> Self
+    where
+        F: Fn(bool) -> Message + 'a,
+    {
+        Self {
+            state,
+            ..Self::new(label, false, on_toggle)
         }
     }
 
@@ -59,18 +106,222 @@ where
         self.width = width.into();
         self
     }
+
+    /// Set which side of the checkbox the label is on.
+    #[must_use]
+    pub const fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Set whether clicking the label toggles the checkbox (default: true).
+    #[must_use]
+    pub const fn label_clickable(mut self, clickable: bool) -> Self {
+        self.label_clickable = clickable;
+        self
+    }
+
+    /// Disable the checkbox: it ignores clicks and is dimmed.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
 }
 
-impl<'a, Message> From<Checkbox<'a, Message>> for Element<'a, Message, iced::Theme>
+impl<'a, Message, Renderer> From<Checkbox<'a, Message>>
+    for Element<'a, Message, iced::Theme, Renderer>
 where
     Message: Clone + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
 {
     fn from(cb: Checkbox<'a, Message>) -> Self {
-        checkbox(cb.label, cb.is_checked)
-            .on_toggle(cb.on_toggle)
-            .size(cb.size)
-            .spacing(cb.spacing)
-            .width(cb.width)
-            .into()
+        let checked = cb.state == CheckState::Checked;
+        let on_click = cb.label_clickable.then(|| (cb.on_toggle)(!checked));
+        let control = if cb.state == CheckState::Indeterminate {
+            // Drawn checked with a dash; any toggle checks the rest.
+            let on_toggle = cb.on_toggle;
+            checkbox("", true)
+                .on_toggle(move |_| on_toggle(true))
+                .icon(checkbox::Icon {
+                    font: Font::DEFAULT,
+                    code_point: '−',
+                    size: None,
+                    line_height: text::LineHeight::Relative(1.0),
+                    shaping: text::Shaping::Advanced,
+                })
+        } else {
+            checkbox("", checked).on_toggle(cb.on_toggle)
+        }
+        .size(cb.size)
+        .spacing(0);
+        let control = if cb.disabled {
+            control.into()
+        } else {
+            keyboard_focusable(control)
+        };
+
+        disable(
+            Labeled::new(
+                control,
+                label(cb.label),
+                cb.label_position,
+                cb.spacing,
+                cb.width,
+                on_click,
+            ),
+            cb.disabled,
+        )
+    }
+}
+
+/// Checked state of a parent checkbox over a group of children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckState {
+    /// No child is checked.
+    Unchecked,
+    /// Every child is checked.
+    Checked,
+    /// Some, but not all, children are checked.
+    Indeterminate,
+}
+
+/// The checked keys of a group of checkboxes with a "select all" parent.
+///
+/// The application keeps the group in its state, shows the parent with
+/// [`Checkbox::tri_state`] and each child with [`is_checked`](Self::is_checked):
+///
+/// ```rust,ignore
+/// Message::SelectAll(_) => self.files.toggle_all(),
+/// Message::Select(id) => self.files.toggle(&id),
+/// ```
+#[derive(Debug, Clone)]
+pub struct CheckGroup<K> {
+    keys: Vec<K>,
+    checked: HashSet<K>,
+}
+
+impl<K> CheckGroup<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Create a group of `keys`, none of them checked.
+    pub fn new(keys: impl IntoIterator<Item = K>) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+            checked: HashSet::new(),
+        }
+    }
+
+    /// The keys of the group, in order.
+    #[must_use]
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Whether `key` is checked.
+    #[must_use]
+    pub fn is_checked(&self, key: &K) -> bool {
+        self.checked.contains(key)
+    }
+
+    /// The checked keys, in group order.
+    pub fn checked(&self) -> impl Iterator<Item = &K> {
+        self.keys.iter().filter(|key| self.checked.contains(*key))
+    }
+
+    /// State of the parent checkbox.
+    #[must_use]
+    pub fn state(&self) -> CheckState {
+        if self.checked.is_empty() {
+            CheckState::Unchecked
+        } else if self.keys.iter().all(|key| self.checked.contains(key)) {
+            CheckState::Checked
+        } else {
+            CheckState::Indeterminate
+        }
+    }
+
+    /// Check or uncheck `key`. Keys outside the group are ignored.
+    pub fn toggle(&mut self, key: &K) {
+        if !self.checked.remove(key) && self.keys.contains(key) {
+            self.checked.insert(key.clone());
+        }
+    }
+
+    /// Uncheck everything if all keys are checked, and otherwise check
+    /// everything, like clicking the parent checkbox.
+    pub fn toggle_all(&mut self) {
+        self.set_all(self.state() != CheckState::Checked);
+    }
+
+    /// Check or uncheck every key.
+    pub fn set_all(&mut self, checked: bool) {
+        self.checked.clear();
+        if checked {
+            self.checked.extend(self.keys.iter().cloned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+
+    #[test]
+    fn disabled_checkbox_ignores_clicks_and_is_dimmed() {
+        let checkbox =
+            |disabled| Checkbox::new("Accept", false, |checked| checked).disabled(disabled);
+        let theme = iced::Theme::Light;
+
+        assert_eq!(interact(checkbox(false).into()), [true]);
+        assert!(interact(checkbox(true).into()).is_empty());
+        assert_eq!(
+            last_quad(&checkbox(true).into(), &theme),
+            Some(theme.disabled_veil())
+        );
+    }
+
+    #[test]
+    fn group_state_follows_the_children() {
+        let mut group = CheckGroup::new(["a", "b", "c"]);
+        assert_eq!(group.state(), CheckState::Unchecked);
+
+        group.toggle(&"a");
+        group.toggle(&"c");
+        assert_eq!(group.state(), CheckState::Indeterminate);
+        assert_eq!(group.checked().collect::<Vec<_>>(), [&"a", &"c"]);
+
+        group.toggle(&"b");
+        assert_eq!(group.state(), CheckState::Checked);
+
+        group.toggle(&"b");
+        group.toggle(&"z");
+        assert_eq!(group.state(), CheckState::Indeterminate);
+    }
+
+    #[test]
+    fn toggle_all_checks_the_rest_then_clears() {
+        let mut group = CheckGroup::new(1..=4);
+        group.toggle(&2);
+
+        group.toggle_all();
+        assert_eq!(group.state(), CheckState::Checked);
+        assert!(group.keys().iter().all(|key| group.is_checked(key)));
+
+        group.toggle_all();
+        assert_eq!(group.state(), CheckState::Unchecked);
+        assert_eq!(group.checked().count(), 0);
+    }
+
+    #[test]
+    fn indeterminate_checkbox_checks_the_rest() {
+        let parent = Checkbox::tri_state("All", CheckState::Indeterminate, |checked| checked);
+        assert_eq!(interact(parent.into()), [true]);
+
+        let parent = Checkbox::tri_state("All", CheckState::Checked, |checked| checked);
+        assert_eq!(interact(parent.into()), [false]);
     }
 }
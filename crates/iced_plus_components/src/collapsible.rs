@@ -0,0 +1,421 @@
+//! Collapsible section that animates its height.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::time::Instant;
+use iced::{event, mouse, window, Element, Event, Length, Rectangle, Size, Vector};
+use iced_plus_tokens::{reduce_motion, Motion};
+
+/// Content that grows open from zero height and shrinks closed again, for
+/// accordions and "show more" sections.
+///
+/// The content is measured at its natural height on every layout, so the
+/// reveal follows content that reflows mid-animation, and is clipped to
+/// the part revealed so far. Toggling `expanded` halfway through reverses
+/// the animation from where it is.
+///
+/// The content should not fill the height: it is measured without a height
+/// limit.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// column![
+///     button("Advanced").on_press(Message::ToggleAdvanced),
+///     Collapsible::new(advanced_settings, self.show_advanced),
+/// ]
+/// ```
+pub struct Collapsible<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    expanded: bool,
+    motion: Motion,
+    reduce_motion: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Collapsible<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Create a section showing `content` while `expanded`.
+    ///
+    /// A section that starts out expanded appears open, without animating.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, expanded: bool) -> Self {
+        Self {
+            content: content.into(),
+            expanded,
+            motion: Motion::default(),
+            reduce_motion: reduce_motion(),
+        }
+    }
+
+    /// Set the duration and easing of the height animation.
+    ///
+    /// Use [`Motion::NONE`] to open and close instantly. It also applies
+    /// if the section was built while reduce motion was on.
+    #[must_use]
+    pub const fn motion(mut self, motion: Motion) -> Self {
+        self.motion = motion;
+        self
+    }
+}
+
+/// How much of the content is revealed, and where it is heading.
+#[derive(Debug)]
+struct Reveal {
+    expanded: bool,
+    /// Revealed fraction of the content height when the animation started.
+    from: f32,
+    /// Revealed fraction of the content height now.
+    fraction: f32,
+    /// Start of the running animation, set on its first frame.
+    started: Option<Instant>,
+    running: bool,
+}
+
+impl Reveal {
+    const fn new(expanded: bool) -> Self {
+        let fraction = if expanded { 1.0 } else { 0.0 };
+
+        Self {
+            expanded,
+            from: fraction,
+            fraction,
+            started: None,
+            running: false,
+        }
+    }
+
+    /// Start animating towards `expanded` from the current fraction.
+    fn toggle(&mut self, expanded: bool) {
+        if expanded != self.expanded {
+            self.expanded = expanded;
+            self.from = self.fraction;
+            self.started = None;
+            self.running = true;
+        }
+    }
+
+    /// Advance to `now`, returning whether the fraction changed.
+    fn advance(&mut self, now: Instant, motion: Motion) -> bool {
+        if !self.running {
+            return false;
+        }
+
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started).as_secs_f32();
+        let duration = motion.duration_seconds();
+        let t = if duration > 0.0 {
+            (elapsed / duration).min(1.0)
+        } else {
+            1.0
+        };
+        let target = if self.expanded { 1.0 } else { 0.0 };
+
+        self.fraction = (target - self.from).mul_add(motion.easing.apply(t), self.from);
+        self.running = t < 1.0;
+
+        true
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Collapsible<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.content.as_widget().size().width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Reveal>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Reveal::new(self.expanded))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<Reveal>().toggle(self.expanded);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let fraction = tree.state.downcast_ref::<Reveal>().fraction;
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(limits.max().width, f32::INFINITY)),
+        );
+        let natural = content.size();
+
+        Node::with_children(
+            Size::new(natural.width, natural.height * fraction),
+            vec![content],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // Collapsing content can't be focused or scrolled to.
+        if tree.state.downcast_ref::<Reveal>().expanded {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout
+                    .children()
+                    .next()
+                    .expect("collapsible content layout"),
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let reveal = tree.state.downcast_mut::<Reveal>();
+
+            if reveal.advance(now, self.motion.reduced(self.reduce_motion)) {
+                shell.invalidate_layout();
+
+                if reveal.running {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        let bounds = layout.bounds();
+        let Some(visible) = viewport.intersection(&bounds) else {
+            return event::Status::Ignored;
+        };
+        let cursor = if cursor.is_over(bounds) {
+            cursor
+        } else {
+            mouse::Cursor::Unavailable
+        };
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout
+                .children()
+                .next()
+                .expect("collapsible content layout"),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &visible,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        match viewport.intersection(&bounds) {
+            Some(visible) if cursor.is_over(bounds) => self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout
+                    .children()
+                    .next()
+                    .expect("collapsible content layout"),
+                cursor,
+                &visible,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(visible) = viewport.intersection(&bounds) else {
+            return;
+        };
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("collapsible content layout");
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                &visible,
+            );
+        };
+
+        // Only clip while part of the content is hidden.
+        if bounds.height < content_layout.bounds().height {
+            renderer.with_layer(bounds, draw);
+        } else {
+            draw(renderer);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if !tree.state.downcast_ref::<Reveal>().expanded {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Collapsible<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(collapsible: Collapsible<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(collapsible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+    use iced::Point;
+    use iced_plus_tokens::Easing;
+    use std::time::Duration;
+
+    fn section(expanded: bool) -> Collapsible<'static, (), (), Recorder> {
+        Collapsible::new(Space::new(Length::Fill, Length::Fixed(200.0)), expanded)
+            .motion(Motion::new(200, Easing::Linear))
+    }
+
+    fn layout(section: &Collapsible<'_, (), (), Recorder>, tree: &mut Tree) -> Node {
+        section.layout(
+            tree,
+            &Recorder::default(),
+            &Limits::new(Size::ZERO, Size::new(300.0, 600.0)),
+        )
+    }
+
+    #[test]
+    fn expanding_reveals_and_clips_the_measured_height() {
+        let mut tree = Tree::new(&section(false) as &dyn Widget<(), (), Recorder>);
+        assert!(layout(&section(false), &mut tree).size().height.abs() < f32::EPSILON);
+
+        let mut expanded = section(true);
+        Widget::<(), (), Recorder>::diff(&expanded, &mut tree);
+
+        let node = layout(&expanded, &mut tree);
+        let started = Instant::now();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for now in [started, started + Duration::from_millis(50)] {
+            let _ = expanded.on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &Recorder::default(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(300.0, 600.0)),
+            );
+        }
+
+        // A quarter of the way through, a quarter of the content shows.
+        let node = layout(&expanded, &mut tree);
+        let visible = node.size().height;
+        let full = node.children()[0].size().height;
+        assert!(visible > 0.0 && visible < full);
+        assert!((visible - 50.0).abs() < 0.01);
+        assert!((full - 200.0).abs() < f32::EPSILON);
+
+        let mut recorder = Recorder::default();
+        expanded.draw(
+            &tree,
+            &mut recorder,
+            &(),
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &Rectangle::with_size(Size::new(300.0, 600.0)),
+        );
+        assert_eq!(
+            recorder.layers,
+            [Rectangle::new(Point::ORIGIN, Size::new(300.0, visible))]
+        );
+    }
+
+    #[test]
+    fn collapsing_midway_reverses_from_the_current_height() {
+        let mut reveal = Reveal::new(false);
+        let motion = Motion::new(200, Easing::Linear);
+        let started = Instant::now();
+
+        reveal.toggle(true);
+        reveal.advance(started, motion);
+        reveal.advance(started + Duration::from_millis(100), motion);
+        assert!((reveal.fraction - 0.5).abs() < 0.01);
+
+        reveal.toggle(false);
+        let reversed = started + Duration::from_millis(100);
+        reveal.advance(reversed, motion);
+        reveal.advance(reversed + Duration::from_millis(100), motion);
+        assert!((reveal.fraction - 0.25).abs() < 0.01);
+
+        reveal.advance(reversed + Duration::from_millis(200), motion);
+        assert!(reveal.fraction.abs() < f32::EPSILON);
+        assert!(!reveal.running);
+    }
+}
@@ -0,0 +1,438 @@
+//! Disabled state shared by interactive components.
+//!
+//! [`Button`](crate::Button), [`TextInput`](crate::TextInput),
+//! [`Checkbox`](crate::Checkbox), [`Radio`](crate::Radio),
+//! [`Switch`](crate::Switch), [`Slider`](crate::Slider) and
+//! [`Select`](crate::Select) take a `.disabled(bool)`. A disabled component
+//! receives no input, so it emits no messages, is skipped by keyboard
+//! focus traversal, and draws dimmed: its
+//! inherited text color fades to the theme's disabled opacity, under a
+//! faint neutral veil that reads the same on the page, on cards and on
+//! colored controls.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::operation::{focusable, Scrollable, TextInput};
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::{event, mouse, Color, Element, Event, Length, Rectangle, Size, Vector};
+use iced_plus_theme::AppTheme;
+use iced_plus_tokens::{OpacityScale, Shade};
+use std::any::Any;
+
+/// Themes that can dim a disabled component.
+pub trait DisabledTheme {
+    /// Opacity of the inherited text color of a disabled component.
+    fn disabled_opacity(&self) -> f32;
+
+    /// Translucent neutral color drawn over a disabled component.
+    ///
+    /// It covers the whole component, so it must not be the page
+    /// background: that would paint a box over cards and colored controls.
+    fn disabled_veil(&self) -> Color;
+}
+
+/// Alpha of the veil: half the dimming of the disabled opacity, faint
+/// enough not to show as a box around rounded controls.
+fn veil_alpha(disabled_opacity: f32) -> f32 {
+    (1.0 - disabled_opacity) / 2.0
+}
+
+impl DisabledTheme for AppTheme<'_> {
+    fn disabled_opacity(&self) -> f32 {
+        self.opacity().disabled
+    }
+
+    fn disabled_veil(&self) -> Color {
+        Color {
+            a: veil_alpha(self.disabled_opacity()),
+            ..self.neutral(Shade::S500)
+        }
+    }
+}
+
+impl DisabledTheme for iced::Theme {
+    fn disabled_opacity(&self) -> f32 {
+        OpacityScale::DEFAULT.disabled
+    }
+
+    fn disabled_veil(&self) -> Color {
+        Color {
+            a: veil_alpha(self.disabled_opacity()),
+            ..self.extended_palette().secondary.base.color
+        }
+    }
+}
+
+/// Wrap `content` so it ignores input and draws dimmed, if `disabled`.
+pub(crate) fn disable<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    disabled: bool,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: DisabledTheme + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    if disabled {
+        Element::new(Disabled {
+            content: content.into(),
+        })
+    } else {
+        content.into()
+    }
+}
+
+/// A component that receives no input and draws under a veil.
+struct Disabled<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Disabled<'_, Message, Theme, Renderer>
+where
+    Theme: DisabledTheme,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::None
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut Unfocusable(operation),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Window events still reach the content, so animations settle.
+        if matches!(
+            event,
+            Event::Mouse(_) | Event::Keyboard(_) | Event::Touch(_)
+        ) {
+            return event::Status::Ignored;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            mouse::Cursor::Unavailable,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::NotAllowed
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let style = renderer::Style {
+            text_color: Color {
+                a: style.text_color.a * theme.disabled_opacity(),
+                ..style.text_color
+            },
+        };
+
+        // Without a cursor the content draws in its resting state.
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &style,
+            layout,
+            mouse::Cursor::Unavailable,
+            viewport,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            theme.disabled_veil(),
+        );
+    }
+}
+
+/// Forwards an [`Operation`] to disabled content, hiding its focusable
+/// widgets so keyboard focus moves past them.
+struct Unfocusable<'a>(&'a mut dyn Operation);
+
+impl Operation for Unfocusable<'_> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        self.0.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut Unfocusable(operation));
+        });
+    }
+
+    fn focusable(&mut self, _state: &mut dyn focusable::Focusable, _id: Option<&Id>) {}
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0
+            .scrollable(state, id, bounds, content_bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.0.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.0.custom(state, id);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+    use iced::advanced::clipboard;
+    use iced::keyboard;
+
+    const VIEWPORT: Size = Size::new(400.0, 100.0);
+
+    fn key(key: keyboard::Key, text: Option<&str>) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            modified_key: key.clone(),
+            key,
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: text.map(Into::into),
+        })
+    }
+
+    /// Click the middle of `element`, then type "a" and press the right
+    /// arrow, returning the published messages.
+    pub fn interact<Message, Theme>(element: Element<'_, Message, Theme, ()>) -> Vec<Message> {
+        let mut element = element;
+        let mut tree = Tree::new(&element);
+        let node = element
+            .as_widget()
+            .layout(&mut tree, &(), &Limits::new(Size::ZERO, VIEWPORT));
+        let cursor = mouse::Cursor::Available(node.bounds().center());
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            key(keyboard::Key::Character("a".into()), Some("a")),
+            key(keyboard::Key::Named(keyboard::key::Named::ArrowRight), None),
+        ] {
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(VIEWPORT),
+            );
+        }
+
+        messages
+    }
+
+    /// Draw `element` and return the color of the last quad.
+    pub fn last_quad<Message, Theme>(
+        element: &Element<'_, Message, Theme, Recorder>,
+        theme: &Theme,
+    ) -> Option<Color> {
+        let mut tree = Tree::new(element);
+        let mut recorder = Recorder::default();
+        let node =
+            element
+                .as_widget()
+                .layout(&mut tree, &recorder, &Limits::new(Size::ZERO, VIEWPORT));
+
+        element.as_widget().draw(
+            &tree,
+            &mut recorder,
+            theme,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(VIEWPORT),
+        );
+
+        recorder.colors().last().copied()
+    }
+
+    #[test]
+    fn veil_is_translucent_and_not_the_background() {
+        for theme in [AppTheme::light(), AppTheme::dark()] {
+            let veil = theme.disabled_veil();
+
+            assert!(veil.a > 0.0 && veil.a < 1.0 - theme.opacity().disabled);
+            assert_ne!(Color { a: 1.0, ..veil }, theme.background());
+            assert_eq!(Color { a: 1.0, ..veil }, theme.neutral(Shade::S500));
+        }
+    }
+
+    #[test]
+    fn disabled_content_inherits_a_faded_text_color() {
+        let theme = iced::Theme::Light;
+        let label: Element<'_, (), iced::Theme, Recorder> =
+            disable(iced::widget::text("Save"), true);
+        let mut tree = Tree::new(&label);
+        let mut recorder = Recorder::default();
+        let node =
+            label
+                .as_widget()
+                .layout(&mut tree, &recorder, &Limits::new(Size::ZERO, VIEWPORT));
+
+        label.as_widget().draw(
+            &tree,
+            &mut recorder,
+            &theme,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(VIEWPORT),
+        );
+
+        let faded = Color {
+            a: OpacityScale::DEFAULT.disabled,
+            ..Color::BLACK
+        };
+        assert_eq!(recorder.paragraphs, [faded]);
+        assert_eq!(recorder.colors(), [theme.disabled_veil()]);
+    }
+
+    #[test]
+    fn focus_traversal_skips_disabled_controls() {
+        use iced::advanced::widget::operation::{
+            self,
+            focusable::{focus_next, focus_previous},
+        };
+        use iced::widget::{column, text_input};
+
+        let form: Element<'_, (), iced::Theme, ()> = column![
+            text_input("Name", "").id(text_input::Id::new("name")),
+            disable(text_input("Email", ""), true),
+            text_input("Phone", ""),
+        ]
+        .into();
+        let mut tree = Tree::new(&form);
+        let node = form
+            .as_widget()
+            .layout(&mut tree, &(), &Limits::new(Size::ZERO, VIEWPORT));
+        let mut run = |operation: Box<dyn Operation>| {
+            let mut operation = operation;
+            loop {
+                form.as_widget()
+                    .operate(&mut tree, Layout::new(&node), &(), operation.as_mut());
+
+                match operation.finish() {
+                    operation::Outcome::Chain(next) => operation = next,
+                    _ => break,
+                }
+            }
+
+            [
+                &tree.children[0],
+                &tree.children[1].children[0],
+                &tree.children[2],
+            ]
+            .map(|field| {
+                field
+                    .state
+                    .downcast_ref::<text_input::State<()>>()
+                    .is_focused()
+            })
+        };
+
+        let name = Id::new("name");
+        assert_eq!(run(Box::new(focusable::focus(name))), [true, false, false]);
+        assert_eq!(run(Box::new(focus_next::<()>())), [false, false, true]);
+        assert_eq!(run(Box::new(focus_previous::<()>())), [true, false, false]);
+    }
+}
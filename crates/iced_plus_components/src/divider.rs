@@ -1,9 +1,12 @@
 //! Divider component for visual separation.
 
+use std::borrow::Cow;
+
 use iced::advanced::layout::{Layout, Limits, Node};
-use iced::advanced::renderer;
 use iced::advanced::widget::{tree, Tree, Widget};
-use iced::{mouse, Color, Element, Length, Rectangle, Size};
+use iced::advanced::{renderer, text as advanced_text};
+use iced::widget::{row, text};
+use iced::{alignment, mouse, Color, Element, Length, Rectangle, Size};
 
 /// Orientation of the divider.
 #[derive(Debug, Clone, Copy, Default)]
@@ -15,6 +18,18 @@ pub enum DividerOrientation {
     Vertical,
 }
 
+/// Where the label of a [`LabeledDivider`] sits along the line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DividerLabelAlign {
+    /// Near the start, after a short leading line (section headings).
+    Start,
+    /// In the middle, between two equal lines ("OR").
+    #[default]
+    Center,
+    /// Near the end, before a short trailing line.
+    End,
+}
+
 /// A simple divider line for visual separation.
 ///
 /// # Example
@@ -26,12 +41,17 @@ pub enum DividerOrientation {
 /// // Vertical divider with custom color
 /// Divider::vertical()
 ///     .color(Color::from_rgb(0.8, 0.8, 0.8))
+///
+/// // Section heading
+/// Divider::with_label("Advanced").label_align(DividerLabelAlign::Start)
 /// ```
 pub struct Divider {
     orientation: DividerOrientation,
     thickness: f32,
     color: Color,
     spacing: f32,
+    /// Extent along the line; `Fill` unless used as a label segment.
+    length: Length,
 }
 
 impl Default for Divider {
@@ -41,6 +61,7 @@ impl Default for Divider {
             thickness: 1.0,
             color: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
             spacing: 0.0,
+            length: Length::Fill,
         }
     }
 }
@@ -81,6 +102,18 @@ impl Divider {
         self.spacing = spacing;
         self
     }
+
+    /// Create a horizontal divider with a text label interrupting the line.
+    #[must_use]
+    pub fn with_label<'a>(label: impl Into<Cow<'a, str>>) -> LabeledDivider<'a> {
+        LabeledDivider {
+            label: label.into(),
+            align: DividerLabelAlign::default(),
+            inset: 16.0,
+            gap: 8.0,
+            line: Self::horizontal(),
+        }
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Divider
@@ -90,12 +123,12 @@ where
     fn size(&self) -> Size<Length> {
         match self.orientation {
             DividerOrientation::Horizontal => Size::new(
-                Length::Fill,
+                self.length,
                 Length::Fixed(self.thickness + self.spacing * 2.0),
             ),
             DividerOrientation::Vertical => Size::new(
                 Length::Fixed(self.thickness + self.spacing * 2.0),
-                Length::Fill,
+                self.length,
             ),
         }
     }
@@ -115,13 +148,19 @@ where
     fn diff(&self, _tree: &mut Tree) {}
 
     fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let extent = |max: f32| match self.length {
+            Length::Fixed(length) => length.min(max),
+            _ => max,
+        };
         let size = match self.orientation {
-            DividerOrientation::Horizontal => {
-                Size::new(limits.max().width, self.thickness + self.spacing * 2.0)
-            }
-            DividerOrientation::Vertical => {
-                Size::new(self.thickness + self.spacing * 2.0, limits.max().height)
-            }
+            DividerOrientation::Horizontal => Size::new(
+                extent(limits.max().width),
+                self.spacing.mul_add(2.0, self.thickness),
+            ),
+            DividerOrientation::Vertical => Size::new(
+                self.spacing.mul_add(2.0, self.thickness),
+                extent(limits.max().height),
+            ),
         };
 
         Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
@@ -184,3 +223,130 @@ where
         Element::new(divider)
     }
 }
+
+/// A horizontal divider with a label; see [`Divider::with_label`].
+pub struct LabeledDivider<'a> {
+    label: Cow<'a, str>,
+    align: DividerLabelAlign,
+    inset: f32,
+    gap: f32,
+    line: Divider,
+}
+
+impl LabeledDivider<'_> {
+    /// Set where the label sits along the line.
+    #[must_use]
+    pub const fn label_align(mut self, align: DividerLabelAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the length of the short line before a start-aligned label or
+    /// after an end-aligned one.
+    #[must_use]
+    pub fn inset(mut self, inset: f32) -> Self {
+        self.inset = inset.max(0.0);
+        self
+    }
+
+    /// Set the space between the label and the lines.
+    #[must_use]
+    pub const fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the line thickness.
+    #[must_use]
+    pub const fn thickness(mut self, thickness: f32) -> Self {
+        self.line.thickness = thickness;
+        self
+    }
+
+    /// Set the line color.
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.line.color = color;
+        self
+    }
+
+    /// Lengths of the lines before and after the label.
+    const fn line_lengths(&self) -> (Length, Length) {
+        let inset = Length::Fixed(self.inset);
+
+        match self.align {
+            DividerLabelAlign::Start => (inset, Length::Fill),
+            DividerLabelAlign::Center => (Length::Fill, Length::Fill),
+            DividerLabelAlign::End => (Length::Fill, inset),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LabeledDivider<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text::Catalog + 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    fn from(divider: LabeledDivider<'a>) -> Self {
+        let (leading, trailing) = divider.line_lengths();
+        let line = |length| Divider {
+            length,
+            ..divider.line
+        };
+
+        row![
+            line(leading),
+            text(divider.label.into_owned()).size(12),
+            line(trailing)
+        ]
+        .spacing(divider.gap)
+        .align_y(alignment::Vertical::Center)
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Widths of the leading and trailing lines in a 400px row.
+    fn line_widths(align: DividerLabelAlign) -> (f32, f32) {
+        let element: Element<'_, (), iced::Theme, ()> =
+            Divider::with_label("OR").label_align(align).into();
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+        let children = node.children();
+
+        (children[0].size().width, children[2].size().width)
+    }
+
+    #[test]
+    fn start_label_has_short_leading_line() {
+        let (leading, trailing) = line_widths(DividerLabelAlign::Start);
+
+        assert!((leading - 16.0).abs() < f32::EPSILON);
+        assert!(trailing > leading * 10.0);
+    }
+
+    #[test]
+    fn center_label_has_equal_lines() {
+        let (leading, trailing) = line_widths(DividerLabelAlign::Center);
+
+        assert!(leading > 0.0);
+        assert!((leading - trailing).abs() < 1.0);
+    }
+
+    #[test]
+    fn end_label_has_short_trailing_line() {
+        let (leading, trailing) = line_widths(DividerLabelAlign::End);
+
+        assert!((trailing - 16.0).abs() < f32::EPSILON);
+        assert!(leading > trailing * 10.0);
+    }
+}
@@ -2,9 +2,17 @@
 
 use iced::advanced::layout::{Layout, Limits, Node};
 use iced::advanced::renderer;
-use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::widget::operation::focusable;
+use iced::advanced::widget::{self, operate, tree, Operation, Tree, Widget};
 use iced::advanced::{Clipboard, Shell};
-use iced::{event, mouse, Color, Element, Event, Length, Point, Rectangle, Size};
+use iced::time::Instant;
+use iced::widget::{scrollable, Scrollable, Space};
+use iced::{
+    event, mouse, touch, window, Color, Element, Event, Length, Padding, Point, Rectangle, Size,
+    Task, Vector,
+};
+use iced_plus_layouts::layered;
+use iced_plus_tokens::{reduce_motion, Easing, Layer, Motion};
 
 /// Drawer position.
 #[derive(Debug, Clone, Copy, Default)]
@@ -30,18 +38,56 @@ pub enum DrawerPosition {
 ///     base_content.into()
 /// }
 /// ```
+///
+/// To hand keyboard focus back to the control that opened the drawer, set
+/// [`restore_focus_to`](Self::restore_focus_to) and return
+/// [`close_task`](Self::close_task) when handling the close message.
+///
+/// Navigation drawers can pin a [`header`](Self::header) to the top and a
+/// [`footer`](Self::footer) to the bottom of the panel; the drawer content
+/// then scrolls between them:
+///
+/// ```rust,ignore
+/// Drawer::new(base_content, side_nav)
+///     .header(user_card)
+///     .footer(button("Log out").on_press(Message::LogOut))
+/// ```
+///
+/// The panel slides in when the drawer appears, over the duration and
+/// [`easing`](Self::easing) of its [`motion`](Self::motion), while the
+/// backdrop fades in alongside it.
+///
+/// With a [`swipe_threshold`](Self::swipe_threshold), the panel can also be
+/// dragged towards its edge to close it, for touch and trackpad users.
 pub struct Drawer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     base: Element<'a, Message, Theme, Renderer>,
+    panel: Panel<'a, Message, Theme, Renderer>,
+    restore_focus: Option<widget::Id>,
+}
+
+/// The scrim and sliding panel of a [`Drawer`], stacked over its base
+/// content on the [`Layer::Overlay`] layer.
+struct Panel<'a, Message, Theme, Renderer> {
+    header: Element<'a, Message, Theme, Renderer>,
     drawer: Element<'a, Message, Theme, Renderer>,
+    footer: Element<'a, Message, Theme, Renderer>,
+    scrolls: bool,
     position: DrawerPosition,
     width: f32,
     backdrop_opacity: f32,
+    scrim: bool,
     on_close: Option<Message>,
+    swipe_threshold: Option<f32>,
+    safe_area: Padding,
+    motion: Motion,
+    reduce_motion: bool,
 }
 
 impl<'a, Message, Theme, Renderer> Drawer<'a, Message, Theme, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
 {
     /// Create a new drawer.
     pub fn new(
@@ -50,45 +96,292 @@ where
     ) -> Self {
         Self {
             base: base.into(),
-            drawer: drawer.into(),
-            position: DrawerPosition::default(),
-            width: 300.0,
-            backdrop_opacity: 0.6,
-            on_close: None,
+            panel: Panel {
+                header: Space::new(Length::Shrink, Length::Shrink).into(),
+                drawer: drawer.into(),
+                footer: Space::new(Length::Shrink, Length::Shrink).into(),
+                scrolls: false,
+                position: DrawerPosition::default(),
+                width: 300.0,
+                backdrop_opacity: 0.6,
+                scrim: true,
+                on_close: None,
+                swipe_threshold: None,
+                safe_area: Padding::ZERO,
+                motion: Motion::default(),
+                reduce_motion: reduce_motion(),
+            },
+            restore_focus: None,
         }
     }
 
     /// Set the drawer position.
     #[must_use]
     pub fn position(mut self, position: DrawerPosition) -> Self {
-        self.position = position;
+        self.panel.position = position;
         self
     }
 
     /// Set the drawer width.
     #[must_use]
     pub fn width(mut self, width: f32) -> Self {
-        self.width = width;
+        self.panel.width = width;
         self
     }
 
     /// Set the backdrop opacity.
     #[must_use]
     pub fn backdrop_opacity(mut self, opacity: f32) -> Self {
-        self.backdrop_opacity = opacity.clamp(0.0, 1.0);
+        self.panel.backdrop_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Remove the dimmed backdrop.
+    ///
+    /// Without a scrim the drawer is non-modal: the base content stays
+    /// interactive and clicking it does not close the drawer.
+    #[must_use]
+    pub const fn no_scrim(mut self) -> Self {
+        self.panel.scrim = false;
+        self
+    }
+
+    /// Keep the panel clear of safe-area insets, e.g. a custom title bar.
+    #[must_use]
+    pub fn safe_area(mut self, insets: impl Into<Padding>) -> Self {
+        self.panel.safe_area = insets.into();
         self
     }
 
     /// Set the message to emit when backdrop is clicked.
     #[must_use]
     pub fn on_close(mut self, message: Message) -> Self {
-        self.on_close = Some(message);
+        self.panel.on_close = Some(message);
         self
     }
+
+    /// Close the drawer when the panel is dragged towards its edge, leftward
+    /// for a left drawer and rightward for a right one, by more than
+    /// `threshold` pixels.
+    ///
+    /// The panel follows the pointer while dragged and emits the
+    /// [`on_close`](Self::on_close) message on release past the threshold;
+    /// released sooner, it springs back open.
+    #[must_use]
+    pub fn swipe_threshold(mut self, threshold: f32) -> Self {
+        self.panel.swipe_threshold = Some(threshold.max(0.0));
+        self
+    }
+
+    /// Set the duration and easing of the slide-in animation.
+    ///
+    /// Use [`Motion::NONE`] to show the drawer without animating. It also
+    /// appears instantly if built while [`reduce_motion`] is on.
+    #[must_use]
+    pub const fn motion(mut self, motion: Motion) -> Self {
+        self.panel.motion = motion;
+        self
+    }
+
+    /// Set the easing curve of the slide-in animation.
+    ///
+    /// Defaults to [`Easing::STANDARD`], the motion tokens' standard curve.
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.panel.motion.easing = easing;
+        self
+    }
+
+    /// Return focus to the widget with this id when the drawer closes.
+    #[must_use]
+    pub fn restore_focus_to(mut self, id: impl Into<widget::Id>) -> Self {
+        self.restore_focus = Some(id.into());
+        self
+    }
+
+    /// The task to run once the drawer has closed.
+    ///
+    /// Focuses the [`restore_focus_to`](Self::restore_focus_to) target, as
+    /// [`close_task`](iced_plus_layouts::close_task) does, or does nothing if
+    /// none was set.
+    pub fn close_task<T>(&self) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        self.restore_focus_operation()
+            .map_or_else(Task::none, operate)
+    }
+
+    fn restore_focus_operation<T: 'static>(&self) -> Option<Box<dyn Operation<T>>> {
+        let target = self.restore_focus.clone()?;
+
+        Some(Box::new(focusable::focus(target)))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Drawer<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: scrollable::Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    /// Pin an element to the top of the panel, above the scrolling content.
+    #[must_use]
+    pub fn header(mut self, header: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.panel.header = header.into();
+        self.scroll_content()
+    }
+
+    /// Pin an element to the bottom of the panel, below the scrolling
+    /// content.
+    #[must_use]
+    pub fn footer(mut self, footer: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.panel.footer = footer.into();
+        self.scroll_content()
+    }
+
+    /// Make the content scroll in the space between header and footer.
+    fn scroll_content(mut self) -> Self {
+        let panel = &mut self.panel;
+        if !panel.scrolls {
+            let content = std::mem::replace(
+                &mut panel.drawer,
+                Space::new(Length::Shrink, Length::Shrink).into(),
+            );
+            panel.drawer = Scrollable::new(content).height(Length::Fill).into();
+            panel.scrolls = true;
+        }
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Panel<'a, Message, Theme, Renderer> {
+    /// The panel's header, content and footer, top to bottom.
+    const fn parts(&self) -> [&Element<'a, Message, Theme, Renderer>; 3] {
+        [&self.header, &self.drawer, &self.footer]
+    }
+
+    /// Horizontal offset of the panel from its open position at `progress`
+    /// through the slide-in, moving it fully past the window edge at 0.0.
+    fn slide_offset(&self, progress: f32) -> f32 {
+        let hidden = 1.0 - self.motion.easing.apply(progress);
+
+        match self.position {
+            DrawerPosition::Left => -(self.width + self.safe_area.left) * hidden,
+            DrawerPosition::Right => (self.width + self.safe_area.right) * hidden,
+        }
+    }
+
+    /// How far `delta` moves the panel towards its edge; drags the other
+    /// way count as zero.
+    fn closing_distance(&self, delta: f32) -> f32 {
+        match self.position {
+            DrawerPosition::Left => (-delta).max(0.0),
+            DrawerPosition::Right => delta.max(0.0),
+        }
+    }
+
+    /// Track a swipe on the panel at `panel`, returning whether it closes
+    /// the drawer.
+    fn swipe(
+        &self,
+        swipe: &mut Swipe,
+        event: &Event,
+        panel: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> bool {
+        if self.swipe_threshold.is_none() {
+            return false;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(panel) {
+                    swipe.start(position.x);
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. })
+                if panel.contains(*position) =>
+            {
+                swipe.start(position.x);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                swipe.move_to(position.x);
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let distance = self.closing_distance(swipe.delta);
+                let dragging = swipe.origin.take().is_some();
+                swipe.delta = 0.0;
+
+                return dragging
+                    && self
+                        .swipe_threshold
+                        .is_some_and(|threshold| distance > threshold);
+            }
+            _ => {}
+        }
+
+        false
+    }
+}
+
+/// Widget state of a [`Drawer`]'s panel.
+#[derive(Debug, Default)]
+struct State {
+    slide: Slide,
+    swipe: Swipe,
+}
+
+/// Progress through the slide-in since the drawer appeared.
+#[derive(Debug, Default)]
+struct Slide {
+    started: Option<Instant>,
+    progress: f32,
+}
+
+impl Slide {
+    /// Advance to `now`, returning whether the slide is still running.
+    fn advance(&mut self, now: Instant, motion: Motion) -> bool {
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started).as_secs_f32();
+        let duration = motion.duration_seconds();
+
+        self.progress = if duration > 0.0 {
+            (elapsed / duration).min(1.0)
+        } else {
+            1.0
+        };
+
+        self.progress < 1.0
+    }
+}
+
+/// A drag on the panel towards or away from its edge.
+#[derive(Debug, Default)]
+struct Swipe {
+    /// Horizontal position the drag started at, while dragging.
+    origin: Option<f32>,
+    /// Horizontal distance dragged so far, reset on release.
+    delta: f32,
+}
+
+impl Swipe {
+    fn start(&mut self, x: f32) {
+        self.origin = Some(x);
+        self.delta = 0.0;
+    }
+
+    fn move_to(&mut self, x: f32) {
+        if let Some(origin) = self.origin {
+            self.delta = x - origin;
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for Drawer<'a, Message, Theme, Renderer>
+    for Panel<'a, Message, Theme, Renderer>
 where
     Message: Clone,
     Renderer: renderer::Renderer,
@@ -98,47 +391,68 @@ where
     }
 
     fn tag(&self) -> tree::Tag {
-        tree::Tag::stateless()
+        tree::Tag::of::<State>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::None
+        tree::State::new(State::default())
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.base), Tree::new(&self.drawer)]
+        self.parts().into_iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.children[0].diff(&self.base);
-        tree.children[1].diff(&self.drawer);
+        tree.diff_children(&self.parts());
     }
 
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         let bounds = limits.max();
 
-        // Layout base content
-        let base_node = self.base.as_widget().layout(
-            &mut tree.children[0],
-            renderer,
-            &Limits::new(Size::ZERO, bounds),
+        // Layout the panel: header on top, footer at the bottom and the
+        // drawer content in between
+        let insets = self.safe_area;
+        let panel_size = Size::new(
+            self.width,
+            (bounds.height - insets.top - insets.bottom).max(0.0),
         );
+        let part_limits = Limits::new(Size::ZERO, panel_size);
 
-        // Layout drawer
-        let drawer_limits = Limits::new(Size::ZERO, Size::new(self.width, bounds.height));
-        let drawer_node =
-            self.drawer
+        let header_node =
+            self.header
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &part_limits);
+        let footer_node =
+            self.footer
                 .as_widget()
-                .layout(&mut tree.children[1], renderer, &drawer_limits);
+                .layout(&mut tree.children[2], renderer, &part_limits);
+
+        let header_height = header_node.size().height;
+        let footer_height = footer_node.size().height;
+        let content_limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                self.width,
+                (panel_size.height - header_height - footer_height).max(0.0),
+            ),
+        );
+        let content_node = self
+            .drawer
+            .as_widget()
+            .layout(&mut tree.children[1], renderer, &content_limits)
+            .move_to(Point::new(0.0, header_height));
+        let footer_node = footer_node.move_to(Point::new(0.0, panel_size.height - footer_height));
 
         // Position drawer based on side
         let drawer_x = match self.position {
-            DrawerPosition::Left => 0.0,
-            DrawerPosition::Right => bounds.width - self.width,
+            DrawerPosition::Left => insets.left,
+            DrawerPosition::Right => bounds.width - self.width - insets.right,
         };
-        let drawer_node = drawer_node.move_to(Point::new(drawer_x, 0.0));
+        let drawer_node =
+            Node::with_children(panel_size, vec![header_node, content_node, footer_node])
+                .move_to(Point::new(drawer_x, insets.top));
 
-        Node::with_children(bounds, vec![base_node, drawer_node])
+        Node::with_children(bounds, vec![drawer_node])
     }
 
     fn operate(
@@ -148,21 +462,16 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
-        let mut children = layout.children();
-
-        if let Some(base_layout) = children.next() {
-            self.base
-                .as_widget()
-                .operate(&mut tree.children[0], base_layout, renderer, operation);
-        }
-
-        if let Some(drawer_layout) = children.next() {
-            self.drawer.as_widget().operate(
-                &mut tree.children[1],
-                drawer_layout,
-                renderer,
-                operation,
-            );
+        if let Some(drawer_layout) = layout.children().next() {
+            for ((part, part_tree), part_layout) in self
+                .parts()
+                .into_iter()
+                .zip(&mut tree.children)
+                .zip(drawer_layout.children())
+            {
+                part.as_widget()
+                    .operate(part_tree, part_layout, renderer, operation);
+            }
         }
     }
 
@@ -177,42 +486,81 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        let mut children = layout.children();
-        let _base_layout = children.next();
-        let drawer_layout = children.next();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if tree
+                .state
+                .downcast_mut::<State>()
+                .slide
+                .advance(now, self.motion.reduced(self.reduce_motion))
+            {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let drawer_layout = layout.children().next();
 
-        // First, let drawer handle events
         if let Some(drawer_layout) = drawer_layout {
-            let status = self.drawer.as_widget_mut().on_event(
-                &mut tree.children[1],
-                event.clone(),
-                drawer_layout,
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                viewport,
-            );
+            let swipe = &mut tree.state.downcast_mut::<State>().swipe;
+            let was_dragged = swipe.delta != 0.0;
 
-            if status == event::Status::Captured {
+            if self.swipe(swipe, &event, drawer_layout.bounds(), cursor) {
+                if let Some(message) = self.on_close.clone() {
+                    shell.publish(message);
+                }
                 return event::Status::Captured;
             }
 
-            // Check for backdrop click
+            if was_dragged || swipe.delta != 0.0 {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        // First, let drawer handle events
+        if let Some(drawer_layout) = drawer_layout {
+            let parts = [&mut self.header, &mut self.drawer, &mut self.footer];
+
+            for ((part, part_tree), part_layout) in parts
+                .into_iter()
+                .zip(&mut tree.children)
+                .zip(drawer_layout.children())
+            {
+                let status = part.as_widget_mut().on_event(
+                    part_tree,
+                    event.clone(),
+                    part_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+
+                if status == event::Status::Captured {
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        // Anywhere on the scrim outside the panel closes the drawer
+        let over_panel = drawer_layout.is_some_and(|layout| cursor.is_over(layout.bounds()));
+        if self.scrim && !over_panel {
             if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
-                if let Some(position) = cursor.position() {
-                    let drawer_bounds = drawer_layout.bounds();
-                    if !drawer_bounds.contains(position) {
-                        if let Some(message) = self.on_close.clone() {
-                            shell.publish(message);
-                            return event::Status::Captured;
-                        }
+                if cursor.is_over(layout.bounds()) {
+                    if let Some(message) = self.on_close.clone() {
+                        shell.publish(message);
                     }
+                    return event::Status::Captured;
                 }
             }
         }
 
-        event::Status::Ignored
+        // The scrim and the panel keep input from the base content beneath,
+        // which still sees window events such as redraws.
+        if (self.scrim || over_panel) && !matches!(event, Event::Window(_)) {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
     }
 
     fn mouse_interaction(
@@ -223,19 +571,33 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let mut children = layout.children();
-        let _base_layout = children.next();
-
-        if let Some(drawer_layout) = children.next() {
-            self.drawer.as_widget().mouse_interaction(
-                &tree.children[1],
-                drawer_layout,
-                cursor,
-                viewport,
-                renderer,
-            )
+        if let Some(drawer_layout) = layout.children().next() {
+            if cursor.is_over(drawer_layout.bounds()) {
+                return self
+                    .parts()
+                    .into_iter()
+                    .zip(&tree.children)
+                    .zip(drawer_layout.children())
+                    .map(|((part, part_tree), part_layout)| {
+                        part.as_widget().mouse_interaction(
+                            part_tree,
+                            part_layout,
+                            cursor,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .find(|interaction| *interaction != mouse::Interaction::None)
+                    .unwrap_or(mouse::Interaction::Idle);
+            }
+        }
+
+        // An idle cursor over the scrim keeps the base content from
+        // showing hover states beneath it.
+        if self.scrim && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Idle
         } else {
-            mouse::Interaction::default()
+            mouse::Interaction::None
         }
     }
 
@@ -249,57 +611,71 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let mut children = layout.children();
-
-        // Draw base content
-        if let Some(base_layout) = children.next() {
-            self.base.as_widget().draw(
-                &tree.children[0],
-                renderer,
-                theme,
-                style,
-                base_layout,
-                cursor,
-                viewport,
-            );
-        }
-
+        let state = tree.state.downcast_ref::<State>();
+        let progress = if self.motion.reduced(self.reduce_motion).duration_ms == 0 {
+            1.0
+        } else {
+            state.slide.progress
+        };
         // Draw backdrop
-        let bounds = layout.bounds();
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds,
-                border: iced::Border::default(),
-                shadow: iced::Shadow::default(),
-            },
-            Color::from_rgba(0.0, 0.0, 0.0, self.backdrop_opacity),
-        );
-
-        // Draw drawer
-        if let Some(drawer_layout) = children.next() {
-            // Drawer background
+        if self.scrim {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: drawer_layout.bounds(),
+                    bounds: layout.bounds(),
                     border: iced::Border::default(),
-                    shadow: iced::Shadow {
-                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
-                        offset: iced::Vector::new(2.0, 0.0),
-                        blur_radius: 8.0,
-                    },
+                    shadow: iced::Shadow::default(),
                 },
-                Color::WHITE,
+                Color::from_rgba(
+                    0.0,
+                    0.0,
+                    0.0,
+                    self.backdrop_opacity * self.motion.easing.apply(progress),
+                ),
             );
+        }
 
-            self.drawer.as_widget().draw(
-                &tree.children[1],
-                renderer,
-                theme,
-                style,
-                drawer_layout,
-                cursor,
-                viewport,
-            );
+        // Draw drawer, shifted towards its edge while sliding in
+        if let Some(drawer_layout) = layout.children().next() {
+            // A swiped panel follows the pointer towards its edge
+            let dragged = self.closing_distance(state.swipe.delta).min(self.width);
+            let dragged = match self.position {
+                DrawerPosition::Left => -dragged,
+                DrawerPosition::Right => dragged,
+            };
+            let offset = Vector::new(self.slide_offset(progress) + dragged, 0.0);
+
+            renderer.with_translation(offset, |renderer| {
+                // Drawer background
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: drawer_layout.bounds(),
+                        border: iced::Border::default(),
+                        shadow: iced::Shadow {
+                            color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+                            offset: iced::Vector::new(2.0, 0.0),
+                            blur_radius: 8.0,
+                        },
+                    },
+                    Color::WHITE,
+                );
+
+                for ((part, part_tree), part_layout) in self
+                    .parts()
+                    .into_iter()
+                    .zip(&tree.children)
+                    .zip(drawer_layout.children())
+                {
+                    part.as_widget().draw(
+                        part_tree,
+                        renderer,
+                        theme,
+                        style,
+                        part_layout,
+                        cursor,
+                        viewport,
+                    );
+                }
+            });
         }
     }
 }
@@ -312,6 +688,332 @@ where
     Renderer: renderer::Renderer + 'a,
 {
     fn from(drawer: Drawer<'a, Message, Theme, Renderer>) -> Self {
-        Element::new(drawer)
+        layered(drawer.base, [(Layer::Overlay, Element::new(drawer.panel))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Close,
+        Base,
+    }
+
+    fn click(panel: &mut Panel<'_, Message, (), ()>, at: Point) -> Vec<Message> {
+        let mut tree = Tree::new(&*panel as &dyn Widget<Message, (), ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(800.0, 600.0));
+        let node = panel.layout(&mut tree, &(), &limits);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let _ = panel.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(at),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::new(800.0, 600.0)),
+        );
+
+        messages
+    }
+
+    fn drawer() -> Drawer<'static, Message, (), ()> {
+        Drawer::new(
+            Space::new(Length::Fill, Length::Fill),
+            Space::new(Length::Fill, Length::Fill),
+        )
+        .width(300.0)
+        .on_close(Message::Close)
+    }
+
+    /// Panel offset after the drawer has been open for `elapsed`.
+    fn offset_after(drawer: Drawer<'_, Message, (), ()>, elapsed: Duration) -> f32 {
+        let mut panel = drawer.panel;
+        let mut tree = Tree::new(&panel as &dyn Widget<Message, (), ()>);
+        let node = panel.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+        let opened = Instant::now();
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for now in [opened, opened + elapsed] {
+            let _ = panel.on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(800.0, 600.0)),
+            );
+        }
+
+        panel.slide_offset(tree.state.downcast_ref::<State>().slide.progress)
+    }
+
+    #[test]
+    fn slide_follows_configured_easing() {
+        let motion = Motion::new(200, Easing::Linear);
+        let quarter = Duration::from_millis(50);
+        let half = Duration::from_millis(100);
+
+        // Linear easing moves the 300px panel at a constant rate.
+        assert!((offset_after(drawer().motion(motion), quarter) + 225.0).abs() < 0.01);
+        assert!((offset_after(drawer().motion(motion), half) + 150.0).abs() < 0.01);
+
+        // The default standard curve has covered more than half by then.
+        let eased = offset_after(drawer(), half);
+        assert!(eased > -150.0 && eased < 0.0);
+        assert!(offset_after(drawer(), Duration::from_millis(200)).abs() < f32::EPSILON);
+
+        // A right drawer slides in from the right edge.
+        let right = drawer()
+            .position(DrawerPosition::Right)
+            .easing(Easing::Linear);
+        assert!((offset_after(right, half) - 150.0).abs() < 0.01);
+    }
+
+    /// Press on the panel at `from`, drag to `to` and release.
+    fn swipe(panel: &mut Panel<'_, Message, (), ()>, from: f32, to: f32) -> Vec<Message> {
+        let mut tree = Tree::new(&*panel as &dyn Widget<Message, (), ()>);
+        let node = panel.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for (event, x) in [
+            (mouse::Event::ButtonPressed(mouse::Button::Left), from),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::new(to, 300.0),
+                },
+                to,
+            ),
+            (mouse::Event::ButtonReleased(mouse::Button::Left), to),
+        ] {
+            let _ = panel.on_event(
+                &mut tree,
+                Event::Mouse(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(x, 300.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(800.0, 600.0)),
+            );
+        }
+
+        let swipe = &tree.state.downcast_ref::<State>().swipe;
+        assert!(swipe.origin.is_none() && swipe.delta == 0.0);
+
+        messages
+    }
+
+    #[test]
+    fn swipe_past_threshold_closes() {
+        let mut left = drawer().swipe_threshold(80.0).panel;
+        assert!(swipe(&mut left, 250.0, 200.0).is_empty());
+        assert!(swipe(&mut left, 250.0, 350.0).is_empty());
+        assert_eq!(swipe(&mut left, 250.0, 150.0), vec![Message::Close]);
+
+        // A right drawer (x 500..800) closes towards the right edge.
+        let mut right = drawer()
+            .position(DrawerPosition::Right)
+            .swipe_threshold(80.0)
+            .panel;
+        assert!(swipe(&mut right, 600.0, 500.0).is_empty());
+        assert_eq!(swipe(&mut right, 600.0, 700.0), vec![Message::Close]);
+
+        // Without a threshold, swiping does nothing.
+        assert!(swipe(&mut drawer().panel, 250.0, 50.0).is_empty());
+    }
+
+    #[test]
+    fn backdrop_click_closes() {
+        assert_eq!(
+            click(&mut drawer().panel, Point::new(600.0, 300.0)),
+            vec![Message::Close]
+        );
+    }
+
+    #[test]
+    fn panel_click_does_not_close() {
+        assert!(click(&mut drawer().panel, Point::new(100.0, 300.0)).is_empty());
+    }
+
+    #[test]
+    fn no_scrim_does_not_close() {
+        assert!(click(&mut drawer().no_scrim().panel, Point::new(600.0, 300.0)).is_empty());
+    }
+
+    #[test]
+    fn safe_area_insets_panel() {
+        let mut panel = drawer().safe_area(Padding::ZERO.top(32)).panel;
+        let mut tree = Tree::new(&panel as &dyn Widget<Message, (), ()>);
+        let node = panel.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+
+        let bounds = node.children()[0].bounds();
+        assert!((bounds.y - 32.0).abs() < f32::EPSILON);
+        assert!((bounds.height - 568.0).abs() < f32::EPSILON);
+
+        // The title bar region above the panel is backdrop, not panel.
+        assert_eq!(
+            click(&mut panel, Point::new(100.0, 16.0)),
+            vec![Message::Close]
+        );
+    }
+
+    #[test]
+    fn content_scrolls_between_pinned_header_and_footer() {
+        let items = (0..30).map(|_| Space::new(Length::Fill, Length::Fixed(40.0)).into());
+        let drawer: Drawer<'_, Message, iced::Theme, ()> = Drawer::new(
+            Space::new(Length::Fill, Length::Fill),
+            iced::widget::Column::with_children(items),
+        )
+        .header(Space::new(Length::Fill, Length::Fixed(64.0)))
+        .footer(Space::new(Length::Fill, Length::Fixed(48.0)));
+        let mut panel = drawer.panel;
+
+        let mut tree = Tree::new(&panel as &dyn Widget<Message, iced::Theme, ()>);
+        let node = panel.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+        let parts: Vec<_> = node.children()[0]
+            .children()
+            .iter()
+            .map(Node::bounds)
+            .collect();
+
+        assert_eq!(
+            parts[0],
+            Rectangle::new(Point::ORIGIN, Size::new(300.0, 64.0))
+        );
+        assert_eq!(
+            parts[1],
+            Rectangle::new(Point::new(0.0, 64.0), Size::new(300.0, 488.0))
+        );
+        assert_eq!(
+            parts[2],
+            Rectangle::new(Point::new(0.0, 552.0), Size::new(300.0, 48.0))
+        );
+
+        // The 1200px list scrolls inside the 488px gap.
+        let mut messages = Vec::new();
+        let status = panel.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -200.0 },
+            }),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::new(100.0, 300.0)),
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(800.0, 600.0)),
+        );
+        assert_eq!(status, event::Status::Captured);
+    }
+
+    /// Press at `at` on a drawer stacked over a base that reports presses.
+    fn press_stacked(scrim: bool, at: Point) -> Vec<Message> {
+        let base = iced::widget::mouse_area(Space::new(Length::Fill, Length::Fill))
+            .on_press(Message::Base);
+        let drawer =
+            Drawer::new(base, Space::new(Length::Fill, Length::Fill)).on_close(Message::Close);
+        let mut element: Element<'_, Message, (), ()> = if scrim {
+            drawer.into()
+        } else {
+            drawer.no_scrim().into()
+        };
+
+        let mut tree = Tree::new(&element);
+        let limits = Limits::new(Size::ZERO, Size::new(800.0, 600.0));
+        let node = element.as_widget().layout(&mut tree, &(), &limits);
+        let mut messages = Vec::new();
+        let _ = element.as_widget_mut().on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(at),
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(800.0, 600.0)),
+        );
+
+        messages
+    }
+
+    #[test]
+    fn panel_is_layered_over_the_base() {
+        let (outside, on_panel) = (Point::new(600.0, 300.0), Point::new(100.0, 300.0));
+
+        assert_eq!(press_stacked(true, outside), vec![Message::Close]);
+        assert!(press_stacked(true, on_panel).is_empty());
+
+        // Without a scrim the base beside the panel stays interactive.
+        assert_eq!(press_stacked(false, outside), vec![Message::Base]);
+        assert!(press_stacked(false, on_panel).is_empty());
+    }
+
+    #[test]
+    fn close_task_focuses_the_opener() {
+        use iced::widget::{column, text_input};
+
+        let page: Element<'_, (), iced::Theme, ()> = column![
+            text_input("Search", "").id(text_input::Id::new("search")),
+            text_input("Name", "").id(text_input::Id::new("open-menu")),
+        ]
+        .into();
+        let mut tree = Tree::new(&page);
+        let node = page.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+        let mut run = |operation: &mut dyn Operation| {
+            page.as_widget()
+                .operate(&mut tree, Layout::new(&node), &(), operation);
+            [0, 1].map(|index| {
+                tree.children[index]
+                    .state
+                    .downcast_ref::<text_input::State<()>>()
+                    .is_focused()
+            })
+        };
+
+        assert_eq!(
+            run(&mut focusable::focus(widget::Id::new("search"))),
+            [true, false]
+        );
+
+        let mut restore = drawer()
+            .restore_focus_to(widget::Id::new("open-menu"))
+            .restore_focus_operation()
+            .expect("restore focus operation");
+        assert_eq!(run(&mut restore), [false, true]);
+        assert!(drawer().restore_focus_operation::<()>().is_none());
     }
 }
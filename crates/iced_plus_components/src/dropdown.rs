@@ -0,0 +1,817 @@
+//! Shared sizing and scrolling behaviour for dropdown overlays.
+//!
+//! [`Select`](crate::select::Select), [`Menu`](crate::menu::Menu) and text
+//! input suggestions cap their option lists at [`DEFAULT_MAX_HEIGHT`] and
+//! scroll once the options overflow it.
+//!
+//! The lists are virtualized: only the rows in view are drawn, and custom
+//! option views are built for those rows alone, so a select over thousands
+//! of options, such as a timezone picker, stays fast. Keyboard navigation
+//! and selection still cover the full list.
+
+use std::ops::Range;
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::text;
+use iced::advanced::widget::Tree;
+use iced::advanced::{Clipboard, Shell};
+use iced::keyboard::{self, key};
+use iced::theme::palette::Extended;
+use iced::time::Instant;
+use iced::{event, mouse, window, Border, Color, Element, Event, Point, Rectangle, Size};
+use iced_plus_theme::{scaled, AppTheme};
+
+/// Default maximum height of a dropdown overlay, in pixels.
+pub const DEFAULT_MAX_HEIGHT: f32 = 300.0;
+
+/// Default height of a single dropdown option, in pixels.
+pub const DEFAULT_OPTION_HEIGHT: f32 = 32.0;
+
+/// Sizing of a dropdown option list.
+///
+/// All options share the same row height, so the content height, visible
+/// range and scroll offsets can be computed without laying out the options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropdownMetrics {
+    /// Height of a single option row.
+    pub option_height: f32,
+    /// Height at which the list stops growing and starts scrolling.
+    pub max_height: f32,
+}
+
+impl DropdownMetrics {
+    /// Default metrics: 32px rows capped at 300px.
+    pub const DEFAULT: Self = Self::new(DEFAULT_OPTION_HEIGHT, DEFAULT_MAX_HEIGHT);
+
+    /// Create metrics from a row height and a maximum height.
+    #[must_use]
+    pub const fn new(option_height: f32, max_height: f32) -> Self {
+        Self {
+            option_height,
+            max_height,
+        }
+    }
+
+    /// These metrics multiplied by the current
+    /// [UI scale](iced_plus_theme::set_ui_scale).
+    #[must_use]
+    pub fn scaled(self) -> Self {
+        Self::new(scaled(self.option_height), scaled(self.max_height))
+    }
+
+    /// Height of all `count` options laid out end to end.
+    #[must_use]
+    // Option counts stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn content_height(&self, count: usize) -> f32 {
+        self.option_height * count as f32
+    }
+
+    /// Height of the visible list for `count` options.
+    #[must_use]
+    pub fn viewport_height(&self, count: usize) -> f32 {
+        self.content_height(count).min(self.max_height)
+    }
+
+    /// Whether `count` options overflow the maximum height.
+    #[must_use]
+    pub fn is_scrollable(&self, count: usize) -> bool {
+        self.content_height(count) > self.max_height
+    }
+
+    /// Largest valid scroll offset for `count` options.
+    #[must_use]
+    pub fn max_offset(&self, count: usize) -> f32 {
+        (self.content_height(count) - self.viewport_height(count)).max(0.0)
+    }
+
+    /// Scroll offset that keeps `index` fully visible, moving as little as
+    /// possible from `offset`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn reveal(&self, index: usize, offset: f32, count: usize) -> f32 {
+        let top = self.option_height * index as f32;
+        let bottom = top + self.option_height;
+        let viewport = self.viewport_height(count);
+
+        let offset = if top < offset {
+            top
+        } else if bottom > offset + viewport {
+            bottom - viewport
+        } else {
+            offset
+        };
+
+        offset.clamp(0.0, self.max_offset(count))
+    }
+
+    /// Indices of the options intersecting the viewport at `offset`.
+    #[must_use]
+    // Offsets are never negative, and the indices are clamped to `count`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn visible_range(&self, offset: f32, count: usize) -> Range<usize> {
+        if self.option_height <= 0.0 {
+            return 0..0;
+        }
+
+        let start = (offset / self.option_height).floor() as usize;
+        let end = ((offset + self.viewport_height(count)) / self.option_height).ceil() as usize;
+
+        start.min(count)..end.min(count)
+    }
+
+    /// Index of the option at `y`, measured from the top of the viewport.
+    #[must_use]
+    // `y` and the offset are never negative, and the index is checked
+    // against `count`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn index_at(&self, y: f32, offset: f32, count: usize) -> Option<usize> {
+        if y < 0.0 || self.option_height <= 0.0 {
+            return None;
+        }
+
+        let index = ((y + offset) / self.option_height) as usize;
+        (index < count).then_some(index)
+    }
+}
+
+impl Default for DropdownMetrics {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Themes that can style a dropdown overlay.
+pub(crate) trait DropdownTheme {
+    /// Palette used to draw the option list.
+    fn dropdown_palette(&self) -> Extended;
+}
+
+impl DropdownTheme for iced::Theme {
+    fn dropdown_palette(&self) -> Extended {
+        *self.extended_palette()
+    }
+}
+
+impl DropdownTheme for AppTheme<'_> {
+    fn dropdown_palette(&self) -> Extended {
+        self.extended_palette()
+    }
+}
+
+/// What a dropdown list shows in place of (or as) its options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListStatus<'b> {
+    /// The options themselves.
+    Options,
+    /// A spinner row while options are being fetched.
+    Loading,
+    /// A single row explaining that there are no options.
+    Empty(&'b str),
+}
+
+impl<'b> ListStatus<'b> {
+    /// Text shown in the status row, if any.
+    pub(crate) const fn message(self) -> Option<&'b str> {
+        match self {
+            Self::Options => None,
+            Self::Loading => Some("Loading…"),
+            Self::Empty(message) => Some(message),
+        }
+    }
+
+    /// Number of rows the list occupies for `count` options.
+    pub(crate) const fn rows(self, count: usize) -> usize {
+        match self {
+            Self::Options => count,
+            Self::Loading | Self::Empty(_) => 1,
+        }
+    }
+}
+
+/// Open/highlight/scroll state of a dropdown list.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ListState {
+    pub(crate) is_open: bool,
+    pub(crate) highlighted: Option<usize>,
+    pub(crate) offset: f32,
+    /// When the loading spinner started animating.
+    pub(crate) spinner_start: Option<Instant>,
+    /// Loading spinner cycle progress, from 0.0 to 1.0.
+    pub(crate) spinner: f32,
+}
+
+impl ListState {
+    /// Open the list with `selected` highlighted and scrolled into view.
+    pub(crate) fn open(&mut self, selected: Option<usize>, metrics: DropdownMetrics, count: usize) {
+        self.is_open = true;
+        self.highlighted = selected;
+        self.offset = selected.map_or(0.0, |index| metrics.reveal(index, 0.0, count));
+    }
+
+    /// Close the list.
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.spinner_start = None;
+    }
+
+    /// Move the highlight by `delta` options, keeping it visible.
+    pub(crate) fn step(&mut self, delta: isize, metrics: DropdownMetrics, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let next = match self.highlighted {
+            Some(current) => current.saturating_add_signed(delta).min(count - 1),
+            None if delta < 0 => count - 1,
+            None => 0,
+        };
+
+        self.highlighted = Some(next);
+        self.offset = metrics.reveal(next, self.offset, count);
+    }
+
+    /// Scroll by `delta` pixels, clamped to the content.
+    pub(crate) fn scroll(&mut self, delta: f32, metrics: DropdownMetrics, count: usize) {
+        self.offset = (self.offset + delta).clamp(0.0, metrics.max_offset(count));
+    }
+}
+
+/// Custom, display-only view of a dropdown option.
+pub(crate) type OptionView<'a, T, Message, Theme, Renderer> =
+    dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a;
+
+/// Lay out `element` at the left of `bounds`, centered vertically, and draw
+/// it with `text_color`.
+///
+/// The element is built for a single frame, so it gets a fresh tree and
+/// never sees events.
+pub(crate) fn draw_view<Message, Theme, Renderer>(
+    element: &Element<'_, Message, Theme, Renderer>,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    text_color: Color,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+) where
+    Renderer: renderer::Renderer,
+{
+    let mut tree = Tree::new(element);
+    let node =
+        element
+            .as_widget()
+            .layout(&mut tree, renderer, &Limits::new(Size::ZERO, bounds.size()));
+    let y = bounds.y + (bounds.height - node.size().height) / 2.0;
+    let node = node.move_to(Point::new(bounds.x, y));
+
+    element.as_widget().draw(
+        &tree,
+        renderer,
+        theme,
+        &renderer::Style { text_color },
+        Layout::new(&node),
+        mouse::Cursor::Unavailable,
+        viewport,
+    );
+}
+
+/// Overlay listing the options of a dropdown below (or above) its trigger.
+pub(crate) struct ListOverlay<'b, 'v, T, Message, Theme, Renderer> {
+    pub(crate) state: &'b mut ListState,
+    pub(crate) options: &'b [T],
+    pub(crate) selected: Option<usize>,
+    pub(crate) on_select: &'b dyn Fn(T) -> Message,
+    /// Renders each option instead of its `to_string`.
+    pub(crate) view_option: Option<&'b OptionView<'v, T, Message, Theme, Renderer>>,
+    pub(crate) anchor: Rectangle,
+    pub(crate) metrics: DropdownMetrics,
+    pub(crate) text_size: f32,
+    pub(crate) padding_x: f32,
+    pub(crate) status: ListStatus<'b>,
+    /// Action row pinned below the options, with the message it emits.
+    pub(crate) footer: Option<(&'b str, &'b dyn Fn() -> Message)>,
+}
+
+impl<T, Message, Theme, Renderer> ListOverlay<'_, '_, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+{
+    /// Number of rows the list occupies.
+    const fn rows(&self) -> usize {
+        self.status.rows(self.options.len())
+    }
+
+    /// Height of the footer row, if any.
+    fn footer_height(&self) -> f32 {
+        if self.footer.is_some() {
+            self.metrics.option_height
+        } else {
+            0.0
+        }
+    }
+
+    /// Split the overlay `bounds` into the option list and the footer row.
+    fn split(&self, bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let footer_height = self.footer_height();
+        let list = Rectangle {
+            height: bounds.height - footer_height,
+            ..bounds
+        };
+        let footer = Rectangle {
+            y: list.y + list.height,
+            height: footer_height,
+            ..bounds
+        };
+
+        (list, footer)
+    }
+
+    /// Draw the footer action row with a divider above it.
+    fn draw_footer(
+        &self,
+        renderer: &mut Renderer,
+        palette: &Extended,
+        bounds: Rectangle,
+        label: &str,
+        cursor: mouse::Cursor,
+    ) where
+        Renderer: text::Renderer<Font = iced::Font>,
+    {
+        if cursor.is_over(bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: iced::border::bottom(6.0),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                palette.primary.weak.color,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: 1.0,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            palette.background.strong.color,
+        );
+
+        renderer.fill_text(
+            text::Text {
+                content: label.to_string(),
+                bounds: Size::new(self.padding_x.mul_add(-2.0, bounds.width), bounds.height),
+                size: iced::Pixels(self.text_size),
+                line_height: text::LineHeight::default(),
+                font: iced::Font::default(),
+                horizontal_alignment: iced::alignment::Horizontal::Left,
+                vertical_alignment: iced::alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(bounds.x + self.padding_x, bounds.center_y()),
+            palette.primary.base.color,
+            bounds,
+        );
+    }
+
+    /// Emit the footer message and close the list if `event` clicks the
+    /// footer row.
+    fn click_footer(
+        &mut self,
+        event: &Event,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some((_, on_footer)) = self.footer else {
+            return false;
+        };
+        let clicked = *event == Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            && cursor.is_over(bounds);
+
+        if clicked {
+            shell.publish(on_footer());
+            self.state.close();
+        }
+
+        clicked
+    }
+
+    fn select(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(option) = self.options.get(index) {
+            shell.publish((self.on_select)(option.clone()));
+            self.state.close();
+        }
+    }
+
+    /// Draw the single loading or empty row.
+    fn draw_status(
+        &self,
+        renderer: &mut Renderer,
+        palette: &Extended,
+        bounds: Rectangle,
+        message: &str,
+    ) where
+        Renderer: text::Renderer<Font = iced::Font>,
+    {
+        let mut x = bounds.x + self.padding_x;
+
+        if self.status == ListStatus::Loading {
+            // Three dots pulsing in turn.
+            let dot = 6.0;
+            for i in 0..3_u8 {
+                let phase = (self.state.spinner - f32::from(i) / 3.0).rem_euclid(1.0);
+                let alpha = 0.7_f32.mul_add(1.0 - phase, 0.3);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: bounds.center_y() - dot / 2.0,
+                            width: dot,
+                            height: dot,
+                        },
+                        border: Border {
+                            radius: (dot / 2.0).into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color {
+                        a: alpha,
+                        ..palette.primary.base.color
+                    },
+                );
+                x += dot + 4.0;
+            }
+            x += 4.0;
+        }
+
+        renderer.fill_text(
+            text::Text {
+                content: message.to_string(),
+                bounds: Size::new(bounds.x + bounds.width - self.padding_x - x, bounds.height),
+                size: iced::Pixels(self.text_size),
+                line_height: text::LineHeight::default(),
+                font: iced::Font::default(),
+                horizontal_alignment: iced::alignment::Horizontal::Left,
+                vertical_alignment: iced::alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(x, bounds.center_y()),
+            palette.background.strong.color,
+            bounds,
+        );
+    }
+
+    /// Draw the option at `index`, scrolled into place within the list
+    /// `bounds`.
+    // Option indices stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
+    fn draw_option(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        palette: &Extended,
+        bounds: Rectangle,
+        index: usize,
+    ) where
+        Renderer: text::Renderer<Font = iced::Font>,
+    {
+        let row = Rectangle {
+            x: bounds.x,
+            y: self.metrics.option_height.mul_add(index as f32, bounds.y) - self.state.offset,
+            width: bounds.width,
+            height: self.metrics.option_height,
+        };
+
+        let text_color = if self.state.highlighted == Some(index) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: row,
+                    ..renderer::Quad::default()
+                },
+                palette.primary.weak.color,
+            );
+            palette.primary.weak.text
+        } else if self.selected == Some(index) {
+            palette.primary.base.color
+        } else {
+            palette.background.base.text
+        };
+
+        if let Some(view) = self.view_option {
+            let content = Rectangle {
+                x: row.x + self.padding_x,
+                width: self.padding_x.mul_add(-2.0, row.width),
+                ..row
+            };
+
+            draw_view(
+                &view(&self.options[index]),
+                renderer,
+                theme,
+                text_color,
+                content,
+                &bounds,
+            );
+            return;
+        }
+
+        renderer.fill_text(
+            text::Text {
+                content: self.options[index].to_string(),
+                bounds: Size::new(self.padding_x.mul_add(-2.0, row.width), row.height),
+                size: iced::Pixels(self.text_size),
+                line_height: text::LineHeight::default(),
+                font: iced::Font::default(),
+                horizontal_alignment: iced::alignment::Horizontal::Left,
+                vertical_alignment: iced::alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(row.x + self.padding_x, row.center_y()),
+            text_color,
+            bounds,
+        );
+    }
+
+    /// Draw the scrollbar thumb along the right edge of the list `bounds`.
+    fn draw_scrollbar(
+        &self,
+        renderer: &mut Renderer,
+        palette: &Extended,
+        bounds: Rectangle,
+        count: usize,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        let content = self.metrics.content_height(count);
+        let thumb_height = (bounds.height * bounds.height / content).max(16.0);
+        let progress = self.state.offset / self.metrics.max_offset(count);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - 6.0,
+                    y: (bounds.height - thumb_height).mul_add(progress, bounds.y),
+                    width: 4.0,
+                    height: thumb_height,
+                },
+                border: Border {
+                    radius: 2.0.into(),
+                    ..Default::default()
+                },
+                ..renderer::Quad::default()
+            },
+            palette.background.strong.color,
+        );
+    }
+
+    /// Handle events while a status row is shown instead of options.
+    fn on_status_event(
+        &mut self,
+        event: &Event,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Window(window::Event::RedrawRequested(now))
+                if self.status == ListStatus::Loading =>
+            {
+                let start = *self.state.spinner_start.get_or_insert(*now);
+                self.state.spinner = now.duration_since(start).as_secs_f32().fract();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(bounds) =>
+            {
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                self.state.close();
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+}
+
+impl<T, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for ListOverlay<'_, '_, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: DropdownTheme,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let count = self.rows();
+        let space_below = bounds.height - (self.anchor.y + self.anchor.height);
+        let space_above = self.anchor.y;
+        let footer_height = self.footer_height();
+        let wanted = self.metrics.viewport_height(count) + footer_height;
+
+        let below = wanted <= space_below || space_below >= space_above;
+        let space = if below { space_below } else { space_above };
+
+        // Never let the list run off screen, even below the configured cap.
+        // The footer stays pinned, so only the options give up room.
+        self.metrics.max_height = self
+            .metrics
+            .max_height
+            .min((space - footer_height).max(0.0));
+        self.state.offset = self.state.offset.min(self.metrics.max_offset(count));
+
+        let height = self.metrics.viewport_height(count) + footer_height;
+        let y = if below {
+            self.anchor.y + self.anchor.height
+        } else {
+            self.anchor.y - height
+        };
+
+        Node::new(Size::new(self.anchor.width, height)).move_to(Point::new(self.anchor.x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let outer = layout.bounds();
+        let palette = theme.dropdown_palette();
+        let count = self.options.len();
+        let (bounds, footer) = self.split(outer);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: outer,
+                border: Border {
+                    color: palette.background.strong.color,
+                    width: 1.0,
+                    radius: 6.0.into(),
+                },
+                shadow: iced::Shadow {
+                    color: Color::from_rgba(0.0, 0.0, 0.0, 0.15),
+                    offset: iced::Vector::new(0.0, 4.0),
+                    blur_radius: 12.0,
+                },
+            },
+            palette.background.base.color,
+        );
+
+        if let Some((label, _)) = self.footer {
+            self.draw_footer(renderer, &palette, footer, label, cursor);
+        }
+
+        if let Some(message) = self.status.message() {
+            self.draw_status(renderer, &palette, bounds, message);
+            return;
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            for index in self.metrics.visible_range(self.state.offset, count) {
+                self.draw_option(renderer, theme, &palette, bounds, index);
+            }
+
+            if self.metrics.is_scrollable(count) {
+                self.draw_scrollbar(renderer, &palette, bounds, count);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let (bounds, footer) = self.split(layout.bounds());
+        let count = self.options.len();
+
+        if self.click_footer(&event, cursor, footer, shell) {
+            return event::Status::Captured;
+        }
+
+        if self.status != ListStatus::Options {
+            return self.on_status_event(&event, cursor, bounds, shell);
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(position) = cursor.position_in(bounds) {
+                    self.state.highlighted =
+                        self.metrics.index_at(position.y, self.state.offset, count);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_in(bounds) {
+                    if let Some(index) = self.metrics.index_at(position.y, self.state.offset, count)
+                    {
+                        self.select(index, shell);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * self.metrics.option_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => -y,
+                };
+                self.state.scroll(delta, self.metrics, count);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key {
+                keyboard::Key::Named(key::Named::ArrowDown) => {
+                    self.state.step(1, self.metrics, count);
+                    return event::Status::Captured;
+                }
+                keyboard::Key::Named(key::Named::ArrowUp) => {
+                    self.state.step(-1, self.metrics, count);
+                    return event::Status::Captured;
+                }
+                keyboard::Key::Named(key::Named::Enter) => {
+                    // Without a highlight, let Enter reach the trigger
+                    // (e.g. to submit a text input).
+                    if let Some(index) = self.state.highlighted {
+                        self.select(index, shell);
+                        return event::Status::Captured;
+                    }
+                }
+                keyboard::Key::Named(key::Named::Escape) => {
+                    self.state.close();
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn many_options_overflow_max_height() {
+        let metrics = DropdownMetrics::DEFAULT;
+
+        assert!(metrics.content_height(50) > metrics.max_height);
+        assert!(metrics.is_scrollable(50));
+        assert!((metrics.viewport_height(50) - DEFAULT_MAX_HEIGHT).abs() < f32::EPSILON);
+        assert!(!metrics.is_scrollable(3));
+    }
+
+    #[test]
+    fn highlighted_option_stays_visible() {
+        let metrics = DropdownMetrics::DEFAULT;
+        let mut state = ListState::default();
+        state.open(None, metrics, 50);
+
+        for _ in 0..40 {
+            state.step(1, metrics, 50);
+            let index = state.highlighted.unwrap();
+            assert!(metrics.visible_range(state.offset, 50).contains(&index));
+        }
+
+        state.open(Some(45), metrics, 50);
+        assert!(metrics.visible_range(state.offset, 50).contains(&45));
+    }
+}
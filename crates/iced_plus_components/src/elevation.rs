@@ -0,0 +1,403 @@
+//! Surfaces that raise on hover.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::time::Instant;
+use iced::{
+    event, mouse, window, Border, Color, Element, Event, Length, Rectangle, Shadow, Size, Theme,
+    Vector,
+};
+use iced_plus_tokens::{reduce_motion, Motion, MotionScale};
+
+use crate::card::Elevation;
+
+/// A surface that animates from a rest elevation to a hover elevation
+/// while the cursor is over it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// InteractiveElevation::new(card_content)
+///     .rest(Elevation::Low)
+///     .hover(Elevation::High)
+///     .motion(theme.motion().fast)
+/// ```
+pub struct InteractiveElevation<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    rest: Elevation,
+    hover: Elevation,
+    motion: Motion,
+    reduce_motion: bool,
+    radius: f32,
+}
+
+impl<'a, Message, Renderer> InteractiveElevation<'a, Message, Renderer> {
+    /// Wrap content in a surface raising from low to high elevation.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            rest: Elevation::Low,
+            hover: Elevation::High,
+            motion: MotionScale::default().normal,
+            reduce_motion: reduce_motion(),
+            radius: 8.0,
+        }
+    }
+
+    /// Set the elevation at rest.
+    #[must_use]
+    pub const fn rest(mut self, elevation: Elevation) -> Self {
+        self.rest = elevation;
+        self
+    }
+
+    /// Set the elevation while hovered.
+    #[must_use]
+    pub const fn hover(mut self, elevation: Elevation) -> Self {
+        self.hover = elevation;
+        self
+    }
+
+    /// Set the transition timing.
+    ///
+    /// The elevation changes at once if the surface was built while
+    /// [`reduce_motion`] was on.
+    #[must_use]
+    pub const fn motion(mut self, motion: Motion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Set the border radius.
+    #[must_use]
+    pub const fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// The shadow for a transition `state`.
+    fn shadow(&self, state: &Hover) -> Shadow {
+        let t = self.motion.easing.apply(state.progress);
+        let (from, to) = (self.rest.shadow(), self.hover.shadow());
+
+        Shadow {
+            color: Color {
+                a: (to.color.a - from.color.a).mul_add(t, from.color.a),
+                ..to.color
+            },
+            offset: from.offset + (to.offset - from.offset) * t,
+            blur_radius: (to.blur_radius - from.blur_radius).mul_add(t, from.blur_radius),
+        }
+    }
+}
+
+/// Hover transition progress, from 0.0 at rest to 1.0 fully raised.
+#[derive(Debug, Default)]
+struct Hover {
+    hovered: bool,
+    progress: f32,
+    last_frame: Option<Instant>,
+}
+
+impl Hover {
+    fn is_settled(&self) -> bool {
+        let target = if self.hovered { 1.0 } else { 0.0 };
+        (self.progress - target).abs() < f32::EPSILON
+    }
+
+    /// Move towards the target by the time since the last frame.
+    fn advance(&mut self, now: Instant, motion: Motion) {
+        let elapsed = self
+            .last_frame
+            .map_or(0.0, |last| now.duration_since(last).as_secs_f32());
+        let step = if motion.duration_ms == 0 {
+            1.0
+        } else {
+            elapsed / motion.duration_seconds()
+        };
+
+        self.progress = if self.hovered {
+            (self.progress + step).min(1.0)
+        } else {
+            (self.progress - step).max(0.0)
+        };
+        self.last_frame = (!self.is_settled()).then_some(now);
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer>
+    for InteractiveElevation<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Hover>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Hover::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<Hover>();
+
+        match event {
+            Event::Mouse(_) => {
+                let hovered = cursor.is_over(layout.bounds());
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) if !state.is_settled() => {
+                state.advance(now, self.motion.reduced(self.reduce_motion));
+                if !state.is_settled() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let palette = theme.extended_palette();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: Border {
+                    radius: self.radius.into(),
+                    width: 1.0,
+                    color: palette.background.weak.color,
+                },
+                shadow: self.shadow(tree.state.downcast_ref::<Hover>()),
+            },
+            palette.background.base.color,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<InteractiveElevation<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(elevation: InteractiveElevation<'a, Message, Renderer>) -> Self {
+        Element::new(elevation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+    use iced::Point;
+    use std::time::Duration;
+
+    struct Harness {
+        widget: InteractiveElevation<'static, (), ()>,
+        tree: Tree,
+        node: Node,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            let widget = InteractiveElevation::new(Space::new(100.0, 100.0));
+            let mut tree = Tree::new(&widget as &dyn Widget<(), Theme, ()>);
+            let node = widget.layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+            );
+
+            Self { widget, tree, node }
+        }
+
+        fn send(&mut self, event: Event, cursor: Point) {
+            let mut messages = Vec::new();
+            let _ = self.widget.on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(200.0, 200.0)),
+            );
+        }
+
+        fn move_to(&mut self, position: Point) {
+            self.send(
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                position,
+            );
+        }
+
+        fn frame(&mut self, now: Instant) {
+            self.send(
+                Event::Window(window::Event::RedrawRequested(now)),
+                Point::ORIGIN,
+            );
+        }
+
+        fn blur(&self) -> f32 {
+            self.widget
+                .shadow(self.tree.state.downcast_ref::<Hover>())
+                .blur_radius
+        }
+    }
+
+    #[test]
+    fn elevation_rises_while_hovered() {
+        let mut harness = Harness::new();
+        let rest = harness.blur();
+        let start = Instant::now();
+
+        harness.move_to(Point::new(50.0, 50.0));
+        harness.frame(start);
+        harness.frame(start + Duration::from_millis(100));
+        let midway = harness.blur();
+        assert!(midway > rest);
+
+        harness.frame(start + Duration::from_secs(1));
+        assert!(harness.blur() > midway);
+        assert!((harness.blur() - Elevation::High.shadow().blur_radius).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn reduced_motion_raises_at_once() {
+        let mut harness = iced_plus_tokens::with_reduce_motion(true, Harness::new);
+
+        harness.move_to(Point::new(50.0, 50.0));
+        harness.frame(Instant::now());
+
+        assert!((harness.blur() - Elevation::High.shadow().blur_radius).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn elevation_returns_to_rest_on_leave() {
+        let mut harness = Harness::new();
+        let rest = harness.blur();
+        let start = Instant::now();
+
+        harness.move_to(Point::new(50.0, 50.0));
+        harness.frame(start);
+        harness.frame(start + Duration::from_secs(1));
+        assert!(harness.blur() > rest);
+
+        harness.move_to(Point::new(150.0, 150.0));
+        harness.frame(start + Duration::from_secs(2));
+        harness.frame(start + Duration::from_secs(3));
+        assert!((harness.blur() - rest).abs() < f32::EPSILON);
+    }
+}
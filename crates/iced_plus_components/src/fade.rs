@@ -0,0 +1,368 @@
+//! Crossfading between two elements.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::{event, mouse, Color, Element, Event, Length, Rectangle, Size, Theme, Vector};
+
+/// Blend from one element to another as `progress` goes from 0.0 to 1.0.
+///
+/// Typical uses are skeleton → content and image placeholder → image
+/// transitions, driven by an animation progress the app owns.
+///
+/// iced cannot draw an element translucently, so `from` fades out behind a
+/// veil of the theme background and `to` fades in through its text color.
+/// Events go to `to` as soon as it starts fading in.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// crossfade(
+///     Skeleton::rounded().aspect(16.0, 9.0),
+///     Card::new(content),
+///     self.reveal_progress,
+/// )
+/// ```
+pub fn crossfade<'a, Message, Renderer>(
+    from: impl Into<Element<'a, Message, Theme, Renderer>>,
+    to: impl Into<Element<'a, Message, Theme, Renderer>>,
+    progress: f32,
+) -> Crossfade<'a, Message, Renderer> {
+    Crossfade {
+        from: from.into(),
+        to: to.into(),
+        progress: progress.clamp(0.0, 1.0),
+    }
+}
+
+/// Two elements crossfading in the same bounds; see [`crossfade`].
+pub struct Crossfade<'a, Message, Renderer = iced::Renderer> {
+    from: Element<'a, Message, Theme, Renderer>,
+    to: Element<'a, Message, Theme, Renderer>,
+    progress: f32,
+}
+
+impl<Message, Renderer> Crossfade<'_, Message, Renderer> {
+    /// Opacity of `from` and `to` at the current progress.
+    #[must_use]
+    pub fn opacities(&self) -> (f32, f32) {
+        (1.0 - self.progress, self.progress)
+    }
+
+    /// The element receiving events: `to` once it has started fading in.
+    fn active(&self) -> usize {
+        usize::from(self.progress > 0.0)
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Crossfade<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.to.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.to.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.from), Tree::new(&self.to)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.from, &self.to]);
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let to = self
+            .to
+            .as_widget()
+            .layout(&mut tree.children[1], renderer, limits);
+
+        // `from` takes the final size, so nothing shifts when it disappears.
+        let from = self.from.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, to.size()),
+        );
+
+        Node::with_children(to.size(), vec![from, to])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let index = self.active();
+        let child = [&self.from, &self.to][index];
+
+        child.as_widget().operate(
+            &mut tree.children[index],
+            layout
+                .children()
+                .nth(index)
+                .expect("crossfade child layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let index = self.active();
+        let child = if index == 0 {
+            &mut self.from
+        } else {
+            &mut self.to
+        };
+
+        child.as_widget_mut().on_event(
+            &mut tree.children[index],
+            event,
+            layout
+                .children()
+                .nth(index)
+                .expect("crossfade child layout"),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let index = self.active();
+
+        [&self.from, &self.to][index].as_widget().mouse_interaction(
+            &tree.children[index],
+            layout
+                .children()
+                .nth(index)
+                .expect("crossfade child layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let (from_opacity, to_opacity) = self.opacities();
+        let mut children = layout.children();
+        let (from_layout, to_layout) = (
+            children.next().expect("crossfade child layout"),
+            children.next().expect("crossfade child layout"),
+        );
+
+        if from_opacity > 0.0 {
+            self.from.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                from_layout,
+                cursor,
+                viewport,
+            );
+
+            if to_opacity > 0.0 {
+                renderer.with_layer(layout.bounds(), |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: from_layout.bounds(),
+                            ..renderer::Quad::default()
+                        },
+                        Color {
+                            a: to_opacity,
+                            ..theme.palette().background
+                        },
+                    );
+                });
+            }
+        }
+
+        if to_opacity > 0.0 {
+            let style = renderer::Style {
+                text_color: Color {
+                    a: style.text_color.a * to_opacity,
+                    ..style.text_color
+                },
+            };
+
+            renderer.with_layer(layout.bounds(), |renderer| {
+                self.to.as_widget().draw(
+                    &tree.children[1],
+                    renderer,
+                    theme,
+                    &style,
+                    to_layout,
+                    cursor,
+                    viewport,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let index = self.active();
+        let child = if index == 0 {
+            &mut self.from
+        } else {
+            &mut self.to
+        };
+
+        child.as_widget_mut().overlay(
+            &mut tree.children[index],
+            layout.children().nth(index)?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Crossfade<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(crossfade: Crossfade<'a, Message, Renderer>) -> Self {
+        Element::new(crossfade)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+    use iced::advanced::renderer::Quad;
+    use iced::advanced::Renderer as _;
+    use iced::Point;
+
+    /// A block in a fixed color, or the inherited text color if `None`.
+    struct Swatch(Option<Color>);
+
+    impl<Message> Widget<Message, Theme, Recorder> for Swatch {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fixed(10.0), Length::Fixed(10.0))
+        }
+
+        fn layout(&self, _tree: &mut Tree, _renderer: &Recorder, limits: &Limits) -> Node {
+            Node::new(limits.resolve(10.0, 10.0, Size::ZERO))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            renderer: &mut Recorder,
+            _theme: &Theme,
+            style: &renderer::Style,
+            layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+            renderer.fill_quad(
+                Quad {
+                    bounds: layout.bounds(),
+                    ..Quad::default()
+                },
+                self.0.unwrap_or(style.text_color),
+            );
+        }
+    }
+
+    const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+
+    /// Colors drawn by a red swatch crossfading into a text-colored one.
+    fn draw_at(progress: f32) -> Vec<Color> {
+        let fade: Crossfade<'_, (), Recorder> = crossfade(
+            Element::new(Swatch(Some(RED))),
+            Element::new(Swatch(None)),
+            progress,
+        );
+        let mut tree = Tree::new(&fade as &dyn Widget<(), Theme, Recorder>);
+        let mut recorder = Recorder::default();
+        let node = fade.layout(
+            &mut tree,
+            &recorder,
+            &Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        fade.draw(
+            &tree,
+            &mut recorder,
+            &Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &Rectangle::with_size(Size::new(100.0, 100.0)),
+        );
+
+        recorder.colors()
+    }
+
+    #[test]
+    fn endpoints_show_a_single_element() {
+        assert_eq!(draw_at(0.0), vec![RED]);
+        assert_eq!(draw_at(1.0), vec![Color::BLACK]);
+    }
+
+    #[test]
+    fn midpoint_shows_both_partially() {
+        let background = Theme::Light.palette().background;
+
+        assert_eq!(
+            draw_at(0.5),
+            vec![
+                RED,
+                Color {
+                    a: 0.5,
+                    ..background
+                },
+                Color {
+                    a: 0.5,
+                    ..Color::BLACK
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,547 @@
+//! Focus rings that follow the last input modality.
+//!
+//! Like CSS `:focus-visible`, a focusable component shows its focus ring
+//! when it was focused from the keyboard, e.g. with Tab, but not after a
+//! click. Mouse users aren't distracted by rings on everything they click,
+//! while keyboard users always see where focus is.
+//!
+//! Every focusable component keeps a [`FocusVisible`], which watches the
+//! events it receives for the last [`Modality`]. Tab reaches every widget
+//! before the focus operation moves focus, so a component focused right
+//! after a key press knows it was focused from the keyboard.
+//!
+//! Buttons, checkboxes, switches and selects can take focus this way, and
+//! Space or Enter activates them while focused. Text inputs keep their own
+//! focus and show the ring while it came from the keyboard.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::widget::operation::Focusable;
+use iced::advanced::widget::{self, tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, renderer, Clipboard, Shell};
+use iced::keyboard::{self, key};
+use iced::{event, mouse, touch, Border, Color, Element, Event, Length, Rectangle, Size, Vector};
+use iced_plus_theme::AppTheme;
+use iced_plus_tokens::Shade;
+
+/// Width of a focus ring.
+const RING_WIDTH: f32 = 2.0;
+
+/// Gap between a component and its focus ring.
+const RING_OFFSET: f32 = 2.0;
+
+/// How the user last interacted with the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Modality {
+    /// Mouse or touch.
+    #[default]
+    Pointer,
+    /// Keyboard.
+    Keyboard,
+}
+
+impl Modality {
+    /// The modality of `event`, if it is a key or button press.
+    #[must_use]
+    pub const fn of(event: &Event) -> Option<Self> {
+        match event {
+            Event::Keyboard(iced::keyboard::Event::KeyPressed { .. }) => Some(Self::Keyboard),
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => Some(Self::Pointer),
+            _ => None,
+        }
+    }
+}
+
+/// Focus state of a component that shows a ring only for keyboard focus.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FocusVisible {
+    is_focused: bool,
+    modality: Modality,
+}
+
+impl FocusVisible {
+    /// Record the modality of `event`.
+    ///
+    /// Call this for every event the component receives, before handling it.
+    pub fn observe(&mut self, event: &Event) {
+        if let Some(modality) = Modality::of(event) {
+            self.modality = modality;
+        }
+    }
+
+    /// The last modality observed.
+    #[must_use]
+    pub const fn modality(&self) -> Modality {
+        self.modality
+    }
+
+    /// Whether the component is focused and the focus ring should show.
+    #[must_use]
+    pub const fn is_focus_visible(&self) -> bool {
+        self.is_focused && matches!(self.modality, Modality::Keyboard)
+    }
+}
+
+impl Focusable for FocusVisible {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Themes that color focus rings.
+pub trait FocusTheme {
+    /// Color of a focus ring.
+    fn focus_ring(&self) -> Color;
+}
+
+impl FocusTheme for AppTheme<'_> {
+    fn focus_ring(&self) -> Color {
+        self.primary(Shade::S500)
+    }
+}
+
+impl FocusTheme for iced::Theme {
+    fn focus_ring(&self) -> Color {
+        self.extended_palette().primary.base.color
+    }
+}
+
+/// Draw a focus ring around `bounds`.
+pub(crate) fn draw_focus_ring<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: bounds.expand(RING_OFFSET + RING_WIDTH),
+            border: Border {
+                color,
+                width: RING_WIDTH,
+                radius: (RING_OFFSET + RING_WIDTH).into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Make `control` focusable, with a ring while focused from the keyboard.
+///
+/// Space and Enter click the middle of the focused control, so a control
+/// that reacts to clicks works from the keyboard too.
+pub(crate) fn keyboard_focusable<'a, Message, Theme, Renderer>(
+    control: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: FocusTheme + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    Element::new(FocusRing {
+        content: control.into(),
+        activates: true,
+    })
+}
+
+/// Show a ring around `input`, which takes focus itself like a text input,
+/// while it is focused from the keyboard.
+pub(crate) fn with_focus_ring<'a, Message, Theme, Renderer>(
+    input: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: FocusTheme + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    Element::new(FocusRing {
+        content: input.into(),
+        activates: false,
+    })
+}
+
+/// Draws a focus ring around its content; see [`keyboard_focusable`] and
+/// [`with_focus_ring`].
+struct FocusRing<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    /// Whether the ring takes focus itself and clicks the content on Space
+    /// and Enter, rather than following the content's own focus.
+    activates: bool,
+}
+
+/// Finds whether any focusable widget is focused.
+struct HasFocus(bool);
+
+impl Operation for HasFocus {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&widget::Id>) {
+        self.0 |= state.is_focused();
+    }
+}
+
+/// Whether `event` activates a focused control.
+const fn activates(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+            ..
+        })
+    )
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusRing<'_, Message, Theme, Renderer>
+where
+    Theme: FocusTheme,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<FocusVisible>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(FocusVisible::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if self.activates {
+            operation.focusable(tree.state.downcast_mut::<FocusVisible>(), None);
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let focus = tree.state.downcast_mut::<FocusVisible>();
+        focus.observe(&event);
+
+        if self.activates {
+            if matches!(Modality::of(&event), Some(Modality::Pointer)) {
+                if cursor.is_over(bounds) {
+                    focus.focus();
+                } else {
+                    focus.unfocus();
+                }
+            }
+
+            if focus.is_focused() && activates(&event) {
+                let center = mouse::Cursor::Available(bounds.center());
+
+                for button in [
+                    mouse::Event::ButtonPressed(mouse::Button::Left),
+                    mouse::Event::ButtonReleased(mouse::Button::Left),
+                ] {
+                    let _ = self.content.as_widget_mut().on_event(
+                        &mut tree.children[0],
+                        Event::Mouse(button),
+                        layout,
+                        center,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if !self.activates {
+            let mut has_focus = HasFocus(false);
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut has_focus,
+            );
+
+            let focus = tree.state.downcast_mut::<FocusVisible>();
+            if has_focus.0 {
+                focus.focus();
+            } else {
+                focus.unfocus();
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if tree.state.downcast_ref::<FocusVisible>().is_focus_visible() {
+            draw_focus_ring(renderer, layout.bounds(), theme.focus_ring());
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::advanced::widget::operation::{self, focusable::focus_next};
+    use iced::keyboard;
+
+    fn key(named: keyboard::key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modified_key: keyboard::Key::Named(named),
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: None,
+        })
+    }
+
+    struct Harness<'a> {
+        element: Element<'a, &'static str, iced::Theme, ()>,
+        tree: Tree,
+        node: Node,
+    }
+
+    impl<'a> Harness<'a> {
+        fn new(element: Element<'a, &'static str, iced::Theme, ()>) -> Self {
+            let mut tree = Tree::new(&element);
+            let node = element.as_widget().layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+            );
+            Self {
+                element,
+                tree,
+                node,
+            }
+        }
+
+        fn send(&mut self, event: Event) -> Vec<&'static str> {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+            let cursor = mouse::Cursor::Available(self.node.bounds().center());
+            let _ = self.element.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.node),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(400.0, 100.0)),
+            );
+            messages
+        }
+
+        fn focus_next(&mut self) {
+            let mut operation: Box<dyn Operation> = Box::new(focus_next::<()>());
+
+            loop {
+                self.element.as_widget().operate(
+                    &mut self.tree,
+                    Layout::new(&self.node),
+                    &(),
+                    operation.as_mut(),
+                );
+
+                match operation.finish() {
+                    operation::Outcome::Chain(next) => operation = next,
+                    _ => break,
+                }
+            }
+        }
+
+        fn ring(&self) -> bool {
+            self.tree
+                .state
+                .downcast_ref::<FocusVisible>()
+                .is_focus_visible()
+        }
+    }
+
+    #[test]
+    fn tabbing_to_a_control_shows_the_ring_and_space_presses_it() {
+        let mut harness = Harness::new(keyboard_focusable(
+            iced::widget::button("Go").on_press("go"),
+        ));
+
+        assert!(harness.send(key(keyboard::key::Named::Space)).is_empty());
+        let _ = harness.send(key(keyboard::key::Named::Tab));
+        harness.focus_next();
+
+        assert!(harness.ring());
+        assert_eq!(harness.send(key(keyboard::key::Named::Space)), vec!["go"]);
+        assert_eq!(harness.send(key(keyboard::key::Named::Enter)), vec!["go"]);
+    }
+
+    #[test]
+    fn clicking_a_control_focuses_it_without_a_ring() {
+        let mut harness = Harness::new(keyboard_focusable(
+            iced::widget::button("Go").on_press("go"),
+        ));
+
+        let _ = harness.send(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        let _ = harness.send(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        assert!(!harness.ring());
+        assert_eq!(harness.send(key(keyboard::key::Named::Space)), vec!["go"]);
+    }
+
+    #[test]
+    fn inputs_show_the_ring_while_focused_from_the_keyboard() {
+        let mut harness = Harness::new(with_focus_ring(
+            iced::widget::text_input("", "").on_input(|_| "typed"),
+        ));
+
+        let _ = harness.send(key(keyboard::key::Named::Tab));
+        harness.focus_next();
+        let _ = harness.send(key(keyboard::key::Named::Tab));
+        assert!(harness.ring());
+
+        let _ = harness.send(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        assert!(!harness.ring());
+    }
+
+    #[test]
+    fn presses_set_the_modality() {
+        let tab = Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Tab),
+            modified_key: keyboard::Key::Named(keyboard::key::Named::Tab),
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: None,
+        });
+        let click = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let moved = Event::Mouse(mouse::Event::CursorMoved {
+            position: iced::Point::ORIGIN,
+        });
+
+        assert_eq!(Modality::of(&tab), Some(Modality::Keyboard));
+        assert_eq!(Modality::of(&click), Some(Modality::Pointer));
+        assert_eq!(Modality::of(&moved), None);
+    }
+}
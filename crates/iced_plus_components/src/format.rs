@@ -0,0 +1,119 @@
+//! Number formatting helpers for consistent display across components.
+//!
+//! # Example
+//!
+//! ```rust
+//! use iced_plus_components::format;
+//! use std::time::Duration;
+//!
+//! assert_eq!(format::percent(0.1234, 0), "12%");
+//! assert_eq!(format::duration_hms(Duration::from_secs(3723)), "1:02:03");
+//! assert_eq!(format::bytes(1536), "1.5 KB");
+//! assert_eq!(format::thousands(1_234_567), "1,234,567");
+//! ```
+
+use std::time::Duration;
+
+/// Byte units, each 1024 times the previous.
+const BYTE_UNITS: [&str; 5] = ["KB", "MB", "GB", "TB", "PB"];
+
+/// Format a fraction (`0.0`-`1.0`) as a percentage with `decimals` places.
+///
+/// `percent(0.1234, 0)` is `"12%"` and `percent(0.1234, 1)` is `"12.3%"`.
+#[must_use]
+pub fn percent(fraction: f32, decimals: usize) -> String {
+    format!("{:.*}%", decimals, fraction * 100.0)
+}
+
+/// Format a duration as `M:SS`, or `H:MM:SS` from one hour up.
+#[must_use]
+pub fn duration_hms(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let mins = (total_secs % 3600) / 60;
+    let secs = total_secs % 60;
+
+    if hours > 0 {
+        format!("{hours}:{mins:02}:{secs:02}")
+    } else {
+        format!("{mins}:{secs:02}")
+    }
+}
+
+/// Format a byte count in binary units with one decimal, e.g. `"1.5 KB"`.
+///
+/// Counts under 1 KB are shown exactly (`"512 B"`) and a trailing `.0` is
+/// dropped (`"2 MB"`).
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn bytes(count: u64) -> String {
+    if count < 1024 {
+        return format!("{count} B");
+    }
+
+    let mut value = count as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    let value = format!("{value:.1}");
+    let value = value.strip_suffix(".0").unwrap_or(&value);
+
+    format!("{value} {}", BYTE_UNITS[unit])
+}
+
+/// Format an integer with commas between groups of three digits.
+#[must_use]
+pub fn thousands(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+
+    if value < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_rounds_to_decimals() {
+        assert_eq!(percent(0.1234, 0), "12%");
+        assert_eq!(percent(0.1234, 1), "12.3%");
+        assert_eq!(percent(1.0, 0), "100%");
+    }
+
+    #[test]
+    fn duration_adds_hours_when_needed() {
+        assert_eq!(duration_hms(Duration::from_secs(65)), "1:05");
+        assert_eq!(duration_hms(Duration::from_secs(3723)), "1:02:03");
+        assert_eq!(duration_hms(Duration::ZERO), "0:00");
+    }
+
+    #[test]
+    fn bytes_use_binary_units() {
+        assert_eq!(bytes(512), "512 B");
+        assert_eq!(bytes(1536), "1.5 KB");
+        assert_eq!(bytes(2 * 1024 * 1024), "2 MB");
+        assert_eq!(bytes(5 * 1024 * 1024 * 1024 + 1024 * 1024 * 300), "5.3 GB");
+    }
+
+    #[test]
+    fn thousands_groups_digits() {
+        assert_eq!(thousands(1_234_567), "1,234,567");
+        assert_eq!(thousands(999), "999");
+        assert_eq!(thousands(-1000), "-1,000");
+        assert_eq!(thousands(i64::MIN), "-9,223,372,036,854,775,808");
+    }
+}
@@ -10,8 +10,19 @@
 //! Icon::new(IconName::Home).size(24.0)
 //! ```
 
+use iced::advanced::graphics::geometry;
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::text;
+use iced::advanced::widget::{Tree, Widget};
 use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
-use iced::{mouse, Color, Element, Length, Point, Rectangle, Renderer, Theme};
+use iced::widget::{button, column, text as label};
+use iced::{
+    mouse, Alignment, Border, Color, Element, Length, Point, Radians, Rectangle, Theme, Vector,
+};
+use iced_plus_layouts::{Masonry, Responsive};
+use iced_plus_tokens::reduce_motion;
+use std::f32::consts::TAU;
 
 /// Available icon names.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -143,11 +154,175 @@ pub enum IconName {
     Unlock,
 }
 
+impl IconName {
+    /// Every icon, in declaration order.
+    pub const ALL: [Self; 55] = [
+        Self::Home,
+        Self::Menu,
+        Self::Close,
+        Self::ArrowLeft,
+        Self::ArrowRight,
+        Self::ArrowUp,
+        Self::ArrowDown,
+        Self::ChevronLeft,
+        Self::ChevronRight,
+        Self::ChevronUp,
+        Self::ChevronDown,
+        Self::Plus,
+        Self::Minus,
+        Self::Check,
+        Self::Search,
+        Self::Settings,
+        Self::Edit,
+        Self::Trash,
+        Self::Refresh,
+        Self::Download,
+        Self::Upload,
+        Self::Share,
+        Self::Copy,
+        Self::Info,
+        Self::Warning,
+        Self::Error,
+        Self::Success,
+        Self::Play,
+        Self::Pause,
+        Self::Stop,
+        Self::Record,
+        Self::Volume,
+        Self::Mute,
+        Self::Microphone,
+        Self::Camera,
+        Self::File,
+        Self::Folder,
+        Self::Image,
+        Self::Grid,
+        Self::List,
+        Self::Mail,
+        Self::Chat,
+        Self::Bell,
+        Self::User,
+        Self::Users,
+        Self::Sun,
+        Self::Moon,
+        Self::Globe,
+        Self::Link,
+        Self::Star,
+        Self::Heart,
+        Self::Eye,
+        Self::EyeOff,
+        Self::Lock,
+        Self::Unlock,
+    ];
+
+    /// The variant name, e.g. `"ArrowLeft"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Home => "Home",
+            Self::Menu => "Menu",
+            Self::Close => "Close",
+            Self::ArrowLeft => "ArrowLeft",
+            Self::ArrowRight => "ArrowRight",
+            Self::ArrowUp => "ArrowUp",
+            Self::ArrowDown => "ArrowDown",
+            Self::ChevronLeft => "ChevronLeft",
+            Self::ChevronRight => "ChevronRight",
+            Self::ChevronUp => "ChevronUp",
+            Self::ChevronDown => "ChevronDown",
+            Self::Plus => "Plus",
+            Self::Minus => "Minus",
+            Self::Check => "Check",
+            Self::Search => "Search",
+            Self::Settings => "Settings",
+            Self::Edit => "Edit",
+            Self::Trash => "Trash",
+            Self::Refresh => "Refresh",
+            Self::Download => "Download",
+            Self::Upload => "Upload",
+            Self::Share => "Share",
+            Self::Copy => "Copy",
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+            Self::Success => "Success",
+            Self::Play => "Play",
+            Self::Pause => "Pause",
+            Self::Stop => "Stop",
+            Self::Record => "Record",
+            Self::Volume => "Volume",
+            Self::Mute => "Mute",
+            Self::Microphone => "Microphone",
+            Self::Camera => "Camera",
+            Self::File => "File",
+            Self::Folder => "Folder",
+            Self::Image => "Image",
+            Self::Grid => "Grid",
+            Self::List => "List",
+            Self::Mail => "Mail",
+            Self::Chat => "Chat",
+            Self::Bell => "Bell",
+            Self::User => "User",
+            Self::Users => "Users",
+            Self::Sun => "Sun",
+            Self::Moon => "Moon",
+            Self::Globe => "Globe",
+            Self::Link => "Link",
+            Self::Star => "Star",
+            Self::Heart => "Heart",
+            Self::Eye => "Eye",
+            Self::EyeOff => "EyeOff",
+            Self::Lock => "Lock",
+            Self::Unlock => "Unlock",
+        }
+    }
+}
+
+/// A continuous icon animation driven by a progress value.
+///
+/// Progress runs from 0.0 to 1.0 per cycle, e.g. from
+/// [`spinner_subscription`](crate::spinner::spinner_subscription). Animated
+/// icons turned into elements while
+/// [`reduce_motion`](iced_plus_tokens::reduce_motion) is on draw at rest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IconAnimation {
+    /// Rotate clockwise one full turn per cycle.
+    Spin(f32),
+    /// Fade out and back in once per cycle.
+    Pulse(f32),
+}
+
+impl IconAnimation {
+    /// Lowest opacity reached while pulsing.
+    const PULSE_MIN_OPACITY: f32 = 0.3;
+
+    /// Rotation applied to the icon paths.
+    #[must_use]
+    pub fn rotation(self) -> Radians {
+        match self {
+            Self::Spin(progress) => Radians(progress.rem_euclid(1.0) * TAU),
+            Self::Pulse(_) => Radians(0.0),
+        }
+    }
+
+    /// Opacity multiplier applied to the icon color.
+    #[must_use]
+    pub fn opacity(self) -> f32 {
+        match self {
+            Self::Spin(_) => 1.0,
+            Self::Pulse(progress) => {
+                let wave = (1.0 + (progress * TAU).cos()) / 2.0;
+                (1.0 - Self::PULSE_MIN_OPACITY).mul_add(wave, Self::PULSE_MIN_OPACITY)
+            }
+        }
+    }
+}
+
 /// A vector icon component.
 pub struct Icon {
     name: IconName,
     size: f32,
     color: Option<Color>,
+    animation: Option<IconAnimation>,
 }
 
 impl Icon {
@@ -158,6 +333,7 @@ impl Icon {
             name,
             size: 24.0,
             color: None,
+            animation: None,
         }
     }
 
@@ -175,6 +351,30 @@ impl Icon {
         self
     }
 
+    /// Spin the icon, e.g. a refresh icon while reloading.
+    ///
+    /// Drive `progress` with the spinner subscription.
+    #[must_use]
+    pub const fn spin(mut self, progress: f32) -> Self {
+        self.animation = Some(IconAnimation::Spin(progress));
+        self
+    }
+
+    /// Pulse the icon's opacity.
+    ///
+    /// Drive `progress` with the spinner subscription.
+    #[must_use]
+    pub const fn pulse(mut self, progress: f32) -> Self {
+        self.animation = Some(IconAnimation::Pulse(progress));
+        self
+    }
+
+    /// The current animation, if any.
+    #[must_use]
+    pub const fn animation(&self) -> Option<IconAnimation> {
+        self.animation
+    }
+
     // Convenience constructors for common icons
 
     /// Home icon
@@ -307,10 +507,27 @@ impl Icon {
 struct IconProgram {
     name: IconName,
     color: Option<Color>,
+    animation: Option<IconAnimation>,
 }
 
 impl IconProgram {
-    fn draw_icon(&self, frame: &mut Frame, bounds: Rectangle, color: Color) {
+    /// Draw `icon` with its animation, dropped while
+    /// [`reduce_motion`](iced_plus_tokens::reduce_motion) is on so the icon
+    /// rests upright and opaque.
+    fn new(icon: &Icon) -> Self {
+        Self {
+            name: icon.name,
+            color: icon.color,
+            animation: icon.animation.filter(|_| !reduce_motion()),
+        }
+    }
+
+    fn draw_icon<Renderer: geometry::Renderer>(
+        &self,
+        frame: &mut Frame<Renderer>,
+        bounds: Rectangle,
+        color: Color,
+    ) {
         let size = bounds.width.min(bounds.height);
         let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
         let stroke = Stroke::default().with_width(size * 0.08).with_color(color);
@@ -1280,7 +1497,10 @@ impl IconProgram {
     }
 }
 
-impl<Message> canvas::Program<Message, Theme> for IconProgram {
+impl<Message, Renderer> canvas::Program<Message, Theme, Renderer> for IconProgram
+where
+    Renderer: geometry::Renderer,
+{
     type State = ();
 
     fn draw(
@@ -1290,26 +1510,37 @@ impl<Message> canvas::Program<Message, Theme> for IconProgram {
         theme: &Theme,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
-    ) -> Vec<Geometry> {
+    ) -> Vec<Geometry<Renderer>> {
         let mut frame = Frame::new(renderer, bounds.size());
 
-        let color = self
+        let mut color = self
             .color
             .unwrap_or_else(|| theme.extended_palette().background.base.text);
 
+        if let Some(animation) = self.animation {
+            color.a *= animation.opacity();
+
+            // Rotate around the center of the icon.
+            let center = Vector::new(bounds.width / 2.0, bounds.height / 2.0);
+            frame.translate(center);
+            frame.rotate(animation.rotation());
+            frame.translate(-center);
+        }
+
         self.draw_icon(&mut frame, bounds, color);
 
         vec![frame.into_geometry()]
     }
 }
 
-impl<'a, Message: 'a> From<Icon> for Element<'a, Message, Theme> {
+impl<'a, Message, Renderer> From<Icon> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: geometry::Renderer + 'a,
+{
     fn from(icon: Icon) -> Self {
         let size = icon.size;
-        let program = IconProgram {
-            name: icon.name,
-            color: icon.color,
-        };
+        let program = IconProgram::new(&icon);
         Canvas::new(program)
             .width(Length::Fixed(size))
             .height(Length::Fixed(size))
@@ -1317,6 +1548,71 @@ impl<'a, Message: 'a> From<Icon> for Element<'a, Message, Theme> {
     }
 }
 
+/// An icon drawn in the text color it inherits, e.g. from a button.
+///
+/// Unlike [`Icon`], it works with any theme, since it never looks at it.
+pub(crate) struct InheritedIcon {
+    name: IconName,
+    size: f32,
+}
+
+impl InheritedIcon {
+    /// Create an icon `size` pixels square.
+    pub(crate) const fn new(name: IconName, size: f32) -> Self {
+        Self { name, size }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for InheritedIcon
+where
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> iced::Size<Length> {
+        iced::Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(
+            Length::Fixed(self.size),
+            Length::Fixed(self.size),
+            iced::Size::new(self.size, self.size),
+        ))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut frame = Frame::new(renderer, bounds.size());
+        let program = IconProgram {
+            name: self.name,
+            color: None,
+            animation: None,
+        };
+        program.draw_icon(&mut frame, bounds, style.text_color);
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<InheritedIcon> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(icon: InheritedIcon) -> Self {
+        Element::new(icon)
+    }
+}
+
 /// Create an icon element quickly.
 ///
 /// # Example
@@ -1336,3 +1632,137 @@ pub fn icon_colored<'a, Message: 'a>(
 ) -> Element<'a, Message, Theme> {
     Icon::new(name).size(size).color(color).into()
 }
+
+/// Every icon with its name, in a grid of clickable cells.
+///
+/// Clicking a cell emits `on_select` with its icon, which makes the
+/// gallery a ready-made icon picker. The column count follows the
+/// breakpoint tier of the available width; wrap the gallery in a
+/// scrollable to fit it in a smaller area.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// scrollable(icon_gallery(Message::IconPicked))
+/// ```
+pub fn icon_gallery<'a, Message, Renderer>(
+    on_select: impl Fn(IconName) -> Message + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: geometry::Renderer + text::Renderer + 'a,
+{
+    Masonry::new()
+        .columns(Responsive::new(3).sm(4).md(6).lg(8))
+        .spacing(4.0)
+        .extend(IconName::ALL.iter().map(|&name| {
+            button(
+                column![Icon::new(name), label(name.name()).size(11)]
+                    .spacing(6)
+                    .align_x(Alignment::Center),
+            )
+            .width(Length::Fill)
+            .padding(8)
+            .on_press(on_select(name))
+            .style(gallery_cell_style)
+        }))
+        .into()
+}
+
+/// Style of an [`icon_gallery`] cell: flat, highlighted under the cursor.
+fn gallery_cell_style(theme: &Theme, status: button::Status) -> button::Style {
+    let palette = theme.extended_palette();
+    let background = match status {
+        button::Status::Hovered | button::Status::Pressed => {
+            Some(palette.background.weak.color.into())
+        }
+        button::Status::Active | button::Status::Disabled => None,
+    };
+
+    button::Style {
+        background,
+        text_color: palette.background.base.text,
+        border: Border::default().rounded(6),
+        ..button::Style::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::layout::{Layout, Limits};
+    use iced::advanced::widget::Tree;
+    use iced::advanced::{clipboard, Shell};
+    use iced::{Event, Size};
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn spin_quarter_turn_rotates_ninety_degrees() {
+        let animation = Icon::new(IconName::Refresh).spin(0.25).animation().unwrap();
+
+        assert!((animation.rotation().0 - FRAC_PI_2).abs() < 1e-6);
+        assert!((animation.opacity() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn pulse_fades_mid_cycle() {
+        assert!((IconAnimation::Pulse(0.0).opacity() - 1.0).abs() < 1e-6);
+        assert!(IconAnimation::Pulse(0.5).opacity() < 0.5);
+        assert!((IconAnimation::Pulse(0.5).rotation().0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn reduced_motion_rests_animated_icons() {
+        let animations = || {
+            [
+                Icon::new(IconName::Refresh).spin(0.25),
+                Icon::new(IconName::Info).pulse(0.5),
+            ]
+            .map(|icon| IconProgram::new(&icon).animation)
+        };
+
+        let still = iced_plus_tokens::with_reduce_motion(true, animations);
+
+        assert_eq!(still, [None, None]);
+        assert!(animations().iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn gallery_has_a_cell_per_icon_and_emits_its_name() {
+        let mut gallery: Element<'_, IconName, Theme, ()> = icon_gallery(|name| name);
+        let mut tree = Tree::new(&gallery);
+        let viewport = Size::new(800.0, 2000.0);
+        let node = gallery
+            .as_widget()
+            .layout(&mut tree, &(), &Limits::new(Size::ZERO, viewport));
+        assert_eq!(tree.children.len(), IconName::ALL.len());
+
+        let index = IconName::ALL
+            .iter()
+            .position(|&name| name == IconName::Heart)
+            .unwrap();
+        let cell = Layout::new(&node).children().nth(index).unwrap().bounds();
+        let cursor = mouse::Cursor::Available(cell.center());
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ] {
+            let _ = gallery.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(viewport),
+            );
+        }
+
+        assert_eq!(messages, [IconName::Heart]);
+        assert_eq!(IconName::Heart.name(), "Heart");
+    }
+}
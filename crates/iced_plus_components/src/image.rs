@@ -5,6 +5,8 @@ use iced::widget::Image as IcedImage;
 use iced::{ContentFit, Element, Length};
 use std::path::PathBuf;
 
+use crate::skeleton::Skeleton;
+
 /// Image source type.
 #[derive(Debug, Clone)]
 pub enum ImageSource {
@@ -61,6 +63,12 @@ impl From<adv_image::Handle> for ImageSource {
 ///     .width(200.0)
 ///     .height(150.0)
 ///     .fit(ContentFit::Cover)
+///
+/// // Reserve a 16:9 skeleton until the image is ready
+/// Image::new(handle)
+///     .width(Length::Fill)
+///     .aspect(16.0, 9.0)
+///     .loading(self.thumbnail_loading)
 /// ```
 pub struct Image {
     source: ImageSource,
@@ -68,6 +76,8 @@ pub struct Image {
     height: Length,
     content_fit: ContentFit,
     filter_method: adv_image::FilterMethod,
+    aspect: Option<(f32, f32)>,
+    loading: bool,
 }
 
 impl Image {
@@ -79,6 +89,8 @@ impl Image {
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
             filter_method: adv_image::FilterMethod::Linear,
+            aspect: None,
+            loading: false,
         }
     }
 
@@ -129,10 +141,35 @@ impl Image {
         self.filter_method = adv_image::FilterMethod::Linear;
         self
     }
+
+    /// Hint the image's `width_ratio:height_ratio` aspect, so the loading
+    /// skeleton takes the same space as the image will.
+    #[must_use]
+    pub const fn aspect(mut self, width_ratio: f32, height_ratio: f32) -> Self {
+        self.aspect = Some((width_ratio, height_ratio));
+        self
+    }
+
+    /// Show a skeleton in place of the image while it loads.
+    #[must_use]
+    pub const fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
 }
 
 impl<'a, Message: 'a> From<Image> for Element<'a, Message, iced::Theme> {
     fn from(img: Image) -> Self {
+        if img.loading {
+            let skeleton = Skeleton::rounded().width(img.width);
+
+            return match img.aspect {
+                Some((width_ratio, height_ratio)) => skeleton.aspect(width_ratio, height_ratio),
+                None => skeleton.height(img.height),
+            }
+            .into();
+        }
+
         let handle = match img.source {
             ImageSource::Path(path) => adv_image::Handle::from_path(path),
             ImageSource::Bytes(bytes) => adv_image::Handle::from_bytes(bytes),
@@ -0,0 +1,309 @@
+//! Editable select: a text input with a filtered option list.
+
+use iced::advanced::text;
+use iced::widget::text_input;
+use iced::{Element, Length, Theme};
+
+use super::suggestions::Suggestions;
+
+/// Options whose label contains `query`, case-insensitively.
+///
+/// Unlike plain suggestions, an empty query lists every option so the list
+/// can be browsed before typing, and an exact match stays in the list.
+fn filter<T: ToString + Clone>(options: &[T], query: &str) -> Vec<T> {
+    let query = query.trim().to_lowercase();
+
+    options
+        .iter()
+        .filter(|option| option.to_string().to_lowercase().contains(&query))
+        .cloned()
+        .collect()
+}
+
+/// A text input whose dropdown offers the options matching what was typed.
+///
+/// This is the editable counterpart to [`Select`](crate::Select): every
+/// keystroke calls `on_input` with the new text, and picking an option with
+/// ArrowDown/ArrowUp and Enter (or a click) calls `on_select`. Text that
+/// matches no option is left as typed, so the app decides whether to accept
+/// free values.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ComboBox::new(
+///     &self.languages,
+///     &self.language_query,
+///     Message::LanguageTyped,
+///     Message::LanguageSelected,
+/// )
+/// .placeholder("Language")
+/// ```
+pub struct ComboBox<'a, T, Message> {
+    options: &'a [T],
+    value: &'a str,
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_submit: Option<Message>,
+    placeholder: &'a str,
+    id: Option<text_input::Id>,
+    width: Length,
+    padding: f32,
+    size: f32,
+}
+
+impl<'a, T, Message> ComboBox<'a, T, Message>
+where
+    T: ToString + Clone + 'a,
+    Message: Clone + 'a,
+{
+    /// Create a combo box over `options` showing `value`.
+    pub fn new<I, S>(options: &'a [T], value: &'a str, on_input: I, on_select: S) -> Self
+    where
+        I: Fn(String) -> Message + 'a,
+        S: Fn(T) -> Message + 'a,
+    {
+        Self {
+            options,
+            value,
+            on_input: Box::new(on_input),
+            on_select: Box::new(on_select),
+            on_submit: None,
+            placeholder: "",
+            id: None,
+            width: Length::Fill,
+            padding: 10.0,
+            size: 14.0,
+        }
+    }
+
+    /// Set the placeholder text.
+    #[must_use]
+    pub const fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Set the message emitted when Enter is pressed without a highlighted
+    /// option, e.g. to accept a free-typed value.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Set the input ID for focusing.
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the width.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the padding.
+    #[must_use]
+    pub const fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the font size.
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Options matching the current value.
+    #[must_use]
+    pub fn matches(&self) -> Vec<T> {
+        filter(self.options, self.value)
+    }
+
+    fn build<Renderer>(self) -> Suggestions<'a, T, Message, Theme, Renderer>
+    where
+        Renderer: text::Renderer<Font = iced::Font> + 'a,
+    {
+        let matches = self.matches();
+        let mut input = text_input(self.placeholder, self.value)
+            .on_input(self.on_input)
+            .padding(self.padding)
+            .size(self.size)
+            .width(self.width);
+
+        if let Some(id) = self.id {
+            input = input.id(id);
+        }
+
+        if let Some(message) = self.on_submit {
+            input = input.on_submit(message);
+        }
+
+        Suggestions::new(
+            input,
+            matches,
+            self.value,
+            self.on_select,
+            self.size,
+            self.padding,
+        )
+    }
+}
+
+impl<'a, T, Message> From<ComboBox<'a, T, Message>> for Element<'a, Message, Theme>
+where
+    T: ToString + Clone + 'a,
+    Message: Clone + 'a,
+{
+    fn from(combo_box: ComboBox<'a, T, Message>) -> Self {
+        combo_box.build::<iced::Renderer>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::layout::{Layout, Limits, Node};
+    use iced::advanced::widget::operation::focusable;
+    use iced::advanced::widget::{Tree, Widget};
+    use iced::advanced::{clipboard, Shell};
+    use iced::keyboard::{self, key};
+    use iced::{event, mouse, Event, Rectangle, Size, Vector};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Input(String),
+        Select(&'static str),
+    }
+
+    const CITIES: [&str; 4] = ["Berlin", "Bern", "Boston", "Oslo"];
+
+    fn combo(value: &'static str) -> ComboBox<'static, &'static str, Message> {
+        ComboBox::new(&CITIES, value, Message::Input, Message::Select)
+            .id(text_input::Id::new("city"))
+    }
+
+    fn key_press(key: keyboard::Key, text: Option<&str>) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            modified_key: key.clone(),
+            key,
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: text.map(Into::into),
+        })
+    }
+
+    type Focused = (
+        Suggestions<'static, &'static str, Message, Theme, ()>,
+        Tree,
+        Node,
+    );
+
+    /// A focused combo box showing `value`, ready for events.
+    fn focused(value: &'static str) -> Focused {
+        let widget = combo(value).build::<()>();
+        let mut tree = Tree::new(&widget as &dyn Widget<Message, Theme, ()>);
+        let node = widget.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(300.0, 600.0)),
+        );
+        widget.operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut focusable::focus(text_input::Id::new("city").into()),
+        );
+
+        (widget, tree, node)
+    }
+
+    /// Send `events` the way the runtime does: to the open list first, then
+    /// to the input if the list ignores them.
+    fn send((widget, tree, node): &mut Focused, events: &[Event]) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for event in events {
+            let status = widget
+                .overlay(tree, Layout::new(node), &(), Vector::ZERO)
+                .map_or(event::Status::Ignored, |mut overlay| {
+                    let overlay_node = overlay.layout(&(), Size::new(300.0, 600.0));
+                    overlay.on_event(
+                        event.clone(),
+                        Layout::new(&overlay_node),
+                        mouse::Cursor::Unavailable,
+                        &(),
+                        &mut clipboard::Null,
+                        &mut shell,
+                    )
+                });
+
+            if status == event::Status::Ignored {
+                let _ = widget.on_event(
+                    tree,
+                    event.clone(),
+                    Layout::new(node),
+                    mouse::Cursor::Unavailable,
+                    &(),
+                    &mut clipboard::Null,
+                    &mut shell,
+                    &Rectangle::with_size(Size::new(300.0, 600.0)),
+                );
+            }
+        }
+
+        messages
+    }
+
+    #[test]
+    fn typing_filters_options() {
+        assert_eq!(combo("").matches(), CITIES);
+        assert_eq!(combo("ber").matches(), ["Berlin", "Bern"]);
+        assert_eq!(combo("BERN").matches(), ["Bern"]);
+        assert!(combo("Paris").matches().is_empty());
+
+        let mut combo = focused("Ber");
+        assert_eq!(
+            send(
+                &mut combo,
+                &[key_press(keyboard::Key::Character("l".into()), Some("l"))]
+            ),
+            vec![Message::Input(String::from("Berl"))]
+        );
+    }
+
+    #[test]
+    fn arrow_down_enter_selects() {
+        let mut combo = focused("ber");
+        let arrow_down = key_press(keyboard::Key::Named(key::Named::ArrowDown), None);
+        let enter = key_press(keyboard::Key::Named(key::Named::Enter), None);
+
+        assert_eq!(
+            send(&mut combo, &[arrow_down.clone(), arrow_down, enter]),
+            vec![Message::Select("Bern")]
+        );
+    }
+
+    #[test]
+    fn free_typed_value_is_kept_when_nothing_matches() {
+        let mut combo = focused("Pari");
+        let messages = send(
+            &mut combo,
+            &[
+                key_press(keyboard::Key::Character("s".into()), Some("s")),
+                key_press(keyboard::Key::Named(key::Named::ArrowDown), None),
+                key_press(keyboard::Key::Named(key::Named::Enter), None),
+            ],
+        );
+
+        assert_eq!(messages, vec![Message::Input(String::from("Paris"))]);
+    }
+}
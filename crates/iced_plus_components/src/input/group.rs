@@ -0,0 +1,210 @@
+//! Text input joined to action buttons.
+
+use std::borrow::Cow;
+
+use iced::border::Radius;
+use iced::widget::{button, row, text, Button};
+use iced::{Alignment, Element};
+use iced_plus_theme::{AppTheme, ButtonClass};
+
+use super::TextInput;
+
+/// The corner radii of segment `index` in a row of `count` joined segments.
+///
+/// Only the outer corners of the first and last segments are rounded.
+pub const fn segment_radius(index: usize, count: usize, radius: f32) -> Radius {
+    let first = index == 0;
+    let last = index + 1 == count;
+
+    Radius {
+        top_left: if first { radius } else { 0.0 },
+        bottom_left: if first { radius } else { 0.0 },
+        top_right: if last { radius } else { 0.0 },
+        bottom_right: if last { radius } else { 0.0 },
+    }
+}
+
+/// A text input with one or more buttons attached flush to its end,
+/// sharing one outline with only the outer corners rounded.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// InputGroup::new(
+///     TextInput::new("Enter URL...", &self.url)
+///         .on_input(Message::UrlChanged)
+///         .on_submit(Message::Navigate),
+/// )
+/// .button("Go", Message::Navigate)
+/// ```
+pub struct InputGroup<'a, Message> {
+    input: TextInput<'a, Message>,
+    buttons: Vec<(Cow<'a, str>, Message)>,
+    radius: f32,
+}
+
+impl<'a, Message> InputGroup<'a, Message> {
+    /// Create a group around a text input.
+    #[must_use]
+    pub const fn new(input: TextInput<'a, Message>) -> Self {
+        Self {
+            input,
+            buttons: Vec::new(),
+            radius: 6.0,
+        }
+    }
+
+    /// Attach a button after the input (or after the previous button).
+    #[must_use]
+    pub fn button(mut self, label: impl Into<Cow<'a, str>>, on_press: Message) -> Self {
+        self.buttons.push((label.into(), on_press));
+        self
+    }
+
+    /// Set the radius of the outer corners.
+    #[must_use]
+    pub const fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'a> InputGroup<'a, Message> {
+    /// Lay the input and its buttons out in a row, letting `style` round
+    /// each button to its segment radius.
+    fn join<Theme, Renderer>(
+        self,
+        style: impl Fn(
+            Button<'a, Message, Theme, Renderer>,
+            Radius,
+        ) -> Button<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Theme: button::Catalog + text::Catalog + 'a,
+        Renderer: iced::advanced::text::Renderer + 'a,
+        TextInput<'a, Message>: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        let count = self.buttons.len() + 1;
+        let (padding, size) = self.input.metrics();
+
+        let input = self
+            .input
+            .corners(segment_radius(0, count, self.radius))
+            .into();
+
+        let buttons = self
+            .buttons
+            .into_iter()
+            .enumerate()
+            .map(|(index, (label, on_press))| {
+                let radius = segment_radius(index + 1, count, self.radius);
+                let button = button(text(label.into_owned()).size(size))
+                    .padding(padding)
+                    .on_press(on_press);
+
+                style(button, radius).into()
+            });
+
+        row(std::iter::once(input).chain(buttons))
+            .align_y(Alignment::Center)
+            .into()
+    }
+}
+
+impl<'a, Message, Renderer> From<InputGroup<'a, Message>>
+    for Element<'a, Message, AppTheme<'a>, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(group: InputGroup<'a, Message>) -> Self {
+        group.join(|button, radius| button.class(ButtonClass::Segment(radius)))
+    }
+}
+
+impl<'a, Message, Renderer> From<InputGroup<'a, Message>>
+    for Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(group: InputGroup<'a, Message>) -> Self {
+        group.join(|button, radius| {
+            button.style(move |theme: &iced::Theme, status| {
+                let mut style = button::primary(theme, status);
+                style.border.radius = radius;
+                style
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+    use iced::advanced::layout::{Layout, Limits};
+    use iced::advanced::renderer;
+    use iced::advanced::widget::Tree;
+    use iced::{mouse, Color, Rectangle, Size};
+
+    /// Draw a field with one attached button and return the corner radii
+    /// of the drawn quads.
+    fn drawn_radii<Theme>(theme: &Theme) -> Vec<Radius>
+    where
+        Element<'static, (), Theme, Recorder>: From<InputGroup<'static, ()>>,
+    {
+        let viewport = Size::new(400.0, 100.0);
+        let element: Element<'_, (), Theme, Recorder> = InputGroup::new(TextInput::new("URL", ""))
+            .button("Go", ())
+            .into();
+        let mut tree = Tree::new(&element);
+        let mut recorder = Recorder::default();
+        let node =
+            element
+                .as_widget()
+                .layout(&mut tree, &recorder, &Limits::new(Size::ZERO, viewport));
+
+        element.as_widget().draw(
+            &tree,
+            &mut recorder,
+            theme,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(viewport),
+        );
+
+        recorder.quads.iter().map(|quad| quad.radius).collect()
+    }
+
+    #[test]
+    fn joined_segments_are_drawn_with_their_radii() {
+        let expected = [segment_radius(0, 2, 6.0), segment_radius(1, 2, 6.0)];
+
+        assert_eq!(drawn_radii(&AppTheme::light()), expected);
+        assert_eq!(drawn_radii(&iced::Theme::Light), expected);
+    }
+
+    #[test]
+    fn attached_button_squares_inner_corners() {
+        let field = segment_radius(0, 2, 6.0);
+        let button = segment_radius(1, 2, 6.0);
+
+        assert_eq!((field.top_right, field.bottom_right), (0.0, 0.0));
+        assert_eq!((button.top_left, button.bottom_left), (0.0, 0.0));
+    }
+
+    #[test]
+    fn field_and_button_share_outer_radius() {
+        let field = segment_radius(0, 3, 6.0);
+        let middle = segment_radius(1, 3, 6.0);
+        let last = segment_radius(2, 3, 6.0);
+
+        assert_eq!((field.top_left, field.bottom_left), (6.0, 6.0));
+        assert_eq!((last.top_right, last.bottom_right), (6.0, 6.0));
+        assert_eq!(middle, Radius::from(0.0));
+    }
+}
@@ -1,5 +1,10 @@
 //! Input components.
 
+mod combo_box;
+mod group;
+mod suggestions;
 mod text_input;
 
+pub use combo_box::ComboBox;
+pub use group::InputGroup;
 pub use text_input::TextInput;
@@ -0,0 +1,419 @@
+//! Autocomplete suggestions shown below a text input.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::text;
+use iced::advanced::widget::operation::Focusable;
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::keyboard::{self, key};
+use iced::{event, mouse, Element, Event, Length, Rectangle, Size, Vector};
+
+use crate::dropdown::{DropdownMetrics, DropdownTheme, ListOverlay, ListState, ListStatus};
+
+/// Suggestions matching `query`, case-insensitively.
+///
+/// An empty query matches nothing, and a suggestion equal to the query is
+/// left out since there is nothing left to complete.
+pub fn matching<T: ToString + Clone>(suggestions: &[T], query: &str) -> Vec<T> {
+    let query = query.trim().to_lowercase();
+
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    suggestions
+        .iter()
+        .filter(|suggestion| {
+            let suggestion = suggestion.to_string().to_lowercase();
+            suggestion != query && suggestion.contains(&query)
+        })
+        .cloned()
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct State {
+    list: ListState,
+    query: String,
+}
+
+/// Wraps a text input and lists the options matching its value.
+///
+/// The caller filters the options (see [`matching`]); `query` is only used to
+/// reset the highlight when the value changes.
+pub struct Suggestions<'a, T, Message, Theme, Renderer> {
+    input: Element<'a, Message, Theme, Renderer>,
+    matches: Vec<T>,
+    query: String,
+    on_pick: Box<dyn Fn(T) -> Message + 'a>,
+    metrics: DropdownMetrics,
+    text_size: f32,
+    padding: f32,
+}
+
+impl<'a, T, Message, Theme, Renderer> Suggestions<'a, T, Message, Theme, Renderer> {
+    pub(crate) fn new(
+        input: impl Into<Element<'a, Message, Theme, Renderer>>,
+        matches: Vec<T>,
+        query: &str,
+        on_pick: Box<dyn Fn(T) -> Message + 'a>,
+        text_size: f32,
+        padding: f32,
+    ) -> Self {
+        Self {
+            input: input.into(),
+            matches,
+            query: query.to_owned(),
+            on_pick,
+            metrics: DropdownMetrics::DEFAULT.scaled(),
+            text_size,
+            padding,
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Suggestions<'_, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: DropdownTheme,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.input.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.input.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            list: ListState::default(),
+            query: self.query.clone(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.input));
+
+        // A new query means new matches; drop the stale highlight.
+        let state = tree.state.downcast_mut::<State>();
+        if state.query != self.query {
+            state.query.clone_from(&self.query);
+            state.list.highlighted = None;
+            state.list.offset = 0.0;
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.input
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.input.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let mut focus = IsFocused(false);
+        self.input
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, &mut focus);
+
+        let state = tree.state.downcast_mut::<State>();
+        let count = self.matches.len();
+
+        if !focus.0 {
+            state.list.close();
+            return status;
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
+            match key {
+                keyboard::Key::Named(key::Named::ArrowDown | key::Named::ArrowUp) if count > 0 => {
+                    // The open list handles arrows itself; this reopens it.
+                    if !state.list.is_open {
+                        state.list.open(None, self.metrics, count);
+                    }
+
+                    let delta = if key == keyboard::Key::Named(key::Named::ArrowUp) {
+                        -1
+                    } else {
+                        1
+                    };
+                    state.list.step(delta, self.metrics, count);
+
+                    return event::Status::Captured;
+                }
+                keyboard::Key::Named(key::Named::Tab) if state.list.is_open => {
+                    if let Some(suggestion) = state
+                        .list
+                        .highlighted
+                        .and_then(|index| self.matches.get(index))
+                    {
+                        shell.publish((self.on_pick)(suggestion.clone()));
+                        state.list.close();
+                        return event::Status::Captured;
+                    }
+                }
+                _ if status == event::Status::Captured && !state.list.is_open => {
+                    // Typing into the field shows the list again.
+                    state.list.open(None, self.metrics, count);
+                }
+                _ => {}
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.input.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.input.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let count = self.matches.len();
+
+        if !state.list.is_open || count == 0 {
+            return self.input.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        if state.list.highlighted.is_some_and(|index| index >= count) {
+            state.list.highlighted = None;
+        }
+
+        Some(overlay::Element::new(Box::new(ListOverlay {
+            state: &mut state.list,
+            options: &self.matches,
+            selected: None,
+            on_select: &*self.on_pick,
+            view_option: None,
+            anchor: layout.bounds() + translation,
+            metrics: self.metrics,
+            text_size: self.text_size,
+            padding_x: self.padding,
+            status: ListStatus::Options,
+            footer: None,
+        })))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Suggestions<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + Clone + 'a,
+    Message: 'a,
+    Theme: DropdownTheme + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(suggestions: Suggestions<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(suggestions)
+    }
+}
+
+/// Checks whether any focusable widget in a subtree has focus.
+struct IsFocused(bool);
+
+impl Operation for IsFocused {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        self.0 |= state.is_focused();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::advanced::widget::operation::focusable;
+    use iced::widget::text_input;
+    use iced::Theme;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Input(String),
+        Pick(String),
+    }
+
+    fn tags() -> Vec<String> {
+        ["rust", "ruby", "python", "Rust"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    fn key_press(named: key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modified_key: keyboard::Key::Named(named),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: None,
+        })
+    }
+
+    #[test]
+    fn typing_filters_suggestions() {
+        let tags = tags();
+
+        assert_eq!(matching(&tags, "ru"), vec!["rust", "ruby", "Rust"]);
+        assert_eq!(matching(&tags, "PY"), vec!["python"]);
+        assert!(matching(&tags, "").is_empty());
+        assert!(matching(&tags, "go").is_empty());
+    }
+
+    #[test]
+    fn arrow_down_enter_picks_highlighted() {
+        let tags = tags();
+        let id = text_input::Id::new("tags");
+        let input = text_input::<Message, Theme, ()>("", "rub")
+            .id(id.clone())
+            .on_input(Message::Input);
+        let mut widget = Suggestions::new(
+            input,
+            matching(&tags, "rub"),
+            "rub",
+            Box::new(Message::Pick),
+            14.0,
+            10.0,
+        );
+
+        let mut tree = Tree::new(&widget as &dyn Widget<Message, Theme, ()>);
+        let node = widget.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(300.0, 600.0)),
+        );
+        widget.operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut focusable::focus(id.into()),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let status = widget.on_event(
+            &mut tree,
+            key_press(key::Named::ArrowDown),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::new(300.0, 600.0)),
+        );
+        assert_eq!(status, event::Status::Captured);
+
+        let mut overlay = widget
+            .overlay(&mut tree, Layout::new(&node), &(), Vector::ZERO)
+            .expect("suggestion list is open");
+        let overlay_node = overlay.layout(&(), Size::new(300.0, 600.0));
+        let _ = overlay.on_event(
+            key_press(key::Named::Enter),
+            Layout::new(&overlay_node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        assert_eq!(messages, vec![Message::Pick(String::from("ruby"))]);
+    }
+}
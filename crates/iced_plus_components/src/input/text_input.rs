@@ -2,11 +2,20 @@
 
 use std::borrow::Cow;
 
+use iced::advanced::text;
+use iced::border::Radius;
 use iced::widget::text_input;
 use iced::{Element, Length};
-use iced_plus_theme::{AppTheme, TextInputClass};
+use iced_plus_theme::{scaled, AppTheme, TextInputClass};
+
+use super::suggestions::{matching, Suggestions};
+use crate::disabled::disable;
+use crate::focus::with_focus_ring;
 
 /// A styled text input component with label and helper text support.
+///
+/// The default padding and font size follow the
+/// [UI scale](iced_plus_theme::set_ui_scale).
 pub struct TextInput<'a, Message> {
     id: Option<text_input::Id>,
     placeholder: Cow<'a, str>,
@@ -21,6 +30,10 @@ pub struct TextInput<'a, Message> {
     size: f32,
     class: TextInputClass,
     secure: bool,
+    suggestions: Option<&'a [String]>,
+    on_pick: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    radius: Option<Radius>,
+    disabled: bool,
 }
 
 impl<'a, Message> TextInput<'a, Message> {
@@ -37,10 +50,14 @@ impl<'a, Message> TextInput<'a, Message> {
             on_input: None,
             on_submit: None,
             width: Length::Fill,
-            padding: 10.0,
-            size: 14.0,
+            padding: scaled(10.0),
+            size: scaled(14.0),
             class: TextInputClass::Default,
             secure: false,
+            suggestions: None,
+            on_pick: None,
+            radius: None,
+            disabled: false,
         }
     }
 
@@ -129,15 +146,63 @@ impl<'a, Message> TextInput<'a, Message> {
     pub fn password(self) -> Self {
         self.secure(true)
     }
+
+    /// Show a dropdown of suggestions matching the current value.
+    ///
+    /// Arrow keys move through the list and Enter or Tab accepts the
+    /// highlighted suggestion, calling `on_pick` with it. The app is
+    /// expected to update the bound value in response.
+    #[must_use]
+    pub fn suggestions<F>(mut self, suggestions: &'a [String], on_pick: F) -> Self
+    where
+        F: Fn(String) -> Message + 'a,
+    {
+        self.suggestions = Some(suggestions);
+        self.on_pick = Some(Box::new(on_pick));
+        self
+    }
+
+    /// Disable the input: it ignores typing and clicks and is dimmed.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Override the corner radii, for inputs joined to other controls.
+    pub(super) const fn corners(mut self, radius: Radius) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    pub(super) const fn metrics(&self) -> (f32, f32) {
+        (self.padding, self.size)
+    }
+
+    /// The theme class, carrying the corner override if one is set.
+    const fn style_class(&self) -> TextInputClass {
+        match self.radius {
+            Some(radius) => TextInputClass::Segment {
+                filled: matches!(self.class, TextInputClass::Filled),
+                radius,
+            },
+            None => self.class,
+        }
+    }
 }
 
-impl<'a, Message: Clone + 'a> From<TextInput<'a, Message>> for Element<'a, Message, AppTheme<'a>> {
+impl<'a, Message, Renderer> From<TextInput<'a, Message>>
+    for Element<'a, Message, AppTheme<'a>, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
     fn from(input: TextInput<'a, Message>) -> Self {
         let mut widget = text_input(&input.placeholder, input.value)
             .padding(input.padding)
             .size(input.size)
             .width(input.width)
-            .class(input.class)
+            .class(input.style_class())
             .secure(input.secure);
 
         if let Some(id) = input.id {
@@ -153,11 +218,29 @@ impl<'a, Message: Clone + 'a> From<TextInput<'a, Message>> for Element<'a, Messa
         }
 
         // TODO: Wrap with label and helper text using VStack
-        widget.into()
+        let content: Element<'a, Message, _, Renderer> = match (input.suggestions, input.on_pick) {
+            (Some(suggestions), Some(on_pick)) => Suggestions::new(
+                with_focus_ring(widget),
+                matching(suggestions, input.value),
+                input.value,
+                on_pick,
+                input.size,
+                input.padding,
+            )
+            .into(),
+            _ => with_focus_ring(widget),
+        };
+
+        disable(content, input.disabled)
     }
 }
 
-impl<'a, Message: Clone + 'a> From<TextInput<'a, Message>> for Element<'a, Message, iced::Theme> {
+impl<'a, Message, Renderer> From<TextInput<'a, Message>>
+    for Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
     fn from(input: TextInput<'a, Message>) -> Self {
         let mut widget = text_input(&input.placeholder, input.value)
             .padding(input.padding)
@@ -165,6 +248,14 @@ impl<'a, Message: Clone + 'a> From<TextInput<'a, Message>> for Element<'a, Messa
             .width(input.width)
             .secure(input.secure);
 
+        if let Some(radius) = input.radius {
+            widget = widget.style(move |theme: &iced::Theme, status| {
+                let mut style = text_input::default(theme, status);
+                style.border.radius = radius;
+                style
+            });
+        }
+
         if let Some(id) = input.id {
             widget = widget.id(id);
         }
@@ -177,6 +268,71 @@ impl<'a, Message: Clone + 'a> From<TextInput<'a, Message>> for Element<'a, Messa
             widget = widget.on_submit(msg);
         }
 
-        widget.into()
+        let content: Element<'a, Message, _, Renderer> = match (input.suggestions, input.on_pick) {
+            (Some(suggestions), Some(on_pick)) => Suggestions::new(
+                with_focus_ring(widget),
+                matching(suggestions, input.value),
+                input.value,
+                on_pick,
+                input.size,
+                input.padding,
+            )
+            .into(),
+            _ => with_focus_ring(widget),
+        };
+
+        disable(content, input.disabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+    use iced::advanced::layout::Limits;
+    use iced::advanced::widget::Tree;
+    use iced::Size;
+    use iced_plus_theme::set_ui_scale;
+
+    #[test]
+    fn disabled_input_ignores_typing_and_is_dimmed() {
+        let input = |disabled| {
+            TextInput::new("Name", "")
+                .on_input(|value| value)
+                .disabled(disabled)
+        };
+        let theme = AppTheme::dark();
+
+        assert_eq!(interact::<_, iced::Theme>(input(false).into()), ["a"]);
+        assert!(interact::<_, iced::Theme>(input(true).into()).is_empty());
+        assert_eq!(
+            last_quad(&input(true).into(), &theme),
+            Some(theme.disabled_veil())
+        );
+    }
+
+    #[test]
+    fn ui_scale_enlarges_inputs() {
+        let height = || {
+            let input: Element<'_, (), iced::Theme, ()> = TextInput::new("Name", "").into();
+            let mut tree = Tree::new(&input);
+            input
+                .as_widget()
+                .layout(
+                    &mut tree,
+                    &(),
+                    &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+                )
+                .size()
+                .height
+        };
+        let unscaled = height();
+
+        set_ui_scale(1.5);
+        let scaled = height();
+        set_ui_scale(1.0);
+
+        assert!((scaled - unscaled * 1.5).abs() < 1e-3);
     }
 }
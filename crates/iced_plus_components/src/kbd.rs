@@ -0,0 +1,328 @@
+//! Keyboard key labels and shortcut cheat sheets.
+//!
+//! [`Kbd`] draws a key combination as a row of keycaps, like the HTML
+//! `<kbd>` element. [`ShortcutSheet`] lists the shortcuts of an application
+//! in titled sections, e.g. in a help overlay opened with `?`; keep its
+//! labels next to the in-app shortcut bindings so the two stay in sync.
+
+use std::borrow::Cow;
+
+use iced::advanced::text as advanced_text;
+use iced::widget::{column, container, row, text};
+use iced::{Alignment, Border, Element, Length, Shadow, Theme, Vector};
+use iced_plus_layouts::{Masonry, Responsive};
+
+use crate::divider::{Divider, DividerLabelAlign};
+
+/// A key combination drawn as keycaps.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Kbd::combo("Ctrl+Shift+Z")
+///
+/// Kbd::new(["⌘", "K"])
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Kbd<'a> {
+    keys: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Kbd<'a> {
+    /// Create a combination of `keys`, pressed together.
+    #[must_use]
+    pub fn new(keys: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Parse a combination written with `+` between keys, e.g. `"Ctrl+S"`.
+    ///
+    /// A doubled `+`, as in `"Ctrl++"`, is the plus key itself.
+    #[must_use]
+    pub fn combo(combo: &'a str) -> Self {
+        let mut keys = Vec::new();
+        let mut segments = combo.split('+');
+
+        while let Some(segment) = segments.next() {
+            if segment.trim().is_empty() {
+                // The empty segments on both sides of a literal `+`.
+                segments.next();
+                keys.push(Cow::Borrowed("+"));
+            } else {
+                keys.push(Cow::Borrowed(segment.trim()));
+            }
+        }
+
+        Self { keys }
+    }
+
+    /// The keys of the combination, in order.
+    #[must_use]
+    pub fn keys(&self) -> &[Cow<'a, str>] {
+        &self.keys
+    }
+}
+
+impl<'a, Message, Renderer> From<Kbd<'a>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    fn from(kbd: Kbd<'a>) -> Self {
+        row(kbd.keys.into_iter().map(|key| {
+            container(text(key.into_owned()).size(12))
+                .padding([2, 6])
+                .style(keycap_style)
+                .into()
+        }))
+        .spacing(4)
+        .align_y(Alignment::Center)
+        .into()
+    }
+}
+
+/// Style of a keycap: a raised, outlined key.
+fn keycap_style(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        background: Some(palette.background.weak.color.into()),
+        text_color: Some(palette.background.weak.text),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        shadow: Shadow {
+            color: palette.background.strong.color,
+            offset: Vector::new(0.0, 1.0),
+            blur_radius: 0.0,
+        },
+    }
+}
+
+/// A titled group of shortcuts in a [`ShortcutSheet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutSection<'a> {
+    title: Cow<'a, str>,
+    shortcuts: Vec<(Cow<'a, str>, Kbd<'a>)>,
+}
+
+impl<'a> ShortcutSection<'a> {
+    /// Create an empty section.
+    #[must_use]
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            shortcuts: Vec::new(),
+        }
+    }
+
+    /// Add a row pairing the label of an action with its keys.
+    #[must_use]
+    pub fn shortcut(mut self, label: impl Into<Cow<'a, str>>, keys: Kbd<'a>) -> Self {
+        self.shortcuts.push((label.into(), keys));
+        self
+    }
+
+    /// The section title.
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The rows of the section, in order.
+    #[must_use]
+    pub fn shortcuts(&self) -> &[(Cow<'a, str>, Kbd<'a>)] {
+        &self.shortcuts
+    }
+}
+
+/// A keyboard shortcut cheat sheet.
+///
+/// Each section is headed by a start-aligned [`Divider`] label, with one
+/// row per shortcut: the action on the left, its [`Kbd`] on the right.
+/// Sections flow into 1, 2 and 3 columns from the XS, MD and LG tiers up.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ShortcutSheet::new()
+///     .section(
+///         ShortcutSection::new("General")
+///             .shortcut("Save", Kbd::combo("Ctrl+S"))
+///             .shortcut("Command palette", Kbd::combo("Ctrl+K")),
+///     )
+///     .section(
+///         ShortcutSection::new("Editing")
+///             .shortcut("Undo", Kbd::combo("Ctrl+Z"))
+///             .shortcut("Redo", Kbd::combo("Ctrl+Shift+Z")),
+///     )
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShortcutSheet<'a> {
+    sections: Vec<ShortcutSection<'a>>,
+    columns: Responsive<usize>,
+    spacing: f32,
+}
+
+impl<'a> ShortcutSheet<'a> {
+    /// Create an empty sheet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sections: Vec::new(),
+            columns: Responsive::new(1).md(2).lg(3),
+            spacing: 24.0,
+        }
+    }
+
+    /// Add a section.
+    #[must_use]
+    pub fn section(mut self, section: ShortcutSection<'a>) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Set the column count per breakpoint.
+    #[must_use]
+    pub const fn columns(mut self, columns: Responsive<usize>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the gap between sections (default 24).
+    #[must_use]
+    pub const fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// The sections of the sheet, in order.
+    #[must_use]
+    pub fn sections(&self) -> &[ShortcutSection<'a>] {
+        &self.sections
+    }
+}
+
+impl Default for ShortcutSheet<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> From<ShortcutSheet<'a>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: advanced_text::Renderer + 'a,
+{
+    fn from(sheet: ShortcutSheet<'a>) -> Self {
+        Masonry::new()
+            .columns(sheet.columns)
+            .spacing(sheet.spacing)
+            .extend(sheet.sections.into_iter().map(|section| {
+                let header = Divider::with_label(section.title)
+                    .label_align(DividerLabelAlign::Start)
+                    .into();
+                let rows = section.shortcuts.into_iter().map(|(label, keys)| {
+                    row![text(label.into_owned()).size(13).width(Length::Fill), keys]
+                        .spacing(12)
+                        .align_y(Alignment::Center)
+                        .into()
+                });
+
+                column(std::iter::once(header).chain(rows))
+                    .spacing(8)
+                    .width(Length::Fill)
+            }))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::Size;
+    use iced_plus_layouts::testing::{measure, LayoutSnapshot};
+
+    fn sheet() -> ShortcutSheet<'static> {
+        ShortcutSheet::new()
+            .section(
+                ShortcutSection::new("General")
+                    .shortcut("Save", Kbd::combo("Ctrl+S"))
+                    .shortcut("Command palette", Kbd::combo("Ctrl+K")),
+            )
+            .section(
+                ShortcutSection::new("Editing")
+                    .shortcut("Undo", Kbd::combo("Ctrl+Z"))
+                    .shortcut("Redo", Kbd::combo("Ctrl+Shift+Z"))
+                    .shortcut("Zoom in", Kbd::combo("Ctrl++")),
+            )
+    }
+
+    fn measure_sheet(sheet: ShortcutSheet<'_>) -> LayoutSnapshot {
+        measure::<(), Theme>(sheet, Size::new(1200.0, 800.0))
+    }
+
+    #[test]
+    fn combo_splits_on_plus() {
+        assert_eq!(Kbd::combo("Ctrl+Shift+Z").keys(), ["Ctrl", "Shift", "Z"]);
+        assert_eq!(Kbd::combo("Ctrl + K").keys(), ["Ctrl", "K"]);
+        assert_eq!(Kbd::combo("Ctrl++").keys(), ["Ctrl", "+"]);
+        assert_eq!(Kbd::combo("+").keys(), ["+"]);
+    }
+
+    #[test]
+    fn sheet_renders_a_header_per_section() {
+        let sheet = sheet();
+        let titles: Vec<_> = sheet
+            .sections()
+            .iter()
+            .map(ShortcutSection::title)
+            .collect();
+        assert_eq!(titles, ["General", "Editing"]);
+
+        let snapshot = measure_sheet(sheet);
+
+        assert_eq!(snapshot.children.len(), 2);
+        for section in &snapshot.children {
+            // A labeled divider: leading line, title, trailing line.
+            assert_eq!(section.children[0].children.len(), 3);
+        }
+        // Side by side on a wide sheet.
+        assert!(snapshot.children[1].bounds.x > snapshot.children[0].bounds.x);
+    }
+
+    #[test]
+    fn each_row_pairs_a_label_with_keycaps() {
+        let sheet = sheet();
+        let key_counts: Vec<Vec<usize>> = sheet
+            .sections()
+            .iter()
+            .map(|section| {
+                section
+                    .shortcuts()
+                    .iter()
+                    .map(|(_, kbd)| kbd.keys().len())
+                    .collect()
+            })
+            .collect();
+
+        let snapshot = measure_sheet(sheet);
+
+        for (section, key_counts) in snapshot.children.iter().zip(key_counts) {
+            let rows = &section.children[1..];
+            assert_eq!(rows.len(), key_counts.len());
+
+            for (row, keys) in rows.iter().zip(key_counts) {
+                let [label, kbd] = &row.children[..] else {
+                    panic!("a row is a label and a Kbd");
+                };
+                assert!(label.bounds.x < kbd.bounds.x);
+                assert_eq!(kbd.children.len(), keys);
+            }
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Label placement and click handling shared by form controls.
+//!
+//! [`Checkbox`](crate::Checkbox), [`Radio`](crate::Radio) and
+//! [`Switch`](crate::Switch) lay out their control and label in a row, and
+//! the whole row acts as the click target like a native form control.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::widget::row;
+use iced::{event, mouse, Element, Event, Length, Rectangle, Size, Vector};
+
+/// Which side of the control its label is placed on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelPosition {
+    /// Label before the control.
+    Left,
+    /// Label after the control.
+    #[default]
+    Right,
+}
+
+/// A control and its label, where pressing the label triggers `on_click`.
+pub(crate) struct Labeled<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_click: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Labeled<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    /// Lay out `control` and `label` in a row.
+    ///
+    /// Clicks on the row outside the control publish `on_click`, or are
+    /// ignored if it is `None`.
+    pub(crate) fn new(
+        control: impl Into<Element<'a, Message, Theme, Renderer>>,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: LabelPosition,
+        spacing: f32,
+        width: Length,
+        on_click: Option<Message>,
+    ) -> Self {
+        let content = match position {
+            LabelPosition::Left => row![label.into(), control.into()],
+            LabelPosition::Right => row![control.into(), label.into()],
+        };
+
+        Self {
+            content: content
+                .spacing(spacing)
+                .width(width)
+                .align_y(iced::Alignment::Center)
+                .into(),
+            on_click,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Labeled<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::None
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // The control handles its own clicks; anything else on the row is
+        // a click on the label.
+        if status == event::Status::Ignored
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            )
+            && cursor.is_over(layout.bounds())
+        {
+            if let Some(message) = &self.on_click {
+                shell.publish(message.clone());
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && self.on_click.is_some()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Labeled<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(labeled: Labeled<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(labeled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Switch;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+    use iced::{Point, Theme};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggle(bool),
+    }
+
+    /// Press at `x` on a switch with a 120px label to its right.
+    fn click_at(x: f32, on_click: Option<Message>) -> Vec<Message> {
+        let mut labeled: Labeled<'_, Message, Theme, ()> = Labeled::new(
+            Switch::new(false, Message::Toggle),
+            Space::new(120.0, 20.0),
+            LabelPosition::Right,
+            8.0,
+            Length::Shrink,
+            on_click,
+        );
+
+        let mut tree = Tree::new(&labeled as &dyn Widget<Message, Theme, ()>);
+        let node = labeled.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let _ = labeled.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::new(x, 12.0)),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::new(400.0, 100.0)),
+        );
+
+        messages
+    }
+
+    #[test]
+    fn clicking_label_toggles_control() {
+        let on_click = Some(Message::Toggle(true));
+
+        assert_eq!(
+            click_at(120.0, on_click.clone()),
+            vec![Message::Toggle(true)]
+        );
+        // The switch itself still toggles exactly once.
+        assert_eq!(click_at(10.0, on_click), vec![Message::Toggle(true)]);
+    }
+
+    #[test]
+    fn label_click_opt_out_ignores_label() {
+        assert!(click_at(120.0, None).is_empty());
+        assert_eq!(click_at(10.0, None), vec![Message::Toggle(true)]);
+    }
+}
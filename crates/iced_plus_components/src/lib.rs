@@ -13,37 +13,57 @@
 //! ## Buttons & Inputs
 //! - [`Button`] - Type-safe buttons with variant and size encoded in types
 //! - [`TextInput`] - Enhanced text input with label and helper text
+//! - [`InputGroup`] - Text input with attached action buttons
+//! - [`ComboBox`] - Text input with a filtered list of options to pick from
 //! - [`Checkbox`] - Styled checkbox component
 //! - [`Radio`], [`RadioGroup`] - Radio buttons for single selection
 //! - [`Switch`] - Toggle switches
 //! - [`Slider`], [`VerticalSlider`] - Range sliders
 //! - [`Select`] - Dropdown selection
+//! - [`SelectableEnum`] - Populate radio groups and selects from an enum
+//! - [`DisabledTheme`] - Dimming of controls built with `.disabled(true)`
+//! - [`FocusVisible`] - Focus rings shown for keyboard focus only
 //!
 //! ## Layout & Display
 //! - [`Text`], [`Heading`] - Typography components
+//! - [`ContainerQueryText`] - Text sized by its container's width
+//! - [`Prose`] - Long-form content capped at a readable line length
 //! - [`Card`] - Elevated content container
+//! - [`Collapsible`] - Sections that animate open and closed
+//! - [`InteractiveElevation`] - Surfaces that raise on hover
 //! - [`Divider`] - Visual separators
+//! - [`Kbd`], [`ShortcutSheet`] - Key combinations and shortcut cheat sheets
 //! - [`Avatar`] - User/entity avatars
 //! - [`Skeleton`] - Loading placeholders
 //! - [`Image`] - Image display with loading states
+//! - [`crossfade`] - Blend from a placeholder to its content
+//! - [`ThemeSwatch`] - Clickable theme preview for theme pickers
 //!
 //! ## Feedback
 //! - [`Badge`] - Status indicators and counts
+//! - [`with_badge`] - Anchor a badge on a corner of any element
 //! - [`Alert`] - Contextual feedback messages
 //! - [`Progress`] - Progress indicators
 //! - [`Toast`] - Toast notifications
 //! - [`Tooltip`] - Hover tooltips
+//! - [`StatusIndicator`] - Colored status dot with a label
+//! - [`NotificationCenter`] - Persistent, dismissible notifications grouped by day
 //!
 //! ## Navigation & Overlays
 //! - [`Tabs`] - Tab navigation
 //! - [`Menu`], [`MenuBar`] - Menus and menu bars
 //! - [`Drawer`] - Side panel overlays
+//! - [`Toolbar`] - Grouped toolbar items with an overflow menu
+//! - [`TitleBar`] - Custom title bar with window controls for borderless windows
 //!
 //! ## Media
 //! - [`AudioControls`], [`VideoControls`] - Media player controls
 //! - [`MediaPlayerState`] - Playback state management
 //! - [`WebViewState`], [`BrowserBar`] - WebView integration helpers
 //!
+//! ## Utilities
+//! - [`format`] - Percentages, durations, byte sizes and grouped numbers
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -62,6 +82,8 @@
 #![warn(missing_docs)]
 
 mod private;
+#[cfg(test)]
+mod test_support;
 
 // Core components
 pub mod alert;
@@ -70,56 +92,84 @@ pub mod badge;
 pub mod button;
 pub mod card;
 pub mod checkbox;
+pub mod collapsible;
 pub mod color_picker;
+pub mod disabled;
 pub mod divider;
 pub mod drawer;
+pub mod dropdown;
+pub mod elevation;
+pub mod fade;
+pub mod focus;
+pub mod format;
 pub mod icons;
 pub mod image;
 pub mod input;
+pub mod kbd;
+pub mod labeled;
 pub mod media;
 pub mod menu;
 pub mod navbar;
+pub mod notification;
 pub mod progress;
+pub mod prose;
 pub mod radio;
 pub mod rich_text;
 pub mod scrollable;
 pub mod select;
+pub mod selectable;
 pub mod skeleton;
 pub mod slider;
 pub mod spinner;
+pub mod status;
 pub mod switch;
 pub mod tabs;
 pub mod text;
 pub mod textarea;
+pub mod theme_swatch;
+pub mod title_bar;
 pub mod toast;
+pub mod toolbar;
 pub mod tooltip;
 pub mod webview;
 
 // Re-exports for convenience
-pub use alert::{Alert, AlertType};
-pub use avatar::{Avatar, AvatarShape, AvatarSize};
-pub use badge::{Badge, BadgeVariant};
+pub use alert::{Alert, AlertType, AlertWidth};
+pub use avatar::{Avatar, AvatarContent, AvatarImage, AvatarLoadEvent, AvatarShape, AvatarSize};
+pub use badge::{with_badge, Badge, BadgeOverlay, BadgePosition, BadgeVariant};
 pub use button::{Button, ButtonSize, ButtonVariant};
-pub use button::{Destructive, Ghost, Outline, Primary, Secondary};
+pub use button::{Destructive, Ghost, Outline, Primary, Secondary, Tonal};
 pub use button::{ExtraSmall, Large, Medium, Small};
 pub use card::{Card, Elevation};
-pub use checkbox::Checkbox;
+pub use checkbox::{CheckGroup, CheckState, Checkbox};
+pub use collapsible::Collapsible;
 pub use color_picker::{
     color_palette, color_picker_view, color_to_hex, hex_to_color, presets as color_presets,
     ColorSwatch, Hsl, HueSlider, SatLightPicker,
 };
-pub use divider::Divider;
+pub use disabled::DisabledTheme;
+pub use divider::{Divider, DividerLabelAlign, LabeledDivider};
 pub use drawer::{Drawer, DrawerPosition};
-pub use icons::{icon, icon_colored, Icon, IconName};
+pub use dropdown::DropdownMetrics;
+pub use elevation::InteractiveElevation;
+pub use fade::{crossfade, Crossfade};
+pub use focus::{FocusTheme, FocusVisible, Modality};
+pub use icons::{icon, icon_colored, icon_gallery, Icon, IconAnimation, IconName};
 pub use image::{Image, ImagePlaceholder, ImageSource};
-pub use input::TextInput;
+pub use input::{ComboBox, InputGroup, TextInput};
+pub use kbd::{Kbd, ShortcutSection, ShortcutSheet};
+pub use labeled::LabelPosition;
 pub use media::{
-    AudioControls, AudioRecorder, MediaPlayerState, PlaybackState, RecorderState, RecordingState,
-    VideoControls, VideoRecorder,
+    AudioControls, AudioRecorder, Chapter, MediaPlayerState, PlaybackState, RecorderState,
+    RecordingState, VideoControls, VideoRecorder,
 };
 pub use menu::{Menu, MenuBar, MenuItem};
 pub use navbar::{AppBar, NavItem, SideNav};
-pub use progress::{Progress, ProgressVariant};
+pub use notification::{Notification, NotificationCenter, NotificationGroup, NotificationId};
+pub use progress::{
+    Progress, ProgressLabel, ProgressLegend, ProgressVariant, StepProgress, StepState,
+};
+pub use prose::{Measure, Prose};
 pub use radio::{Radio, RadioGroup};
 pub use rich_text::{formatting, FormattingState, RichTextAction, RichTextContent, RichTextEditor};
 pub use scrollable::{
@@ -127,6 +177,7 @@ pub use scrollable::{
     AnchorSection, ScrollDirection, ScrollableBuilder, ScrollableConfig, SnapAlignment,
 };
 pub use select::Select;
+pub use selectable::SelectableEnum;
 pub use skeleton::{Skeleton, SkeletonShape};
 pub use slider::{Slider, VerticalSlider};
 pub use spinner::{
@@ -134,12 +185,19 @@ pub use spinner::{
     CircularSpinner, DotsSpinner, LinearSpinner, PulseSpinner, SpinnerMessage,
     DEFAULT_CYCLE_DURATION, DEFAULT_FRAME_DURATION,
 };
+pub use status::{StatusIndicator, StatusKind, StatusTheme};
 pub use switch::Switch;
 pub use tabs::{Tab, TabWidth, Tabs};
-pub use text::{Heading, HeadingLevel, Text, TextStyle};
+pub use text::{
+    CollapsibleText, ContainerQueryText, Heading, HeadingLevel, SelectableText, Text, TextStyle,
+};
 pub use textarea::{SimpleTextArea, TextArea, TextAreaContent};
+pub use theme_swatch::ThemeSwatch;
+pub use title_bar::TitleBar;
 pub use toast::{
-    toast_container, toast_view, toasts, Toast, ToastManager, ToastPosition, ToastVariant,
+    toast_container, toast_container_with_safe_area, toast_stack, toast_view, toasts, Toast,
+    ToastManager, ToastPosition, ToastVariant,
 };
+pub use toolbar::{Toolbar, ToolbarItem};
 pub use tooltip::{Tooltip, TooltipPosition};
 pub use webview::{BrowserBar, WebViewCommand, WebViewConfig, WebViewState};
@@ -6,6 +6,10 @@
 
 use std::time::Duration;
 
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::{tree, Tree, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+
 /// Playback state.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum PlaybackState {
@@ -22,6 +26,26 @@ pub enum PlaybackState {
     Error,
 }
 
+/// A chapter of a media item, e.g. a podcast segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chapter {
+    /// Chapter title.
+    pub title: String,
+    /// Position the chapter starts at.
+    pub start: Duration,
+}
+
+impl Chapter {
+    /// Create a chapter starting at `start`.
+    #[must_use]
+    pub fn new(title: impl Into<String>, start: Duration) -> Self {
+        Self {
+            title: title.into(),
+            start,
+        }
+    }
+}
+
 /// Media player state (for audio or video).
 ///
 /// This struct manages playback state. Actual media playback
@@ -73,6 +97,8 @@ pub struct MediaPlayerState {
     pub speed: f32,
     /// Whether looping is enabled.
     pub looping: bool,
+    /// Chapters, ordered by start.
+    pub chapters: Vec<Chapter>,
 }
 
 impl Default for MediaPlayerState {
@@ -93,6 +119,7 @@ impl MediaPlayerState {
             muted: false,
             speed: 1.0,
             looping: false,
+            chapters: Vec::new(),
         }
     }
 
@@ -166,6 +193,34 @@ impl MediaPlayerState {
         self.looping = !self.looping;
     }
 
+    /// Add a chapter, keeping the chapters ordered by start.
+    pub fn add_chapter(&mut self, title: impl Into<String>, start: Duration) {
+        let index = self
+            .chapters
+            .partition_point(|chapter| chapter.start <= start);
+        self.chapters.insert(index, Chapter::new(title, start));
+    }
+
+    /// The chapter playing at the current position: the last one starting
+    /// at or before it.
+    #[must_use]
+    pub fn current_chapter(&self) -> Option<&Chapter> {
+        let index = self
+            .chapters
+            .partition_point(|chapter| chapter.start <= self.position);
+        index.checked_sub(1).map(|index| &self.chapters[index])
+    }
+
+    /// Start of the first chapter after the current position, e.g. for a
+    /// "next chapter" button.
+    #[must_use]
+    pub fn next_chapter_start(&self) -> Option<Duration> {
+        let index = self
+            .chapters
+            .partition_point(|chapter| chapter.start <= self.position);
+        self.chapters.get(index).map(|chapter| chapter.start)
+    }
+
     /// Get progress as a percentage (0.0-1.0).
     #[must_use]
     pub fn progress(&self) -> f32 {
@@ -204,16 +259,7 @@ impl MediaPlayerState {
     /// Format time as MM:SS or HH:MM:SS.
     #[must_use]
     pub fn format_time(duration: Duration) -> String {
-        let total_secs = duration.as_secs();
-        let hours = total_secs / 3600;
-        let mins = (total_secs % 3600) / 60;
-        let secs = total_secs % 60;
-
-        if hours > 0 {
-            format!("{hours}:{mins:02}:{secs:02}")
-        } else {
-            format!("{mins}:{secs:02}")
-        }
+        crate::format::duration_hms(duration)
     }
 
     /// Get formatted position string.
@@ -251,9 +297,11 @@ pub struct AudioControls<'a, Message> {
     on_pause: Option<Message>,
     on_stop: Option<Message>,
     on_seek: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_seek_release: Option<Box<dyn Fn(f32) -> Message + 'a>>,
     on_volume: Option<Box<dyn Fn(f32) -> Message + 'a>>,
     on_mute: Option<Message>,
     show_time: bool,
+    show_chapters: bool,
     compact: bool,
 }
 
@@ -269,9 +317,11 @@ where
             on_pause: None,
             on_stop: None,
             on_seek: None,
+            on_seek_release: None,
             on_volume: None,
             on_mute: None,
             show_time: true,
+            show_chapters: false,
             compact: false,
         }
     }
@@ -307,6 +357,21 @@ where
         self
     }
 
+    /// Set the callback for the end of a drag on the seek bar (receives the
+    /// final progress 0.0-1.0).
+    ///
+    /// [`on_seek`](Self::on_seek) still fires on every step of the drag, so
+    /// it can update a preview while this one commits the actual seek, once
+    /// per drag. Without it, the seek bar behaves as before.
+    #[must_use]
+    pub fn on_seek_release<F>(mut self, f: F) -> Self
+    where
+        F: Fn(f32) -> Message + 'a,
+    {
+        self.on_seek_release = Some(Box::new(f));
+        self
+    }
+
     /// Set the volume callback (receives volume 0.0-1.0).
     #[must_use]
     pub fn on_volume<F>(mut self, f: F) -> Self
@@ -331,6 +396,13 @@ where
         self
     }
 
+    /// Show the title of the current chapter above the seek bar.
+    #[must_use]
+    pub const fn show_chapters(mut self, show: bool) -> Self {
+        self.show_chapters = show;
+        self
+    }
+
     /// Use compact layout.
     #[must_use]
     pub fn compact(mut self) -> Self {
@@ -371,13 +443,17 @@ where
             .on_stop
             .map(|msg| button(text("⏹").size(16)).on_press(msg).into());
 
-        let seek_bar: Option<iced::Element<'a, Message, iced::Theme>> =
-            controls.on_seek.map(|on_seek| {
-                slider(0.0..=1.0, controls.state.progress(), on_seek)
-                    .step(0.01)
-                    .width(Length::Fill)
-                    .into()
-            });
+        let chapter = controls
+            .state
+            .current_chapter()
+            .filter(|_| controls.show_chapters)
+            .map(|chapter| chapter.title.as_str());
+        let seek_bar = seek_area(
+            chapter,
+            controls.state.progress(),
+            controls.on_seek,
+            controls.on_seek_release,
+        );
 
         let time_display: Option<iced::Element<'a, Message, iced::Theme>> = if controls.show_time {
             Some(
@@ -455,6 +531,212 @@ where
     }
 }
 
+/// The seek bar, with the title of the current chapter above it.
+fn seek_area<'a, Message: Clone + 'a>(
+    chapter: Option<&'a str>,
+    progress: f32,
+    on_seek: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_seek_release: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+) -> Option<iced::Element<'a, Message, iced::Theme>> {
+    use iced::widget::{column, slider, text};
+    use iced::Length;
+
+    let chapter = chapter.map(|title| text(title).size(12));
+    let Some(on_seek) = on_seek else {
+        return chapter.map(Into::into);
+    };
+    let slider = slider(0.0..=1.0, progress, on_seek)
+        .step(SEEK_STEP)
+        .width(Length::Fill);
+    let slider: iced::Element<'a, Message, iced::Theme> = match on_seek_release {
+        Some(on_release) => SeekBar::new(slider, on_release).into(),
+        None => slider.into(),
+    };
+
+    Some(match chapter {
+        Some(chapter) => column![chapter, slider]
+            .spacing(4)
+            .width(Length::Fill)
+            .into(),
+        None => slider,
+    })
+}
+
+/// The step of the seek bar, as a fraction of the duration.
+const SEEK_STEP: f32 = 0.01;
+
+/// A seek slider that also reports where a drag ended.
+///
+/// iced's `slider` only has a fixed `on_release` message, so this wrapper
+/// follows the drag itself and publishes the progress under the pointer
+/// when it is released, rounded to the slider's step.
+struct SeekBar<'a, Message, Theme, Renderer> {
+    slider: iced::Element<'a, Message, Theme, Renderer>,
+    on_release: Box<dyn Fn(f32) -> Message + 'a>,
+}
+
+/// The progress under the pointer during a drag; `None` when idle.
+#[derive(Debug, Clone, Copy, Default)]
+struct Scrub {
+    progress: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> SeekBar<'a, Message, Theme, Renderer> {
+    fn new(
+        slider: impl Into<iced::Element<'a, Message, Theme, Renderer>>,
+        on_release: Box<dyn Fn(f32) -> Message + 'a>,
+    ) -> Self {
+        Self {
+            slider: slider.into(),
+            on_release,
+        }
+    }
+}
+
+/// The progress at `x` along a seek bar spanning `bounds`.
+fn progress_at(bounds: iced::Rectangle, x: f32) -> f32 {
+    let fraction = ((x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+
+    ((fraction / SEEK_STEP).round() * SEEK_STEP).min(1.0)
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SeekBar<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> iced::Size<iced::Length> {
+        self.slider.as_widget().size()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Scrub>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Scrub::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.slider)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.slider));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.slider
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: Layout<'_>,
+        cursor: iced::mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &iced::Rectangle,
+    ) -> iced::event::Status {
+        use iced::{mouse, touch, Event};
+
+        let bounds = layout.bounds();
+        let scrub = tree.state.downcast_mut::<Scrub>();
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    scrub.progress = Some(progress_at(bounds, position.x));
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(progress), Some(position)) = (&mut scrub.progress, cursor.position()) {
+                    *progress = progress_at(bounds, position.x);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some(progress) = scrub.progress.take() {
+                    shell.publish((self.on_release)(progress));
+                }
+            }
+            _ => {}
+        }
+
+        self.slider.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: iced::mouse::Cursor,
+        viewport: &iced::Rectangle,
+        renderer: &Renderer,
+    ) -> iced::mouse::Interaction {
+        self.slider.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: iced::mouse::Cursor,
+        viewport: &iced::Rectangle,
+    ) {
+        self.slider.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SeekBar<'a, Message, Theme, Renderer>>
+    for iced::Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(seek_bar: SeekBar<'a, Message, Theme, Renderer>) -> Self {
+        iced::Element::new(seek_bar)
+    }
+}
+
 /// Recording state.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum RecordingState {
@@ -1165,6 +1447,16 @@ where
         self
     }
 
+    /// Set the callback for the end of a drag on the seek bar.
+    #[must_use]
+    pub fn on_seek_release<F>(mut self, f: F) -> Self
+    where
+        F: Fn(f32) -> Message + 'a,
+    {
+        self.audio = self.audio.on_seek_release(f);
+        self
+    }
+
     /// Set the volume callback.
     #[must_use]
     pub fn on_volume<F>(mut self, f: F) -> Self
@@ -1212,3 +1504,163 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn podcast() -> MediaPlayerState {
+        let mut player = MediaPlayerState::new();
+        player.set_duration(Duration::from_secs(3600));
+        player.add_chapter("Interview", Duration::from_secs(600));
+        player.add_chapter("Intro", Duration::from_secs(60));
+        player.add_chapter("Listener questions", Duration::from_secs(2400));
+        player
+    }
+
+    fn chapter_at(player: &mut MediaPlayerState, secs: u64) -> Option<&str> {
+        player.seek(Duration::from_secs(secs));
+        player
+            .current_chapter()
+            .map(|chapter| chapter.title.as_str())
+    }
+
+    #[test]
+    fn chapters_stay_ordered_by_start() {
+        let titles: Vec<_> = podcast()
+            .chapters
+            .iter()
+            .map(|chapter| chapter.title.clone())
+            .collect();
+
+        assert_eq!(titles, ["Intro", "Interview", "Listener questions"]);
+    }
+
+    #[test]
+    fn current_chapter_at_boundaries() {
+        let mut player = podcast();
+
+        assert_eq!(chapter_at(&mut player, 0), None);
+        assert_eq!(chapter_at(&mut player, 59), None);
+        assert_eq!(chapter_at(&mut player, 60), Some("Intro"));
+        assert_eq!(chapter_at(&mut player, 599), Some("Intro"));
+        assert_eq!(chapter_at(&mut player, 600), Some("Interview"));
+        assert_eq!(chapter_at(&mut player, 3600), Some("Listener questions"));
+        assert!(MediaPlayerState::new().current_chapter().is_none());
+    }
+
+    #[test]
+    fn next_chapter_start_skips_the_current_one() {
+        let mut player = podcast();
+
+        assert_eq!(player.next_chapter_start(), Some(Duration::from_secs(60)));
+
+        player.seek(Duration::from_secs(600));
+        assert_eq!(player.next_chapter_start(), Some(Duration::from_secs(2400)));
+
+        player.seek(Duration::from_secs(2400));
+        assert_eq!(player.next_chapter_start(), None);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Scrubbed {
+        Preview(f32),
+        Commit(f32),
+    }
+
+    /// Send pointer `events` at their x positions to a 200 by 20 seek bar.
+    fn scrub(events: &[(iced::Event, f32)]) -> Vec<Scrubbed> {
+        use iced::advanced::clipboard;
+        use iced::{mouse, Point, Rectangle, Size};
+
+        let slider = iced::widget::slider(0.0..=1.0, 0.0, Scrubbed::Preview).step(SEEK_STEP);
+        let mut seek_bar: SeekBar<'_, Scrubbed, iced::Theme, ()> =
+            SeekBar::new(slider, Box::new(Scrubbed::Commit));
+        let mut tree = Tree::new(&seek_bar as &dyn Widget<Scrubbed, iced::Theme, ()>);
+        let node = seek_bar.layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 20.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for (event, x) in events {
+            let _ = seek_bar.on_event(
+                &mut tree,
+                event.clone(),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(*x, 10.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(200.0, 20.0)),
+            );
+        }
+
+        messages
+    }
+
+    fn commits(messages: &[Scrubbed]) -> Vec<f32> {
+        messages
+            .iter()
+            .filter_map(|message| match message {
+                Scrubbed::Commit(progress) => Some(*progress),
+                Scrubbed::Preview(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn seek_release_fires_once_on_mouse_up() {
+        use iced::mouse::{Button, Event as MouseEvent};
+        use iced::{Event, Point};
+
+        let moved = |x| {
+            Event::Mouse(MouseEvent::CursorMoved {
+                position: Point::new(x, 10.0),
+            })
+        };
+        let messages = scrub(&[
+            (Event::Mouse(MouseEvent::ButtonPressed(Button::Left)), 50.0),
+            (moved(100.0), 100.0),
+            (moved(150.0), 150.0),
+            (
+                Event::Mouse(MouseEvent::ButtonReleased(Button::Left)),
+                150.0,
+            ),
+            (
+                Event::Mouse(MouseEvent::ButtonReleased(Button::Left)),
+                150.0,
+            ),
+        ]);
+
+        // Every step of the drag previews, as without a release callback.
+        let previews = messages
+            .iter()
+            .filter(|message| matches!(message, Scrubbed::Preview(_)))
+            .count();
+        assert_eq!(previews, 3);
+
+        let commits = commits(&messages);
+        assert_eq!(commits.len(), 1);
+        assert!((commits[0] - 0.75).abs() < 1e-6);
+        assert_eq!(messages.last(), Some(&Scrubbed::Commit(commits[0])));
+    }
+
+    #[test]
+    fn seek_release_ignores_presses_outside_the_bar() {
+        use iced::mouse::{Button, Event as MouseEvent};
+        use iced::Event;
+
+        let messages = scrub(&[
+            (Event::Mouse(MouseEvent::ButtonPressed(Button::Left)), 250.0),
+            (
+                Event::Mouse(MouseEvent::ButtonReleased(Button::Left)),
+                100.0,
+            ),
+        ]);
+
+        assert!(messages.is_empty());
+    }
+}
@@ -1,6 +1,26 @@
 //! Menu and context menu components.
 
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::ops::Range;
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::widget::{button, container, horizontal_rule, row, scrollable, text, Space};
+use iced::{event, mouse, Element, Event, Length, Point, Rectangle, Size, Task};
+
+use crate::dropdown::{DEFAULT_MAX_HEIGHT, DEFAULT_OPTION_HEIGHT};
+
+/// Height of a separator row in a rendered menu.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// Width of the leading checkmark slot of selectable items.
+const CHECK_WIDTH: f32 = 16.0;
+
+/// Glyph marking the selected item.
+const CHECK_GLYPH: &str = "✓";
 
 /// A menu item.
 #[derive(Clone)]
@@ -17,6 +37,10 @@ pub enum MenuItem<'a, Message> {
         enabled: bool,
         /// Optional keyboard shortcut hint.
         shortcut: Option<Cow<'a, str>>,
+        /// Whether the item performs a destructive action.
+        destructive: bool,
+        /// Checkmark state, or `None` for items without a checkmark slot.
+        selected: Option<bool>,
     },
     /// Separator line.
     Separator,
@@ -38,6 +62,8 @@ impl<'a, Message> MenuItem<'a, Message> {
             on_click,
             enabled: true,
             shortcut: None,
+            destructive: false,
+            selected: None,
         }
     }
 
@@ -78,6 +104,35 @@ impl<'a, Message> MenuItem<'a, Message> {
         self
     }
 
+    /// Style the item as a destructive action (e.g. "Delete").
+    #[must_use]
+    pub fn destructive(mut self) -> Self {
+        if let Self::Item {
+            destructive: ref mut d,
+            ..
+        } = self
+        {
+            *d = true;
+        }
+        self
+    }
+
+    /// Show a leading checkmark when `selected`, for radio-style choices
+    /// such as the current zoom level.
+    ///
+    /// Unselected items keep the checkmark's space so labels stay aligned.
+    #[must_use]
+    pub fn selected(mut self, selected: bool) -> Self {
+        if let Self::Item {
+            selected: ref mut s,
+            ..
+        } = self
+        {
+            *s = Some(selected);
+        }
+        self
+    }
+
     /// Add a shortcut hint.
     #[must_use]
     pub fn shortcut(mut self, shortcut: impl Into<Cow<'a, str>>) -> Self {
@@ -92,17 +147,105 @@ impl<'a, Message> MenuItem<'a, Message> {
     }
 }
 
+impl<Message> MenuItem<'_, Message> {
+    const fn height(&self) -> f32 {
+        match self {
+            Self::Separator => SEPARATOR_HEIGHT,
+            Self::Item { .. } | Self::Submenu { .. } => DEFAULT_OPTION_HEIGHT,
+        }
+    }
+}
+
 /// A menu definition.
+///
+/// Rendered menus are capped at [`DEFAULT_MAX_HEIGHT`] and scroll when their
+/// items overflow it. Only the items in view are built into rows, so long
+/// menus stay fast.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Menu::new()
+///     .push("Open", Message::Open)
+///     .push("Save", Message::Save)
+///     .separator()
+///     .push("Quit", Message::Quit)
+///     .max_height(240.0)
+/// ```
 pub struct Menu<'a, Message> {
     /// Menu items.
     pub items: Vec<MenuItem<'a, Message>>,
+    max_height: f32,
+    highlighted: Option<usize>,
+    id: Option<scrollable::Id>,
 }
 
 impl<'a, Message> Menu<'a, Message> {
     /// Create a new empty menu.
     #[must_use]
     pub fn new() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: Vec::new(),
+            max_height: DEFAULT_MAX_HEIGHT,
+            highlighted: None,
+            id: None,
+        }
+    }
+
+    /// Set the maximum height before the menu scrolls.
+    #[must_use]
+    pub const fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Set the highlighted item index.
+    #[must_use]
+    pub const fn highlighted(mut self, index: Option<usize>) -> Self {
+        self.highlighted = index;
+        self
+    }
+
+    /// Set the id of the menu's scrollable, used by [`Menu::reveal_highlighted`].
+    #[must_use]
+    pub fn id(mut self, id: scrollable::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Total height of all items laid out end to end.
+    #[must_use]
+    pub fn content_height(&self) -> f32 {
+        self.items.iter().map(MenuItem::height).sum()
+    }
+
+    /// Whether the items overflow the maximum height.
+    #[must_use]
+    pub fn is_scrollable(&self) -> bool {
+        self.content_height() > self.max_height
+    }
+
+    /// Scroll offset that centers the highlighted item in the menu.
+    #[must_use]
+    pub fn highlighted_offset(&self) -> Option<f32> {
+        let index = self.highlighted.filter(|&index| index < self.items.len())?;
+        let top: f32 = self.items[..index].iter().map(MenuItem::height).sum();
+        let viewport = self.content_height().min(self.max_height);
+        let max_offset = self.content_height() - viewport;
+
+        Some((top + self.items[index].height() / 2.0 - viewport / 2.0).clamp(0.0, max_offset))
+    }
+
+    /// Scroll the menu so the highlighted item is visible.
+    ///
+    /// Requires an [`id`](Menu::id); returns [`Task::none`] otherwise.
+    pub fn reveal_highlighted<T>(&self) -> Task<T> {
+        match (&self.id, self.highlighted_offset()) {
+            (Some(id), Some(y)) => {
+                scrollable::scroll_to(id.clone(), scrollable::AbsoluteOffset { x: 0.0, y })
+            }
+            _ => Task::none(),
+        }
     }
 
     /// Add an item to the menu.
@@ -144,6 +287,339 @@ impl<'a, Message> Default for Menu<'a, Message> {
     }
 }
 
+/// Text of the checkmark slot.
+const fn check_glyph(selected: bool) -> &'static str {
+    if selected {
+        CHECK_GLYPH
+    } else {
+        ""
+    }
+}
+
+/// Leading slot of a selectable item: a checkmark when selected, blank
+/// space of the same width otherwise.
+fn check_slot<'a, Renderer>(selected: bool) -> text::Text<'a, iced::Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+{
+    text(check_glyph(selected))
+        .size(14)
+        .width(Length::Fixed(CHECK_WIDTH))
+}
+
+/// Style of a clickable menu item.
+///
+/// Destructive items use the theme's danger color and fill with it on hover,
+/// matching the destructive button variant.
+fn item_style(
+    theme: &iced::Theme,
+    status: button::Status,
+    destructive: bool,
+    highlighted: bool,
+) -> button::Style {
+    if destructive {
+        let danger = theme.extended_palette().danger;
+
+        match status {
+            button::Status::Hovered | button::Status::Pressed => button::Style {
+                background: Some(danger.base.color.into()),
+                text_color: danger.base.text,
+                ..button::text(theme, status)
+            },
+            _ if highlighted => button::Style {
+                background: Some(danger.weak.color.into()),
+                text_color: danger.weak.text,
+                ..button::text(theme, status)
+            },
+            _ => button::Style {
+                text_color: danger.base.color,
+                ..button::text(theme, status)
+            },
+        }
+    } else if highlighted {
+        button::secondary(theme, status)
+    } else {
+        button::text(theme, status)
+    }
+}
+
+/// The row of a single menu item.
+fn item_view<'a, Message, Renderer>(
+    item: &MenuItem<'a, Message>,
+    highlighted: bool,
+) -> Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    match item {
+        MenuItem::Item {
+            label,
+            icon,
+            on_click,
+            enabled,
+            shortcut,
+            destructive,
+            selected,
+        } => {
+            let destructive = *destructive;
+            let mut content = row![].spacing(8).align_y(iced::Alignment::Center);
+            if let Some(selected) = *selected {
+                content = content.push(check_slot(selected));
+            }
+            if let Some(icon) = icon {
+                content = content.push(text(icon.clone()).size(14));
+            }
+            content = content
+                .push(text(label.clone()).size(14))
+                .push(Space::with_width(Length::Fill));
+            if let Some(shortcut) = shortcut {
+                content = content.push(text(shortcut.clone()).size(12).style(text::secondary));
+            }
+
+            button(content)
+                .on_press_maybe(enabled.then(|| on_click.clone()))
+                .width(Length::Fill)
+                .height(Length::Fixed(DEFAULT_OPTION_HEIGHT))
+                .padding([6, 12])
+                .style(move |theme, status| item_style(theme, status, destructive, highlighted))
+                .into()
+        }
+        MenuItem::Separator => container(horizontal_rule(1))
+            .height(Length::Fixed(SEPARATOR_HEIGHT))
+            .center_y(Length::Fixed(SEPARATOR_HEIGHT))
+            .into(),
+        MenuItem::Submenu { label, .. } => container(
+            row![
+                text(label.clone()).size(14),
+                Space::with_width(Length::Fill),
+                text("›").size(14),
+            ]
+            .align_y(iced::Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fixed(DEFAULT_OPTION_HEIGHT))
+        .padding([6, 12])
+        .into(),
+    }
+}
+
+/// The items of a rendered menu, built into rows only while in view.
+///
+/// The scrollable around it sees one node as tall as all the items; each
+/// layout pass for drawing or events builds just the rows inside the
+/// scrollable's viewport, so menus of thousands of items stay fast.
+struct MenuRows<'a, Message> {
+    items: Vec<MenuItem<'a, Message>>,
+    highlighted: Option<usize>,
+    /// Top of each item, followed by the content height.
+    tops: Vec<f32>,
+}
+
+/// Trees of the rows built for the last pass, by item index.
+#[derive(Default)]
+struct RowTrees(RefCell<Vec<(usize, Tree)>>);
+
+impl<'a, Message> MenuRows<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn new(items: Vec<MenuItem<'a, Message>>, highlighted: Option<usize>) -> Self {
+        let tops = std::iter::once(0.0)
+            .chain(items.iter().scan(0.0, |top, item| {
+                *top += item.height();
+                Some(*top)
+            }))
+            .collect();
+
+        Self {
+            items,
+            highlighted,
+            tops,
+        }
+    }
+
+    /// Indices of the items intersecting `viewport`, for rows laid out
+    /// from the top of `bounds`.
+    fn visible(&self, bounds: Rectangle, viewport: &Rectangle) -> Range<usize> {
+        let top = viewport.y - bounds.y;
+        let bottom = top + viewport.height;
+        let count = self.items.len();
+
+        let start = self.tops[1..].partition_point(|&end| end <= top);
+        let end = self.tops[..count].partition_point(|&start| start < bottom);
+
+        start..end.max(start)
+    }
+
+    /// Build and lay out the rows in view, keeping the trees of rows that
+    /// stay in view and dropping the rest.
+    ///
+    /// `trees` ends up holding one tree per returned row, in order.
+    fn build<Renderer>(
+        &self,
+        trees: &mut Vec<(usize, Tree)>,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Vec<(Element<'a, Message, iced::Theme, Renderer>, Node)>
+    where
+        Renderer: iced::advanced::text::Renderer + 'a,
+    {
+        let mut previous = std::mem::take(trees);
+
+        self.visible(bounds, viewport)
+            .map(|index| {
+                let element = item_view(&self.items[index], self.highlighted == Some(index));
+                let mut tree = previous
+                    .iter()
+                    .position(|(built, _)| *built == index)
+                    .map_or_else(
+                        || Tree::new(&element),
+                        |position| previous.swap_remove(position).1,
+                    );
+                tree.diff(&element);
+
+                let height = self.tops[index + 1] - self.tops[index];
+                let limits = Limits::new(Size::ZERO, Size::new(bounds.width, height));
+                let node = element
+                    .as_widget()
+                    .layout(&mut tree, renderer, &limits)
+                    .move_to(Point::new(bounds.x, bounds.y + self.tops[index]));
+
+                trees.push((index, tree));
+                (element, node)
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, iced::Theme, Renderer> for MenuRows<'a, Message>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<RowTrees>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(RowTrees::default())
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height = self.tops[self.items.len()];
+
+        Node::new(limits.resolve(Length::Fill, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let trees = tree.state.downcast_mut::<RowTrees>().0.get_mut();
+        let mut rows = self.build(trees, renderer, layout.bounds(), viewport);
+
+        rows.iter_mut()
+            .zip(trees.iter_mut())
+            .map(|((element, node), (_, tree))| {
+                element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    Layout::new(node),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut trees = tree.state.downcast_ref::<RowTrees>().0.borrow_mut();
+        let rows = self.build(&mut trees, renderer, layout.bounds(), viewport);
+
+        rows.iter()
+            .zip(trees.iter())
+            .map(|((element, node), (_, tree))| {
+                element.as_widget().mouse_interaction(
+                    tree,
+                    Layout::new(node),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &iced::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut trees = tree.state.downcast_ref::<RowTrees>().0.borrow_mut();
+        let rows = self.build(&mut trees, renderer, layout.bounds(), viewport);
+
+        for ((element, node), (_, tree)) in rows.iter().zip(trees.iter()) {
+            element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                Layout::new(node),
+                cursor,
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Menu<'a, Message>> for Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn from(menu: Menu<'a, Message>) -> Self {
+        let mut list = scrollable(Element::new(MenuRows::new(menu.items, menu.highlighted)));
+        if let Some(id) = menu.id {
+            list = list.id(id);
+        }
+
+        container(list)
+            .max_height(menu.max_height)
+            .width(Length::Fill)
+            .style(container::bordered_box)
+            .into()
+    }
+}
+
 /// Menu bar containing multiple menus.
 pub struct MenuBar<'a, Message> {
     /// Named menus in the bar.
@@ -170,3 +646,105 @@ impl<'a, Message> Default for MenuBar<'a, Message> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::interact;
+    use crate::test_support::Recorder;
+    use iced::Color;
+
+    fn long_menu() -> Menu<'static, usize> {
+        (0..5_000).fold(Menu::new(), |menu, index| {
+            menu.push(format!("Item {index}"), index)
+        })
+    }
+
+    #[test]
+    fn long_menus_only_build_the_rows_in_view() {
+        let viewport = Size::new(400.0, 600.0);
+        let element: Element<'_, usize, iced::Theme, Recorder> = long_menu().into();
+        let mut tree = Tree::new(&element);
+        let mut recorder = Recorder::default();
+        let node =
+            element
+                .as_widget()
+                .layout(&mut tree, &recorder, &Limits::new(Size::ZERO, viewport));
+
+        element.as_widget().draw(
+            &tree,
+            &mut recorder,
+            &iced::Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(viewport),
+        );
+
+        // One label per item row: the 300px menu shows at most 11 rows of
+        // 32px, counting a partly visible row at each edge.
+        assert!(!recorder.paragraphs.is_empty());
+        assert!(recorder.paragraphs.len() <= 11);
+    }
+
+    #[test]
+    fn rows_in_view_receive_clicks() {
+        // The test viewport is 100px tall, so its center is on the second row.
+        assert_eq!(interact::<_, iced::Theme>(long_menu().into()), [1]);
+    }
+
+    #[test]
+    fn destructive_item_uses_danger_color() {
+        let theme = iced::Theme::Light;
+        let danger = theme.extended_palette().danger;
+
+        let normal = item_style(&theme, button::Status::Active, false, false);
+        let destructive = item_style(&theme, button::Status::Active, true, false);
+
+        assert_eq!(destructive.text_color, danger.base.color);
+        assert_ne!(destructive.text_color, normal.text_color);
+
+        let hovered = item_style(&theme, button::Status::Hovered, true, false);
+        assert_eq!(hovered.background, Some(danger.base.color.into()));
+    }
+
+    #[test]
+    fn selected_item_shows_check_in_reserved_slot() {
+        let item = MenuItem::new("100%", ()).selected(true);
+        assert!(matches!(
+            item,
+            MenuItem::Item {
+                selected: Some(true),
+                ..
+            }
+        ));
+        assert!(matches!(
+            MenuItem::new("150%", ()).selected(false),
+            MenuItem::Item {
+                selected: Some(false),
+                ..
+            }
+        ));
+
+        let width = |selected: bool| {
+            let slot = check_slot::<()>(selected);
+            let slot = &slot as &dyn Widget<(), iced::Theme, ()>;
+            let mut tree = Tree::new(slot);
+            slot.layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+            )
+            .size()
+            .width
+        };
+
+        assert_eq!(check_glyph(true), CHECK_GLYPH);
+        assert_eq!(check_glyph(false), "");
+        // Both reserve the same leading space, so labels line up.
+        assert!((width(true) - CHECK_WIDTH).abs() < f32::EPSILON);
+        assert!((width(false) - CHECK_WIDTH).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,331 @@
+//! Notification center: a persistent, dismissible list of alerts.
+
+use std::cmp::Reverse;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iced::widget::{button, column, container, row, scrollable, text, Space};
+use iced::{Alignment, Element, Length, Theme};
+
+use crate::alert::{Alert, AlertType};
+use crate::divider::Divider;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A notification kept in a [`NotificationCenter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// Optional bold title.
+    pub title: Option<String>,
+    /// Body text.
+    pub message: String,
+    /// Severity, which sets the alert colors.
+    pub alert_type: AlertType,
+    /// When the notification arrived.
+    pub time: SystemTime,
+}
+
+impl Notification {
+    /// Create a notification that arrived at `time`.
+    #[must_use]
+    pub fn new(alert_type: AlertType, message: impl Into<String>, time: SystemTime) -> Self {
+        Self {
+            title: None,
+            message: message.into(),
+            alert_type,
+            time,
+        }
+    }
+
+    /// Set the title.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+/// Identifies a notification in a [`NotificationCenter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotificationId(u64);
+
+/// Notifications arriving on the same day, newest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationGroup<'a> {
+    /// "Today", "Yesterday" or the date as `YYYY-MM-DD`.
+    pub label: String,
+    /// The day's notifications.
+    pub notifications: Vec<(NotificationId, &'a Notification)>,
+}
+
+/// State of a bell-icon notification panel.
+///
+/// Unlike toasts, notifications stay until the user dismisses them. The app
+/// owns the center, pushes notifications into it and renders it with
+/// [`view`](Self::view), which groups them under day headers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // In update:
+/// self.notifications.push(
+///     Notification::new(AlertType::Success, "Backup finished", SystemTime::now()),
+/// );
+/// Message::Dismiss(id) => { self.notifications.dismiss(id); }
+/// Message::ClearAll => self.notifications.clear_all(),
+///
+/// // In view:
+/// self.notifications
+///     .view(SystemTime::now(), Message::Dismiss, Message::ClearAll)
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NotificationCenter {
+    notifications: Vec<(NotificationId, Notification)>,
+    next_id: u64,
+    utc_offset: i64,
+}
+
+impl NotificationCenter {
+    /// Create an empty notification center.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Offset of the user's time zone from UTC, in seconds, so days start
+    /// at local midnight. Defaults to UTC.
+    #[must_use]
+    pub const fn utc_offset(mut self, seconds: i64) -> Self {
+        self.utc_offset = seconds;
+        self
+    }
+
+    /// Add a notification and return its id.
+    pub fn push(&mut self, notification: Notification) -> NotificationId {
+        let id = NotificationId(self.next_id);
+        self.next_id += 1;
+        self.notifications.push((id, notification));
+        id
+    }
+
+    /// Remove a notification. Returns whether it was present.
+    pub fn dismiss(&mut self, id: NotificationId) -> bool {
+        let before = self.notifications.len();
+        self.notifications.retain(|(other, _)| *other != id);
+        self.notifications.len() < before
+    }
+
+    /// Remove every notification.
+    pub fn clear_all(&mut self) {
+        self.notifications.clear();
+    }
+
+    /// Number of notifications.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.notifications.len()
+    }
+
+    /// Whether there are no notifications.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    /// Notifications grouped by day, newest day first.
+    #[must_use]
+    pub fn groups(&self, now: SystemTime) -> Vec<NotificationGroup<'_>> {
+        let today = self.day(now);
+        let mut sorted: Vec<_> = self
+            .notifications
+            .iter()
+            .map(|(id, notification)| (*id, notification))
+            .collect();
+        sorted.sort_by_key(|(_, notification)| Reverse(notification.time));
+
+        let mut groups: Vec<(i64, NotificationGroup<'_>)> = Vec::new();
+        for (id, notification) in sorted {
+            let day = self.day(notification.time);
+
+            match groups.last_mut() {
+                Some((last, group)) if *last == day => group.notifications.push((id, notification)),
+                _ => groups.push((
+                    day,
+                    NotificationGroup {
+                        label: day_label(day, today),
+                        notifications: vec![(id, notification)],
+                    },
+                )),
+            }
+        }
+
+        groups.into_iter().map(|(_, group)| group).collect()
+    }
+
+    /// Render the panel: a header with "Clear all" and the grouped,
+    /// scrollable list of dismissible alerts.
+    pub fn view<'a, Message>(
+        &'a self,
+        now: SystemTime,
+        on_dismiss: impl Fn(NotificationId) -> Message + 'a,
+        on_clear_all: Message,
+    ) -> Element<'a, Message, Theme>
+    where
+        Message: Clone + 'a,
+    {
+        let header = row![
+            text("Notifications").size(16),
+            Space::with_width(Length::Fill),
+            button(text("Clear all").size(13))
+                .style(button::text)
+                .on_press_maybe((!self.is_empty()).then_some(on_clear_all)),
+        ]
+        .align_y(Alignment::Center);
+
+        let body: Element<'a, Message, Theme> = if self.is_empty() {
+            container(text("No notifications").size(13))
+                .padding(24)
+                .center_x(Length::Fill)
+                .into()
+        } else {
+            let mut list = column![].spacing(8);
+            for group in self.groups(now) {
+                list = list.push(Divider::with_label(group.label));
+                for (id, notification) in group.notifications {
+                    let mut alert = Alert::new(notification.message.as_str())
+                        .alert_type(notification.alert_type)
+                        .on_dismiss(on_dismiss(id));
+                    if let Some(title) = &notification.title {
+                        alert = alert.title(title.as_str());
+                    }
+                    list = list.push(alert);
+                }
+            }
+            scrollable(list).height(Length::Fill).into()
+        };
+
+        column![header, Divider::horizontal(), body]
+            .spacing(8)
+            .padding(12)
+            .into()
+    }
+
+    /// Days since the Unix epoch in the configured time zone.
+    fn day(&self, time: SystemTime) -> i64 {
+        let seconds = time.duration_since(UNIX_EPOCH).map_or(0, |since| {
+            i64::try_from(since.as_secs()).unwrap_or(i64::MAX)
+        });
+
+        seconds
+            .saturating_add(self.utc_offset)
+            .div_euclid(SECONDS_PER_DAY)
+    }
+}
+
+/// Header for a day, relative to today.
+fn day_label(day: i64, today: i64) -> String {
+    match today - day {
+        0 => String::from("Today"),
+        1 => String::from("Yesterday"),
+        _ => {
+            let (year, month, day) = civil_from_days(day);
+            format!("{year:04}-{month:02}-{day:02}")
+        }
+    }
+}
+
+/// Calendar date of a day number counted from 1970-01-01.
+///
+/// Howard Hinnant's `civil_from_days` algorithm for the proleptic
+/// Gregorian calendar.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// 2024-10-04 10:00 UTC.
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(20_000 * 86_400 + 10 * 3600)
+    }
+
+    fn hours_ago(hours: u64) -> SystemTime {
+        now() - Duration::from_secs(hours * 3600)
+    }
+
+    fn center() -> (NotificationCenter, Vec<NotificationId>) {
+        let mut center = NotificationCenter::new();
+        let ids = [
+            (AlertType::Info, "Deploy started", 1),
+            (AlertType::Success, "Deploy finished", 0),
+            (AlertType::Warning, "Disk almost full", 20),
+            (AlertType::Error, "Backup failed", 72),
+        ]
+        .into_iter()
+        .map(|(kind, message, hours)| {
+            center.push(Notification::new(kind, message, hours_ago(hours)))
+        })
+        .collect();
+
+        (center, ids)
+    }
+
+    fn messages<'a>(group: &NotificationGroup<'a>) -> Vec<&'a str> {
+        group
+            .notifications
+            .iter()
+            .map(|(_, notification)| notification.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn dismissing_removes_only_that_notification() {
+        let (mut center, ids) = center();
+
+        assert!(center.dismiss(ids[2]));
+        assert!(!center.dismiss(ids[2]));
+        assert_eq!(center.len(), 3);
+        assert!(center
+            .groups(now())
+            .iter()
+            .all(|group| !messages(group).contains(&"Disk almost full")));
+    }
+
+    #[test]
+    fn clear_all_empties_the_list() {
+        let (mut center, _) = center();
+
+        center.clear_all();
+
+        assert!(center.is_empty());
+        assert!(center.groups(now()).is_empty());
+    }
+
+    #[test]
+    fn notifications_group_under_day_headers() {
+        let (center, _) = center();
+        let groups = center.groups(now());
+
+        let labels: Vec<_> = groups.iter().map(|group| group.label.as_str()).collect();
+        assert_eq!(labels, ["Today", "Yesterday", "2024-10-01"]);
+        assert_eq!(messages(&groups[0]), ["Deploy finished", "Deploy started"]);
+        assert_eq!(messages(&groups[1]), ["Disk almost full"]);
+        assert_eq!(messages(&groups[2]), ["Backup failed"]);
+
+        // At UTC+11 it is 21:00, so 20 hours ago was still today.
+        let local = center.clone().utc_offset(11 * 3600);
+        assert_eq!(messages(&local.groups(now())[0]).len(), 3);
+    }
+}
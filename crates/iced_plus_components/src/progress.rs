@@ -1,9 +1,21 @@
 //! Progress bar and indicators.
 
+use std::borrow::Cow;
+
 use iced::advanced::layout::{Layout, Limits, Node};
 use iced::advanced::renderer;
+use iced::advanced::text::{self, Paragraph as _};
 use iced::advanced::widget::{tree, Tree, Widget};
-use iced::{mouse, Color, Element, Length, Rectangle, Size};
+use iced::theme::palette;
+use iced::{alignment, border, mouse, Color, Element, Length, Pixels, Point, Rectangle, Size};
+
+use crate::format;
+
+/// Font size of progress labels.
+const LABEL_SIZE: f32 = 12.0;
+
+/// Gap between the bar and a label on its right.
+const LABEL_SPACING: f32 = 8.0;
 
 /// Progress bar variant.
 #[derive(Debug, Clone, Copy, Default)]
@@ -30,6 +42,43 @@ impl ProgressVariant {
     }
 }
 
+/// Text of a progress label.
+fn label_paragraph<C>(
+    content: C,
+    bounds: Size,
+    horizontal_alignment: alignment::Horizontal,
+) -> text::Text<C> {
+    text::Text {
+        content,
+        bounds,
+        size: Pixels(LABEL_SIZE),
+        line_height: text::LineHeight::default(),
+        font: iced::Font::default(),
+        horizontal_alignment,
+        vertical_alignment: alignment::Vertical::Center,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::None,
+    }
+}
+
+/// Text shown with a [`Progress`] bar.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProgressLabel {
+    /// No text.
+    #[default]
+    None,
+    /// The percentage, centered inside the bar.
+    ///
+    /// Where it overlaps the fill, the text switches to a color readable on
+    /// the fill. The bar needs a [`height`](Progress::height) of about 16 to
+    /// fit the text.
+    Inside,
+    /// The percentage, to the right of the bar.
+    Right,
+    /// Custom text to the right of the bar, e.g. `"3 of 12 files"`.
+    Custom(String),
+}
+
 /// A progress bar component.
 ///
 /// # Example
@@ -38,14 +87,29 @@ impl ProgressVariant {
 /// Progress::new(0.75) // 75% progress
 ///     .variant(ProgressVariant::Success)
 ///     .height(8.0)
+///
+/// // Disk usage: amber from 70%, red from 90%
+/// Progress::new(usage)
+///     .success()
+///     .thresholds(&[(0.7, ProgressVariant::Warning), (0.9, ProgressVariant::Error)])
+///
+/// // Storage by file type
+/// Progress::segments(&[(0.2, images), (0.3, video), (0.1, documents)])
+///     .legend(["Images", "Video", "Documents"])
+///
+/// // Upload with its percentage in the bar
+/// Progress::new(0.42).height(16.0).label(ProgressLabel::Inside)
 /// ```
 pub struct Progress {
     value: f32,
     variant: ProgressVariant,
+    thresholds: Vec<(f32, ProgressVariant)>,
+    segments: Vec<(f32, Color)>,
     height: f32,
     width: Length,
     track_color: Color,
     radius: f32,
+    label: ProgressLabel,
 }
 
 impl Progress {
@@ -55,13 +119,81 @@ impl Progress {
         Self {
             value: value.clamp(0.0, 1.0),
             variant: ProgressVariant::default(),
+            thresholds: Vec::new(),
+            segments: Vec::new(),
             height: 6.0,
             width: Length::Fill,
             track_color: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
             radius: 3.0,
+            label: ProgressLabel::None,
         }
     }
 
+    /// Create a stacked bar of contiguous colored segments, e.g. storage used
+    /// per file type.
+    ///
+    /// Each fraction is the share of the whole bar. Negative fractions count
+    /// as zero and segments are cut off once the total reaches 1.0.
+    #[must_use]
+    pub fn segments(segments: &[(f32, Color)]) -> Self {
+        let mut total = 0.0;
+        let segments: Vec<_> = segments
+            .iter()
+            .map(|&(fraction, color)| {
+                let fraction = fraction.max(0.0).min(1.0 - total);
+                total += fraction;
+                (fraction, color)
+            })
+            .collect();
+
+        Self {
+            segments,
+            ..Self::new(total)
+        }
+    }
+
+    /// Add a legend below the segments, one label per segment.
+    #[must_use]
+    pub fn legend<'a, L>(self, labels: impl IntoIterator<Item = L>) -> ProgressLegend<'a>
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        ProgressLegend {
+            progress: self,
+            labels: labels.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Fill rectangles within `bounds`: one per non-empty segment, or the
+    /// single value fill.
+    fn fills(&self, bounds: Rectangle) -> Vec<(Rectangle, Color)> {
+        if self.segments.is_empty() {
+            return vec![(
+                Rectangle {
+                    width: bounds.width * self.value,
+                    ..bounds
+                },
+                self.fill_color(),
+            )];
+        }
+
+        let mut start = 0.0;
+        self.segments
+            .iter()
+            .filter(|(fraction, _)| *fraction > 0.0)
+            .map(|&(fraction, color)| {
+                let end = start + fraction;
+                let fill = Rectangle {
+                    x: bounds.width.mul_add(start, bounds.x),
+                    width: bounds.width * (end - start),
+                    ..bounds
+                };
+                start = end;
+                (fill, color)
+            })
+            .collect()
+    }
+
     /// Set the progress variant.
     #[must_use]
     pub fn variant(mut self, variant: ProgressVariant) -> Self {
@@ -69,6 +201,33 @@ impl Progress {
         self
     }
 
+    /// Switch variant once the value reaches each threshold.
+    ///
+    /// The highest threshold at or below the value wins; below all of them
+    /// the bar uses its own [`variant`](Self::variant).
+    #[must_use]
+    pub fn thresholds(mut self, thresholds: &[(f32, ProgressVariant)]) -> Self {
+        self.thresholds = thresholds.to_vec();
+        self.thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self
+    }
+
+    /// The variant for the current value, taking thresholds into account.
+    #[must_use]
+    pub fn resolved_variant(&self) -> ProgressVariant {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(threshold, _)| self.value >= *threshold)
+            .map_or(self.variant, |&(_, variant)| variant)
+    }
+
+    /// The fill color for the current value.
+    #[must_use]
+    pub fn fill_color(&self) -> Color {
+        self.resolved_variant().color()
+    }
+
     /// Set the height.
     #[must_use]
     pub fn height(mut self, height: f32) -> Self {
@@ -91,6 +250,28 @@ impl Progress {
         self
     }
 
+    /// Set the text shown with the bar.
+    #[must_use]
+    pub fn label(mut self, label: ProgressLabel) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// The label text, e.g. `"75%"`, if a label is set.
+    #[must_use]
+    pub fn label_text(&self) -> Option<String> {
+        match &self.label {
+            ProgressLabel::None => None,
+            ProgressLabel::Inside | ProgressLabel::Right => Some(format::percent(self.value, 0)),
+            ProgressLabel::Custom(label) => Some(label.clone()),
+        }
+    }
+
+    /// Whether the label sits to the right of the bar.
+    const fn has_side_label(&self) -> bool {
+        matches!(self.label, ProgressLabel::Right | ProgressLabel::Custom(_))
+    }
+
     /// Use success variant.
     #[must_use]
     pub fn success(self) -> Self {
@@ -112,10 +293,14 @@ impl Progress {
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Progress
 where
-    Renderer: renderer::Renderer,
+    Renderer: text::Renderer<Font = iced::Font>,
 {
     fn size(&self) -> Size<Length> {
-        Size::new(self.width, Length::Fixed(self.height))
+        if self.has_side_label() {
+            Size::new(self.width, Length::Shrink)
+        } else {
+            Size::new(self.width, Length::Fixed(self.height))
+        }
     }
 
     fn tag(&self) -> tree::Tag {
@@ -140,7 +325,32 @@ where
             Length::FillPortion(_) => limits.max().width,
         };
 
-        Node::new(Size::new(width, self.height))
+        let label = match self.label_text() {
+            Some(label) if self.has_side_label() => label,
+            _ => return Node::new(Size::new(width, self.height)),
+        };
+
+        // The bar shrinks to make room for the label, centered beside it.
+        let label = Renderer::Paragraph::with_text(label_paragraph(
+            &*label,
+            Size::INFINITY,
+            alignment::Horizontal::Left,
+        ))
+        .min_bounds();
+        let height = self.height.max(label.height);
+        let bar_width = (width - label.width - LABEL_SPACING).max(0.0);
+
+        Node::with_children(
+            Size::new(width, height),
+            vec![
+                Node::new(Size::new(bar_width, self.height))
+                    .move_to(Point::new(0.0, (height - self.height) / 2.0)),
+                Node::new(label).move_to(Point::new(
+                    width - label.width,
+                    (height - label.height) / 2.0,
+                )),
+            ],
+        )
     }
 
     fn mouse_interaction(
@@ -159,12 +369,15 @@ where
         _tree: &Tree,
         renderer: &mut Renderer,
         _theme: &Theme,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let bounds = children
+            .next()
+            .map_or_else(|| layout.bounds(), |bar| bar.bounds());
 
         // Draw track (background)
         renderer.fill_quad(
@@ -179,24 +392,73 @@ where
             self.track_color,
         );
 
-        // Draw progress fill
+        // Draw progress fill, rounding only the outer ends of segments
         if self.value > 0.0 {
-            let fill_width = bounds.width * self.value;
-            let fill_bounds = Rectangle {
-                width: fill_width,
-                ..bounds
-            };
+            let fills = self.fills(bounds);
+            let last = fills.len() - 1;
+
+            for (index, (fill_bounds, color)) in fills.into_iter().enumerate() {
+                let mut radius = border::Radius::default();
+                if index == 0 {
+                    radius = radius.left(self.radius);
+                }
+                if index == last {
+                    radius = radius.right(self.radius);
+                }
 
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: fill_bounds,
-                    border: iced::Border {
-                        radius: self.radius.into(),
-                        ..Default::default()
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: fill_bounds,
+                        border: iced::Border {
+                            radius,
+                            ..Default::default()
+                        },
+                        shadow: iced::Shadow::default(),
                     },
-                    shadow: iced::Shadow::default(),
-                },
-                self.variant.color(),
+                    color,
+                );
+            }
+        }
+
+        let Some(label) = self.label_text() else {
+            return;
+        };
+
+        if let Some(side) = children.next() {
+            let side = side.bounds();
+            renderer.fill_text(
+                label_paragraph(label, side.size(), alignment::Horizontal::Left),
+                Point::new(side.x, side.center_y()),
+                style.text_color,
+                side,
+            );
+            return;
+        }
+
+        // Draw the label once per fill and once over the track, each clipped
+        // to its area, so the text changes color exactly at the fill edge.
+        let filled = bounds.width * self.value;
+        let track = Rectangle {
+            x: bounds.x + filled,
+            width: bounds.width - filled,
+            ..bounds
+        };
+        let fills = if self.value > 0.0 {
+            self.fills(bounds)
+        } else {
+            Vec::new()
+        };
+
+        for (clip, color) in fills
+            .into_iter()
+            .map(|(fill, color)| (fill, palette::Pair::new(color, Color::WHITE).text))
+            .chain(std::iter::once((track, style.text_color)))
+        {
+            renderer.fill_text(
+                label_paragraph(label.clone(), bounds.size(), alignment::Horizontal::Center),
+                bounds.center(),
+                color,
+                clip,
             );
         }
     }
@@ -204,9 +466,403 @@ where
 
 impl<'a, Message: 'a, Theme: 'a, Renderer> From<Progress> for Element<'a, Message, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
 {
     fn from(progress: Progress) -> Self {
         Element::new(progress)
     }
 }
+
+/// A segmented [`Progress`] with a legend below it; see
+/// [`Progress::legend`].
+pub struct ProgressLegend<'a> {
+    progress: Progress,
+    labels: Vec<Cow<'a, str>>,
+}
+
+impl<'a, Message: 'a> From<ProgressLegend<'a>> for Element<'a, Message, iced::Theme> {
+    fn from(legend: ProgressLegend<'a>) -> Self {
+        use iced::widget::{column, container, row, text, Space};
+        use iced::Alignment;
+
+        let entries: Vec<Element<'a, Message, iced::Theme>> = legend
+            .progress
+            .segments
+            .iter()
+            .zip(legend.labels)
+            .map(|(&(_, color), label)| {
+                let swatch = container(Space::new(8.0, 8.0)).style(move |_theme| {
+                    container::Style::default()
+                        .background(color)
+                        .border(border::rounded(4.0))
+                });
+
+                row![swatch, text(label).size(12)]
+                    .spacing(6)
+                    .align_y(Alignment::Center)
+                    .into()
+            })
+            .collect();
+
+        column![
+            legend.progress,
+            row(entries).spacing(16).align_y(Alignment::Center)
+        ]
+        .spacing(8)
+        .into()
+    }
+}
+
+/// State of a single step in a [`StepProgress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepState {
+    /// The step has been completed.
+    Completed,
+    /// The step is in progress.
+    Current,
+    /// The step has not been reached yet.
+    Upcoming,
+}
+
+impl StepState {
+    /// Whether the step marker shows a check icon instead of its number.
+    #[must_use]
+    pub const fn shows_check(self) -> bool {
+        matches!(self, Self::Completed)
+    }
+}
+
+/// A display-only indicator of progress through numbered steps.
+///
+/// Steps before `current_step` (zero-based) are marked completed with a check
+/// icon, the current step is highlighted and later steps are muted.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// StepProgress::new(1, 3).labels(["Account", "Profile", "Confirm"])
+/// ```
+pub struct StepProgress<'a> {
+    current_step: usize,
+    total_steps: usize,
+    labels: Vec<Cow<'a, str>>,
+    marker_size: f32,
+    width: Length,
+}
+
+impl<'a> StepProgress<'a> {
+    /// Create a step indicator at `current_step` (zero-based) of `total_steps`.
+    #[must_use]
+    pub const fn new(current_step: usize, total_steps: usize) -> Self {
+        Self {
+            current_step,
+            total_steps,
+            labels: Vec::new(),
+            marker_size: 28.0,
+            width: Length::Fill,
+        }
+    }
+
+    /// Set the step labels, shown below the markers.
+    #[must_use]
+    pub fn labels<L>(mut self, labels: impl IntoIterator<Item = L>) -> Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the diameter of the step markers.
+    #[must_use]
+    pub const fn marker_size(mut self, size: f32) -> Self {
+        self.marker_size = size;
+        self
+    }
+
+    /// Set the width.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// State of the step at `index` (zero-based).
+    #[must_use]
+    pub fn step_state(&self, index: usize) -> StepState {
+        match index.cmp(&self.current_step) {
+            std::cmp::Ordering::Less => StepState::Completed,
+            std::cmp::Ordering::Equal => StepState::Current,
+            std::cmp::Ordering::Greater => StepState::Upcoming,
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<StepProgress<'a>> for Element<'a, Message, iced::Theme> {
+    fn from(steps: StepProgress<'a>) -> Self {
+        use iced::widget::{column, container, row, text, Space};
+        use iced::Alignment;
+
+        let size = steps.marker_size;
+        let mut markers = row![].align_y(Alignment::Center).width(steps.width);
+        let mut labels = row![].width(steps.width);
+
+        for index in 0..steps.total_steps {
+            let state = steps.step_state(index);
+
+            let marker = step_marker(index, state, size);
+
+            if index > 0 {
+                let reached = state != StepState::Upcoming;
+                markers = markers.push(
+                    container(Space::new(Length::Fill, Length::Fixed(2.0))).style(
+                        move |theme: &iced::Theme| {
+                            let palette = theme.extended_palette();
+                            container::Style::default().background(if reached {
+                                palette.primary.base.color
+                            } else {
+                                palette.background.strong.color
+                            })
+                        },
+                    ),
+                );
+            }
+            markers = markers.push(marker);
+
+            if let Some(label) = steps.labels.get(index) {
+                labels = labels.push(
+                    text(label.clone())
+                        .size(12)
+                        .width(Length::Fill)
+                        .align_x(if index == 0 {
+                            iced::alignment::Horizontal::Left
+                        } else if index + 1 == steps.total_steps {
+                            iced::alignment::Horizontal::Right
+                        } else {
+                            iced::alignment::Horizontal::Center
+                        })
+                        .style(move |theme: &iced::Theme| {
+                            let palette = theme.extended_palette();
+                            text::Style {
+                                color: Some(match state {
+                                    StepState::Upcoming => palette.background.strong.color,
+                                    _ => palette.background.base.text,
+                                }),
+                            }
+                        }),
+                );
+            }
+        }
+
+        if steps.labels.is_empty() {
+            markers.into()
+        } else {
+            column![markers, labels].spacing(6).into()
+        }
+    }
+}
+
+/// The numbered circle for the step at `index`, or a check once it is done.
+fn step_marker<'a, Message: 'a>(
+    index: usize,
+    state: StepState,
+    size: f32,
+) -> Element<'a, Message, iced::Theme> {
+    use iced::widget::{container, text};
+    use iced::{Background, Border};
+
+    let content: Element<'a, Message, iced::Theme> = if state.shows_check() {
+        crate::icons::Icon::check()
+            .size(size * 0.5)
+            .color(Color::WHITE)
+            .into()
+    } else {
+        text((index + 1).to_string())
+            .size(size * 0.45)
+            .style(move |theme: &iced::Theme| {
+                let palette = theme.extended_palette();
+                text::Style {
+                    color: Some(match state {
+                        StepState::Current => palette.primary.base.text,
+                        _ => palette.background.strong.text,
+                    }),
+                }
+            })
+            .into()
+    };
+
+    container(content)
+        .center_x(Length::Fixed(size))
+        .center_y(Length::Fixed(size))
+        .style(move |theme: &iced::Theme| {
+            let palette = theme.extended_palette();
+            let (background, border) = match state {
+                StepState::Completed => (palette.primary.base.color, palette.primary.base.color),
+                StepState::Current => (palette.primary.base.color, palette.primary.weak.color),
+                StepState::Upcoming => (
+                    palette.background.weak.color,
+                    palette.background.strong.color,
+                ),
+            };
+
+            container::Style {
+                background: Some(Background::Color(background)),
+                border: Border {
+                    color: border,
+                    width: if state == StepState::Current {
+                        3.0
+                    } else {
+                        1.0
+                    },
+                    radius: (size / 2.0).into(),
+                },
+                ..container::Style::default()
+            }
+        })
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+
+    /// Draw `progress` 100 pixels wide.
+    fn draw(progress: &Progress) -> Recorder {
+        let widget = progress as &dyn Widget<(), iced::Theme, Recorder>;
+        let mut tree = Tree::new(widget);
+        let mut recorder = Recorder::default();
+        let node = widget.layout(
+            &mut tree,
+            &recorder,
+            &Limits::new(Size::ZERO, Size::new(100.0, 20.0)),
+        );
+
+        widget.draw(
+            &tree,
+            &mut recorder,
+            &iced::Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(100.0, 20.0)),
+        );
+
+        recorder
+    }
+
+    /// Draw `progress` 100 pixels wide and return its fills, without the track.
+    fn fills(progress: &Progress) -> Vec<(Rectangle, Color)> {
+        draw(progress)
+            .quads
+            .iter()
+            .skip(1)
+            .filter_map(|quad| Some((quad.bounds, quad.color?)))
+            .collect()
+    }
+
+    fn spans(fills: &[(Rectangle, Color)]) -> Vec<(f32, f32, Color)> {
+        fills
+            .iter()
+            .map(|(bounds, color)| (bounds.x.round(), bounds.width.round(), *color))
+            .collect()
+    }
+
+    #[test]
+    fn steps_before_current_are_completed() {
+        let steps = StepProgress::new(2, 4);
+
+        assert_eq!(steps.step_state(0), StepState::Completed);
+        assert!(steps.step_state(1).shows_check());
+        assert_eq!(steps.step_state(2), StepState::Current);
+        assert!(!steps.step_state(2).shows_check());
+        assert_eq!(steps.step_state(3), StepState::Upcoming);
+    }
+
+    #[test]
+    fn thresholds_pick_fill_color_by_value() {
+        let meter = |value| {
+            Progress::new(value).success().thresholds(&[
+                (0.9, ProgressVariant::Error),
+                (0.7, ProgressVariant::Warning),
+            ])
+        };
+
+        assert_eq!(meter(0.95).fill_color(), ProgressVariant::Error.color());
+        assert_eq!(meter(0.8).fill_color(), ProgressVariant::Warning.color());
+        assert_eq!(meter(0.5).fill_color(), ProgressVariant::Success.color());
+        assert_eq!(
+            Progress::new(0.5).fill_color(),
+            ProgressVariant::Default.color()
+        );
+    }
+
+    #[test]
+    fn segments_stack_at_cumulative_offsets() {
+        let (red, green, blue) = (
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.0, 1.0, 0.0),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        );
+        let progress = Progress::segments(&[(0.2, red), (0.3, green), (0.5, blue)]);
+
+        assert_eq!(
+            spans(&fills(&progress)),
+            [(0.0, 20.0, red), (20.0, 30.0, green), (50.0, 50.0, blue)]
+        );
+    }
+
+    #[test]
+    fn segments_over_one_are_clamped() {
+        let (red, green, blue) = (
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.0, 1.0, 0.0),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        );
+        let progress = Progress::segments(&[(0.6, red), (0.6, green), (0.3, blue)]);
+
+        // The second segment is cut at the end of the bar and the third is
+        // dropped.
+        assert_eq!(
+            spans(&fills(&progress)),
+            [(0.0, 60.0, red), (60.0, 40.0, green)]
+        );
+    }
+
+    #[test]
+    fn inside_label_contrasts_with_the_fill() {
+        let navy = Color::from_rgb(0.05, 0.1, 0.35);
+        let progress = Progress::segments(&[(0.5, navy)])
+            .height(16.0)
+            .label(ProgressLabel::Inside);
+
+        // White over the dark fill, the style's black over the track.
+        assert_eq!(
+            draw(&progress).texts,
+            [
+                ("50%".to_owned(), Color::WHITE),
+                ("50%".to_owned(), Color::BLACK)
+            ]
+        );
+    }
+
+    #[test]
+    fn right_label_shows_the_percentage_beside_the_bar() {
+        let progress = Progress::new(0.75).label(ProgressLabel::Right);
+        let recorder = draw(&progress);
+
+        assert_eq!(recorder.texts, [("75%".to_owned(), Color::BLACK)]);
+        // The bar gives up the label spacing; the label itself measures
+        // nothing in the recorder.
+        assert!((recorder.quads[0].bounds.width - (100.0 - LABEL_SPACING)).abs() < f32::EPSILON);
+        assert_eq!(
+            Progress::new(0.5)
+                .label(ProgressLabel::Custom("3 of 12 files".into()))
+                .label_text()
+                .as_deref(),
+            Some("3 of 12 files")
+        );
+    }
+}
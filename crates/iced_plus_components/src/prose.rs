@@ -0,0 +1,174 @@
+//! Long-form text container with a readable line length.
+
+use iced::advanced::renderer;
+use iced::widget::{column, container, Column};
+use iced::{Element, Length, Padding, Pixels};
+
+/// Width of one `ch` relative to the font size.
+///
+/// The width of "0" is font dependent; like CSS when it can't measure the
+/// font, this assumes half an em.
+const CH_WIDTH: f32 = 0.5;
+
+/// Maximum line length of a [`Prose`] block.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Measure {
+    /// Characters, resolved against the prose text size.
+    Ch(f32),
+    /// Pixels.
+    Px(f32),
+}
+
+impl Measure {
+    /// The measure in pixels for text of `text_size`.
+    #[must_use]
+    pub fn pixels(self, text_size: f32) -> f32 {
+        match self {
+            Self::Ch(ch) => ch * CH_WIDTH * text_size,
+            Self::Px(px) => px,
+        }
+    }
+}
+
+impl Default for Measure {
+    /// 70 characters, in the 65–75 range comfortable for body text.
+    fn default() -> Self {
+        Self::Ch(70.0)
+    }
+}
+
+/// A column of long-form content capped at a comfortable line length and
+/// centered in the available width.
+///
+/// On wide windows, paragraphs stop growing at the
+/// [`max_measure`](Self::max_measure) instead of stretching edge to edge.
+/// Children should fill the width they are given, as text does by default.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Prose::new()
+///     .push(Heading::h2("Release notes"))
+///     .push(text(&self.notes))
+///     .max_measure(Measure::Ch(65.0))
+/// ```
+pub struct Prose<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Column<'a, Message, Theme, Renderer>,
+    max_measure: Measure,
+    text_size: f32,
+    padding: Padding,
+}
+
+impl<'a, Message, Theme, Renderer> Prose<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Create an empty prose block.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            content: column![].spacing(12),
+            max_measure: Measure::default(),
+            text_size: 14.0,
+            padding: Padding::ZERO,
+        }
+    }
+
+    /// Add a child, e.g. a paragraph or heading.
+    #[must_use]
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.content = self.content.push(child);
+        self
+    }
+
+    /// Set the maximum line length (default 70ch).
+    #[must_use]
+    pub const fn max_measure(mut self, measure: Measure) -> Self {
+        self.max_measure = measure;
+        self
+    }
+
+    /// Set the body text size that `ch` measures resolve against
+    /// (default 14).
+    #[must_use]
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into().0;
+        self
+    }
+
+    /// Set the spacing between children (default 12).
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.content = self.content.spacing(spacing);
+        self
+    }
+
+    /// Set the padding around the prose column.
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Prose<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Prose<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: container::Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(prose: Prose<'a, Message, Theme, Renderer>) -> Self {
+        let measure = prose.max_measure.pixels(prose.text_size);
+
+        container(prose.content.width(Length::Fill).max_width(measure))
+            .padding(prose.padding)
+            .center_x(Length::Fill)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::layout::{Layout, Limits};
+    use iced::advanced::widget::Tree;
+    use iced::widget::Space;
+    use iced::{Size, Theme};
+
+    #[test]
+    fn prose_caps_and_centers_its_children() {
+        let prose: Element<'_, (), Theme, ()> = Prose::new()
+            .push(Space::new(Length::Fill, 40.0))
+            .push(Space::new(Length::Fill, 40.0))
+            .max_measure(Measure::Px(700.0))
+            .into();
+        let mut tree = Tree::new(&prose);
+        let node = prose.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(1400.0, 800.0)),
+        );
+
+        let column = Layout::new(&node).children().next().unwrap();
+        let paragraph = column.children().next().unwrap().bounds();
+        assert!(paragraph.width <= 700.0);
+        assert!((column.bounds().x - 350.0).abs() < f32::EPSILON);
+        assert!((node.size().width - 1400.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn ch_measure_follows_text_size() {
+        assert!((Measure::Ch(70.0).pixels(16.0) - 560.0).abs() < f32::EPSILON);
+        assert!((Measure::Px(600.0).pixels(16.0) - 600.0).abs() < f32::EPSILON);
+    }
+}
@@ -1,8 +1,13 @@
 //! Styled radio button component.
 
-use iced::widget::radio;
+use iced::advanced::text;
+use iced::widget::{radio, text as label};
 use iced::{Element, Length};
 
+use crate::disabled::disable;
+use crate::labeled::{LabelPosition, Labeled};
+use crate::selectable::SelectableEnum;
+
 /// A styled radio button component.
 ///
 /// # Example
@@ -11,6 +16,9 @@ use iced::{Element, Length};
 /// Radio::new("Option A", Choice::A, selected, Message::Select)
 ///     .size(20.0)
 /// ```
+///
+/// Clicking the label selects the option too, unless
+/// [`label_clickable(false)`](Self::label_clickable) is set.
 pub struct Radio<'a, Message, V> {
     label: &'a str,
     value: V,
@@ -19,6 +27,9 @@ pub struct Radio<'a, Message, V> {
     size: f32,
     spacing: f32,
     width: Length,
+    label_position: LabelPosition,
+    label_clickable: bool,
+    disabled: bool,
 }
 
 impl<'a, Message, V> Radio<'a, Message, V>
@@ -39,6 +50,9 @@ where
             size: 20.0,
             spacing: 10.0,
             width: Length::Shrink,
+            label_position: LabelPosition::default(),
+            label_clickable: true,
+            disabled: false,
         }
     }
 
@@ -62,19 +76,53 @@ where
         self.width = width.into();
         self
     }
+
+    /// Set which side of the radio button the label is on.
+    #[must_use]
+    pub const fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Set whether clicking the label selects the option (default: true).
+    #[must_use]
+    pub const fn label_clickable(mut self, clickable: bool) -> Self {
+        self.label_clickable = clickable;
+        self
+    }
+
+    /// Disable the radio button: it ignores clicks and is dimmed.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
 }
 
-impl<'a, Message, V> From<Radio<'a, Message, V>> for Element<'a, Message, iced::Theme>
+impl<'a, Message, V, Renderer> From<Radio<'a, Message, V>>
+    for Element<'a, Message, iced::Theme, Renderer>
 where
     Message: Clone + 'a,
     V: Copy + Eq + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(r: Radio<'a, Message, V>) -> Self {
-        radio(r.label, r.value, r.selected, r.on_select)
+        let on_click = r.label_clickable.then(|| (r.on_select)(r.value));
+        let control = radio("", r.value, r.selected, r.on_select)
             .size(r.size)
-            .spacing(r.spacing)
-            .width(r.width)
-            .into()
+            .spacing(0);
+
+        disable(
+            Labeled::new(
+                control,
+                label(r.label),
+                r.label_position,
+                r.spacing,
+                r.width,
+                on_click,
+            ),
+            r.disabled,
+        )
     }
 }
 
@@ -149,6 +197,27 @@ where
     }
 }
 
+impl<'a, Message, V> RadioGroup<'a, Message, V>
+where
+    Message: Clone + 'a,
+    V: SelectableEnum,
+{
+    /// Create a radio group listing every variant of `V`.
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::from_enum(Some(self.size), Message::SizeChanged)
+    /// ```
+    pub fn from_enum<F>(selected: Option<V>, on_select: F) -> Self
+    where
+        F: Fn(V) -> Message + 'a,
+    {
+        let options: Vec<_> = V::variants()
+            .map(|variant| (variant.label(), variant))
+            .collect();
+        Self::new(&options, selected, on_select)
+    }
+}
+
 impl<'a, Message, V> From<RadioGroup<'a, Message, V>> for Element<'a, Message, iced::Theme>
 where
     Message: Clone + 'a,
@@ -177,3 +246,52 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+    use crate::selectable::tests::Fruit;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Picked(Fruit),
+    }
+
+    #[test]
+    fn from_enum_lists_every_variant() {
+        let group = RadioGroup::from_enum(Some(Fruit::Apple), Message::Picked);
+
+        assert_eq!(
+            group.options,
+            vec![
+                ("Apple", Fruit::Apple),
+                ("Banana", Fruit::Banana),
+                ("Cherry pie", Fruit::Cherry),
+            ]
+        );
+        assert_eq!(group.selected, Some(Fruit::Apple));
+        assert_eq!(
+            (group.on_select)(Fruit::Banana),
+            Message::Picked(Fruit::Banana)
+        );
+    }
+
+    #[test]
+    fn disabled_radio_ignores_clicks_and_is_dimmed() {
+        let radio =
+            |disabled| Radio::new("Apple", Fruit::Apple, None, Message::Picked).disabled(disabled);
+        let theme = iced::Theme::Light;
+
+        assert_eq!(
+            interact(radio(false).into()),
+            [Message::Picked(Fruit::Apple)]
+        );
+        assert!(interact(radio(true).into()).is_empty());
+        assert_eq!(
+            last_quad(&radio(true).into(), &theme),
+            Some(theme.disabled_veil())
+        );
+    }
+}
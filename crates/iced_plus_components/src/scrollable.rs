@@ -11,8 +11,16 @@
 //!     .scroll_to(self.target_anchor.clone())
 //! ```
 
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::gradient::Linear;
 use iced::widget::{container, scrollable, Column};
-use iced::{Background, Border, Color, Element, Length, Theme};
+use iced::{
+    event, mouse, Background, Border, Color, Element, Event, Gradient, Length, Radians, Rectangle,
+    Size, Theme, Vector,
+};
 
 /// Scroll direction.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -132,6 +140,7 @@ pub struct ScrollableBuilder<'a, Message> {
     padding: f32,
     width: Length,
     height: Length,
+    edge_fade: bool,
 }
 
 impl<'a, Message: Clone + 'a> Default for ScrollableBuilder<'a, Message> {
@@ -150,6 +159,7 @@ impl<'a, Message: Clone + 'a> ScrollableBuilder<'a, Message> {
             padding: 0.0,
             width: Length::Fill,
             height: Length::Fill,
+            edge_fade: false,
         }
     }
 
@@ -207,6 +217,14 @@ impl<'a, Message: Clone + 'a> ScrollableBuilder<'a, Message> {
         self
     }
 
+    /// Fade the top and bottom edges into the background while there is
+    /// more content to scroll to in that direction.
+    #[must_use]
+    pub const fn edge_fade(mut self) -> Self {
+        self.edge_fade = true;
+        self
+    }
+
     /// Build the scrollable element.
     #[must_use]
     pub fn build(self) -> Element<'a, Message, Theme> {
@@ -215,10 +233,254 @@ impl<'a, Message: Clone + 'a> ScrollableBuilder<'a, Message> {
             .padding(self.padding)
             .width(self.width);
 
-        scrollable(content)
-            .width(self.width)
-            .height(self.height)
-            .into()
+        let scroll = scrollable(content).width(self.width).height(self.height);
+
+        if self.edge_fade {
+            Element::new(EdgeFade {
+                content: scroll.into(),
+            })
+        } else {
+            scroll.into()
+        }
+    }
+}
+
+/// Height of the edge fade gradients.
+const FADE_SIZE: f32 = 16.0;
+
+/// Which edges of a scrollable have more content past them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct EdgeFades {
+    start: bool,
+    end: bool,
+}
+
+impl EdgeFades {
+    /// Fades for a viewport of `viewport` length scrolled `offset` into
+    /// `content` length.
+    fn new(viewport: f32, content: f32, offset: f32) -> Self {
+        // Allow for sub-pixel rounding at either end.
+        Self {
+            start: offset > 0.5,
+            end: offset + viewport < content - 0.5,
+        }
+    }
+}
+
+/// Reads the scroll position of the first scrollable in a subtree.
+struct ScrollPosition(Option<EdgeFades>);
+
+impl Operation for ScrollPosition {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn iced::advanced::widget::operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if self.0.is_none() {
+            self.0 = Some(EdgeFades::new(
+                bounds.height,
+                content_bounds.height,
+                translation.y,
+            ));
+        }
+    }
+}
+
+/// Draws fades over the edges of a scrollable that can scroll further.
+struct EdgeFade<'a, Message, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Renderer> EdgeFade<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn track(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) {
+        let mut position = ScrollPosition(None);
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, &mut position);
+
+        *tree.state.downcast_mut::<EdgeFades>() = position.0.unwrap_or_default();
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for EdgeFade<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<EdgeFades>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(EdgeFades::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let node = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        // The content may have grown or shrunk past the viewport.
+        self.track(tree, Layout::new(&node), renderer);
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        self.track(tree, layout, renderer);
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let fades = tree.state.downcast_ref::<EdgeFades>();
+        let bounds = layout.bounds();
+        let size = FADE_SIZE.min(bounds.height / 2.0);
+        let background = theme.palette().background;
+
+        // A zero angle runs the gradient from bottom to top.
+        let mut fade = |y: f32, angle: f32| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y,
+                        height: size,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                Background::Gradient(Gradient::Linear(
+                    Linear::new(Radians(angle))
+                        .add_stop(0.0, background)
+                        .add_stop(
+                            1.0,
+                            Color {
+                                a: 0.0,
+                                ..background
+                            },
+                        ),
+                )),
+            );
+        };
+
+        if fades.start {
+            fade(bounds.y, std::f32::consts::PI);
+        }
+        if fades.end {
+            fade(bounds.y + bounds.height - size, 0.0);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
     }
 }
 
@@ -363,3 +625,25 @@ pub mod position {
         RelativeOffset { x: 0.0, y: 1.0 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_fade_at_top_shows_only_bottom() {
+        let fades = EdgeFades::new(200.0, 600.0, 0.0);
+
+        assert!(!fades.start);
+        assert!(fades.end);
+    }
+
+    #[test]
+    fn edge_fade_in_middle_shows_both() {
+        let fades = EdgeFades::new(200.0, 600.0, 200.0);
+        assert!(fades.start && fades.end);
+
+        let bottom = EdgeFades::new(200.0, 600.0, 400.0);
+        assert!(bottom.start && !bottom.end);
+    }
+}
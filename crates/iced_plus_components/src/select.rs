@@ -1,11 +1,26 @@
 //! Select/Dropdown component.
 
-use iced::widget::pick_list;
-use iced::{Element, Length};
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::text;
+use iced::advanced::widget::{tree, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::{event, mouse, Border, Element, Event, Length, Point, Rectangle, Size, Theme, Vector};
+use iced_plus_theme::scaled;
+
+use crate::disabled::disable;
+use crate::dropdown::{draw_view, DropdownMetrics, ListOverlay, ListState, ListStatus, OptionView};
+use crate::focus::keyboard_focusable;
+use crate::selectable::SelectableEnum;
+
+/// Builds the message of a [`Select`] footer row.
+type FooterAction<'a, Message> = dyn Fn() -> Message + 'a;
 
 /// A styled select/dropdown component.
 ///
-/// This wraps iced's pick_list with consistent styling.
+/// The option list opens below the trigger (or above it when there is more
+/// room there) and scrolls once it grows past its maximum height.
 ///
 /// # Example
 ///
@@ -16,19 +31,52 @@ use iced::{Element, Length};
 ///     Message::Selected,
 /// )
 /// .placeholder("Choose an option")
+/// .max_height(240.0)
 /// ```
-pub struct Select<'a, T, Message>
+///
+/// For options fetched remotely, [`loading`](Self::loading) shows a spinner
+/// row while the request is in flight and
+/// [`empty_message`](Self::empty_message) explains an empty result.
+/// [`footer`](Self::footer) pins an action such as "Add new…" below the
+/// options.
+///
+/// Options are shown with their `Display` text unless
+/// [`view_option`](Self::view_option) renders them, e.g. as an icon with
+/// two lines of text:
+///
+/// ```rust,ignore
+/// Select::new(&self.accounts, self.account.clone(), Message::Account)
+///     .option_height(48.0)
+///     .view_option(|account| {
+///         row![avatar(account), column![text(&account.name), text(&account.email).size(12)]]
+///             .spacing(8)
+///             .into()
+///     })
+/// ```
+///
+/// The default padding, text size and option heights follow the
+/// [UI scale](iced_plus_theme::set_ui_scale).
+pub struct Select<'a, T, Message, Renderer = iced::Renderer>
 where
     T: ToString + PartialEq + Clone,
 {
     options: &'a [T],
     selected: Option<T>,
     on_select: Box<dyn Fn(T) -> Message + 'a>,
+    view_option: Option<Box<OptionView<'a, T, Message, Theme, Renderer>>>,
+    view_selected: Option<Box<OptionView<'a, T, Message, Theme, Renderer>>>,
     placeholder: Option<&'a str>,
     width: Length,
+    padding: f32,
+    text_size: f32,
+    metrics: DropdownMetrics,
+    loading: bool,
+    empty_message: &'a str,
+    footer: Option<(&'a str, Box<FooterAction<'a, Message>>)>,
+    disabled: bool,
 }
 
-impl<'a, T, Message> Select<'a, T, Message>
+impl<'a, T, Message, Renderer> Select<'a, T, Message, Renderer>
 where
     T: ToString + PartialEq + Clone + 'a,
     Message: Clone + 'a,
@@ -42,8 +90,17 @@ where
             options,
             selected,
             on_select: Box::new(on_select),
+            view_option: None,
+            view_selected: None,
             placeholder: None,
             width: Length::Fill,
+            padding: scaled(10.0),
+            text_size: scaled(14.0),
+            metrics: DropdownMetrics::DEFAULT.scaled(),
+            loading: false,
+            empty_message: "No options",
+            footer: None,
+            disabled: false,
         }
     }
 
@@ -60,22 +117,661 @@ where
         self.width = width.into();
         self
     }
+
+    /// Set the maximum height of the option list before it scrolls.
+    #[must_use]
+    pub const fn max_height(mut self, max_height: f32) -> Self {
+        self.metrics.max_height = max_height;
+        self
+    }
+
+    /// Set the height of each option in the list.
+    #[must_use]
+    pub const fn option_height(mut self, option_height: f32) -> Self {
+        self.metrics.option_height = option_height;
+        self
+    }
+
+    /// Show a spinner and "Loading…" in the dropdown instead of the options.
+    #[must_use]
+    pub const fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the message shown when there are no options (default "No options").
+    #[must_use]
+    pub const fn empty_message(mut self, message: &'a str) -> Self {
+        self.empty_message = message;
+        self
+    }
+
+    /// Pin an action row, e.g. "Add new…", below the options.
+    ///
+    /// Clicking it emits `message` and closes the dropdown without
+    /// selecting an option. The row stays visible while the options
+    /// scroll, load or are empty.
+    #[must_use]
+    pub fn footer(mut self, label: &'a str, message: Message) -> Self {
+        self.footer = Some((label, Box::new(move || message.clone())));
+        self
+    }
+
+    /// Disable the select: it doesn't open and is dimmed.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Render each option in the list with `view` instead of its text.
+    ///
+    /// Rows keep the fixed [`option_height`](Self::option_height), and
+    /// highlighting, keyboard navigation and selection work as before. The
+    /// views are display-only: they are rebuilt every frame and receive no
+    /// events. The closed field still shows the text unless
+    /// [`view_selected`](Self::view_selected) is set.
+    #[must_use]
+    pub fn view_option(
+        mut self,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.view_option = Some(Box::new(view));
+        self
+    }
+
+    /// Render the selected option in the closed field with `view` instead
+    /// of its text.
+    #[must_use]
+    pub fn view_selected(
+        mut self,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.view_selected = Some(Box::new(view));
+        self
+    }
+
+    /// What the dropdown list shows.
+    const fn status(&self) -> ListStatus<'a> {
+        if self.loading {
+            ListStatus::Loading
+        } else if self.options.is_empty() {
+            ListStatus::Empty(self.empty_message)
+        } else {
+            ListStatus::Options
+        }
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.options.iter().position(|option| option == selected)
+    }
+}
+
+impl<'a, T, Message, Renderer> Select<'a, T, Message, Renderer>
+where
+    T: SelectableEnum,
+    Message: Clone + 'a,
+{
+    /// Create a select offering every variant of `T`.
+    ///
+    /// ```rust,ignore
+    /// Select::from_enum(self.fruit, Message::FruitSelected)
+    /// ```
+    pub fn from_enum<F>(selected: Option<T>, on_select: F) -> Self
+    where
+        F: Fn(T) -> Message + 'a,
+    {
+        Self::new(T::ALL, selected, on_select)
+    }
 }
 
-impl<'a, T, Message> From<Select<'a, T, Message>> for Element<'a, Message, iced::Theme>
+impl<'a, T, Message, Renderer> Widget<Message, Theme, Renderer> for Select<'a, T, Message, Renderer>
 where
     T: ToString + PartialEq + Clone + 'a,
     Message: Clone + 'a,
+    Renderer: text::Renderer<Font = iced::Font>,
 {
-    fn from(select: Select<'a, T, Message>) -> Self {
-        let mut widget = pick_list(select.options, select.selected, select.on_select)
-            .width(select.width)
-            .padding(10);
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<ListState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(ListState::default())
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let line_height = text::LineHeight::default()
+            .to_absolute(iced::Pixels(self.text_size))
+            .0;
+        let height = self.padding.mul_add(2.0, line_height);
+
+        Node::new(limits.resolve(self.width, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if event == Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) {
+            let state = tree.state.downcast_mut::<ListState>();
 
-        if let Some(placeholder) = select.placeholder {
-            widget = widget.placeholder(placeholder);
+            if state.is_open {
+                // Clicks inside the list are captured by the overlay first.
+                state.close();
+                return event::Status::Captured;
+            }
+
+            if cursor.is_over(layout.bounds()) {
+                state.open(self.selected_index(), self.metrics, self.options.len());
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
         }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let palette = theme.extended_palette();
+        let state = tree.state.downcast_ref::<ListState>();
+
+        let border_color = if state.is_open || cursor.is_over(bounds) {
+            palette.primary.strong.color
+        } else {
+            palette.background.strong.color
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: border_color,
+                    width: 1.0,
+                    radius: 6.0.into(),
+                },
+                ..renderer::Quad::default()
+            },
+            palette.background.base.color,
+        );
+
+        let (label, color) = match (&self.selected, self.placeholder) {
+            (Some(selected), _) => (selected.to_string(), palette.background.base.text),
+            (None, Some(placeholder)) => (placeholder.to_string(), palette.background.strong.color),
+            (None, None) => (String::new(), palette.background.base.text),
+        };
+
+        let text_bounds = Size::new(self.padding.mul_add(-2.0, bounds.width), bounds.height);
+
+        if let (Some(selected), Some(view)) = (&self.selected, &self.view_selected) {
+            // Leave room for the arrow.
+            let content = Rectangle {
+                x: bounds.x + self.padding,
+                width: text_bounds.width - self.text_size,
+                ..bounds
+            };
+
+            draw_view(&view(selected), renderer, theme, color, content, &bounds);
+        } else {
+            renderer.fill_text(
+                text::Text {
+                    content: label,
+                    bounds: text_bounds,
+                    size: iced::Pixels(self.text_size),
+                    line_height: text::LineHeight::default(),
+                    font: iced::Font::default(),
+                    horizontal_alignment: iced::alignment::Horizontal::Left,
+                    vertical_alignment: iced::alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(bounds.x + self.padding, bounds.center_y()),
+                color,
+                bounds,
+            );
+        }
+
+        renderer.fill_text(
+            text::Text {
+                content: String::from("▾"),
+                bounds: text_bounds,
+                size: iced::Pixels(self.text_size),
+                line_height: text::LineHeight::default(),
+                font: iced::Font::default(),
+                horizontal_alignment: iced::alignment::Horizontal::Right,
+                vertical_alignment: iced::alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(bounds.x + bounds.width - self.padding, bounds.center_y()),
+            palette.background.base.text,
+            bounds,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<ListState>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let selected = self.selected_index();
+
+        Some(overlay::Element::new(Box::new(ListOverlay {
+            state,
+            options: self.options,
+            selected,
+            on_select: &*self.on_select,
+            view_option: self.view_option.as_deref(),
+            anchor: layout.bounds() + translation,
+            metrics: self.metrics,
+            text_size: self.text_size,
+            padding_x: self.padding,
+            status: self.status(),
+            footer: self
+                .footer
+                .as_ref()
+                .map(|(label, message)| (*label, &**message)),
+        })))
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Select<'a, T, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    Message: Clone + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(select: Select<'a, T, Message, Renderer>) -> Self {
+        if select.disabled {
+            disable(Element::new(select), true)
+        } else {
+            keyboard_focusable(Element::new(select))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+    use crate::selectable::tests::Fruit;
+    use crate::test_support::Recorder;
+    use iced::advanced::clipboard;
+    use iced::advanced::Renderer as _;
+    use iced::keyboard::{self, key};
+    use iced::Color;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(&'static str),
+        Fruit(Fruit),
+        Zone(String),
+        AddNew,
+    }
+
+    /// Open `select` and lay out its list in a 400x400 window.
+    fn open_list(select: &mut Select<'_, &'static str, Message, ()>) -> Size {
+        let mut tree = Tree::new(&*select as &dyn Widget<Message, Theme, ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+        let node = Widget::<Message, Theme, ()>::layout(select, &mut tree, &(), &limits);
+        tree.state
+            .downcast_mut::<ListState>()
+            .open(None, select.metrics, select.options.len());
+
+        let mut overlay = Widget::<Message, Theme, ()>::overlay(
+            select,
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            Vector::ZERO,
+        )
+        .expect("list is open");
+        overlay.layout(&(), Size::new(400.0, 400.0)).size()
+    }
+
+    #[test]
+    fn loading_select_shows_spinner_row() {
+        let mut select = Select::new(&["A", "B", "C"], None, Message::Selected).loading(true);
+
+        assert_eq!(select.status(), ListStatus::Loading);
+        assert_eq!(select.status().message(), Some("Loading…"));
+        assert!(
+            (open_list(&mut select).height - select.metrics.option_height).abs() < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn empty_select_shows_empty_message() {
+        let mut select =
+            Select::new(&[], None, Message::Selected).empty_message("No matching users");
+
+        assert_eq!(select.status().message(), Some("No matching users"));
+        assert!(
+            (open_list(&mut select).height - select.metrics.option_height).abs() < f32::EPSILON
+        );
+
+        let mut loaded = Select::new(&["A", "B", "C"], None, Message::Selected);
+        assert_eq!(loaded.status(), ListStatus::Options);
+        assert!(
+            loaded
+                .metrics
+                .option_height
+                .mul_add(-3.0, open_list(&mut loaded).height)
+                .abs()
+                < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn footer_emits_its_message_instead_of_selecting() {
+        let mut select =
+            Select::<'_, _, Message, ()>::new(&["A", "B", "C"], None, Message::Selected)
+                .footer("Add new…", Message::AddNew);
+        let option_height = select.metrics.option_height;
+        assert!(
+            option_height
+                .mul_add(-4.0, open_list(&mut select).height)
+                .abs()
+                < f32::EPSILON
+        );
+
+        let mut tree = Tree::new(&select as &dyn Widget<Message, Theme, ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+        let node = select.layout(&mut tree, &(), &limits);
+        tree.state
+            .downcast_mut::<ListState>()
+            .open(None, select.metrics, select.options.len());
+        let mut overlay = select
+            .overlay(&mut tree, Layout::new(&node), &(), Vector::ZERO)
+            .expect("list is open");
+        let overlay_node = overlay.layout(&(), Size::new(400.0, 400.0));
+        let footer = Point::new(50.0, option_height.mul_add(3.5, overlay_node.bounds().y));
+
+        let mut messages = Vec::new();
+        let status = overlay.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&overlay_node),
+            mouse::Cursor::Available(footer),
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+        drop(overlay);
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::AddNew]);
+        assert!(!tree.state.downcast_ref::<ListState>().is_open);
+    }
+
+    #[test]
+    fn from_enum_offers_every_variant() {
+        let select: Select<'_, Fruit, Message> = Select::from_enum(None, Message::Fruit);
+
+        assert_eq!(select.options, Fruit::ALL);
+        assert_eq!(
+            select
+                .options
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["Apple", "Banana", "Cherry pie"]
+        );
+        assert_eq!(
+            (select.on_select)(Fruit::Cherry),
+            Message::Fruit(Fruit::Cherry)
+        );
+    }
+
+    /// A 16x16 block in a fixed color, standing in for a custom option view.
+    struct Swatch(Color);
+
+    impl Widget<Message, Theme, Recorder> for Swatch {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fixed(16.0), Length::Fixed(16.0))
+        }
+
+        fn layout(&self, _tree: &mut Tree, _renderer: &Recorder, limits: &Limits) -> Node {
+            Node::new(limits.resolve(16.0, 16.0, Size::ZERO))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            renderer: &mut Recorder,
+            _theme: &Theme,
+            _style: &renderer::Style,
+            layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..renderer::Quad::default()
+                },
+                self.0,
+            );
+        }
+    }
+
+    fn swatch(option: &&'static str) -> Element<'static, Message, Theme, Recorder> {
+        let color = match *option {
+            "Red" => Color::from_rgb(1.0, 0.0, 0.0),
+            "Green" => Color::from_rgb(0.0, 1.0, 0.0),
+            _ => Color::from_rgb(0.0, 0.0, 1.0),
+        };
+
+        Element::new(Swatch(color))
+    }
+
+    #[test]
+    fn custom_option_view_renders_each_option() {
+        const COLORS: [&str; 3] = ["Red", "Green", "Blue"];
+        let mut select = Select::new(&COLORS, Some("Red"), Message::Selected)
+            .view_option(swatch)
+            .view_selected(swatch);
+        let style = renderer::Style {
+            text_color: Color::BLACK,
+        };
+        let mut recorder = Recorder::default();
+
+        let mut tree = Tree::new(&select as &dyn Widget<Message, Theme, Recorder>);
+        let limits = Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+        let node = select.layout(&mut tree, &recorder, &limits);
+        select.draw(
+            &tree,
+            &mut recorder,
+            &Theme::Light,
+            &style,
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(200.0, 400.0)),
+        );
+        assert!(recorder.colors().contains(&Color::from_rgb(1.0, 0.0, 0.0)));
+
+        tree.state
+            .downcast_mut::<ListState>()
+            .open(None, select.metrics, COLORS.len());
+        let field = node.size();
+        let mut overlay = select
+            .overlay(&mut tree, Layout::new(&node), &recorder, Vector::ZERO)
+            .expect("list is open");
+        let overlay_node = overlay.layout(&recorder, Size::new(400.0, 400.0));
+
+        recorder.clear();
+        overlay.draw(
+            &mut recorder,
+            &Theme::Light,
+            &style,
+            Layout::new(&overlay_node),
+            mouse::Cursor::Unavailable,
+        );
+        for color in [
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.0, 1.0, 0.0),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        ] {
+            assert!(recorder.colors().contains(&color), "{color:?}");
+        }
+
+        // Clicking the second row still selects the underlying value.
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let _ = overlay.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&overlay_node),
+            mouse::Cursor::Available(Point::new(50.0, field.height + 48.0)),
+            &recorder,
+            &mut clipboard::Null,
+            &mut shell,
+        );
+        assert_eq!(messages, vec![Message::Selected("Green")]);
+    }
+
+    #[test]
+    fn large_select_only_builds_visible_rows() {
+        let zones: Vec<String> = (0..5000).map(|i| format!("Zone {i}")).collect();
+        let built = std::cell::Cell::new(0);
+        let mut select = Select::new(&zones, None, Message::Zone).view_option(|_| {
+            built.set(built.get() + 1);
+            Element::new(Swatch(Color::BLACK))
+        });
+        let recorder = &mut Recorder::default();
+
+        let mut tree = Tree::new(&select as &dyn Widget<Message, Theme, Recorder>);
+        let limits = Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+        let node = select.layout(&mut tree, recorder, &limits);
+        tree.state
+            .downcast_mut::<ListState>()
+            .open(None, select.metrics, zones.len());
+        let mut overlay = select
+            .overlay(&mut tree, Layout::new(&node), recorder, Vector::ZERO)
+            .expect("list is open");
+        let overlay_node = overlay.layout(recorder, Size::new(400.0, 800.0));
+        overlay.draw(
+            recorder,
+            &Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&overlay_node),
+            mouse::Cursor::Unavailable,
+        );
+
+        // A 300px list of 32px rows shows at most 11 partial rows.
+        assert!(built.get() <= 11, "built {} rows", built.get());
+
+        // Keyboard navigation still covers the whole list.
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for key in [key::Named::ArrowUp, key::Named::Enter] {
+            let _ = overlay.on_event(
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key),
+                    modified_key: keyboard::Key::Named(key),
+                    physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+                    location: keyboard::Location::Standard,
+                    modifiers: keyboard::Modifiers::empty(),
+                    text: None,
+                }),
+                Layout::new(&overlay_node),
+                mouse::Cursor::Unavailable,
+                recorder,
+                &mut clipboard::Null,
+                &mut shell,
+            );
+        }
+        assert_eq!(messages, vec![Message::Zone("Zone 4999".to_owned())]);
+    }
+
+    #[test]
+    fn disabled_select_does_not_open_and_is_dimmed() {
+        let select = |disabled| {
+            Select::<'_, _, Message, ()>::new(&["Apple", "Banana"], None, Message::Selected)
+                .disabled(disabled)
+        };
+        let opens = |select: Select<'_, &'static str, Message, ()>| {
+            let mut element = Element::from(select);
+            let mut tree = Tree::new(&element);
+            let node = element.as_widget().layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(200.0, 400.0)),
+            );
+            let mut messages = Vec::new();
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Layout::new(&node),
+                mouse::Cursor::Available(node.bounds().center()),
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(200.0, 400.0)),
+            );
+
+            let open = element
+                .as_widget_mut()
+                .overlay(&mut tree, Layout::new(&node), &(), Vector::ZERO)
+                .is_some();
+            open
+        };
+        let theme = Theme::Light;
+
+        assert!(opens(select(false)));
+        assert!(!opens(select(true)));
+        assert!(interact(select(true).into()).is_empty());
 
-        widget.into()
+        let dimmed = Select::<'_, _, Message, Recorder>::new(&["Apple"], None, Message::Selected)
+            .disabled(true);
+        assert_eq!(
+            last_quad(&dimmed.into(), &theme),
+            Some(theme.disabled_veil())
+        );
     }
 }
@@ -0,0 +1,95 @@
+//! Building option lists from enums.
+//!
+//! Implement [`SelectableEnum`] (by hand or with [`selectable_enum!`]) to
+//! populate a [`RadioGroup`](crate::RadioGroup) or [`Select`](crate::Select)
+//! with every variant of a type instead of listing the options manually.
+
+/// An enum whose variants can be offered as choices.
+///
+/// [`Display`](std::fmt::Display) is used where a widget formats options
+/// itself, and should match [`label`](Self::label).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Size { Small, Medium, Large }
+///
+/// selectable_enum!(Size {
+///     Small => "Small",
+///     Medium => "Medium",
+///     Large => "Large",
+/// });
+///
+/// RadioGroup::from_enum(Some(self.size), Message::SizeChanged)
+/// ```
+pub trait SelectableEnum: Copy + Eq + std::fmt::Display + 'static {
+    /// Every variant, in display order.
+    const ALL: &'static [Self];
+
+    /// Human-readable label for the variant.
+    fn label(self) -> &'static str;
+
+    /// Iterate over every variant, in display order.
+    fn variants() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+}
+
+/// Implement [`SelectableEnum`] and [`Display`](std::fmt::Display) for a
+/// fieldless enum from a list of variants and labels.
+///
+/// ```rust,ignore
+/// selectable_enum!(Theme {
+///     Light => "Light",
+///     Dark => "Dark",
+///     System => "Follow system",
+/// });
+/// ```
+#[macro_export]
+macro_rules! selectable_enum {
+    ($name:ident { $($variant:ident => $label:expr),+ $(,)? }) => {
+        impl $crate::selectable::SelectableEnum for $name {
+            const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            fn label(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $label),+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str($crate::selectable::SelectableEnum::label(*self))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Fruit {
+        Apple,
+        Banana,
+        Cherry,
+    }
+
+    selectable_enum!(Fruit {
+        Apple => "Apple",
+        Banana => "Banana",
+        Cherry => "Cherry pie",
+    });
+
+    #[test]
+    fn macro_lists_variants_with_labels() {
+        use super::SelectableEnum;
+
+        assert_eq!(
+            Fruit::variants().collect::<Vec<_>>(),
+            vec![Fruit::Apple, Fruit::Banana, Fruit::Cherry]
+        );
+        assert_eq!(Fruit::Cherry.to_string(), "Cherry pie");
+    }
+}
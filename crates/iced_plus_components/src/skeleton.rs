@@ -34,11 +34,15 @@ pub enum SkeletonShape {
 /// Skeleton::new()
 ///     .width(Length::Fill)
 ///     .height(Length::Fixed(100.0))
+///
+/// // 16:9 image placeholder
+/// Skeleton::rounded().aspect(16.0, 9.0)
 /// ```
 pub struct Skeleton {
     width: Length,
     height: Length,
     shape: SkeletonShape,
+    aspect: Option<f32>,
 }
 
 impl Default for Skeleton {
@@ -47,6 +51,7 @@ impl Default for Skeleton {
             width: Length::Fill,
             height: Length::Fixed(20.0),
             shape: SkeletonShape::default(),
+            aspect: None,
         }
     }
 }
@@ -75,6 +80,7 @@ impl Skeleton {
             shape: SkeletonShape::Circle,
             width: Length::Fixed(40.0),
             height: Length::Fixed(40.0),
+            aspect: None,
         }
     }
 
@@ -115,6 +121,19 @@ impl Skeleton {
         self.shape = shape;
         self
     }
+
+    /// Derive the height from the width with a `width_ratio:height_ratio`
+    /// aspect, e.g. `aspect(16.0, 9.0)`.
+    ///
+    /// Use this to reserve the space of content that has not loaded yet.
+    #[must_use]
+    pub fn aspect(mut self, width_ratio: f32, height_ratio: f32) -> Self {
+        if width_ratio > 0.0 {
+            self.aspect = Some(height_ratio / width_ratio);
+            self.height = Length::Shrink;
+        }
+        self
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Skeleton
@@ -147,11 +166,11 @@ where
             Length::FillPortion(_) => limits.max().width,
         };
 
-        let height = match self.height {
-            Length::Fill => limits.max().height,
-            Length::Fixed(h) => h,
-            Length::Shrink => 20.0,
-            Length::FillPortion(_) => limits.max().height,
+        let height = match (self.aspect, self.height) {
+            (Some(ratio), _) => (width * ratio).min(limits.max().height),
+            (None, Length::Fill | Length::FillPortion(_)) => limits.max().height,
+            (None, Length::Fixed(h)) => h,
+            (None, Length::Shrink) => 20.0,
         };
 
         Node::new(Size::new(width, height))
@@ -212,3 +231,23 @@ where
         Element::new(skeleton)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aspect_sets_height_from_width() {
+        let skeleton = Skeleton::rounded().aspect(16.0, 9.0);
+        let mut tree = Tree::new(&skeleton as &dyn Widget<(), iced::Theme, ()>);
+
+        let node = Widget::<(), iced::Theme, ()>::layout(
+            &skeleton,
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(320.0, 1000.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(320.0, 180.0));
+    }
+}
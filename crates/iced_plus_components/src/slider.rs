@@ -1,7 +1,43 @@
 //! Styled slider component.
+//!
+//! Sliders respond to the keyboard once focused by a click: arrow keys step
+//! by `step`, PageUp/PageDown by `page_step`, and Home/End jump to the ends
+//! of the range, firing `on_release` after each change like the end of a
+//! drag. Scrolling the wheel over a slider steps it as well; touchpad
+//! scrolling steps once per [`SCROLL_PIXELS_PER_STEP`] pixels, and scrolling
+//! a slider already at the end of its range scrolls the page instead.
+//!
+//! Sliders are as thick as the [UI scale](iced_plus_theme::set_ui_scale)
+//! makes them.
+//!
+//! A focused slider shows a focus ring while it is used from the keyboard;
+//! see [`focus`](crate::focus).
 
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::operation::Focusable as _;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::keyboard::{self, key};
 use iced::widget::slider;
-use iced::{Element, Length};
+use iced::{event, mouse, Element, Event, Length, Rectangle, Size, Vector};
+use iced_plus_theme::scaled;
+
+use crate::disabled::disable;
+use crate::focus::{draw_focus_ring, FocusTheme, FocusVisible};
+
+/// Default number of steps moved by PageUp/PageDown.
+const PAGE_STEPS: f32 = 10.0;
+
+/// Touchpad scrolling, in pixels, that moves a slider by one step.
+pub const SCROLL_PIXELS_PER_STEP: f32 = 20.0;
+
+/// Thickness of a slider before the [UI scale](iced_plus_theme::set_ui_scale)
+/// is applied (iced's default).
+const THICKNESS: f32 = 16.0;
 
 /// A styled slider component for f32 values.
 ///
@@ -13,12 +49,14 @@ use iced::{Element, Length};
 ///     .width(200.0)
 /// ```
 pub struct Slider<'a, Message> {
-    range: std::ops::RangeInclusive<f32>,
+    range: RangeInclusive<f32>,
     value: f32,
     on_change: Box<dyn Fn(f32) -> Message + 'a>,
     on_release: Option<Message>,
     step: f32,
+    page_step: Option<f32>,
     width: Length,
+    disabled: bool,
 }
 
 impl<'a, Message> Slider<'a, Message>
@@ -26,7 +64,7 @@ where
     Message: Clone,
 {
     /// Create a new slider.
-    pub fn new<F>(range: std::ops::RangeInclusive<f32>, value: f32, on_change: F) -> Self
+    pub fn new<F>(range: RangeInclusive<f32>, value: f32, on_change: F) -> Self
     where
         F: Fn(f32) -> Message + 'a,
     {
@@ -36,7 +74,9 @@ where
             on_change: Box::new(on_change),
             on_release: None,
             step: 1.0,
+            page_step: None,
             width: Length::Fill,
+            disabled: false,
         }
     }
 
@@ -47,6 +87,13 @@ where
         self
     }
 
+    /// Set the amount moved by PageUp/PageDown (10 steps by default).
+    #[must_use]
+    pub const fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
     /// Set the width.
     #[must_use]
     pub fn width(mut self, width: impl Into<Length>) -> Self {
@@ -60,22 +107,47 @@ where
         self.on_release = Some(message);
         self
     }
+
+    /// Disable the slider: it ignores dragging, keys and the wheel and is
+    /// dimmed.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
 }
 
-impl<'a, Message> From<Slider<'a, Message>> for Element<'a, Message, iced::Theme>
+impl<'a, Message, Renderer> From<Slider<'a, Message>>
+    for Element<'a, Message, iced::Theme, Renderer>
 where
     Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
 {
     fn from(s: Slider<'a, Message>) -> Self {
-        let mut slider = slider(s.range, s.value, s.on_change)
+        let on_change: Rc<dyn Fn(f32) -> Message + 'a> = Rc::from(s.on_change);
+        let forward = Rc::clone(&on_change);
+
+        let mut slider = slider(s.range.clone(), s.value, move |value| forward(value))
             .step(s.step)
-            .width(s.width);
+            .width(s.width)
+            .height(scaled(THICKNESS));
 
-        if let Some(on_release) = s.on_release {
+        if let Some(on_release) = s.on_release.clone() {
             slider = slider.on_release(on_release);
         }
 
-        slider.into()
+        disable(
+            Element::new(KeyboardSlider {
+                content: slider.into(),
+                range: s.range,
+                value: s.value,
+                step: s.step,
+                page_step: s.page_step.unwrap_or(s.step * PAGE_STEPS),
+                on_change,
+                on_release: s.on_release,
+            }),
+            s.disabled,
+        )
     }
 }
 
@@ -88,11 +160,12 @@ where
 ///     .height(200.0)
 /// ```
 pub struct VerticalSlider<'a, Message> {
-    range: std::ops::RangeInclusive<f32>,
+    range: RangeInclusive<f32>,
     value: f32,
     on_change: Box<dyn Fn(f32) -> Message + 'a>,
     on_release: Option<Message>,
     step: f32,
+    page_step: Option<f32>,
     height: Length,
 }
 
@@ -101,7 +174,7 @@ where
     Message: Clone,
 {
     /// Create a new vertical slider.
-    pub fn new<F>(range: std::ops::RangeInclusive<f32>, value: f32, on_change: F) -> Self
+    pub fn new<F>(range: RangeInclusive<f32>, value: f32, on_change: F) -> Self
     where
         F: Fn(f32) -> Message + 'a,
     {
@@ -111,6 +184,7 @@ where
             on_change: Box::new(on_change),
             on_release: None,
             step: 1.0,
+            page_step: None,
             height: Length::Fill,
         }
     }
@@ -122,6 +196,13 @@ where
         self
     }
 
+    /// Set the amount moved by PageUp/PageDown (10 steps by default).
+    #[must_use]
+    pub const fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
     /// Set the height.
     #[must_use]
     pub fn height(mut self, height: impl Into<Length>) -> Self {
@@ -144,14 +225,458 @@ where
     fn from(s: VerticalSlider<'a, Message>) -> Self {
         use iced::widget::vertical_slider;
 
-        let mut slider = vertical_slider(s.range, s.value, s.on_change)
+        let on_change: Rc<dyn Fn(f32) -> Message + 'a> = Rc::from(s.on_change);
+        let forward = Rc::clone(&on_change);
+
+        let mut slider = vertical_slider(s.range.clone(), s.value, move |value| forward(value))
             .step(s.step)
+            .width(scaled(THICKNESS))
             .height(s.height);
 
-        if let Some(on_release) = s.on_release {
+        if let Some(on_release) = s.on_release.clone() {
             slider = slider.on_release(on_release);
         }
 
-        slider.into()
+        Element::new(KeyboardSlider {
+            content: slider.into(),
+            range: s.range,
+            value: s.value,
+            step: s.step,
+            page_step: s.page_step.unwrap_or(s.step * PAGE_STEPS),
+            on_change,
+            on_release: s.on_release,
+        })
+    }
+}
+
+/// Move `value` by `steps` steps, snapping to the step grid of `range`.
+fn stepped(value: f32, range: &RangeInclusive<f32>, step: f32, steps: f32) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+
+    if step <= 0.0 {
+        return value.clamp(start, end);
+    }
+
+    let current = ((value - start) / step).round();
+    (current + steps).mul_add(step, start).clamp(start, end)
+}
+
+/// Value a key press moves the slider to, if the key adjusts it.
+fn key_value(
+    key: &keyboard::Key,
+    value: f32,
+    range: &RangeInclusive<f32>,
+    step: f32,
+    page_step: f32,
+) -> Option<f32> {
+    let page = if step > 0.0 { page_step / step } else { 0.0 };
+
+    match key {
+        keyboard::Key::Named(key::Named::ArrowRight | key::Named::ArrowUp) => {
+            Some(stepped(value, range, step, 1.0))
+        }
+        keyboard::Key::Named(key::Named::ArrowLeft | key::Named::ArrowDown) => {
+            Some(stepped(value, range, step, -1.0))
+        }
+        keyboard::Key::Named(key::Named::PageUp) => Some(stepped(value, range, step, page)),
+        keyboard::Key::Named(key::Named::PageDown) => Some(stepped(value, range, step, -page)),
+        keyboard::Key::Named(key::Named::Home) => Some(*range.start()),
+        keyboard::Key::Named(key::Named::End) => Some(*range.end()),
+        _ => None,
+    }
+}
+
+/// Adds focus, keyboard and scroll-wheel stepping to an iced slider.
+struct KeyboardSlider<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    range: RangeInclusive<f32>,
+    value: f32,
+    step: f32,
+    page_step: f32,
+    on_change: Rc<dyn Fn(f32) -> Message + 'a>,
+    on_release: Option<Message>,
+}
+
+/// Focus of a [`KeyboardSlider`], and touchpad scrolling not yet turned
+/// into a step.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    focus: FocusVisible,
+    scrolled: f32,
+}
+
+impl<Message, Theme, Renderer> KeyboardSlider<'_, Message, Theme, Renderer> {
+    /// Publish a change to `value`, returning whether the value changed.
+    fn change(&self, value: f32, shell: &mut Shell<'_, Message>) -> bool {
+        let changed = (value - self.value).abs() > f32::EPSILON;
+        if changed {
+            shell.publish((self.on_change)(value));
+        }
+        changed
+    }
+
+    /// Steps a wheel `delta` moves the slider by, accumulating touchpad
+    /// pixels in `scrolled` until they make a whole step.
+    fn wheel_steps(delta: mouse::ScrollDelta, scrolled: &mut f32) -> f32 {
+        match delta {
+            mouse::ScrollDelta::Lines { y, .. } => {
+                *scrolled = 0.0;
+                if y == 0.0 {
+                    0.0
+                } else {
+                    y.signum()
+                }
+            }
+            mouse::ScrollDelta::Pixels { y, .. } => {
+                // A change of direction starts over.
+                if y * *scrolled < 0.0 {
+                    *scrolled = 0.0;
+                }
+                *scrolled += y;
+
+                let steps = (*scrolled / SCROLL_PIXELS_PER_STEP).trunc();
+                *scrolled -= steps * SCROLL_PIXELS_PER_STEP;
+                steps
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for KeyboardSlider<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: FocusTheme,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.focusable(&mut tree.state.downcast_mut::<State>().focus, None);
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        state.focus.observe(&event);
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if cursor.is_over(bounds) {
+                    state.focus.focus();
+                } else {
+                    state.focus.unfocus();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if state.focus.is_focused() =>
+            {
+                if let Some(value) =
+                    key_value(key, self.value, &self.range, self.step, self.page_step)
+                {
+                    if self.change(value, shell) {
+                        if let Some(on_release) = self.on_release.clone() {
+                            shell.publish(on_release);
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let steps = Self::wheel_steps(*delta, &mut state.scrolled);
+                let value = stepped(self.value, &self.range, self.step, steps);
+
+                // At the end of the range, let the page scroll instead.
+                if steps != 0.0 && self.change(value, shell) {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if tree.state.downcast_ref::<State>().focus.is_focus_visible() {
+            draw_focus_ring(renderer, layout.bounds(), theme.focus_ring());
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+    use iced::advanced::clipboard;
+    use iced::Theme;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(f32),
+        Released,
+    }
+
+    fn press(named: key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modified_key: keyboard::Key::Named(named),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: None,
+        })
+    }
+
+    fn keyboard_slider(value: f32) -> KeyboardSlider<'static, Message, Theme, ()> {
+        let on_change: Rc<dyn Fn(f32) -> Message> = Rc::new(Message::Changed);
+        let forward = Rc::clone(&on_change);
+        KeyboardSlider {
+            content: slider(0.0..=100.0, value, move |value| forward(value))
+                .step(5.0)
+                .into(),
+            range: 0.0..=100.0,
+            value,
+            step: 5.0,
+            page_step: 50.0,
+            on_change,
+            on_release: Some(Message::Released),
+        }
+    }
+
+    /// Send `events` to a 200 by 40 slider at `value`, returning the
+    /// messages, the status of each event and the focus state afterwards.
+    fn send(
+        value: f32,
+        focused: bool,
+        events: impl IntoIterator<Item = Event>,
+    ) -> (Vec<Message>, Vec<event::Status>, FocusVisible) {
+        let mut widget = keyboard_slider(value);
+        let mut tree = Tree::new(&widget as &dyn Widget<Message, Theme, ()>);
+        let node = widget.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+        if focused {
+            tree.state.downcast_mut::<State>().focus.focus();
+        }
+
+        let mut messages = Vec::new();
+        let mut statuses = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for event in events {
+            let status = widget.on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(node.bounds().center()),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(200.0, 40.0)),
+            );
+            statuses.push(status);
+        }
+
+        (messages, statuses, tree.state.downcast_ref::<State>().focus)
+    }
+
+    fn press_key(value: f32, key: key::Named, focused: bool) -> Vec<Message> {
+        send(value, focused, [press(key)]).0
+    }
+
+    fn scroll(delta: mouse::ScrollDelta) -> Event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta })
+    }
+
+    fn pixels(y: f32) -> Event {
+        scroll(mouse::ScrollDelta::Pixels { x: 0.0, y })
+    }
+
+    #[test]
+    fn focused_arrow_right_steps_once() {
+        assert_eq!(
+            press_key(20.0, key::Named::ArrowRight, true),
+            vec![Message::Changed(25.0), Message::Released]
+        );
+        assert!(press_key(20.0, key::Named::ArrowRight, false).is_empty());
+    }
+
+    #[test]
+    fn focused_end_jumps_to_max() {
+        assert_eq!(
+            press_key(20.0, key::Named::End, true),
+            vec![Message::Changed(100.0), Message::Released]
+        );
+    }
+
+    #[test]
+    fn keyboard_changes_fire_on_release() {
+        // Once per change, like the end of a drag; none at the end of the range.
+        assert_eq!(
+            press_key(20.0, key::Named::ArrowLeft, true),
+            vec![Message::Changed(15.0), Message::Released]
+        );
+        assert!(press_key(100.0, key::Named::End, true).is_empty());
+    }
+
+    #[test]
+    fn wheel_at_the_end_of_the_range_lets_the_page_scroll() {
+        let up = scroll(mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 });
+
+        let (messages, statuses, _) = send(50.0, false, [up.clone()]);
+        assert_eq!(messages, vec![Message::Changed(55.0)]);
+        assert_eq!(statuses, [event::Status::Captured]);
+
+        let (messages, statuses, _) = send(100.0, false, [up]);
+        assert!(messages.is_empty());
+        assert_eq!(statuses, [event::Status::Ignored]);
+    }
+
+    #[test]
+    fn touchpad_scrolling_accumulates_pixels_into_steps() {
+        let third = SCROLL_PIXELS_PER_STEP / 3.0;
+
+        // Small deltas pass through until they add up to a step.
+        let (messages, statuses, _) = send(50.0, false, [pixels(third), pixels(third)]);
+        assert!(messages.is_empty());
+        assert_eq!(statuses, [event::Status::Ignored; 2]);
+
+        let (messages, _, _) = send(50.0, false, [pixels(third), pixels(third), pixels(third)]);
+        assert_eq!(messages, vec![Message::Changed(55.0)]);
+
+        // A fast swipe moves several steps at once.
+        let (messages, _, _) = send(50.0, false, [pixels(-3.0 * SCROLL_PIXELS_PER_STEP)]);
+        assert_eq!(messages, vec![Message::Changed(35.0)]);
+    }
+
+    #[test]
+    fn disabled_slider_ignores_input_and_is_dimmed() {
+        let slider = |disabled| Slider::new(0.0..=100.0, 20.0, Message::Changed).disabled(disabled);
+        let theme = Theme::Light;
+
+        assert!(!interact(slider(false).into()).is_empty());
+        assert!(interact(slider(true).into()).is_empty());
+        assert_eq!(
+            last_quad(&slider(true).into(), &theme),
+            Some(theme.disabled_veil())
+        );
+    }
+
+    #[test]
+    fn focus_ring_shows_for_tab_focus_but_not_click_focus() {
+        // Tab passes through the slider, then the app's focus operation
+        // focuses it.
+        let (_, _, mut tabbed) = send(20.0, false, [press(key::Named::Tab)]);
+        tabbed.focus();
+        assert!(tabbed.is_focus_visible());
+
+        let click = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let (_, _, clicked) = send(20.0, false, [press(key::Named::Tab), click]);
+        assert!(clicked.is_focused());
+        assert!(!clicked.is_focus_visible());
     }
 }
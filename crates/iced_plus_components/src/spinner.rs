@@ -26,12 +26,22 @@
 //! // In your view:
 //! CircularSpinner::new().progress(self.spinner_progress)
 //! ```
+//!
+//! # Reduced motion
+//!
+//! Spinners turned into elements while
+//! [`reduce_motion`](iced_plus_tokens::reduce_motion) is on ignore `progress`
+//! and draw a still frame: a partial arc, a centered bar, or resting dots.
 
 use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
 use iced::{mouse, Element, Length, Point, Rectangle, Renderer, Size, Subscription, Theme};
+use iced_plus_theme::{scaled, ComponentSize};
+use iced_plus_tokens::reduce_motion;
 use std::f32::consts::PI;
 use std::time::{Duration, Instant};
 
+use crate::button::LINE_HEIGHT;
+
 /// Easing function type.
 pub type EasingFn = fn(f32) -> f32;
 
@@ -96,11 +106,30 @@ pub mod easing {
 ///
 /// // In your subscription, update progress every ~16ms
 /// ```
+///
+/// Inside a button or next to an input, size the spinner from the same
+/// [`ComponentSize`] so it lines up with the label:
+///
+/// ```rust,ignore
+/// CircularSpinner::new()
+///     .component_size(Small)
+///     .progress(self.spinner_progress)
+/// ```
+///
+/// Once the real progress of the work is known, switch to a proportional arc
+/// with [`determinate`](Self::determinate):
+///
+/// ```rust,ignore
+/// CircularSpinner::new()
+///     .progress(self.spinner_progress)
+///     .determinate(self.download.fraction())
+/// ```
 pub struct CircularSpinner {
     size: f32,
     bar_height: f32,
     progress: f32,
     easing: EasingFn,
+    value: Option<f32>,
 }
 
 impl Default for CircularSpinner {
@@ -118,6 +147,7 @@ impl CircularSpinner {
             bar_height: 4.0,
             progress: 0.0,
             easing: easing::ease_in_out,
+            value: None,
         }
     }
 
@@ -135,6 +165,19 @@ impl CircularSpinner {
         self
     }
 
+    /// Size the spinner for a component of size `S`.
+    ///
+    /// The diameter is the label line height at `S`'s font size, so a
+    /// spinner swapped in for a button's label keeps the button's height.
+    /// The stroke is an eighth of the diameter. Both follow the current
+    /// [UI scale](iced_plus_theme::set_ui_scale).
+    #[must_use]
+    pub fn component_size<S: ComponentSize>(mut self, _size: S) -> Self {
+        self.size = scaled(S::FONT_SIZE * LINE_HEIGHT);
+        self.bar_height = self.size / 8.0;
+        self
+    }
+
     /// Set the animation progress (0.0 to 1.0).
     /// Update this periodically for animation.
     #[must_use]
@@ -149,12 +192,58 @@ impl CircularSpinner {
         self.easing = easing;
         self
     }
+
+    /// Show a known completion (0.0 to 1.0) as an arc growing clockwise
+    /// from the top, or keep the indeterminate animation with `None`.
+    ///
+    /// Passing `Option` straight through lets a spinner hand off from
+    /// "unknown" to "known" progress without swapping widgets.
+    #[must_use]
+    pub fn determinate(mut self, value: Option<f32>) -> Self {
+        self.value = value.map(|value| value.clamp(0.0, 1.0));
+        self
+    }
 }
 
 struct CircularProgram {
     bar_height: f32,
     progress: f32,
     easing: EasingFn,
+    value: Option<f32>,
+    reduce_motion: bool,
+}
+
+impl CircularProgram {
+    /// Start and sweep angles of the arc, in radians.
+    fn arc(&self) -> (f32, f32) {
+        if let Some(value) = self.value {
+            // Clockwise from 12 o'clock.
+            return (-PI / 2.0, value * 2.0 * PI);
+        }
+
+        if self.reduce_motion {
+            // A still three-quarter arc from 12 o'clock.
+            return (-PI / 2.0, 1.5 * PI);
+        }
+
+        let rotation = self.progress * 2.0 * PI * 2.0; // Two full rotations per cycle
+
+        // Expand then contract
+        let cycle_progress = (self.progress * 2.0) % 1.0;
+        let is_expanding = (self.progress * 2.0) < 1.0;
+
+        let min_angle = 0.1 * PI;
+        let max_angle = 1.5 * PI;
+
+        if is_expanding {
+            let sweep = min_angle + (max_angle - min_angle) * (self.easing)(cycle_progress);
+            (rotation, sweep)
+        } else {
+            let sweep = max_angle - (max_angle - min_angle) * (self.easing)(cycle_progress);
+            let start = rotation + (max_angle - min_angle) * (self.easing)(cycle_progress);
+            (start, sweep)
+        }
+    }
 }
 
 impl<Message> canvas::Program<Message, Theme> for CircularProgram {
@@ -183,24 +272,7 @@ impl<Message> canvas::Program<Message, Theme> for CircularProgram {
                 .with_color(palette.background.weak.color),
         );
 
-        // Calculate arc based on progress
-        let rotation = self.progress * 2.0 * PI * 2.0; // Two full rotations per cycle
-
-        // Expand then contract
-        let cycle_progress = (self.progress * 2.0) % 1.0;
-        let is_expanding = (self.progress * 2.0) < 1.0;
-
-        let min_angle = 0.1 * PI;
-        let max_angle = 1.5 * PI;
-
-        let (start_angle, sweep_angle) = if is_expanding {
-            let sweep = min_angle + (max_angle - min_angle) * (self.easing)(cycle_progress);
-            (rotation, sweep)
-        } else {
-            let sweep = max_angle - (max_angle - min_angle) * (self.easing)(cycle_progress);
-            let start = rotation + (max_angle - min_angle) * (self.easing)(cycle_progress);
-            (start, sweep)
-        };
+        let (start_angle, sweep_angle) = self.arc();
 
         // Draw animated arc
         let arc = Path::new(|builder| {
@@ -230,6 +302,8 @@ impl<'a, Message: 'a> From<CircularSpinner> for Element<'a, Message, Theme> {
             bar_height: spinner.bar_height,
             progress: spinner.progress,
             easing: spinner.easing,
+            value: spinner.value,
+            reduce_motion: reduce_motion(),
         };
         Canvas::new(program)
             .width(Length::Fixed(size))
@@ -305,6 +379,7 @@ impl LinearSpinner {
 struct LinearProgram {
     progress: f32,
     easing: EasingFn,
+    reduce_motion: bool,
 }
 
 impl<Message> canvas::Program<Message, Theme> for LinearProgram {
@@ -327,8 +402,14 @@ impl<Message> canvas::Program<Message, Theme> for LinearProgram {
 
         // Calculate bar position based on progress
         // Bar moves from left to right, then right to left
-        let cycle_progress = (self.progress * 2.0) % 1.0;
-        let is_first_half = (self.progress * 2.0) < 1.0;
+        // (held in the middle under reduced motion)
+        let progress = if self.reduce_motion {
+            0.25
+        } else {
+            self.progress
+        };
+        let cycle_progress = (progress * 2.0) % 1.0;
+        let is_first_half = (progress * 2.0) < 1.0;
 
         let eased = (self.easing)(cycle_progress);
 
@@ -353,6 +434,7 @@ impl<'a, Message: 'a> From<LinearSpinner> for Element<'a, Message, Theme> {
         let program = LinearProgram {
             progress: spinner.progress,
             easing: spinner.easing,
+            reduce_motion: reduce_motion(),
         };
         Canvas::new(program)
             .width(spinner.width)
@@ -430,6 +512,7 @@ struct DotsProgram {
     dot_size: f32,
     spacing: f32,
     progress: f32,
+    reduce_motion: bool,
 }
 
 impl<Message> canvas::Program<Message, Theme> for DotsProgram {
@@ -458,14 +541,17 @@ impl<Message> canvas::Program<Message, Theme> for DotsProgram {
             let dot_offset = i as f32 / self.dot_count as f32;
             let dot_progress = (self.progress + dot_offset) % 1.0;
 
-            // Bounce animation using sine wave
-            let bounce = (dot_progress * PI).sin();
-            let y_offset = bounce * self.dot_size * 0.5;
+            // Bounce animation using sine wave; dots rest at full opacity
+            // under reduced motion
+            let (y_offset, opacity) = if self.reduce_motion {
+                (0.0, 1.0)
+            } else {
+                let bounce = (dot_progress * PI).sin();
+                (bounce * self.dot_size * 0.5, 0.6_f32.mul_add(bounce, 0.4))
+            };
 
             let center = Point::new(x, center_y - y_offset);
 
-            // Opacity based on bounce
-            let opacity = 0.4 + 0.6 * bounce;
             let color = iced::Color {
                 a: opacity,
                 ..palette.primary.base.color
@@ -489,6 +575,7 @@ impl<'a, Message: 'a> From<DotsSpinner> for Element<'a, Message, Theme> {
             dot_size: spinner.dot_size,
             spacing: spinner.spacing,
             progress: spinner.progress,
+            reduce_motion: reduce_motion(),
         };
         Canvas::new(program)
             .width(Length::Fixed(width))
@@ -544,6 +631,7 @@ impl PulseSpinner {
 
 struct PulseProgram {
     progress: f32,
+    reduce_motion: bool,
 }
 
 impl<Message> canvas::Program<Message, Theme> for PulseProgram {
@@ -562,8 +650,12 @@ impl<Message> canvas::Program<Message, Theme> for PulseProgram {
         let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
         let max_radius = bounds.width.min(bounds.height) / 2.0;
 
-        // Pulse animation
-        let pulse = (self.progress * PI * 2.0).sin() * 0.5 + 0.5;
+        // Pulse animation, held fully grown under reduced motion
+        let pulse = if self.reduce_motion {
+            1.0
+        } else {
+            (self.progress * PI * 2.0).sin().mul_add(0.5, 0.5)
+        };
         let radius = max_radius * (0.6 + 0.4 * pulse);
         let opacity = 0.3 + 0.7 * pulse;
 
@@ -584,6 +676,7 @@ impl<'a, Message: 'a> From<PulseSpinner> for Element<'a, Message, Theme> {
         let size = spinner.size;
         let program = PulseProgram {
             progress: spinner.progress,
+            reduce_motion: reduce_motion(),
         };
         Canvas::new(program)
             .width(Length::Fixed(size))
@@ -676,3 +769,86 @@ pub fn calculate_progress(start: Instant, cycle_duration: Duration) -> f32 {
     let cycle_secs = cycle_duration.as_secs_f32();
     (elapsed % cycle_secs) / cycle_secs
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(spinner: &CircularSpinner) -> CircularProgram {
+        CircularProgram {
+            bar_height: spinner.bar_height,
+            progress: spinner.progress,
+            easing: spinner.easing,
+            value: spinner.value,
+            reduce_motion: reduce_motion(),
+        }
+    }
+
+    #[test]
+    fn determinate_value_draws_proportional_sweep() {
+        let (start, sweep) = program(&CircularSpinner::new().determinate(Some(0.5))).arc();
+
+        assert!((start + PI / 2.0).abs() < f32::EPSILON);
+        assert!((sweep - PI).abs() < f32::EPSILON);
+
+        let (_, full) = program(&CircularSpinner::new().determinate(Some(1.5))).arc();
+        assert!((full - std::f32::consts::TAU).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn no_value_keeps_the_animated_sweep() {
+        let early = program(&CircularSpinner::new().progress(0.1).determinate(None)).arc();
+        let later = program(&CircularSpinner::new().progress(0.3).determinate(None)).arc();
+
+        // The indeterminate arc rotates and grows with the animation progress.
+        assert!(later.0 > early.0);
+        assert!(later.1 > early.1);
+        assert!(later.1 < 1.5_f32.mul_add(PI, f32::EPSILON));
+    }
+
+    #[test]
+    fn reduced_motion_holds_the_arc_still() {
+        let frames =
+            [0.1, 0.4, 0.7].map(|progress| program(&CircularSpinner::new().progress(progress)));
+
+        let (still, determinate) = iced_plus_tokens::with_reduce_motion(true, || {
+            (
+                [0.1, 0.4, 0.7].map(|progress| program(&CircularSpinner::new().progress(progress))),
+                program(&CircularSpinner::new().determinate(Some(0.5))),
+            )
+        });
+
+        // Drawn after the scope ends, the spinners keep the setting they
+        // were built with.
+        let still = still.each_ref().map(CircularProgram::arc);
+        let determinate = determinate.arc();
+
+        assert!(still.iter().all(|arc| arc == &still[0]));
+        assert!(still[0].1 > 0.0 && still[0].1 < 2.0 * PI);
+        // Known progress still shows as a proportional arc.
+        assert!((determinate.1 - PI).abs() < f32::EPSILON);
+        assert_ne!(frames[0].arc(), frames[1].arc());
+    }
+
+    #[test]
+    fn component_size_matches_button_height() {
+        use crate::button::{self, ButtonSize};
+        use iced_plus_theme::{Large, Small};
+
+        let small = CircularSpinner::new().component_size(Small);
+        let large = CircularSpinner::new().component_size(Large);
+
+        // 14px label at a 1.3 line height.
+        assert!((small.size - 18.2).abs() < 1e-4);
+        assert!((small.bar_height - small.size / 8.0).abs() < f32::EPSILON);
+        assert!(large.size > small.size);
+
+        // In place of the label, the spinner keeps each button's height.
+        for (spinner, height, padding) in [
+            (small, button::Small::height(), button::Small::PADDING_V),
+            (large, button::Large::height(), button::Large::PADDING_V),
+        ] {
+            assert!((padding.mul_add(2.0, spinner.size) - height).abs() < 1e-4);
+        }
+    }
+}
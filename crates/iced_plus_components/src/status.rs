@@ -0,0 +1,379 @@
+//! Status indicator: a colored dot with a label.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::text::{self, Paragraph as _};
+use iced::advanced::widget::{tree, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::time::Instant;
+use iced::{
+    alignment, event, mouse, window, Border, Color, Element, Event, Length, Pixels, Point,
+    Rectangle, Size,
+};
+use iced_plus_theme::AppTheme;
+use iced_plus_tokens::{reduce_motion, Shade};
+
+/// Diameter of the dot.
+const DOT_SIZE: f32 = 8.0;
+
+/// Gap between the dot and the label.
+const SPACING: f32 = 6.0;
+
+/// Length of one pulse, from the dot outwards until the halo fades.
+const PULSE_PERIOD: Duration = Duration::from_millis(1600);
+
+/// Meaning of a [`StatusIndicator`], mapped to the theme's semantic colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusKind {
+    /// Healthy or connected.
+    Success,
+    /// Degraded or reconnecting.
+    Warning,
+    /// Failed or disconnected.
+    Error,
+    /// Idle, offline or unknown.
+    #[default]
+    Neutral,
+}
+
+/// Themes that can color a [`StatusIndicator`].
+pub trait StatusTheme {
+    /// Color of the dot for `kind`.
+    fn status_color(&self, kind: StatusKind) -> Color;
+}
+
+impl StatusTheme for AppTheme<'_> {
+    fn status_color(&self, kind: StatusKind) -> Color {
+        match kind {
+            StatusKind::Success => self.success(Shade::S500),
+            StatusKind::Warning => self.warning(Shade::S500),
+            StatusKind::Error => self.destructive(Shade::S500),
+            StatusKind::Neutral => self.neutral(Shade::S400),
+        }
+    }
+}
+
+impl StatusTheme for iced::Theme {
+    fn status_color(&self, kind: StatusKind) -> Color {
+        let palette = self.extended_palette();
+
+        match kind {
+            StatusKind::Success => palette.success.base.color,
+            // iced's palette has no warning color; match the warning badge.
+            StatusKind::Warning => Color::from_rgb(0.95, 0.65, 0.15),
+            StatusKind::Error => palette.danger.base.color,
+            StatusKind::Neutral => palette.background.strong.color,
+        }
+    }
+}
+
+/// A colored dot followed by a label, e.g. "● Connected".
+///
+/// The dot color comes from the theme's semantic colors. A pulsing halo can
+/// mark live states such as an active stream or recording.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// if self.connected {
+///     StatusIndicator::success("Connected").pulse(true)
+/// } else {
+///     StatusIndicator::error("Disconnected")
+/// }
+/// ```
+pub struct StatusIndicator<'a> {
+    label: Cow<'a, str>,
+    kind: StatusKind,
+    pulse: bool,
+    reduce_motion: bool,
+    text_size: f32,
+}
+
+impl<'a> StatusIndicator<'a> {
+    /// Create an indicator with a label and status.
+    #[must_use]
+    pub fn new(label: impl Into<Cow<'a, str>>, kind: StatusKind) -> Self {
+        Self {
+            label: label.into(),
+            kind,
+            pulse: false,
+            reduce_motion: reduce_motion(),
+            text_size: 13.0,
+        }
+    }
+
+    /// Create a success indicator.
+    #[must_use]
+    pub fn success(label: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(label, StatusKind::Success)
+    }
+
+    /// Create a warning indicator.
+    #[must_use]
+    pub fn warning(label: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(label, StatusKind::Warning)
+    }
+
+    /// Create an error indicator.
+    #[must_use]
+    pub fn error(label: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(label, StatusKind::Error)
+    }
+
+    /// Create a neutral indicator.
+    #[must_use]
+    pub fn neutral(label: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(label, StatusKind::Neutral)
+    }
+
+    /// Pulse a halo around the dot, for live states.
+    ///
+    /// No halo is drawn if the indicator was built while [`reduce_motion`]
+    /// was on.
+    #[must_use]
+    pub const fn pulse(mut self, pulse: bool) -> Self {
+        self.pulse = pulse;
+        self
+    }
+
+    /// Set the label font size.
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.text_size = size;
+        self
+    }
+
+    /// Whether the halo is animating.
+    const fn pulses(&self) -> bool {
+        self.pulse && !self.reduce_motion
+    }
+
+    fn text<C>(&self, content: C, bounds: Size) -> text::Text<C> {
+        text::Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: text::LineHeight::default(),
+            font: iced::Font::default(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+/// Progress through the current pulse.
+#[derive(Debug, Default)]
+struct Pulse {
+    started: Option<Instant>,
+    progress: f32,
+}
+
+impl Pulse {
+    fn advance(&mut self, now: Instant) {
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started).as_secs_f32();
+
+        self.progress = (elapsed / PULSE_PERIOD.as_secs_f32()).fract();
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for StatusIndicator<'_>
+where
+    Theme: StatusTheme,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Pulse>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Pulse::default())
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let label =
+            Renderer::Paragraph::with_text(self.text(&*self.label, Size::INFINITY)).min_bounds();
+
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(DOT_SIZE + SPACING + label.width, DOT_SIZE.max(label.height)),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.pulses() {
+                tree.state.downcast_mut::<Pulse>().advance(now);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let color = theme.status_color(self.kind);
+        let center = Point::new(bounds.x + DOT_SIZE / 2.0, bounds.center_y());
+        let circle = |diameter: f32| renderer::Quad {
+            bounds: Rectangle::new(
+                Point::new(center.x - diameter / 2.0, center.y - diameter / 2.0),
+                Size::new(diameter, diameter),
+            ),
+            border: Border {
+                radius: (diameter / 2.0).into(),
+                ..Border::default()
+            },
+            ..renderer::Quad::default()
+        };
+
+        if self.pulses() {
+            let progress = tree.state.downcast_ref::<Pulse>().progress;
+
+            renderer.fill_quad(
+                circle(DOT_SIZE * (1.0 + progress)),
+                Color {
+                    a: color.a * 0.4 * (1.0 - progress),
+                    ..color
+                },
+            );
+        }
+
+        renderer.fill_quad(circle(DOT_SIZE), color);
+
+        let label_x = bounds.x + DOT_SIZE + SPACING;
+        renderer.fill_text(
+            self.text(
+                self.label.to_string(),
+                Size::new(bounds.x + bounds.width - label_x, bounds.height),
+            ),
+            Point::new(label_x, bounds.center_y()),
+            style.text_color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StatusIndicator<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: StatusTheme + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(indicator: StatusIndicator<'a>) -> Self {
+        Element::new(indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+
+    fn draw(indicator: &StatusIndicator<'_>, theme: &AppTheme<'_>) -> Recorder {
+        let widget = indicator as &dyn Widget<(), AppTheme<'_>, Recorder>;
+        let mut tree = Tree::new(widget);
+        let mut recorder = Recorder::default();
+        let node = widget.layout(
+            &mut tree,
+            &recorder,
+            &Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+
+        widget.draw(
+            &tree,
+            &mut recorder,
+            theme,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(200.0, 40.0)),
+        );
+
+        recorder
+    }
+
+    #[test]
+    fn dot_color_comes_from_the_theme() {
+        let theme = AppTheme::light();
+        let cases = [
+            (
+                StatusIndicator::success("Online"),
+                theme.success(Shade::S500),
+            ),
+            (StatusIndicator::warning("Slow"), theme.warning(Shade::S500)),
+            (
+                StatusIndicator::error("Down"),
+                theme.destructive(Shade::S500),
+            ),
+            (StatusIndicator::neutral("Idle"), theme.neutral(Shade::S400)),
+        ];
+
+        for (indicator, expected) in cases {
+            assert_eq!(draw(&indicator, &theme).colors(), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn label_is_drawn_after_the_dot() {
+        let recorder = draw(&StatusIndicator::success("Connected"), &AppTheme::dark());
+
+        assert_eq!(recorder.text_contents(), ["Connected"]);
+    }
+
+    #[test]
+    fn pulse_draws_a_fading_halo() {
+        let theme = AppTheme::light();
+        let quads = draw(&StatusIndicator::success("Live").pulse(true), &theme).colors();
+        let color = theme.success(Shade::S500);
+
+        assert_eq!(quads.len(), 2);
+        // A fresh pulse starts at the dot's size, at 40% of its opacity.
+        assert!((quads[0].a / color.a - 0.4).abs() < f32::EPSILON);
+        assert_eq!(quads[1], color);
+    }
+
+    #[test]
+    fn reduced_motion_draws_no_halo() {
+        let theme = AppTheme::light();
+        let indicator = iced_plus_tokens::with_reduce_motion(true, || {
+            StatusIndicator::success("Live").pulse(true)
+        });
+
+        // Drawn after the scope ends, the indicator keeps its setting.
+        let quads = draw(&indicator, &theme).colors();
+
+        assert_eq!(quads, vec![theme.success(Shade::S500)]);
+    }
+}
@@ -4,8 +4,16 @@ use iced::advanced::layout::{Layout, Limits, Node};
 use iced::advanced::renderer;
 use iced::advanced::widget::{tree, Tree, Widget};
 use iced::advanced::{Clipboard, Shell};
+use iced::widget::text;
 use iced::{event, mouse, Color, Element, Event, Length, Rectangle, Size};
 
+use crate::disabled::{disable, DisabledTheme};
+use crate::focus::{keyboard_focusable, FocusTheme};
+use crate::labeled::{LabelPosition, Labeled};
+
+/// Gap between the switch and its label.
+const LABEL_SPACING: f32 = 10.0;
+
 /// A toggle switch component.
 ///
 /// # Example
@@ -14,12 +22,18 @@ use iced::{event, mouse, Color, Element, Event, Length, Rectangle, Size};
 /// Switch::new(is_enabled, Message::ToggleEnabled)
 ///     .label("Enable feature")
 /// ```
+///
+/// Clicking the label toggles the switch too, unless
+/// [`label_clickable(false)`](Self::label_clickable) is set.
 pub struct Switch<'a, Message> {
     is_on: bool,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: Option<String>,
+    label_position: LabelPosition,
+    label_clickable: bool,
     width: f32,
     height: f32,
+    disabled: bool,
 }
 
 impl<'a, Message> Switch<'a, Message> {
@@ -32,8 +46,11 @@ impl<'a, Message> Switch<'a, Message> {
             is_on,
             on_toggle: Box::new(on_toggle),
             label: None,
+            label_position: LabelPosition::default(),
+            label_clickable: true,
             width: 44.0,
             height: 24.0,
+            disabled: false,
         }
     }
 
@@ -44,6 +61,20 @@ impl<'a, Message> Switch<'a, Message> {
         self
     }
 
+    /// Set which side of the switch the label is on.
+    #[must_use]
+    pub const fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Set whether clicking the label toggles the switch (default: true).
+    #[must_use]
+    pub const fn label_clickable(mut self, clickable: bool) -> Self {
+        self.label_clickable = clickable;
+        self
+    }
+
     /// Set custom dimensions.
     #[must_use]
     pub fn size(mut self, width: f32, height: f32) -> Self {
@@ -51,6 +82,13 @@ impl<'a, Message> Switch<'a, Message> {
         self.height = height;
         self
     }
+
+    /// Disable the switch: it ignores clicks and is dimmed.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Switch<'a, Message>
@@ -190,10 +228,60 @@ impl<'a, Message, Theme, Renderer> From<Switch<'a, Message>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: Clone + 'a,
-    Theme: 'a,
-    Renderer: renderer::Renderer + 'a,
+    Theme: text::Catalog + DisabledTheme + FocusTheme + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
 {
-    fn from(switch: Switch<'a, Message>) -> Self {
-        Element::new(switch)
+    fn from(mut switch: Switch<'a, Message>) -> Self {
+        let disabled = switch.disabled;
+        let label = switch.label.take();
+        let on_click = switch
+            .label_clickable
+            .then(|| (switch.on_toggle)(!switch.is_on));
+        let position = switch.label_position;
+
+        let control = if disabled {
+            Element::new(switch)
+        } else {
+            keyboard_focusable(Element::new(switch))
+        };
+        let Some(label) = label else {
+            return disable(control, disabled);
+        };
+
+        disable(
+            Labeled::new(
+                control,
+                text(label),
+                position,
+                LABEL_SPACING,
+                Length::Shrink,
+                on_click,
+            ),
+            disabled,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disabled::tests::{interact, last_quad};
+    use crate::disabled::DisabledTheme;
+
+    #[test]
+    fn disabled_switch_ignores_clicks_and_is_dimmed() {
+        let switch = |disabled| {
+            Switch::new(false, |on| on)
+                .label("Wi-Fi")
+                .disabled(disabled)
+        };
+        let theme = iced::Theme::Dark;
+
+        assert_eq!(interact::<_, iced::Theme>(switch(false).into()), [true]);
+        assert!(interact::<_, iced::Theme>(switch(true).into()).is_empty());
+        assert_eq!(
+            last_quad(&switch(true).into(), &theme),
+            Some(theme.disabled_veil())
+        );
     }
 }
@@ -35,6 +35,9 @@ impl<'a> Tab<'a> {
     }
 }
 
+/// Distance the cursor must travel before a press becomes a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
 /// Tab bar component for switching between views.
 ///
 /// # Example
@@ -44,11 +47,13 @@ impl<'a> Tab<'a> {
 ///     .push(Tab::new("Overview"))
 ///     .push(Tab::new("Settings"))
 ///     .push(Tab::new("Help"))
+///     .reorderable(Message::TabMoved)
 /// ```
 pub struct Tabs<'a, Message> {
     tabs: Vec<Tab<'a>>,
     active: usize,
     on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
     tab_width: TabWidth,
     height: f32,
 }
@@ -75,6 +80,7 @@ impl<'a, Message> Tabs<'a, Message> {
             tabs: Vec::new(),
             active,
             on_select: Box::new(on_select),
+            on_reorder: None,
             tab_width: TabWidth::default(),
             height: 40.0,
         }
@@ -101,6 +107,26 @@ impl<'a, Message> Tabs<'a, Message> {
         self
     }
 
+    /// Let the user drag tabs to new positions.
+    ///
+    /// On drop, `on_reorder` receives the tab's old index and its new index
+    /// (counted after removing it), ready for `Vec::remove` + `Vec::insert`.
+    #[must_use]
+    pub fn reorderable<F>(mut self, on_reorder: F) -> Self
+    where
+        F: Fn(usize, usize) -> Message + 'a,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Index of the tab under `rel_x`.
+    fn tab_at(&self, total_width: f32, rel_x: f32) -> Option<usize> {
+        self.tab_bounds(total_width)
+            .iter()
+            .position(|(x, w)| rel_x >= *x && rel_x < x + w)
+    }
+
     fn tab_bounds(&self, total_width: f32) -> Vec<(f32, f32)> {
         let tab_count = self.tabs.len();
         if tab_count == 0 {
@@ -131,6 +157,69 @@ impl<'a, Message> Tabs<'a, Message> {
     }
 }
 
+/// A tab being dragged to a new position.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    start_x: f32,
+    delta: f32,
+    moved: bool,
+}
+
+impl Drag {
+    /// Position the dragged tab would take if dropped now.
+    fn target(&self, tab_bounds: &[(f32, f32)]) -> usize {
+        let (x, w) = tab_bounds[self.index];
+        let center = x + self.delta + w / 2.0;
+
+        tab_bounds
+            .iter()
+            .enumerate()
+            .filter(|(i, (x, w))| *i != self.index && x + w / 2.0 < center)
+            .count()
+    }
+
+    /// Horizontal offset of every tab: the dragged tab follows the cursor
+    /// and the others close up around its target slot.
+    fn positions(&self, tab_bounds: &[(f32, f32)]) -> Vec<f32> {
+        let mut order: Vec<usize> = (0..tab_bounds.len()).filter(|&i| i != self.index).collect();
+        order.insert(self.target(tab_bounds), self.index);
+
+        let mut positions = vec![0.0; tab_bounds.len()];
+        let mut x = 0.0;
+        for i in order {
+            positions[i] = x;
+            x += tab_bounds[i].1;
+        }
+        positions[self.index] = tab_bounds[self.index].0 + self.delta;
+
+        positions
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+/// Tab indices in paint order, with a dragged tab last so it draws on top,
+/// and the horizontal offset of each tab.
+fn draw_order(state: &State, tab_bounds: &[(f32, f32)]) -> (Vec<usize>, Vec<f32>) {
+    let drag = state
+        .drag
+        .filter(|drag| drag.moved && drag.index < tab_bounds.len());
+    let positions = drag.map_or_else(
+        || tab_bounds.iter().map(|(x, _)| *x).collect(),
+        |drag| drag.positions(tab_bounds),
+    );
+    let order = (0..tab_bounds.len())
+        .filter(|&i| drag.map_or(true, |drag| drag.index != i))
+        .chain(drag.map(|drag| drag.index))
+        .collect();
+
+    (order, positions)
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Tabs<'a, Message>
 where
     Message: Clone,
@@ -141,11 +230,11 @@ where
     }
 
     fn tag(&self) -> tree::Tag {
-        tree::Tag::stateless()
+        tree::Tag::of::<State>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::None
+        tree::State::new(State::default())
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -161,7 +250,7 @@ where
 
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -170,23 +259,54 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
-            if let Some(position) = cursor.position() {
-                let bounds = layout.bounds();
-                if bounds.contains(position) {
-                    let tab_bounds = self.tab_bounds(bounds.width);
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(bounds) {
                     let rel_x = position.x - bounds.x;
 
-                    for (i, (x, w)) in tab_bounds.iter().enumerate() {
-                        if rel_x >= *x && rel_x < x + w {
-                            if i != self.active {
-                                shell.publish((self.on_select)(i));
+                    if let Some(i) = self.tab_at(bounds.width, rel_x) {
+                        if i != self.active {
+                            shell.publish((self.on_select)(i));
+                        }
+                        if self.on_reorder.is_some() {
+                            state.drag = Some(Drag {
+                                index: i,
+                                start_x: rel_x,
+                                delta: 0.0,
+                                moved: false,
+                            });
+                        }
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(drag) = state.drag.as_mut() {
+                    drag.delta = position.x - bounds.x - drag.start_x;
+                    drag.moved |= drag.delta.abs() > DRAG_THRESHOLD;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = state.drag.take() {
+                    let tab_bounds = self.tab_bounds(bounds.width);
+
+                    if drag.moved && drag.index < tab_bounds.len() {
+                        let target = drag.target(&tab_bounds);
+
+                        if let Some(on_reorder) = self.on_reorder.as_ref() {
+                            if target != drag.index {
+                                shell.publish(on_reorder(drag.index, target));
                             }
-                            return event::Status::Captured;
                         }
                     }
+                    return event::Status::Captured;
                 }
             }
+            _ => {}
         }
 
         event::Status::Ignored
@@ -194,13 +314,20 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if cursor.is_over(layout.bounds()) {
+        if tree
+            .state
+            .downcast_ref::<State>()
+            .drag
+            .is_some_and(|drag| drag.moved)
+        {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -209,7 +336,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         _theme: &Theme,
         _style: &renderer::Style,
@@ -234,12 +361,15 @@ where
             Color::from_rgba(0.95, 0.95, 0.97, 1.0),
         );
 
+        let (order, positions) = draw_order(tree.state.downcast_ref::<State>(), &tab_bounds);
+
         // Draw tabs
-        for (i, (tab, (x, w))) in self.tabs.iter().zip(tab_bounds.iter()).enumerate() {
+        for i in order {
+            let tab = &self.tabs[i];
             let tab_rect = Rectangle {
-                x: bounds.x + x,
+                x: bounds.x + positions[i],
                 y: bounds.y,
-                width: *w,
+                width: tab_bounds[i].1,
                 height: bounds.height,
             };
 
@@ -342,3 +472,95 @@ where
         Element::new(tabs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(usize),
+        Reordered(usize, usize),
+    }
+
+    /// Press at `from`, move through `path` and release, on three 100px tabs.
+    fn drag(from: f32, path: &[f32]) -> Vec<Message> {
+        let mut tabs = Tabs::new(0, Message::Selected)
+            .push(Tab::new("One"))
+            .push(Tab::new("Two"))
+            .push(Tab::new("Three"))
+            .tab_width(TabWidth::Fixed(100.0))
+            .reorderable(Message::Reordered);
+
+        let mut tree = Tree::new(&tabs as &dyn Widget<Message, (), ()>);
+        let node = <Tabs<'_, Message> as Widget<Message, (), ()>>::layout(
+            &tabs,
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 40.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut send = |event: Event, x: f32| {
+            let _ = Widget::<Message, (), ()>::on_event(
+                &mut tabs,
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(x, 20.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(400.0, 40.0)),
+            );
+        };
+
+        send(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            from,
+        );
+        let mut x = from;
+        for &to in path {
+            x = to;
+            send(
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(x, 20.0),
+                }),
+                x,
+            );
+        }
+        send(
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            x,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn dragging_first_tab_to_the_end_reorders() {
+        assert_eq!(drag(50.0, &[120.0, 270.0]), vec![Message::Reordered(0, 2)]);
+    }
+
+    #[test]
+    fn dropping_in_place_emits_nothing() {
+        assert!(drag(50.0, &[]).is_empty());
+        assert!(drag(50.0, &[52.0]).is_empty());
+        assert!(drag(50.0, &[140.0, 60.0]).is_empty());
+    }
+
+    #[test]
+    fn other_tabs_shift_around_the_dragged_one() {
+        let tab_bounds = [(0.0, 100.0), (100.0, 100.0), (200.0, 100.0)];
+        let drag = Drag {
+            index: 0,
+            start_x: 50.0,
+            delta: 130.0,
+            moved: true,
+        };
+
+        assert_eq!(drag.target(&tab_bounds), 1);
+        assert_eq!(drag.positions(&tab_bounds), vec![130.0, 0.0, 200.0]);
+    }
+}
@@ -0,0 +1,3 @@
+//! Fixtures shared by the tests of the components.
+
+pub use iced_plus_layouts::testing::Recorder;
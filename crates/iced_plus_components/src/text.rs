@@ -1,10 +1,22 @@
 //! Text and heading components.
 
 use std::borrow::Cow;
+use std::ops::Range;
 
-use iced::widget::text;
-use iced::{Color, Element, Length};
-use iced_plus_theme::AppTheme;
+use iced::advanced::clipboard;
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::text::{self as core_text, Paragraph as _, Span};
+use iced::advanced::widget::{tree, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::widget::{text, text_input, Row};
+use iced::{
+    event, keyboard, mouse, Color, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+use iced_plus_layouts::{app_breakpoints, Breakpoints, Responsive};
+use iced_plus_theme::{scaled, ui_scale, AppTheme};
+use iced_plus_tokens::TextTransform;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Text style variants.
 #[derive(Debug, Clone, Copy, Default)]
@@ -24,12 +36,50 @@ pub enum TextStyle {
     Custom(Color),
 }
 
+/// Render `content` with `letter_spacing` between characters.
+///
+/// Both sizes are multiplied by the current
+/// [UI scale](iced_plus_theme::set_ui_scale).
+///
+/// iced has no tracking support, so spaced text is laid out one grapheme
+/// cluster per cell on a single line. It never wraps and loses kerning and
+/// ligatures, so it is meant for short labels such as small-caps headers.
+/// Combining marks and emoji sequences stay together in their cluster.
+fn styled_text<'a, Message, Theme, Renderer>(
+    content: String,
+    size: f32,
+    width: Length,
+    letter_spacing: f32,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    let (size, letter_spacing) = (scaled(size), scaled(letter_spacing));
+
+    if letter_spacing == 0.0 {
+        return text(content).size(size).width(width).into();
+    }
+
+    Row::with_children(
+        content
+            .graphemes(true)
+            .map(|grapheme| text(grapheme.to_owned()).size(size).into()),
+    )
+    .spacing(letter_spacing)
+    .width(width)
+    .into()
+}
+
 /// A styled text component.
 pub struct Text<'a> {
     content: Cow<'a, str>,
     size: f32,
     style: TextStyle,
     width: Length,
+    letter_spacing: f32,
+    transform: TextTransform,
 }
 
 impl<'a> Text<'a> {
@@ -41,12 +91,14 @@ impl<'a> Text<'a> {
             size: 14.0,
             style: TextStyle::Body,
             width: Length::Shrink,
+            letter_spacing: 0.0,
+            transform: TextTransform::None,
         }
     }
 
     /// Set the font size.
     #[must_use]
-    pub fn size(mut self, size: f32) -> Self {
+    pub const fn size(mut self, size: f32) -> Self {
         self.size = size;
         self
     }
@@ -71,19 +123,58 @@ impl<'a> Text<'a> {
         self.width = width.into();
         self
     }
+
+    /// Set the spacing between characters, in pixels.
+    ///
+    /// Spaced text stays on a single line and never wraps, so keep it to
+    /// short labels; see [`TextStyle::letter_spacing`](iced_plus_tokens::TextStyle::letter_spacing).
+    #[must_use]
+    pub const fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Set the case transform.
+    #[must_use]
+    pub const fn transform(mut self, transform: TextTransform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Take size, letter spacing and case transform from a typography token.
+    #[must_use]
+    pub const fn typography(self, style: &iced_plus_tokens::TextStyle) -> Self {
+        self.size(style.size)
+            .letter_spacing(style.letter_spacing)
+            .transform(style.text_transform)
+    }
+
+    /// Let the user drag-select the text and copy it with Ctrl+C (Cmd+C on
+    /// macOS). Ctrl+A selects everything.
+    ///
+    /// Selectable text wraps like a paragraph, so letter spacing is ignored.
+    #[must_use]
+    pub fn selectable(self) -> SelectableText<'a> {
+        SelectableText::new(self.rendered())
+            .size(self.size)
+            .width(self.width)
+    }
+
+    /// The content with the case transform applied.
+    fn rendered(&self) -> String {
+        self.transform.apply(&self.content)
+    }
 }
 
 impl<'a, Message: 'a> From<Text<'a>> for Element<'a, Message, AppTheme<'a>> {
     fn from(t: Text<'a>) -> Self {
-        let content: String = t.content.into_owned();
-        text(content).size(t.size).width(t.width).into()
+        styled_text(t.rendered(), t.size, t.width, t.letter_spacing)
     }
 }
 
 impl<'a, Message: 'a> From<Text<'a>> for Element<'a, Message, iced::Theme> {
     fn from(t: Text<'a>) -> Self {
-        let content: String = t.content.into_owned();
-        text(content).size(t.size).width(t.width).into()
+        styled_text(t.rendered(), t.size, t.width, t.letter_spacing)
     }
 }
 
@@ -119,6 +210,8 @@ pub struct Heading<'a> {
     content: Cow<'a, str>,
     level: HeadingLevel,
     width: Length,
+    letter_spacing: f32,
+    transform: TextTransform,
 }
 
 impl<'a> Heading<'a> {
@@ -129,6 +222,8 @@ impl<'a> Heading<'a> {
             content: content.into(),
             level: HeadingLevel::H2,
             width: Length::Shrink,
+            letter_spacing: 0.0,
+            transform: TextTransform::None,
         }
     }
 
@@ -169,18 +264,1112 @@ impl<'a> Heading<'a> {
         self.width = width.into();
         self
     }
+
+    /// Set the spacing between characters, in pixels.
+    ///
+    /// Spaced text stays on a single line and never wraps, so keep it to
+    /// short labels; see [`TextStyle::letter_spacing`](iced_plus_tokens::TextStyle::letter_spacing).
+    #[must_use]
+    pub const fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Set the case transform.
+    #[must_use]
+    pub const fn transform(mut self, transform: TextTransform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Let the user select and copy the heading, see [`Text::selectable`].
+    #[must_use]
+    pub fn selectable(self) -> SelectableText<'a> {
+        SelectableText::new(self.rendered())
+            .size(self.level.size())
+            .width(self.width)
+    }
+
+    /// The content with the case transform applied.
+    fn rendered(&self) -> String {
+        self.transform.apply(&self.content)
+    }
 }
 
 impl<'a, Message: 'a> From<Heading<'a>> for Element<'a, Message, AppTheme<'a>> {
     fn from(h: Heading<'a>) -> Self {
-        let content: String = h.content.into_owned();
-        text(content).size(h.level.size()).width(h.width).into()
+        styled_text(h.rendered(), h.level.size(), h.width, h.letter_spacing)
     }
 }
 
 impl<'a, Message: 'a> From<Heading<'a>> for Element<'a, Message, iced::Theme> {
     fn from(h: Heading<'a>) -> Self {
-        let content: String = h.content.into_owned();
-        text(content).size(h.level.size()).width(h.width).into()
+        styled_text(h.rendered(), h.level.size(), h.width, h.letter_spacing)
+    }
+}
+
+/// Read-only text the user can select and copy.
+///
+/// Dragging selects, Ctrl+A (Cmd+A on macOS) selects everything and Ctrl+C
+/// writes the selection to the clipboard. Pressing outside the text clears
+/// the selection. The highlight uses the theme's text input selection color.
+///
+/// Usually created with [`Text::selectable`] or [`Heading::selectable`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Text::new(&self.log_line).selectable()
+/// ```
+pub struct SelectableText<'a> {
+    content: Cow<'a, str>,
+    size: f32,
+    scale: f32,
+    width: Length,
+}
+
+impl<'a> SelectableText<'a> {
+    /// Create selectable text.
+    #[must_use]
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            size: 14.0,
+            scale: ui_scale(),
+            width: Length::Shrink,
+        }
+    }
+
+    /// Set the font size.
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the width.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Lay out every line of the content with the current selection
+    /// split into its own span.
+    fn shape<P: core_text::Paragraph>(&self, state: &mut Selection<P>, font: P::Font) {
+        let selection = state.range();
+        let mut start = 0;
+        let mut y = 0.0;
+
+        state.lines = self
+            .content
+            .split('\n')
+            .map(|line| {
+                let range = start..start + line.len();
+                start = range.end + 1;
+
+                let selected = selection.start.max(range.start)..selection.end.min(range.end);
+                let highlighted = selected.start < selected.end;
+                let paragraph = if highlighted {
+                    let spans: [Span<'_, (), P::Font>; 3] = [
+                        Span::new(&self.content[range.start..selected.start]),
+                        Span::new(&self.content[selected.clone()]),
+                        Span::new(&self.content[selected.end..range.end]),
+                    ];
+                    P::with_spans(self.text(&spans[..], state.width, font))
+                } else {
+                    P::with_text(self.text(line, state.width, font))
+                };
+
+                let line = SelectableLine {
+                    range,
+                    y,
+                    highlighted,
+                    paragraph,
+                };
+                y += line.paragraph.min_bounds().height;
+                line
+            })
+            .collect();
+    }
+
+    fn text<C, Font>(&self, content: C, width: f32, font: Font) -> core_text::Text<C, Font> {
+        core_text::Text {
+            content,
+            bounds: Size::new(width, f32::INFINITY),
+            size: Pixels(self.size * self.scale),
+            line_height: core_text::LineHeight::default(),
+            font,
+            horizontal_alignment: iced::alignment::Horizontal::Left,
+            vertical_alignment: iced::alignment::Vertical::Top,
+            shaping: core_text::Shaping::Advanced,
+            wrapping: core_text::Wrapping::Word,
+        }
+    }
+}
+
+/// One `\n`-separated line of a [`SelectableText`].
+struct SelectableLine<P> {
+    /// Byte range of the line in the content.
+    range: Range<usize>,
+    /// Offset from the top of the widget.
+    y: f32,
+    /// Whether the second span is selected.
+    highlighted: bool,
+    paragraph: P,
+}
+
+/// Selection of a [`SelectableText`], as byte offsets into the content.
+struct Selection<P> {
+    lines: Vec<SelectableLine<P>>,
+    anchor: usize,
+    head: usize,
+    dragging: bool,
+    focused: bool,
+    width: f32,
+}
+
+impl<P> Selection<P> {
+    fn range(&self) -> Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+
+    /// Byte offset of the character boundary closest to `point`, relative to
+    /// the widget.
+    fn offset_at(&self, point: Point) -> usize
+    where
+        P: core_text::Paragraph,
+    {
+        let Some(line) = self
+            .lines
+            .iter()
+            .rev()
+            .find(|line| point.y >= line.y)
+            .or_else(|| self.lines.first())
+        else {
+            return 0;
+        };
+
+        line.paragraph
+            .hit_test(Point::new(point.x, point.y - line.y))
+            .map_or(line.range.end, |hit| {
+                (line.range.start + hit.cursor()).min(line.range.end)
+            })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for SelectableText<'_>
+where
+    Theme: text_input::Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Selection<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Selection::<Renderer::Paragraph> {
+            lines: Vec::new(),
+            anchor: 0,
+            head: 0,
+            dragging: false,
+            focused: false,
+            width: 0.0,
+        })
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<Selection<Renderer::Paragraph>>();
+        let limits = limits.width(self.width);
+
+        // The content may have changed since the selection was made.
+        state.anchor = state.anchor.min(self.content.len());
+        state.head = state.head.min(self.content.len());
+        if !self.content.is_char_boundary(state.anchor)
+            || !self.content.is_char_boundary(state.head)
+        {
+            state.anchor = 0;
+            state.head = 0;
+        }
+
+        state.width = limits.max().width;
+        self.shape(state, renderer.default_font());
+
+        let size = state.lines.iter().fold(Size::ZERO, |size, line| {
+            let bounds = line.paragraph.min_bounds();
+            Size::new(size.width.max(bounds.width), size.height + bounds.height)
+        });
+
+        Node::new(limits.resolve(self.width, Length::Shrink, size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<Selection<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_in(bounds) {
+                    let offset = state.offset_at(position);
+                    state.anchor = offset;
+                    state.head = offset;
+                    state.dragging = true;
+                    state.focused = true;
+                    self.shape(state, renderer.default_font());
+
+                    return event::Status::Captured;
+                }
+
+                if state.focused {
+                    state.anchor = 0;
+                    state.head = 0;
+                    state.focused = false;
+                    self.shape(state, renderer.default_font());
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.dragging => {
+                let offset = state.offset_at(position - Vector::new(bounds.x, bounds.y));
+                if offset != state.head {
+                    state.head = offset;
+                    self.shape(state, renderer.default_font());
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.dragging = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if state.focused && modifiers.command() => match c.as_str() {
+                "a" => {
+                    state.anchor = 0;
+                    state.head = self.content.len();
+                    self.shape(state, renderer.default_font());
+
+                    return event::Status::Captured;
+                }
+                "c" => {
+                    let range = state.range();
+                    if !range.is_empty() {
+                        clipboard.write(clipboard::Kind::Standard, self.content[range].to_owned());
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<Selection<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let selection = <Theme as text_input::Catalog>::style(
+            theme,
+            &<Theme as text_input::Catalog>::default(),
+            text_input::Status::Focused,
+        )
+        .selection;
+
+        for line in &state.lines {
+            let origin = Vector::new(bounds.x, bounds.y + line.y);
+
+            if line.highlighted {
+                for highlight in line.paragraph.span_bounds(1) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: highlight + origin,
+                            ..renderer::Quad::default()
+                        },
+                        selection,
+                    );
+                }
+            }
+
+            renderer.fill_paragraph(
+                &line.paragraph,
+                Point::ORIGIN + origin,
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SelectableText<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: text_input::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(text: SelectableText<'a>) -> Self {
+        Element::new(text)
+    }
+}
+
+/// Gap between the clamped text and its toggle, in pixels.
+const TOGGLE_GAP: f32 = 4.0;
+
+/// Long text clamped to a number of lines with a "Show more"/"Show less" toggle.
+///
+/// The toggle only appears when the text actually overflows the clamp. The
+/// expanded state is owned by the application and flipped via `on_toggle`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// CollapsibleText::new(&self.description)
+///     .max_lines(3)
+///     .expanded(self.description_expanded)
+///     .on_toggle(Message::ToggleDescription)
+/// ```
+pub struct CollapsibleText<'a, Message> {
+    content: Cow<'a, str>,
+    max_lines: usize,
+    expanded: bool,
+    on_toggle: Option<Message>,
+    size: f32,
+    width: Length,
+    more_label: Cow<'a, str>,
+    less_label: Cow<'a, str>,
+}
+
+impl<'a, Message> CollapsibleText<'a, Message> {
+    /// Create a new collapsible text clamped to three lines.
+    #[must_use]
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            max_lines: 3,
+            expanded: false,
+            on_toggle: None,
+            size: 14.0,
+            width: Length::Fill,
+            more_label: Cow::Borrowed("Show more"),
+            less_label: Cow::Borrowed("Show less"),
+        }
+    }
+
+    /// Set the number of lines shown while collapsed.
+    #[must_use]
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    /// Set whether the full text is shown.
+    #[must_use]
+    pub const fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Set the message emitted when the toggle is pressed.
+    #[must_use]
+    pub fn on_toggle(mut self, message: Message) -> Self {
+        self.on_toggle = Some(message);
+        self
+    }
+
+    /// Set the font size.
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the width.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the toggle labels.
+    #[must_use]
+    pub fn labels(mut self, more: impl Into<Cow<'a, str>>, less: impl Into<Cow<'a, str>>) -> Self {
+        self.more_label = more.into();
+        self.less_label = less.into();
+        self
+    }
+
+    /// Lines shown and toggle label for text measuring `total_lines` lines.
+    ///
+    /// The toggle is `None` when the text fits within the clamp.
+    #[must_use]
+    pub fn clamp(&self, total_lines: usize) -> (usize, Option<&str>) {
+        if total_lines <= self.max_lines {
+            (total_lines, None)
+        } else if self.expanded {
+            (total_lines, Some(&self.less_label))
+        } else {
+            (self.max_lines, Some(&self.more_label))
+        }
+    }
+
+    fn line_height(&self) -> f32 {
+        core_text::LineHeight::default()
+            .to_absolute(Pixels(self.size))
+            .0
+    }
+
+    fn text<'b, Font>(
+        &self,
+        content: &'b str,
+        width: f32,
+        font: Font,
+    ) -> core_text::Text<&'b str, Font> {
+        core_text::Text {
+            content,
+            bounds: Size::new(width, f32::INFINITY),
+            size: Pixels(self.size),
+            line_height: core_text::LineHeight::default(),
+            font,
+            horizontal_alignment: iced::alignment::Horizontal::Left,
+            vertical_alignment: iced::alignment::Vertical::Top,
+            shaping: core_text::Shaping::Advanced,
+            wrapping: core_text::Wrapping::Word,
+        }
+    }
+
+    /// Bounds of a toggle label `label_width` wide below `visible_lines`.
+    // Line counts stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
+    fn toggle_bounds(
+        &self,
+        visible_lines: usize,
+        label_width: f32,
+        bounds: Rectangle,
+    ) -> Rectangle {
+        let line_height = self.line_height();
+
+        Rectangle {
+            x: bounds.x,
+            y: (visible_lines as f32).mul_add(line_height, bounds.y) + TOGGLE_GAP,
+            width: label_width.min(bounds.width),
+            height: line_height,
+        }
+    }
+}
+
+struct CollapsibleState<P> {
+    paragraph: P,
+    toggle: Option<P>,
+    visible_lines: usize,
+}
+
+impl<Message, Renderer> Widget<Message, iced::Theme, Renderer> for CollapsibleText<'_, Message>
+where
+    Message: Clone,
+    Renderer: core_text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<CollapsibleState<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(CollapsibleState::<Renderer::Paragraph> {
+            paragraph: Renderer::Paragraph::default(),
+            toggle: None,
+            visible_lines: 0,
+        })
+    }
+
+    // Line counts are small and never negative, so the casts are exact.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree
+            .state
+            .downcast_mut::<CollapsibleState<Renderer::Paragraph>>();
+        let limits = limits.width(self.width);
+        let line_height = self.line_height();
+
+        state.paragraph = Renderer::Paragraph::with_text(self.text(
+            &self.content,
+            limits.max().width,
+            renderer.default_font(),
+        ));
+        let measured = state.paragraph.min_bounds();
+        let total_lines = (measured.height / line_height).round() as usize;
+
+        let (visible_lines, toggle) = self.clamp(total_lines);
+        state.visible_lines = visible_lines;
+        state.toggle = toggle.map(|label| {
+            Renderer::Paragraph::with_text(self.text(label, f32::INFINITY, renderer.default_font()))
+        });
+
+        let mut height = visible_lines as f32 * line_height;
+        if state.toggle.is_some() {
+            height += TOGGLE_GAP + line_height;
+        }
+
+        Node::new(limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(measured.width, height),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree
+            .state
+            .downcast_ref::<CollapsibleState<Renderer::Paragraph>>();
+
+        if event == Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            && state.toggle.as_ref().is_some_and(|toggle| {
+                cursor.is_over(self.toggle_bounds(
+                    state.visible_lines,
+                    toggle.min_bounds().width,
+                    layout.bounds(),
+                ))
+            })
+        {
+            if let Some(message) = self.on_toggle.clone() {
+                shell.publish(message);
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree
+            .state
+            .downcast_ref::<CollapsibleState<Renderer::Paragraph>>();
+
+        if self.on_toggle.is_some()
+            && state.toggle.as_ref().is_some_and(|toggle| {
+                cursor.is_over(self.toggle_bounds(
+                    state.visible_lines,
+                    toggle.min_bounds().width,
+                    layout.bounds(),
+                ))
+            })
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &iced::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree
+            .state
+            .downcast_ref::<CollapsibleState<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let clip = Rectangle {
+            height: state.visible_lines as f32 * self.line_height(),
+            ..bounds
+        };
+
+        renderer.fill_paragraph(&state.paragraph, bounds.position(), style.text_color, clip);
+
+        if let Some(toggle) = &state.toggle {
+            let toggle_bounds =
+                self.toggle_bounds(state.visible_lines, toggle.min_bounds().width, bounds);
+
+            renderer.fill_paragraph(
+                toggle,
+                Point::new(toggle_bounds.x, toggle_bounds.y),
+                theme.extended_palette().primary.base.color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message> From<CollapsibleText<'a, Message>> for Element<'a, Message, iced::Theme>
+where
+    Message: Clone + 'a,
+{
+    fn from(text: CollapsibleText<'a, Message>) -> Self {
+        Element::new(text)
+    }
+}
+
+/// Text sized by the width of its container rather than the window.
+///
+/// The font size is picked from a [`Responsive`] value by the breakpoint
+/// tier of the width the text is laid out in, like a CSS container query.
+/// The same heading can then be large in a wide main column and shrink in
+/// a narrow card, whatever the window size.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // 18px in narrow containers, 24px from 480px, 32px from 768px
+/// ContainerQueryText::new(&self.title, Responsive::new(18.0).sm(24.0).md(32.0))
+/// ```
+pub struct ContainerQueryText<'a> {
+    content: Cow<'a, str>,
+    sizes: Responsive<f32>,
+    scale: f32,
+    breakpoints: Breakpoints,
+    width: Length,
+}
+
+impl<'a> ContainerQueryText<'a> {
+    /// Create text sized by `sizes` for the width of its container.
+    #[must_use]
+    pub fn new(content: impl Into<Cow<'a, str>>, sizes: Responsive<f32>) -> Self {
+        Self {
+            content: content.into(),
+            sizes,
+            scale: ui_scale(),
+            breakpoints: app_breakpoints(),
+            width: Length::Fill,
+        }
+    }
+
+    /// Use custom breakpoints instead of the app-wide ones.
+    #[must_use]
+    pub const fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Set the width (default: fill, so the query sees the container).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Font size in a container `width` pixels wide, including the UI scale
+    /// the text was built with.
+    #[must_use]
+    pub fn size_for(&self, width: f32) -> f32 {
+        *self.sizes.get(self.breakpoints.tier(width)) * self.scale
+    }
+}
+
+/// Paragraph of a [`ContainerQueryText`] and the size it was shaped at.
+#[derive(Default)]
+struct QueryState<P> {
+    paragraph: P,
+    size: f32,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ContainerQueryText<'_>
+where
+    Renderer: core_text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<QueryState<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(QueryState::<Renderer::Paragraph>::default())
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<QueryState<Renderer::Paragraph>>();
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+
+        state.size = self.size_for(width);
+        state.paragraph = Renderer::Paragraph::with_text(core_text::Text {
+            content: &*self.content,
+            bounds: Size::new(width, f32::INFINITY),
+            size: Pixels(state.size),
+            line_height: core_text::LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: iced::alignment::Horizontal::Left,
+            vertical_alignment: iced::alignment::Vertical::Top,
+            shaping: core_text::Shaping::Advanced,
+            wrapping: core_text::Wrapping::Word,
+        });
+
+        Node::new(limits.resolve(self.width, Length::Shrink, state.paragraph.min_bounds()))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<QueryState<Renderer::Paragraph>>();
+
+        renderer.fill_paragraph(
+            &state.paragraph,
+            layout.bounds().position(),
+            style.text_color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContainerQueryText<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(text: ContainerQueryText<'a>) -> Self {
+        Element::new(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A renderer that shapes text with a real font.
+    fn shaping_renderer() -> iced::Renderer {
+        iced::Renderer::Secondary(iced_tiny_skia::Renderer::new(
+            iced::Font::default(),
+            Pixels(16.0),
+        ))
+    }
+
+    const LONG: &str = "This description runs on well past the three lines it is \
+        clamped to, so the reader has to expand it to see how it ends.";
+
+    /// Lay out `text` 200px wide with shaped text, returning its size,
+    /// whether it shows a toggle, and the messages of a press at `at`,
+    /// relative to its top left corner.
+    fn press_collapsible(text: CollapsibleText<'_, ()>, at: Point) -> (Size, bool, Vec<()>) {
+        let renderer = shaping_renderer();
+        let mut element: Element<'_, (), iced::Theme> = text.on_toggle(()).into();
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &mut tree,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 600.0)),
+        );
+        let has_toggle = tree
+            .state
+            .downcast_ref::<CollapsibleState<<iced::Renderer as core_text::Renderer>::Paragraph>>()
+            .toggle
+            .is_some();
+
+        let mut messages = Vec::new();
+        let _ = element.as_widget_mut().on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(at),
+            &renderer,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(200.0, 600.0)),
+        );
+
+        (node.size(), has_toggle, messages)
+    }
+
+    #[test]
+    fn short_text_has_no_toggle() {
+        let text = CollapsibleText::new("Short").max_lines(3);
+        let line_height = text.line_height();
+        let (size, has_toggle, _) = press_collapsible(text, Point::ORIGIN);
+
+        assert!(!has_toggle);
+        assert!((size.height - line_height).abs() < 1e-3);
+    }
+
+    #[test]
+    fn long_text_toggles_line_count() {
+        let collapsed = CollapsibleText::new(LONG).max_lines(3);
+        let line_height = collapsed.line_height();
+        let (size, has_toggle, _) = press_collapsible(collapsed, Point::ORIGIN);
+
+        assert!(has_toggle);
+        assert!((size.height - 4.0f32.mul_add(line_height, TOGGLE_GAP)).abs() < 1e-3);
+
+        let expanded = CollapsibleText::new(LONG).max_lines(3).expanded(true);
+        let (expanded_size, has_toggle, _) = press_collapsible(expanded, Point::ORIGIN);
+
+        assert!(has_toggle);
+        assert!(expanded_size.height > size.height);
+    }
+
+    #[test]
+    fn only_the_toggle_label_is_pressable() {
+        let text = || CollapsibleText::new(LONG).max_lines(3);
+        let toggle_y = 3.0f32.mul_add(text().line_height(), TOGGLE_GAP) + 4.0;
+
+        let (_, _, on_label) = press_collapsible(text(), Point::new(10.0, toggle_y));
+        assert_eq!(on_label, vec![()]);
+
+        // "Show more" ends well before the right edge of the text.
+        let (_, _, beside_label) = press_collapsible(text(), Point::new(190.0, toggle_y));
+        assert!(beside_label.is_empty());
+    }
+
+    #[test]
+    fn uppercase_transform_renders_transformed_string() {
+        let label = Text::new("Navigation").transform(TextTransform::Uppercase);
+        assert_eq!(label.rendered(), "NAVIGATION");
+
+        let heading = Heading::h4("Recent files").transform(TextTransform::Capitalize);
+        assert_eq!(heading.rendered(), "Recent Files");
+    }
+
+    #[test]
+    fn letter_spacing_separates_characters() {
+        let label: Element<'_, (), iced::Theme, ()> =
+            styled_text(String::from("abc"), 12.0, Length::Shrink, 2.0);
+        let mut tree = Tree::new(&label);
+
+        // The null renderer measures glyphs as zero-width, leaving only
+        // the two gaps between three characters.
+        let node = label.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(300.0, 100.0)),
+        );
+        assert!((node.size().width - 4.0).abs() < f32::EPSILON);
+        assert_eq!(node.children().len(), 3);
+    }
+
+    #[test]
+    fn letter_spacing_keeps_grapheme_clusters_together() {
+        // "é" as `e` plus a combining acute accent, and a ZWJ emoji
+        // sequence, are one cell each.
+        let label: Element<'_, (), iced::Theme, ()> = styled_text(
+            String::from("e\u{301}t\u{1F469}\u{200D}\u{1F4BB}"),
+            12.0,
+            Length::Shrink,
+            2.0,
+        );
+        let mut tree = Tree::new(&label);
+
+        let node = label.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(300.0, 100.0)),
+        );
+        assert_eq!(node.children().len(), 3);
+    }
+
+    /// Clipboard recording what is written to it.
+    #[derive(Default)]
+    struct RecordingClipboard {
+        written: Vec<String>,
+    }
+
+    impl Clipboard for RecordingClipboard {
+        fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+            None
+        }
+
+        fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+            self.written.push(contents);
+        }
+    }
+
+    fn command(c: &str) -> Event {
+        let key = keyboard::Key::Character(c.into());
+
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            modified_key: key.clone(),
+            key,
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::COMMAND,
+            text: None,
+        })
+    }
+
+    /// Send `events` with the cursor at `cursor` and return what was copied.
+    fn copied(
+        text: &mut SelectableText<'_>,
+        tree: &mut Tree,
+        cursor: Point,
+        events: &[Event],
+    ) -> Vec<String> {
+        let widget = text as &mut dyn Widget<(), iced::Theme, ()>;
+        // The null renderer measures text as empty, so give it a line's room.
+        let node = widget.layout(
+            tree,
+            &(),
+            &Limits::new(Size::new(300.0, 20.0), Size::new(300.0, 100.0)),
+        );
+        let mut clipboard = RecordingClipboard::default();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for event in events {
+            let _ = widget.on_event(
+                tree,
+                event.clone(),
+                Layout::new(&node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut clipboard,
+                &mut shell,
+                &Rectangle::with_size(Size::new(300.0, 100.0)),
+            );
+        }
+
+        clipboard.written
+    }
+
+    #[test]
+    fn selectable_text_handles_selection() {
+        let mut text = Text::new("Hello world").selectable();
+        let mut tree = Tree::new(&text as &dyn Widget<(), iced::Theme, ()>);
+        let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        // Shortcuts do nothing until the text is clicked.
+        assert!(copied(
+            &mut text,
+            &mut tree,
+            Point::ORIGIN,
+            &[command("a"), command("c")]
+        )
+        .is_empty());
+
+        let selected = copied(
+            &mut text,
+            &mut tree,
+            Point::ORIGIN,
+            &[press.clone(), command("a"), command("c")],
+        );
+        assert_eq!(selected, vec![String::from("Hello world")]);
+
+        // Pressing elsewhere clears the selection.
+        let outside = Point::new(500.0, 500.0);
+        assert!(copied(&mut text, &mut tree, outside, &[press, command("c")]).is_empty());
+    }
+
+    #[test]
+    fn copy_writes_selected_substring() {
+        let mut text = Text::new("Hello world\nsecond line").selectable();
+        let mut tree = Tree::new(&text as &dyn Widget<(), iced::Theme, ()>);
+
+        // A drag from the end of "world" back into "second".
+        let state = tree.state.downcast_mut::<Selection<()>>();
+        state.focused = true;
+        state.anchor = 18;
+        state.head = 6;
+
+        let copied = copied(&mut text, &mut tree, Point::ORIGIN, &[command("c")]);
+        assert_eq!(copied, vec![String::from("world\nsecond")]);
+
+        let state = tree.state.downcast_ref::<Selection<()>>();
+        let highlighted: Vec<_> = state.lines.iter().map(|line| line.highlighted).collect();
+        assert_eq!(highlighted, [true, true]);
+    }
+
+    #[test]
+    fn container_query_text_follows_container_width() {
+        let heading = ContainerQueryText::new("Overview", Responsive::new(18.0).md(32.0))
+            .breakpoints(Breakpoints::STANDARD);
+        let resolved = |width| {
+            let widget = &heading as &dyn Widget<(), iced::Theme, ()>;
+            let mut tree = Tree::new(widget);
+            let _ = widget.layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(width, 600.0)),
+            );
+            tree.state.downcast_ref::<QueryState<()>>().size
+        };
+
+        assert!((resolved(300.0) - 18.0).abs() < f32::EPSILON);
+        assert!((resolved(900.0) - 32.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn container_query_text_keeps_the_scale_it_was_built_with() {
+        let heading = iced_plus_theme::with_ui_scale(2.0, || {
+            ContainerQueryText::new("Overview", Responsive::new(18.0))
+                .breakpoints(Breakpoints::STANDARD)
+        });
+
+        // Laid out after the view scope has ended.
+        assert!((heading.size_for(300.0) - 36.0).abs() < f32::EPSILON);
     }
 }
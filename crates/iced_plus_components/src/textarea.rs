@@ -8,8 +8,18 @@
 //!     .rows(5)
 //! ```
 
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
 use iced::widget::{container, scrollable, text_editor, TextEditor};
-use iced::{Background, Border, Element, Length, Padding, Theme};
+use iced::{
+    event, mouse, Background, Border, Element, Event, Length, Padding, Point, Rectangle, Size,
+    Theme, Vector,
+};
+
+/// Side of the square resize grip in the bottom-right corner.
+const GRIP_SIZE: f32 = 14.0;
 
 /// Text area content state.
 ///
@@ -31,6 +41,13 @@ pub type TextAreaContent = text_editor::Content;
 ///     .on_action(Message::TextAreaAction)
 ///     .placeholder("Enter text...")
 ///     .height(200.0)
+///
+/// // With a resize grip; the app owns the height
+/// TextArea::new(&self.content)
+///     .height(self.notes_height)
+///     .resizable()
+///     .max_height(480.0)
+///     .on_resize(Message::NotesResized)
 /// ```
 pub struct TextArea<'a, Message> {
     content: &'a TextAreaContent,
@@ -39,6 +56,10 @@ pub struct TextArea<'a, Message> {
     width: Length,
     height: Length,
     padding: Padding,
+    resizable: bool,
+    min_height: f32,
+    max_height: f32,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, Message: Clone + 'a> TextArea<'a, Message> {
@@ -51,6 +72,10 @@ impl<'a, Message: Clone + 'a> TextArea<'a, Message> {
             width: Length::Fill,
             height: Length::Fixed(150.0),
             padding: Padding::new(12.0),
+            resizable: false,
+            min_height: 60.0,
+            max_height: f32::INFINITY,
+            on_resize: None,
         }
     }
 
@@ -99,6 +124,42 @@ impl<'a, Message: Clone + 'a> TextArea<'a, Message> {
         self.padding = padding.into();
         self
     }
+
+    /// Show a grip in the bottom-right corner that resizes the text area
+    /// vertically when dragged.
+    ///
+    /// The height stays owned by the app: dragging reports the new height
+    /// through [`on_resize`](Self::on_resize), to be passed back via
+    /// [`height`](Self::height). Without `on_resize` the grip is not shown.
+    #[must_use]
+    pub const fn resizable(mut self) -> Self {
+        self.resizable = true;
+        self
+    }
+
+    /// Set the smallest height the grip can resize to (default 60).
+    #[must_use]
+    pub const fn min_height(mut self, min_height: f32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Set the largest height the grip can resize to (default unbounded).
+    #[must_use]
+    pub const fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Set the handler receiving the new height while the grip is dragged.
+    #[must_use]
+    pub fn on_resize<F>(mut self, on_resize: F) -> Self
+    where
+        F: Fn(f32) -> Message + 'a,
+    {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
 }
 
 impl<'a, Message: Clone + 'a> From<TextArea<'a, Message>> for Element<'a, Message, Theme> {
@@ -149,10 +210,230 @@ impl<'a, Message: Clone + 'a> From<TextArea<'a, Message>> for Element<'a, Messag
                 }
             });
 
-        container(editor)
+        let area = container(editor)
             .width(textarea.width)
-            .height(textarea.height)
-            .into()
+            .height(textarea.height);
+
+        match textarea.on_resize {
+            Some(on_resize) if textarea.resizable => ResizeGrip {
+                content: area.into(),
+                min_height: textarea.min_height,
+                max_height: textarea.max_height.max(textarea.min_height),
+                on_resize,
+            }
+            .into(),
+            _ => area.into(),
+        }
+    }
+}
+
+/// Where a grip drag started.
+#[derive(Debug, Clone, Copy, Default)]
+struct Drag {
+    /// Cursor y and content height when the grip was pressed.
+    origin: Option<(f32, f32)>,
+}
+
+/// Wraps a fixed-height element with a vertical resize grip in its
+/// bottom-right corner.
+struct ResizeGrip<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    min_height: f32,
+    max_height: f32,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+}
+
+/// Bounds of the grip within `bounds`.
+fn grip_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + bounds.width - GRIP_SIZE,
+        y: bounds.y + bounds.height - GRIP_SIZE,
+        width: GRIP_SIZE,
+        height: GRIP_SIZE,
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for ResizeGrip<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Drag>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Drag::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().expect("grip content layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let drag = tree.state.downcast_mut::<Drag>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(grip_bounds(bounds)) {
+                    drag.origin = Some((position.y, bounds.height));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some((y, height)) = drag.origin {
+                    let height = (height + position.y - y).clamp(self.min_height, self.max_height);
+                    shell.publish((self.on_resize)(height));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if drag.origin.take().is_some() =>
+            {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().expect("grip content layout"),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<Drag>().origin.is_some()
+            || cursor.is_over(grip_bounds(layout.bounds()))
+        {
+            return mouse::Interaction::ResizingVertically;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().expect("grip content layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().expect("grip content layout"),
+            cursor,
+            viewport,
+        );
+
+        // Three dots along the diagonal, like a browser textarea grip.
+        let grip = grip_bounds(layout.bounds());
+        let color = theme.extended_palette().background.strong.color;
+        for (dx, dy) in [(8.0, 4.0), (4.0, 8.0), (8.0, 8.0)] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        Point::new(grip.x + dx, grip.y + dy),
+                        Size::new(2.0, 2.0),
+                    ),
+                    border: Border::default().rounded(1.0),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<ResizeGrip<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(grip: ResizeGrip<'a, Message, Renderer>) -> Self {
+        Element::new(grip)
     }
 }
 
@@ -257,3 +538,69 @@ impl<'a, Message: Clone + 'a> From<SimpleTextArea<'a, Message>> for Element<'a,
         .into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+
+    /// Drag the grip of a 200x100 area from its corner down by `dy`.
+    fn drag_grip(dy: f32) -> Vec<f32> {
+        let mut grip = ResizeGrip::<f32, ()> {
+            content: Space::new(200.0, 100.0).into(),
+            min_height: 60.0,
+            max_height: 300.0,
+            on_resize: Box::new(|height| height),
+        };
+        let mut tree = Tree::new(&grip as &dyn Widget<f32, Theme, ()>);
+        let node = grip.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        let start = Point::new(195.0, 95.0);
+        let end = Point::new(195.0, 95.0 + dy);
+        let mut heights = Vec::new();
+        let mut shell = Shell::new(&mut heights);
+        for (event, position) in [
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                start,
+            ),
+            (
+                Event::Mouse(mouse::Event::CursorMoved { position: end }),
+                end,
+            ),
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                end,
+            ),
+        ] {
+            let _ = grip.on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(400.0, 400.0)),
+            );
+        }
+
+        heights
+    }
+
+    #[test]
+    fn dragging_grip_down_grows_height() {
+        assert_eq!(drag_grip(40.0), [140.0]);
+    }
+
+    #[test]
+    fn resized_height_is_clamped() {
+        assert_eq!(drag_grip(500.0), [300.0]);
+        assert_eq!(drag_grip(-80.0), [60.0]);
+    }
+}
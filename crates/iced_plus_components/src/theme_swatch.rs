@@ -0,0 +1,258 @@
+//! Theme preview swatch for theme pickers.
+
+use std::borrow::Cow;
+
+use iced::advanced::{renderer, text};
+use iced::widget::{button, column, container, text as label, Space};
+use iced::{Background, Border, Color, Element, Length, Theme};
+use iced_plus_theme::AppTheme;
+use iced_plus_tokens::{Shade, ThemeTokens};
+
+/// A clickable miniature of a theme: a surface card with a primary accent
+/// bar and two lines in the text color, captioned with the theme name.
+///
+/// Tokens don't record whether they describe a light or dark theme, so pass
+/// [`dark`](Self::dark) for dark tokens to get the matching surface and text
+/// colors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// row(themes.iter().map(|(name, tokens, dark)| {
+///     ThemeSwatch::new(*name, tokens)
+///         .dark(*dark)
+///         .selected(self.theme == *name)
+///         .on_press(Message::ThemePicked(*name))
+///         .into()
+/// }))
+/// ```
+pub struct ThemeSwatch<'a, Message> {
+    label: Cow<'a, str>,
+    tokens: &'a ThemeTokens,
+    dark: bool,
+    selected: bool,
+    on_press: Option<Message>,
+    width: f32,
+}
+
+impl<'a, Message> ThemeSwatch<'a, Message> {
+    /// Create a swatch previewing `tokens`.
+    #[must_use]
+    pub fn new(label: impl Into<Cow<'a, str>>, tokens: &'a ThemeTokens) -> Self {
+        Self {
+            label: label.into(),
+            tokens,
+            dark: false,
+            selected: false,
+            on_press: None,
+            width: 120.0,
+        }
+    }
+
+    /// Preview the tokens as a dark theme.
+    #[must_use]
+    pub const fn dark(mut self, dark: bool) -> Self {
+        self.dark = dark;
+        self
+    }
+
+    /// Outline the swatch as the current choice.
+    #[must_use]
+    pub const fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set the message emitted when the swatch is clicked.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Set the width of the swatch.
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// The previewed primary, surface and text colors.
+    #[must_use]
+    pub fn colors(&self) -> (Color, Color, Color) {
+        let theme = AppTheme::from_ref(self.tokens, if self.dark { "dark" } else { "light" });
+
+        (theme.primary(Shade::S500), theme.surface(), theme.text())
+    }
+}
+
+/// A rounded bar filled with `color`.
+fn bar<'a, Message, Renderer>(
+    color: Color,
+    width: Length,
+    height: f32,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    container(Space::new(width, height))
+        .style(move |_theme| container::Style {
+            background: Some(Background::Color(color)),
+            border: Border {
+                radius: (height / 2.0).into(),
+                ..Border::default()
+            },
+            ..container::Style::default()
+        })
+        .into()
+}
+
+impl<'a, Message, Renderer> From<ThemeSwatch<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(swatch: ThemeSwatch<'a, Message>) -> Self {
+        let (primary, surface, text_color) = swatch.colors();
+        let selected = swatch.selected;
+
+        let preview = container(
+            column![
+                bar(primary, Length::Fill, 10.0),
+                bar(text_color, Length::FillPortion(3), 4.0),
+                bar(text_color, Length::FillPortion(2), 4.0),
+            ]
+            .spacing(6),
+        )
+        .padding(8)
+        .width(Length::Fill)
+        .height(72)
+        .style(move |theme: &Theme| container::Style {
+            background: Some(Background::Color(surface)),
+            border: Border {
+                color: theme.extended_palette().background.strong.color,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..container::Style::default()
+        });
+
+        let content = column![preview, label(swatch.label).size(12)]
+            .spacing(6)
+            .width(swatch.width);
+
+        button(content)
+            .padding(4)
+            .on_press_maybe(swatch.on_press)
+            .style(move |theme: &Theme, status| {
+                let palette = theme.extended_palette();
+                let border_color = match status {
+                    _ if selected => palette.primary.base.color,
+                    button::Status::Hovered | button::Status::Pressed => {
+                        palette.background.strong.color
+                    }
+                    button::Status::Active | button::Status::Disabled => Color::TRANSPARENT,
+                };
+
+                button::Style {
+                    background: None,
+                    text_color: palette.background.base.text,
+                    border: Border {
+                        color: border_color,
+                        width: 2.0,
+                        radius: 8.0.into(),
+                    },
+                    ..button::Style::default()
+                }
+            })
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+    use iced::advanced::layout::{Layout, Limits};
+    use iced::advanced::widget::Tree;
+    use iced::advanced::{clipboard, Shell};
+    use iced::{mouse, Event, Point, Rectangle, Size};
+    use iced_plus_theme::token_to_iced;
+    use iced_plus_tokens::presets;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Picked(&'static str),
+    }
+
+    #[test]
+    fn preview_uses_token_colors() {
+        let tokens = presets::light_tokens();
+        let element: Element<'_, Message, Theme, Recorder> =
+            ThemeSwatch::new("Light", &tokens).into();
+        let mut tree = Tree::new(&element);
+        let mut recorder = Recorder::default();
+        let node = element.as_widget().layout(
+            &mut tree,
+            &recorder,
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        element.as_widget().draw(
+            &tree,
+            &mut recorder,
+            &Theme::Dark,
+            &renderer::Style {
+                text_color: Color::WHITE,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(400.0, 400.0)),
+        );
+
+        let primary = token_to_iced(tokens.colors.primary.s500);
+        let colors = recorder.colors();
+        assert!(colors.contains(&Color::WHITE), "light surface");
+        assert!(colors.contains(&primary), "primary accent");
+
+        let dark = ThemeSwatch::<Message>::new("Dark", &tokens).dark(true);
+        assert_eq!(dark.colors().1, token_to_iced(tokens.colors.neutral.s800));
+    }
+
+    #[test]
+    fn click_emits_selection() {
+        let tokens = presets::dark_tokens();
+        let mut element: Element<'_, Message, Theme, ()> = ThemeSwatch::new("Dark", &tokens)
+            .dark(true)
+            .on_press(Message::Picked("dark"))
+            .into();
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+        let cursor = mouse::Cursor::Available(Point::new(20.0, 20.0));
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ] {
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(400.0, 400.0)),
+            );
+        }
+
+        assert_eq!(messages, vec![Message::Picked("dark")]);
+    }
+}
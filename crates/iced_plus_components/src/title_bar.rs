@@ -0,0 +1,271 @@
+//! Custom title bar for borderless windows.
+
+use std::borrow::Cow;
+
+use iced::advanced::text;
+use iced::widget::{button, container, mouse_area, row, text as label};
+use iced::{alignment, Alignment, Color, Element, Length, Theme};
+
+/// Width of each window control button.
+const CONTROL_WIDTH: f32 = 46.0;
+
+/// Title bar for windows created without decorations.
+///
+/// The title area is the drag region: pressing it emits the
+/// [`on_drag`](Self::on_drag) message, which should start a window move.
+/// Minimize, maximize and close buttons are shown for the messages that are
+/// set. The messages pair naturally with `iced_plus_platform`'s
+/// `window::WindowAction`, whose `task` performs the action.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // In view:
+/// TitleBar::new("Notes")
+///     .leading(icon(IconName::Menu))
+///     .maximized(self.maximized)
+///     .on_drag(Message::Window(WindowAction::Drag))
+///     .on_minimize(Message::Window(WindowAction::Minimize))
+///     .on_maximize(Message::Window(WindowAction::ToggleMaximize))
+///     .on_close(Message::Window(WindowAction::Close))
+///
+/// // In update:
+/// Message::Window(action) => action.task(self.window_id),
+/// ```
+pub struct TitleBar<'a, Message, Renderer = iced::Renderer> {
+    title: Cow<'a, str>,
+    leading: Option<Element<'a, Message, Theme, Renderer>>,
+    trailing: Option<Element<'a, Message, Theme, Renderer>>,
+    on_drag: Option<Message>,
+    on_minimize: Option<Message>,
+    on_maximize: Option<Message>,
+    on_close: Option<Message>,
+    maximized: bool,
+    height: f32,
+}
+
+impl<'a, Message, Renderer> TitleBar<'a, Message, Renderer> {
+    /// Create a title bar showing `title`.
+    #[must_use]
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            leading: None,
+            trailing: None,
+            on_drag: None,
+            on_minimize: None,
+            on_maximize: None,
+            on_close: None,
+            maximized: false,
+            height: 32.0,
+        }
+    }
+
+    /// Set content before the title, e.g. an app icon or menu button.
+    #[must_use]
+    pub fn leading(mut self, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.leading = Some(content.into());
+        self
+    }
+
+    /// Set content between the title and the window controls.
+    #[must_use]
+    pub fn trailing(mut self, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.trailing = Some(content.into());
+        self
+    }
+
+    /// Set the message emitted when the title area is pressed.
+    #[must_use]
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// Show a minimize button emitting `message`.
+    #[must_use]
+    pub fn on_minimize(mut self, message: Message) -> Self {
+        self.on_minimize = Some(message);
+        self
+    }
+
+    /// Show a maximize/restore button emitting `message`.
+    #[must_use]
+    pub fn on_maximize(mut self, message: Message) -> Self {
+        self.on_maximize = Some(message);
+        self
+    }
+
+    /// Show a close button emitting `message`.
+    #[must_use]
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Show the restore glyph instead of maximize.
+    #[must_use]
+    pub const fn maximized(mut self, maximized: bool) -> Self {
+        self.maximized = maximized;
+        self
+    }
+
+    /// Set the bar height.
+    #[must_use]
+    pub const fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+/// Style of a window control; the close button turns red on hover.
+fn control_style(theme: &Theme, status: button::Status, close: bool) -> button::Style {
+    let palette = theme.extended_palette();
+    let base = button::Style {
+        background: None,
+        text_color: palette.background.base.text,
+        ..button::Style::default()
+    };
+
+    match status {
+        button::Status::Hovered | button::Status::Pressed if close => button::Style {
+            background: Some(palette.danger.base.color.into()),
+            text_color: Color::WHITE,
+            ..base
+        },
+        button::Status::Hovered | button::Status::Pressed => button::Style {
+            background: Some(palette.background.weak.color.into()),
+            ..base
+        },
+        button::Status::Active | button::Status::Disabled => base,
+    }
+}
+
+impl<'a, Message, Renderer> From<TitleBar<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(bar: TitleBar<'a, Message, Renderer>) -> Self {
+        let control = |glyph: &'static str, message: Message, close: bool| {
+            button(
+                label(glyph)
+                    .size(12)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .align_x(alignment::Horizontal::Center)
+                    .align_y(alignment::Vertical::Center),
+            )
+            .width(CONTROL_WIDTH)
+            .height(Length::Fill)
+            .padding(0)
+            .on_press(message)
+            .style(move |theme: &Theme, status| control_style(theme, status, close))
+        };
+
+        let title = container(label(bar.title).size(13))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding([0, 12])
+            .align_y(alignment::Vertical::Center);
+        let title: Element<'a, Message, Theme, Renderer> = match bar.on_drag {
+            Some(message) => mouse_area(title).on_press(message).into(),
+            None => title.into(),
+        };
+
+        let mut content = row![].align_y(Alignment::Center).height(bar.height);
+        if let Some(leading) = bar.leading {
+            content = content.push(leading);
+        }
+        content = content.push(title);
+        if let Some(trailing) = bar.trailing {
+            content = content.push(trailing);
+        }
+        if let Some(message) = bar.on_minimize {
+            content = content.push(control("—", message, false));
+        }
+        if let Some(message) = bar.on_maximize {
+            let glyph = if bar.maximized { "❐" } else { "□" };
+            content = content.push(control(glyph, message, false));
+        }
+        if let Some(message) = bar.on_close {
+            content = content.push(control("✕", message, true));
+        }
+
+        container(content)
+            .width(Length::Fill)
+            .style(|theme: &Theme| {
+                container::Style::default()
+                    .background(theme.extended_palette().background.weak.color)
+            })
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::layout::{Layout, Limits};
+    use iced::advanced::widget::Tree;
+    use iced::advanced::{clipboard, Shell};
+    use iced::{mouse, Event, Point, Rectangle, Size};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Action {
+        Drag,
+        Minimize,
+        Maximize,
+        Close,
+    }
+
+    /// Click at `x` in a 400 pixel wide title bar and return the messages.
+    fn click(x: f32) -> Vec<Action> {
+        let mut bar: Element<'_, Action, Theme, ()> = TitleBar::new("Notes")
+            .on_drag(Action::Drag)
+            .on_minimize(Action::Minimize)
+            .on_maximize(Action::Maximize)
+            .on_close(Action::Close)
+            .into();
+        let mut tree = Tree::new(&bar);
+        let node = bar.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+        let cursor = mouse::Cursor::Available(Point::new(x, 16.0));
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ] {
+            let _ = bar.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(400.0, 100.0)),
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn controls_emit_window_actions() {
+        // Controls are 46 pixels wide, packed against the right edge.
+        assert_eq!(click(377.0), [Action::Close]);
+        assert_eq!(click(331.0), [Action::Maximize]);
+        assert_eq!(click(285.0), [Action::Minimize]);
+    }
+
+    #[test]
+    fn title_area_starts_drag() {
+        assert_eq!(click(100.0), [Action::Drag]);
+    }
+}
@@ -27,11 +27,41 @@
 //! }
 //! ```
 
+use iced::advanced::graphics::geometry;
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
 use iced::widget::{button, column, container, row, text, Space};
-use iced::{alignment, Background, Border, Color, Element, Length, Padding, Theme};
+use iced::{
+    alignment, event, mouse, Background, Border, Color, Element, Event, Length, Padding, Rectangle,
+    Size, Theme, Vector,
+};
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::time::Duration;
 
+use iced_plus_layouts::layered;
+use iced_plus_tokens::{Easing, Layer, Motion};
+
+use crate::alert::Alert;
+
+/// Default maximum width of a toast card, in pixels.
+pub const DEFAULT_MAX_WIDTH: f32 = 360.0;
+
+const TEXT_SIZE: f32 = 14.0;
+const LINE_HEIGHT: f32 = 1.3;
+/// Average glyph advance relative to the font size, used to estimate wrapping.
+const AVERAGE_GLYPH_WIDTH: f32 = 0.55;
+const PADDING_H: f32 = 16.0;
+const PADDING_V: f32 = 12.0;
+/// Gap between a toast stack and the window edges.
+const STACK_MARGIN: f32 = 16.0;
+/// Width taken by the leading icon and its gap.
+const ICON_WIDTH: f32 = 16.0 + 8.0 + 4.0;
+/// Width taken by the close button and its gap.
+const CLOSE_WIDTH: f32 = 14.0 + 12.0 + 4.0;
+
 /// Toast variant/severity.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ToastVariant {
@@ -97,6 +127,23 @@ pub enum ToastPosition {
     BottomCenter,
 }
 
+impl ToastPosition {
+    /// Unit vector towards the screen edge toasts at this position slide in
+    /// from and out to.
+    ///
+    /// Corners use their side edge; centered positions use the top or
+    /// bottom edge.
+    #[must_use]
+    pub const fn slide_direction(self) -> Vector {
+        match self {
+            Self::TopRight | Self::BottomRight => Vector::new(1.0, 0.0),
+            Self::TopLeft | Self::BottomLeft => Vector::new(-1.0, 0.0),
+            Self::TopCenter => Vector::new(0.0, -1.0),
+            Self::BottomCenter => Vector::new(0.0, 1.0),
+        }
+    }
+}
+
 /// A toast notification definition.
 ///
 /// Toasts are typically managed by a toast manager/container at the app level.
@@ -106,9 +153,12 @@ pub enum ToastPosition {
 /// ```rust,ignore
 /// let toast = Toast::success("File saved successfully!")
 ///     .duration(Duration::from_secs(3));
+///
+/// // Play a sound when it appears
+/// let toast = Toast::error("Disk full").on_show(Message::PlayAlertSound);
 /// ```
 #[derive(Clone)]
-pub struct Toast<'a> {
+pub struct Toast<'a, Message = ()> {
     /// Toast message.
     pub message: Cow<'a, str>,
     /// Optional title.
@@ -119,6 +169,10 @@ pub struct Toast<'a> {
     pub duration: Option<Duration>,
     /// Whether the toast is dismissible.
     pub dismissible: bool,
+    /// Maximum width of the toast card; longer messages wrap.
+    pub max_width: f32,
+    /// Message emitted once when the toast first becomes visible.
+    pub on_show: Option<Message>,
 }
 
 impl<'a> Toast<'a> {
@@ -131,6 +185,8 @@ impl<'a> Toast<'a> {
             variant: ToastVariant::default(),
             duration: Some(Duration::from_secs(5)),
             dismissible: true,
+            max_width: DEFAULT_MAX_WIDTH,
+            on_show: None,
         }
     }
 
@@ -158,6 +214,29 @@ impl<'a> Toast<'a> {
         Self::new(message).variant(ToastVariant::Error)
     }
 
+    /// Create a toast from an alert; see [`Alert::floating`].
+    #[must_use]
+    pub fn from_alert<M>(alert: Alert<'a, M>) -> Self {
+        alert.floating()
+    }
+}
+
+impl<'a, Message> Toast<'a, Message> {
+    /// Emit `message` once when the toast first becomes visible in a
+    /// [`toast_stack`], e.g. to play a sound for critical alerts.
+    #[must_use]
+    pub fn on_show<M>(self, message: M) -> Toast<'a, M> {
+        Toast {
+            message: self.message,
+            title: self.title,
+            variant: self.variant,
+            duration: self.duration,
+            dismissible: self.dismissible,
+            max_width: self.max_width,
+            on_show: Some(message),
+        }
+    }
+
     /// Set the variant.
     #[must_use]
     pub fn variant(mut self, variant: ToastVariant) -> Self {
@@ -192,23 +271,134 @@ impl<'a> Toast<'a> {
         self.dismissible = dismissible;
         self
     }
+
+    /// Set the maximum width of the toast card.
+    #[must_use]
+    pub const fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Estimate the size of the rendered toast card.
+    ///
+    /// The width never exceeds [`Toast::max_width`]; long messages wrap and
+    /// grow the height instead. Text is measured with an average glyph width,
+    /// so the height is an estimate suitable for stacking and animating
+    /// toasts. The card itself is laid out with the real text, shrinking to
+    /// its content up to [`Toast::max_width`].
+    #[must_use]
+    // Line counts stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn measure(&self) -> iced::Size {
+        let close = if self.dismissible { CLOSE_WIDTH } else { 0.0 };
+        let chrome = PADDING_H.mul_add(2.0, ICON_WIDTH) + close;
+        let text_width = (self.max_width - chrome).max(0.0);
+
+        let (title_width, title_lines) = self
+            .title
+            .as_deref()
+            .map_or((0.0, 0), |title| wrap_text(title, text_width));
+        let (message_width, message_lines) = wrap_text(&self.message, text_width);
+
+        let lines = (title_lines + message_lines).max(1) as f32;
+        let width = (title_width.max(message_width) + chrome).min(self.max_width);
+
+        iced::Size::new(
+            width,
+            (lines * TEXT_SIZE).mul_add(LINE_HEIGHT, PADDING_V * 2.0),
+        )
+    }
+
+    /// Render the toast as a card.
+    ///
+    /// `on_close` receives `id` when the close button is pressed.
+    pub fn view<M>(&self, id: usize, on_close: impl Fn(usize) -> M + 'a) -> Element<'_, M, Theme>
+    where
+        M: Clone + 'a,
+    {
+        self.card(self.dismissible.then(|| on_close(id)))
+    }
+
+    /// The card of [`Toast::view`], at most [`Toast::max_width`] wide.
+    fn card<M, Renderer>(&self, on_close: Option<M>) -> Element<'_, M, Theme, Renderer>
+    where
+        M: Clone + 'a,
+        Renderer: geometry::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+    {
+        toast_card(
+            self.title.as_deref(),
+            &self.message,
+            self.variant,
+            on_close,
+            self.max_width,
+        )
+    }
+}
+
+/// Greedily word-wrap `content` at `max_width`, returning the widest line and
+/// the number of lines.
+// Word lengths stay far below where `f32` loses integer precision.
+#[allow(clippy::cast_precision_loss)]
+fn wrap_text(content: &str, max_width: f32) -> (f32, usize) {
+    let glyph = TEXT_SIZE * AVERAGE_GLYPH_WIDTH;
+    let mut widest = 0.0_f32;
+    let mut lines = 0;
+
+    for paragraph in content.lines() {
+        let mut line = 0.0_f32;
+        lines += 1;
+
+        for word in paragraph.split_whitespace() {
+            let word_width = word.chars().count() as f32 * glyph;
+            let spaced = if line > 0.0 {
+                line + glyph + word_width
+            } else {
+                word_width
+            };
+
+            if spaced > max_width && line > 0.0 {
+                widest = widest.max(line);
+                lines += 1;
+                line = word_width;
+            } else {
+                line = spaced;
+            }
+        }
+
+        widest = widest.max(line);
+    }
+
+    (widest.min(max_width), lines)
 }
 
 /// Toast manager state for tracking active toasts.
-pub struct ToastManager<'a> {
+///
+/// Call [`tick`](Self::tick) from a timer subscription to expire toasts once
+/// their [`duration`](Toast::duration) has elapsed. The same ages drive the
+/// slide-in, reported by [`entry_progress`](Self::entry_progress), and the
+/// slide-out over the last moments of a toast's duration, reported by
+/// [`exit_progress`](Self::exit_progress). [`view`](Self::view) renders the
+/// toasts sliding from the edge nearest their position.
+pub struct ToastManager<'a, Message = ()> {
     /// Active toasts.
-    pub toasts: Vec<Toast<'a>>,
+    pub toasts: Vec<Toast<'a, Message>>,
     /// Maximum number of visible toasts.
     pub max_visible: usize,
+    /// Time each toast has been shown, parallel to `toasts`.
+    ages: Vec<Duration>,
+    /// Duration and easing of the slide-in.
+    motion: Motion,
 }
 
-impl<'a> ToastManager<'a> {
+impl<'a, Message> ToastManager<'a, Message> {
     /// Create a new toast manager.
     #[must_use]
     pub fn new() -> Self {
         Self {
             toasts: Vec::new(),
             max_visible: 5,
+            ages: Vec::new(),
+            motion: Motion::default(),
         }
     }
 
@@ -219,29 +409,172 @@ impl<'a> ToastManager<'a> {
         self
     }
 
+    /// Set the duration and easing of the slide-in.
+    ///
+    /// Toasts don't slide while
+    /// [`reduce_motion`](iced_plus_tokens::reduce_motion) is on. The manager
+    /// lives in app state, so the setting is read when the progress is
+    /// queried: call [`view`](Self::view) inside
+    /// [`with_reduce_motion`](iced_plus_tokens::with_reduce_motion) to scope it.
+    #[must_use]
+    pub const fn motion(mut self, motion: Motion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Set the easing curve of the slide-in.
+    ///
+    /// Defaults to [`Easing::STANDARD`], the motion tokens' standard curve.
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.motion.easing = easing;
+        self
+    }
+
+    /// How far the toast at `index` has slid in, eased: 0.0 when pushed and
+    /// 1.0 once in place or when there is no such toast.
+    #[must_use]
+    pub fn entry_progress(&self, index: usize) -> f32 {
+        let motion = self.motion.effective();
+        let duration = motion.duration_seconds();
+        let age = self.ages.get(index).copied().unwrap_or_default();
+
+        if index >= self.toasts.len() || duration <= 0.0 {
+            return 1.0;
+        }
+
+        motion.easing.apply(age.as_secs_f32() / duration)
+    }
+
+    /// How far the toast at `index` has slid out, eased: 0.0 until the
+    /// final slide of its duration and 1.0 when it expires. Persistent
+    /// toasts never slide out.
+    #[must_use]
+    pub fn exit_progress(&self, index: usize) -> f32 {
+        let motion = self.motion.effective();
+        let slide = motion.duration_seconds();
+        let (Some(toast), Some(age)) = (self.toasts.get(index), self.ages.get(index)) else {
+            return 0.0;
+        };
+        let Some(duration) = toast.duration else {
+            return 0.0;
+        };
+        let remaining = duration.saturating_sub(*age).as_secs_f32();
+
+        if remaining >= slide {
+            0.0
+        } else {
+            motion.easing.apply(1.0 - remaining / slide)
+        }
+    }
+
+    /// Offset of the toast at `index` from its place in a stack at
+    /// `position`: off screen past the nearest edge when pushed or expiring,
+    /// and zero while fully shown.
+    #[must_use]
+    pub fn slide_offset(&self, index: usize, position: ToastPosition) -> Vector {
+        let Some(toast) = self.toasts.get(index) else {
+            return Vector::ZERO;
+        };
+        let direction = position.slide_direction();
+        let distance = if direction.x == 0.0 {
+            toast.measure().height + STACK_MARGIN
+        } else {
+            toast.measure().width + STACK_MARGIN
+        };
+        let hidden = (1.0 - self.entry_progress(index)).max(self.exit_progress(index));
+
+        direction * (distance * hidden)
+    }
+
+    /// Render the toasts over `content` at `position`, each sliding in from
+    /// and out to the nearest edge.
+    ///
+    /// Toasts are identified by index: `on_close` receives the index to
+    /// pass to [`remove`](Self::remove). The slide follows the ages set by
+    /// [`tick`](Self::tick), so tick from a frame-rate subscription while
+    /// toasts are animating.
+    pub fn view<M>(
+        &self,
+        content: impl Into<Element<'a, M, Theme>>,
+        on_close: impl Fn(usize) -> M + Copy + 'a,
+        position: ToastPosition,
+    ) -> Element<'_, M, Theme>
+    where
+        M: Clone + 'a,
+    {
+        if self.toasts.is_empty() {
+            return content.into();
+        }
+
+        let toast_list = column(self.toasts.iter().enumerate().map(|(index, toast)| {
+            Element::new(Slide {
+                content: toast.view(index, on_close),
+                offset: self.slide_offset(index, position),
+            })
+        }))
+        .spacing(8);
+
+        place_toasts(content.into(), toast_list.into(), position, Padding::ZERO)
+    }
+
     /// Add a toast.
-    pub fn push(&mut self, toast: Toast<'a>) {
+    pub fn push(&mut self, toast: Toast<'a, Message>) {
+        self.sync_ages();
         self.toasts.push(toast);
+        self.ages.push(Duration::ZERO);
         // Remove oldest if over limit
         while self.toasts.len() > self.max_visible {
             self.toasts.remove(0);
+            self.ages.remove(0);
         }
     }
 
     /// Remove a toast by index.
     pub fn remove(&mut self, index: usize) {
+        self.sync_ages();
         if index < self.toasts.len() {
             self.toasts.remove(index);
+            self.ages.remove(index);
         }
     }
 
     /// Clear all toasts.
     pub fn clear(&mut self) {
         self.toasts.clear();
+        self.ages.clear();
+    }
+
+    /// Advance every toast by `elapsed` and remove the ones whose duration
+    /// has run out. Persistent toasts are kept.
+    ///
+    /// Returns the number of toasts removed.
+    pub fn tick(&mut self, elapsed: Duration) -> usize {
+        self.sync_ages();
+        let before = self.toasts.len();
+
+        let mut ages = std::mem::take(&mut self.ages).into_iter();
+        let mut kept = Vec::with_capacity(before);
+        self.toasts.retain(|toast| {
+            let age = ages.next().unwrap_or_default() + elapsed;
+            let alive = toast.duration.map_or(true, |duration| age < duration);
+            if alive {
+                kept.push(age);
+            }
+            alive
+        });
+        self.ages = kept;
+
+        before - self.toasts.len()
+    }
+
+    /// Keep `ages` in step with `toasts`, which callers may edit directly.
+    fn sync_ages(&mut self) {
+        self.ages.resize(self.toasts.len(), Duration::ZERO);
     }
 }
 
-impl<'a> Default for ToastManager<'a> {
+impl<Message> Default for ToastManager<'_, Message> {
     fn default() -> Self {
         Self::new()
     }
@@ -262,38 +595,74 @@ pub fn toast_view<'a, Message>(
 ) -> Element<'a, Message, Theme>
 where
     Message: Clone + 'a,
+{
+    toast_card(
+        None,
+        message,
+        variant,
+        Some(on_close(id)),
+        DEFAULT_MAX_WIDTH,
+    )
+}
+
+fn toast_card<'a, Message, Renderer>(
+    title: Option<&'a str>,
+    message: &'a str,
+    variant: ToastVariant,
+    on_close: Option<Message>,
+    max_width: f32,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: geometry::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
 {
     let bg_color = variant.background_color();
     let text_color = variant.text_color();
-    let icon: Element<'a, Message, Theme> = crate::icons::Icon::new(variant.icon_name())
+    let icon: Element<'a, Message, Theme, Renderer> = crate::icons::Icon::new(variant.icon_name())
         .size(16.0)
         .color(text_color)
         .into();
 
-    let close_icon: Element<'a, Message, Theme> = crate::icons::Icon::close()
-        .size(14.0)
-        .color(text_color)
-        .into();
+    let mut body = column![].spacing(2);
+    if let Some(title) = title {
+        body = body.push(
+            text(title)
+                .size(TEXT_SIZE)
+                .font(iced::Font {
+                    weight: iced::font::Weight::Bold,
+                    ..iced::Font::default()
+                })
+                .color(text_color),
+        );
+    }
+    body = body.push(text(message).size(TEXT_SIZE).color(text_color));
 
-    let content = row![
-        icon,
-        Space::with_width(8),
-        text(message).size(14).color(text_color),
-        Space::with_width(Length::Fill),
-        button(close_icon)
-            .style(move |_theme, _status| button::Style {
-                background: Some(Background::Color(Color::TRANSPARENT)),
-                text_color,
-                ..button::Style::default()
-            })
-            .padding(Padding::from([4, 6]))
-            .on_press(on_close(id)),
-    ]
-    .align_y(alignment::Vertical::Center)
-    .spacing(4);
+    // Top-aligned so the icon and close button stay on the first line
+    // when the message wraps.
+    let mut content = row![icon, Space::with_width(8), body]
+        .align_y(alignment::Vertical::Top)
+        .spacing(4);
+
+    if let Some(on_close) = on_close {
+        let close_icon: Element<'a, Message, Theme, Renderer> = crate::icons::Icon::close()
+            .size(14.0)
+            .color(text_color)
+            .into();
+
+        content = content.push(
+            button(close_icon)
+                .style(move |_theme, _status| button::Style {
+                    background: Some(Background::Color(Color::TRANSPARENT)),
+                    text_color,
+                    ..button::Style::default()
+                })
+                .padding(Padding::from([4, 6]))
+                .on_press(on_close),
+        );
+    }
 
     container(content)
-        .padding(Padding::from([12, 16]))
+        .padding(Padding::from([PADDING_V, PADDING_H]))
         .style(move |_theme| container::Style {
             background: Some(Background::Color(bg_color)),
             border: Border {
@@ -302,7 +671,7 @@ where
             },
             ..container::Style::default()
         })
-        .width(Length::Fixed(320.0))
+        .max_width(max_width)
         .into()
 }
 
@@ -335,8 +704,36 @@ pub fn toast_container<'a, Message>(
 where
     Message: Clone + 'a,
 {
-    use iced::widget::{stack, Column, Row};
+    if toasts.is_empty() {
+        return content.into();
+    }
 
+    let toast_list: Element<'a, Message, Theme> = column(
+        toasts
+            .iter()
+            .map(|(id, msg, variant)| toast_view(*id, msg, *variant, on_close))
+            .collect::<Vec<_>>(),
+    )
+    .spacing(8)
+    .into();
+
+    place_toasts(content.into(), toast_list, position, Padding::ZERO)
+}
+
+/// Create a toast container kept clear of safe-area `insets`.
+///
+/// Use this with custom-decorated windows so toasts do not cover a title
+/// bar or notch region; the toasts are offset inward by the insets.
+pub fn toast_container_with_safe_area<'a, Message>(
+    content: impl Into<Element<'a, Message, Theme>>,
+    toasts: &'a [(usize, String, ToastVariant)],
+    on_close: impl Fn(usize) -> Message + Copy + 'a,
+    position: ToastPosition,
+    insets: impl Into<Padding>,
+) -> Element<'a, Message, Theme>
+where
+    Message: Clone + 'a,
+{
     if toasts.is_empty() {
         return content.into();
     }
@@ -350,13 +747,75 @@ where
     .spacing(8)
     .into();
 
+    place_toasts(content.into(), toast_list, position, insets.into())
+}
+
+/// Create a toast container from [`Toast`]s, keyed by a stable id.
+///
+/// Each toast's [`on_show`](Toast::on_show) message is emitted once, the
+/// first time the toast appears; re-rendering the same ids emits nothing.
+///
+/// Toasts only cover `content`, so wrap an open
+/// [`modal`](iced_plus_layouts::modal) in the stack, not the stack in the
+/// modal, to keep toasts above its backdrop.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// toast_stack(
+///     your_main_content,
+///     &self.toasts, // Vec<(usize, Toast<'static, Message>)>
+///     Message::CloseToast,
+///     ToastPosition::TopRight,
+/// )
+/// ```
+pub fn toast_stack<'a, Message>(
+    content: impl Into<Element<'a, Message, Theme>>,
+    toasts: &'a [(usize, Toast<'a, Message>)],
+    on_close: impl Fn(usize) -> Message + Copy + 'a,
+    position: ToastPosition,
+) -> Element<'a, Message, Theme>
+where
+    Message: Clone + 'a,
+{
+    if toasts.is_empty() {
+        return content.into();
+    }
+
+    let toast_list = column(toasts.iter().map(|(id, toast)| toast.view(*id, on_close))).spacing(8);
+    let on_show = toasts
+        .iter()
+        .map(|(id, toast)| (*id, toast.on_show.clone()))
+        .collect();
+
+    place_toasts(
+        content.into(),
+        Element::new(ShowOnce::new(toast_list, on_show)),
+        position,
+        Padding::ZERO,
+    )
+}
+
+/// Overlay `toast_list` on `content` at `position`, inset by `safe_area`.
+fn place_toasts<'a, Message, Renderer>(
+    content: Element<'a, Message, Theme, Renderer>,
+    toast_list: Element<'a, Message, Theme, Renderer>,
+    position: ToastPosition,
+    safe_area: Padding,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    use iced::widget::{Column, Row};
+
     // Create a positioned toast container that doesn't block mouse events
     // by only covering the area needed for the toasts
-    let toast_container_el = container(toast_list).padding(16);
+    let toast_container_el = container(toast_list).padding(STACK_MARGIN);
 
     // Position the toasts using row/column alignment without a full-screen container
     // This prevents the overlay from capturing scroll events
-    let toast_overlay: Element<'a, Message, Theme> = match position {
+    let toast_overlay: Element<'a, Message, Theme, Renderer> = match position {
         ToastPosition::TopRight => Column::new()
             .push(
                 Row::new()
@@ -409,7 +868,13 @@ where
             .into(),
     };
 
-    stack![content.into(), toast_overlay].into()
+    layered(
+        content,
+        [(
+            Layer::Toast,
+            container(toast_overlay).padding(safe_area).into(),
+        )],
+    )
 }
 
 /// Create a toast container with default top-right positioning.
@@ -423,3 +888,587 @@ where
 {
     toast_container(content, toasts, on_close, ToastPosition::TopRight)
 }
+
+/// Publishes each item's message the first time its id is seen.
+struct ShowOnce<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_show: Vec<(usize, Option<Message>)>,
+}
+
+impl<'a, Message, Theme, Renderer> ShowOnce<'a, Message, Theme, Renderer> {
+    fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_show: Vec<(usize, Option<Message>)>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_show,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ShowOnce<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<HashSet<usize>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(HashSet::<usize>::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+
+        // Forget dismissed toasts so a reused id shows again.
+        tree.state
+            .downcast_mut::<HashSet<usize>>()
+            .retain(|seen| self.on_show.iter().any(|(id, _)| id == seen));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Any event means the toasts have been laid out and drawn.
+        let seen = tree.state.downcast_mut::<HashSet<usize>>();
+        for (id, message) in &self.on_show {
+            if seen.insert(*id) {
+                if let Some(message) = message {
+                    shell.publish(message.clone());
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ShowOnce<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(show_once: ShowOnce<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(show_once)
+    }
+}
+
+/// Draws and hit-tests its content shifted by `offset`, leaving the layout
+/// of its siblings untouched.
+struct Slide<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    offset: Vector,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Slide<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content.translate(self.offset)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().expect("slide content layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().expect("slide content layout"),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().expect("slide content layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().expect("slide content layout"),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alert::AlertType;
+
+    #[test]
+    fn long_message_wraps_within_max_width() {
+        let short = Toast::info("Saved").measure();
+        let long = Toast::info("This is a very long toast message ".repeat(8)).measure();
+
+        assert!(long.width <= DEFAULT_MAX_WIDTH);
+        assert!(long.height > short.height);
+
+        let narrow = Toast::info("This is a very long toast message ".repeat(8))
+            .max_width(240.0)
+            .measure();
+        assert!(narrow.width <= 240.0);
+        assert!(narrow.height > long.height);
+    }
+
+    #[test]
+    fn cards_shrink_to_their_text_up_to_the_max_width() {
+        fn laid_out(toast: &Toast<'_>) -> Size {
+            let renderer = iced::Renderer::Secondary(iced_tiny_skia::Renderer::new(
+                iced::Font::default(),
+                iced::Pixels(16.0),
+            ));
+            let card = toast.card::<(), iced::Renderer>(Some(()));
+            let mut tree = Tree::new(&card);
+            card.as_widget()
+                .layout(
+                    &mut tree,
+                    &renderer,
+                    &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+                )
+                .size()
+        }
+
+        let short = laid_out(&Toast::info("Saved"));
+        let long = laid_out(&Toast::info("This is a very long toast message ".repeat(8)));
+        let narrow =
+            laid_out(&Toast::info("This is a very long toast message ".repeat(8)).max_width(240.0));
+
+        assert!(short.width < DEFAULT_MAX_WIDTH / 2.0);
+        assert!((long.width - DEFAULT_MAX_WIDTH).abs() < 1.0);
+        assert!(long.height > short.height);
+        assert!(narrow.width <= 240.0);
+        assert!(narrow.height > long.height);
+    }
+
+    #[test]
+    fn on_show_emits_once_per_toast() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Message {
+            Shown(usize),
+        }
+
+        fn stack(ids: &[usize]) -> ShowOnce<'static, Message, Theme, ()> {
+            ShowOnce::new(
+                Space::new(10.0, 10.0),
+                ids.iter()
+                    .map(|id| (*id, Some(Message::Shown(*id))))
+                    .collect(),
+            )
+        }
+
+        fn frame(
+            widget: &mut ShowOnce<'static, Message, Theme, ()>,
+            tree: &mut Tree,
+        ) -> Vec<Message> {
+            let node = widget.layout(tree, &(), &Limits::new(Size::ZERO, Size::new(100.0, 100.0)));
+            let mut messages = Vec::new();
+            let _ = widget.on_event(
+                tree,
+                Event::Mouse(mouse::Event::CursorLeft),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut iced::advanced::clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(100.0, 100.0)),
+            );
+            messages
+        }
+
+        let mut widget = stack(&[1]);
+        let mut tree = Tree::new(&widget as &dyn Widget<Message, Theme, ()>);
+        assert_eq!(frame(&mut widget, &mut tree), vec![Message::Shown(1)]);
+        assert!(frame(&mut widget, &mut tree).is_empty());
+
+        // Re-rendering with a new toast only announces the new one.
+        let mut widget = stack(&[1, 2]);
+        tree.diff(&widget as &dyn Widget<Message, Theme, ()>);
+        assert_eq!(frame(&mut widget, &mut tree), vec![Message::Shown(2)]);
+
+        // Removing the first toast shifts the rest without re-announcing.
+        let mut widget = stack(&[2]);
+        tree.diff(&widget as &dyn Widget<Message, Theme, ()>);
+        assert!(frame(&mut widget, &mut tree).is_empty());
+    }
+
+    #[test]
+    fn toasts_wrapping_a_modal_stay_above_its_backdrop() {
+        use iced::Point;
+        use iced_plus_layouts::modal;
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum Message {
+            Blur,
+            Close(usize),
+            Shown,
+        }
+
+        fn press<'a>(view: impl Fn(Element<'a, Message>) -> Element<'a, Message>) -> Vec<Message> {
+            let renderer = iced::Renderer::Secondary(iced_tiny_skia::Renderer::new(
+                iced::Font::default(),
+                iced::Pixels(16.0),
+            ));
+            let mut element = view(Space::new(Length::Fill, Length::Fill).into());
+            let mut tree = Tree::new(&element);
+            let node = element.as_widget().layout(
+                &mut tree,
+                &renderer,
+                &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+            );
+
+            // The centre of the 26 x 22 close button, at the top right of
+            // the card inside the stack margin and the card padding.
+            let close = Point::new(
+                800.0 - STACK_MARGIN - PADDING_H - 13.0,
+                STACK_MARGIN + PADDING_V + 11.0,
+            );
+            let mut messages = Vec::new();
+            for event in [
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+            ] {
+                let _ = element.as_widget_mut().on_event(
+                    &mut tree,
+                    Event::Mouse(event),
+                    Layout::new(&node),
+                    mouse::Cursor::Available(close),
+                    &renderer,
+                    &mut iced::advanced::clipboard::Null,
+                    &mut Shell::new(&mut messages),
+                    &Rectangle::with_size(Size::new(800.0, 600.0)),
+                );
+            }
+            messages.retain(|message| *message != Message::Shown);
+            messages
+        }
+
+        let toasts = [(7, Toast::info("Saved").on_show(Message::Shown))];
+        let dialog = || Space::new(100.0, 100.0);
+        let stack =
+            |content| toast_stack(content, &toasts, Message::Close, ToastPosition::TopRight);
+
+        // Layers only order siblings of the same `layered` call, so the
+        // backdrop of a modal covers toasts nested in its base...
+        let nested = press(|page| modal(stack(page), dialog(), Message::Blur));
+        assert_eq!(nested, vec![Message::Blur]);
+
+        // ...while toasts wrapping the modal stay on top.
+        let wrapping = press(|page| stack(modal(page, dialog(), Message::Blur)));
+        assert_eq!(wrapping, vec![Message::Close(7)]);
+    }
+
+    #[test]
+    fn safe_area_offsets_top_right_toasts() {
+        use iced::Point;
+
+        fn toast_origin(insets: Padding) -> Point {
+            let overlay: Element<'_, (), Theme, ()> = place_toasts(
+                Space::new(Length::Fill, Length::Fill).into(),
+                Space::new(200.0, 40.0).into(),
+                ToastPosition::TopRight,
+                insets,
+            );
+            let mut tree = Tree::new(&overlay);
+            let node = overlay.as_widget().layout(
+                &mut tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+            );
+
+            // stack > inset container > column > row > padded container > list
+            let mut layout = Layout::new(&node);
+            for index in [1, 0, 0, 1, 0] {
+                layout = layout.children().nth(index).expect("toast layout");
+            }
+            layout.bounds().position()
+        }
+
+        let plain = toast_origin(Padding::ZERO);
+        let inset = toast_origin(Padding::ZERO.top(32));
+
+        assert_eq!(plain, Point::new(800.0 - 16.0 - 200.0, 16.0));
+        assert_eq!(inset, Point::new(plain.x, plain.y + 32.0));
+    }
+
+    #[test]
+    fn floating_alert_expires_after_its_duration() {
+        let alert = Alert::<()>::warning("Connection lost").title("Offline");
+        let toast = Toast::from_alert(alert).duration(Duration::from_secs(2));
+
+        assert_eq!(toast.variant, AlertType::Warning.toast_variant());
+        assert_eq!(toast.title.as_deref(), Some("Offline"));
+        assert_eq!(toast.duration, Some(Duration::from_secs(2)));
+
+        let mut manager = ToastManager::new();
+        manager.push(toast);
+        manager.push(Alert::<()>::info("Synced").floating().persistent());
+        manager.push(Alert::<()>::error("Failed").floating());
+
+        assert_eq!(manager.tick(Duration::from_secs(1)), 0);
+        assert_eq!(manager.tick(Duration::from_millis(1500)), 1);
+        assert_eq!(manager.toasts.len(), 2);
+        assert_eq!(manager.toasts[0].message, "Synced");
+
+        // The default duration runs out next; the persistent toast stays.
+        assert_eq!(manager.tick(Duration::from_secs(3)), 1);
+        assert_eq!(manager.tick(Duration::from_secs(60)), 0);
+        assert_eq!(manager.toasts.len(), 1);
+    }
+    #[test]
+    fn entry_progress_follows_configured_easing() {
+        let mut linear = ToastManager::new().motion(Motion::new(200, Easing::Linear));
+        let mut standard = ToastManager::new();
+        linear.push(Toast::info("Saved"));
+        standard.push(Toast::info("Saved"));
+
+        assert!(linear.entry_progress(0).abs() < f32::EPSILON);
+        linear.tick(Duration::from_millis(100));
+        standard.tick(Duration::from_millis(100));
+
+        assert!((linear.entry_progress(0) - 0.5).abs() < 0.001);
+        assert!(standard.entry_progress(0) > 0.5);
+        assert!((linear.entry_progress(1) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn toasts_slide_in_from_the_nearest_edge() {
+        let mut manager = ToastManager::new().motion(Motion::new(200, Easing::Linear));
+        manager.push(Toast::info("Saved"));
+
+        let right = manager.slide_offset(0, ToastPosition::TopRight);
+        let left = manager.slide_offset(0, ToastPosition::BottomLeft);
+        let below = manager.slide_offset(0, ToastPosition::BottomCenter);
+        let above = manager.slide_offset(0, ToastPosition::TopCenter);
+
+        // Fully off screen: the card width plus the stack margin.
+        let width = Toast::info("Saved").measure().width;
+        assert_eq!(right, Vector::new(width + STACK_MARGIN, 0.0));
+        assert_eq!(left, Vector::new(-right.x, 0.0));
+        assert!(below.x == 0.0 && below.y > 0.0);
+        assert!(above.x == 0.0 && above.y < 0.0);
+
+        manager.tick(Duration::from_millis(100));
+        let halfway = manager.slide_offset(0, ToastPosition::TopRight);
+        assert!((halfway.x - right.x / 2.0).abs() < 0.01);
+
+        manager.tick(Duration::from_millis(100));
+        assert_eq!(
+            manager.slide_offset(0, ToastPosition::TopRight),
+            Vector::ZERO
+        );
+    }
+
+    #[test]
+    fn expiring_toasts_slide_out_towards_their_edge() {
+        let mut manager = ToastManager::new().motion(Motion::new(200, Easing::Linear));
+        manager.push(Toast::info("Saved").duration(Duration::from_secs(2)));
+        manager.push(Toast::info("Pinned").persistent());
+
+        manager.tick(Duration::from_millis(1700));
+        assert!(manager.exit_progress(0).abs() < f32::EPSILON);
+
+        manager.tick(Duration::from_millis(200));
+        assert!((manager.exit_progress(0) - 0.5).abs() < 0.01);
+        assert!(manager.slide_offset(0, ToastPosition::TopRight).x > 0.0);
+        assert!(manager.slide_offset(0, ToastPosition::TopLeft).x < 0.0);
+        assert_eq!(
+            manager.slide_offset(1, ToastPosition::TopRight),
+            Vector::ZERO
+        );
+    }
+}
@@ -0,0 +1,742 @@
+//! Toolbar component with grouped items and overflow menu.
+
+use std::fmt;
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::text;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::{event, mouse, Border, Element, Event, Length, Point, Rectangle, Size, Theme, Vector};
+
+use crate::dropdown::{DropdownMetrics, ListOverlay, ListState, ListStatus};
+
+/// Width of the "…" overflow button.
+const OVERFLOW_WIDTH: f32 = 32.0;
+
+/// A single item in a [`Toolbar`].
+///
+/// The label and message are used for the overflow menu when the item does
+/// not fit in the toolbar.
+pub struct ToolbarItem<'a, Message, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    label: String,
+    on_press: Option<Message>,
+}
+
+impl<'a, Message, Renderer> ToolbarItem<'a, Message, Renderer> {
+    /// Create a toolbar item with the label shown in the overflow menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            label: label.into(),
+            on_press: None,
+        }
+    }
+
+    /// Set the message emitted when the item is picked from the overflow menu.
+    ///
+    /// Items without a message are hidden when they overflow.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+}
+
+enum Entry<'a, Message, Renderer> {
+    Group(Vec<ToolbarItem<'a, Message, Renderer>>),
+    FlexibleSpace,
+}
+
+/// An overflowed item as listed in the overflow menu.
+#[derive(Clone)]
+struct OverflowEntry<Message> {
+    label: String,
+    message: Message,
+}
+
+impl<Message> fmt::Display for OverflowEntry<Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+fn overflow_message<Message>(entry: OverflowEntry<Message>) -> Message {
+    entry.message
+}
+
+#[derive(Debug, Default)]
+struct State {
+    menu: ListState,
+    /// Number of items (in push order) that fit in the toolbar.
+    visible: usize,
+}
+
+/// A horizontal bar of item groups.
+///
+/// Adjacent groups are separated by vertical dividers, and
+/// [`flexible_space`](Self::flexible_space) pushes the following groups to
+/// the right. When the items do not fit, trailing items are moved into a
+/// "…" overflow menu.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Toolbar::new()
+///     .group([
+///         ToolbarItem::new(icon_button(IconName::Bold), "Bold").on_press(Message::Bold),
+///         ToolbarItem::new(icon_button(IconName::Italic), "Italic").on_press(Message::Italic),
+///     ])
+///     .group([ToolbarItem::new(icon_button(IconName::Link), "Link").on_press(Message::Link)])
+///     .flexible_space()
+///     .group([ToolbarItem::new(share_button, "Share").on_press(Message::Share)])
+/// ```
+pub struct Toolbar<'a, Message, Renderer = iced::Renderer> {
+    entries: Vec<Entry<'a, Message, Renderer>>,
+    overflow: Vec<OverflowEntry<Message>>,
+    spacing: f32,
+    padding: f32,
+    metrics: DropdownMetrics,
+}
+
+impl<'a, Message, Renderer> Toolbar<'a, Message, Renderer>
+where
+    Message: Clone,
+{
+    /// Create an empty toolbar.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            overflow: Vec::new(),
+            spacing: 4.0,
+            padding: 8.0,
+            metrics: DropdownMetrics::DEFAULT,
+        }
+    }
+
+    /// Add a group of items.
+    ///
+    /// A divider is drawn between this group and an adjacent previous group.
+    #[must_use]
+    pub fn group(
+        mut self,
+        items: impl IntoIterator<Item = ToolbarItem<'a, Message, Renderer>>,
+    ) -> Self {
+        self.entries.push(Entry::Group(items.into_iter().collect()));
+        self
+    }
+
+    /// Add a flexible space that takes up the remaining width.
+    ///
+    /// Multiple flexible spaces share the remaining width equally.
+    #[must_use]
+    pub fn flexible_space(mut self) -> Self {
+        self.entries.push(Entry::FlexibleSpace);
+        self
+    }
+
+    /// Set the spacing between items.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the padding around the items.
+    #[must_use]
+    pub const fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    fn items(&self) -> impl Iterator<Item = &ToolbarItem<'a, Message, Renderer>> {
+        self.entries.iter().flat_map(|entry| match entry {
+            Entry::Group(items) => items.as_slice(),
+            Entry::FlexibleSpace => &[],
+        })
+    }
+
+    fn items_mut(&mut self) -> impl Iterator<Item = &mut ToolbarItem<'a, Message, Renderer>> {
+        self.entries.iter_mut().flat_map(|entry| match entry {
+            Entry::Group(items) => items.as_mut_slice(),
+            Entry::FlexibleSpace => &mut [],
+        })
+    }
+
+    /// Items past `visible` that can be picked from the overflow menu.
+    fn overflow_entries(&self, visible: usize) -> Vec<OverflowEntry<Message>> {
+        self.items()
+            .skip(visible)
+            .filter_map(|item| {
+                item.on_press.clone().map(|message| OverflowEntry {
+                    label: item.label.clone(),
+                    message,
+                })
+            })
+            .collect()
+    }
+
+    /// Width of the items that are shown when `visible` of them fit,
+    /// including spacing and dividers but not padding or flexible space.
+    // Item counts stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
+    fn content_width(&self, widths: &[f32], visible: usize) -> f32 {
+        let mut width = 0.0;
+        let mut index = 0;
+        let mut previous_group = false;
+
+        for entry in &self.entries {
+            match entry {
+                Entry::FlexibleSpace => previous_group = false,
+                Entry::Group(items) => {
+                    let start = index;
+                    let shown = items.len().min(visible.saturating_sub(start));
+                    index += items.len();
+
+                    if shown == 0 {
+                        continue;
+                    }
+
+                    if previous_group {
+                        width += self.divider_width();
+                    } else if width > 0.0 {
+                        width += self.spacing;
+                    }
+
+                    width += self.spacing.mul_add(
+                        (shown - 1) as f32,
+                        widths[start..start + shown].iter().sum(),
+                    );
+                    previous_group = true;
+                }
+            }
+        }
+
+        width
+    }
+
+    fn divider_width(&self) -> f32 {
+        self.spacing.mul_add(2.0, 1.0)
+    }
+}
+
+impl<Message, Renderer> Default for Toolbar<'_, Message, Renderer>
+where
+    Message: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Toolbar<'_, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items().map(|item| Tree::new(&item.content)).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let contents: Vec<_> = self.items().map(|item| &item.content).collect();
+        tree.diff_children(&contents);
+    }
+
+    // Entry counts stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let max = limits.max();
+        let child_limits = Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, self.padding.mul_add(-2.0, max.height)),
+        );
+
+        let mut nodes: Vec<Node> = self
+            .items()
+            .zip(tree.children.iter_mut())
+            .map(|(item, child_tree)| {
+                item.content
+                    .as_widget()
+                    .layout(child_tree, renderer, &child_limits)
+            })
+            .collect();
+
+        let widths: Vec<f32> = nodes.iter().map(|node| node.size().width).collect();
+        let count = nodes.len();
+        let available = self.padding.mul_add(-2.0, max.width);
+
+        // Drop trailing items until the rest and the overflow button fit.
+        let mut visible = count;
+        if self.content_width(&widths, count) > available {
+            while visible > 0
+                && self.content_width(&widths, visible) + self.spacing + OVERFLOW_WIDTH > available
+            {
+                visible -= 1;
+            }
+        }
+        let overflowing = visible < count;
+
+        let content_height = nodes
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0_f32, f32::max);
+        let height = self.padding.mul_add(2.0, content_height);
+
+        let flexible = self
+            .entries
+            .iter()
+            .filter(|entry| matches!(entry, Entry::FlexibleSpace))
+            .count();
+        let mut remaining = available - self.content_width(&widths, visible);
+        if overflowing {
+            remaining -= self.spacing + OVERFLOW_WIDTH;
+        }
+        let flex_width = if flexible > 0 {
+            remaining.max(0.0) / flexible as f32
+        } else {
+            0.0
+        };
+
+        let mut x = self.padding;
+        let mut index = 0;
+        let mut previous_group = false;
+        let mut placed_any = false;
+
+        for entry in &self.entries {
+            match entry {
+                Entry::FlexibleSpace => {
+                    x += flex_width;
+                    previous_group = false;
+                }
+                Entry::Group(items) => {
+                    let shown = items.len().min(visible.saturating_sub(index));
+
+                    if shown > 0 {
+                        if previous_group {
+                            x += self.divider_width();
+                        } else if placed_any {
+                            x += self.spacing;
+                        }
+
+                        for node in &mut nodes[index..index + shown] {
+                            let size = node.size();
+                            node.move_to_mut(Point::new(
+                                x,
+                                self.padding + (content_height - size.height) / 2.0,
+                            ));
+                            x += size.width + self.spacing;
+                        }
+                        x -= self.spacing;
+
+                        previous_group = true;
+                        placed_any = true;
+                    }
+
+                    index += items.len();
+                }
+            }
+        }
+
+        for node in &mut nodes[visible..] {
+            *node = Node::default();
+        }
+
+        let overflow_button = if overflowing {
+            Node::new(Size::new(OVERFLOW_WIDTH, content_height)).move_to(Point::new(
+                max.width - self.padding - OVERFLOW_WIDTH,
+                self.padding,
+            ))
+        } else {
+            Node::default()
+        };
+        nodes.push(overflow_button);
+
+        let state = tree.state.downcast_mut::<State>();
+        state.visible = visible;
+        if !overflowing {
+            state.menu.close();
+        }
+
+        Node::with_children(
+            limits.resolve(Length::Fill, Length::Shrink, Size::new(max.width, height)),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let visible = tree.state.downcast_ref::<State>().visible;
+
+        for ((item, child_tree), child_layout) in self
+            .items()
+            .zip(tree.children.iter_mut())
+            .zip(layout.children())
+            .take(visible)
+        {
+            item.content
+                .as_widget()
+                .operate(child_tree, child_layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let visible = state.visible;
+        let overflowing = visible < tree.children.len();
+
+        if let Some(button) = layout.children().nth(tree.children.len()) {
+            if event == Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) {
+                if state.menu.is_open {
+                    // Clicks inside the menu are captured by the overlay first.
+                    state.menu.close();
+                    return event::Status::Captured;
+                }
+
+                if overflowing && cursor.is_over(button.bounds()) {
+                    let count = self.overflow_entries(visible).len();
+                    state.menu.open(None, self.metrics, count);
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+
+        for ((item, child_tree), child_layout) in self
+            .items_mut()
+            .zip(tree.children.iter_mut())
+            .zip(layout.children())
+            .take(visible)
+        {
+            let child_status = item.content.as_widget_mut().on_event(
+                child_tree,
+                event.clone(),
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if child_status == event::Status::Captured {
+                status = event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let visible = tree.state.downcast_ref::<State>().visible;
+
+        if visible < tree.children.len() {
+            if let Some(button) = layout.children().nth(tree.children.len()) {
+                if cursor.is_over(button.bounds()) {
+                    return mouse::Interaction::Pointer;
+                }
+            }
+        }
+
+        self.items()
+            .zip(tree.children.iter())
+            .zip(layout.children())
+            .take(visible)
+            .map(|((item, child_tree), child_layout)| {
+                item.content.as_widget().mouse_interaction(
+                    child_tree,
+                    child_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default())
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let palette = theme.extended_palette();
+        let bounds = layout.bounds();
+        let children: Vec<Layout<'_>> = layout.children().collect();
+
+        for ((item, child_tree), child_layout) in self
+            .items()
+            .zip(tree.children.iter())
+            .zip(children.iter())
+            .take(state.visible)
+        {
+            item.content.as_widget().draw(
+                child_tree,
+                renderer,
+                theme,
+                style,
+                *child_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        // Dividers between adjacent visible groups.
+        let mut index = 0;
+        let mut previous_end: Option<usize> = None;
+
+        for entry in &self.entries {
+            match entry {
+                Entry::FlexibleSpace => previous_end = None,
+                Entry::Group(items) => {
+                    let shown = items.len().min(state.visible.saturating_sub(index));
+
+                    if shown > 0 {
+                        if let Some(end) = previous_end {
+                            let left = children[end].bounds();
+                            let right = children[index].bounds();
+                            let x = (left.x + left.width + right.x) / 2.0;
+
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: x.floor(),
+                                        y: bounds.y + self.padding,
+                                        width: 1.0,
+                                        height: self.padding.mul_add(-2.0, bounds.height),
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                palette.background.strong.color,
+                            );
+                        }
+
+                        previous_end = Some(index + shown - 1);
+                    }
+
+                    index += items.len();
+                }
+            }
+        }
+
+        if state.visible < tree.children.len() {
+            let button = children[tree.children.len()].bounds();
+
+            if state.menu.is_open || cursor.is_over(button) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: button,
+                        border: Border {
+                            radius: 4.0.into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    palette.background.weak.color,
+                );
+            }
+
+            renderer.fill_text(
+                text::Text {
+                    content: String::from("…"),
+                    bounds: button.size(),
+                    size: renderer.default_size(),
+                    line_height: text::LineHeight::default(),
+                    font: iced::Font::default(),
+                    horizontal_alignment: iced::alignment::Horizontal::Center,
+                    vertical_alignment: iced::alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                button.center(),
+                palette.background.base.text,
+                button,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let count = tree.children.len();
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.menu.is_open && state.visible < count {
+            self.overflow = self.overflow_entries(state.visible);
+
+            let button = layout.children().nth(count)?.bounds();
+            let width = 200.0_f32.max(button.width);
+            let anchor = Rectangle {
+                x: (button.x + button.width - width).max(0.0),
+                width,
+                ..button
+            };
+
+            return Some(overlay::Element::new(Box::new(ListOverlay {
+                state: &mut state.menu,
+                options: &self.overflow,
+                selected: None,
+                on_select: &overflow_message,
+                view_option: None,
+                anchor: anchor + translation,
+                metrics: self.metrics,
+                text_size: 14.0,
+                padding_x: 12.0,
+                status: ListStatus::Options,
+                footer: None,
+            })));
+        }
+
+        let visible = state.visible;
+        let children = self
+            .entries
+            .iter_mut()
+            .flat_map(|entry| match entry {
+                Entry::Group(items) => items.as_mut_slice(),
+                Entry::FlexibleSpace => &mut [],
+            })
+            .zip(tree.children.iter_mut())
+            .zip(layout.children())
+            .take(visible)
+            .filter_map(|((item, child_tree), child_layout)| {
+                item.content.as_widget_mut().overlay(
+                    child_tree,
+                    child_layout,
+                    renderer,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Toolbar<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(toolbar: Toolbar<'a, Message, Renderer>) -> Self {
+        Element::new(toolbar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::Space;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Action(usize),
+    }
+
+    fn item(index: usize) -> ToolbarItem<'static, Message, ()> {
+        ToolbarItem::new(
+            Space::new(Length::Fixed(40.0), Length::Fixed(24.0)),
+            format!("Action {index}"),
+        )
+        .on_press(Message::Action(index))
+    }
+
+    fn layout(toolbar: &Toolbar<'_, Message, ()>, width: f32) -> (Tree, Node) {
+        let mut tree = Tree::new(toolbar as &dyn Widget<Message, Theme, ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(width, 100.0));
+        let node = toolbar.layout(&mut tree, &(), &limits);
+        (tree, node)
+    }
+
+    #[test]
+    fn flexible_space_pushes_groups_right() {
+        let toolbar = Toolbar::new()
+            .padding(8.0)
+            .group([item(0)])
+            .flexible_space()
+            .group([item(1)]);
+        let (_, node) = layout(&toolbar, 500.0);
+
+        let children = node.children();
+        assert!((children[0].bounds().x - 8.0).abs() < f32::EPSILON);
+        assert!((children[1].bounds().x - (500.0 - 8.0 - 40.0)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn overflow_moves_items_into_menu() {
+        let toolbar = Toolbar::new()
+            .group([item(0), item(1), item(2)])
+            .group([item(3), item(4)]);
+
+        let (tree, _) = layout(&toolbar, 1000.0);
+        assert_eq!(tree.state.downcast_ref::<State>().visible, 5);
+
+        let (tree, node) = layout(&toolbar, 160.0);
+        let visible = tree.state.downcast_ref::<State>().visible;
+        assert!(visible < 5);
+
+        let overflow: Vec<Message> = toolbar
+            .overflow_entries(visible)
+            .into_iter()
+            .map(overflow_message)
+            .collect();
+        let expected: Vec<Message> = (visible..5).map(Message::Action).collect();
+        assert_eq!(overflow, expected);
+
+        // The overflow button sits at the right edge.
+        let button = node.children()[5].bounds();
+        assert!(button.width > 0.0);
+        assert!(button.x + button.width <= 160.0);
+    }
+}
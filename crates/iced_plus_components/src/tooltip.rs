@@ -1,7 +1,14 @@
 //! Tooltip component.
 
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{Tree, Widget};
 use iced::widget::tooltip;
-use iced::Element;
+use iced::{mouse, Border, Color, Element, Length, Point, Rectangle, Size, Theme, Vector};
+
+/// Height of the caret in whole pixels, from the tooltip edge to its tip.
+const ARROW_ROWS: u16 = 6;
+const ARROW_SIZE: f32 = ARROW_ROWS as f32;
 
 /// Tooltip position.
 #[derive(Debug, Clone, Copy, Default)]
@@ -31,6 +38,19 @@ impl From<TooltipPosition> for tooltip::Position {
     }
 }
 
+impl TooltipPosition {
+    /// The side of the tooltip facing its anchor, where the caret goes.
+    const fn caret_side(self) -> Option<CaretSide> {
+        match self {
+            Self::Top => Some(CaretSide::Bottom),
+            Self::Bottom => Some(CaretSide::Top),
+            Self::Left => Some(CaretSide::Right),
+            Self::Right => Some(CaretSide::Left),
+            Self::FollowCursor => None,
+        }
+    }
+}
+
 /// A tooltip wrapper that shows text on hover.
 ///
 /// # Example
@@ -38,12 +58,14 @@ impl From<TooltipPosition> for tooltip::Position {
 /// ```rust,ignore
 /// Tooltip::new(button, "Click to submit")
 ///     .position(TooltipPosition::Top)
+///     .arrow(true)
 /// ```
 pub struct Tooltip<'a, Message> {
     content: Element<'a, Message, iced::Theme>,
     tooltip_text: String,
     position: TooltipPosition,
     gap: f32,
+    arrow: bool,
 }
 
 impl<'a, Message> Tooltip<'a, Message> {
@@ -57,6 +79,7 @@ impl<'a, Message> Tooltip<'a, Message> {
             tooltip_text: tooltip_text.into(),
             position: TooltipPosition::default(),
             gap: 4.0,
+            arrow: false,
         }
     }
 
@@ -73,29 +96,274 @@ impl<'a, Message> Tooltip<'a, Message> {
         self.gap = gap;
         self
     }
+
+    /// Draw a caret on the side facing the anchor, pointing at its center.
+    ///
+    /// iced centers the tooltip on the anchor, so the caret sits at the
+    /// middle of the facing side. Cursor-following tooltips have no caret.
+    #[must_use]
+    pub const fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
 }
 
 impl<'a, Message: 'a> From<Tooltip<'a, Message>> for Element<'a, Message, iced::Theme> {
     fn from(t: Tooltip<'a, Message>) -> Self {
-        let tooltip_text = t.tooltip_text;
+        let label = iced::widget::text(t.tooltip_text).size(12);
+        let caret = t.position.caret_side().filter(|_| t.arrow);
+
+        let Some(caret) = caret else {
+            return tooltip(t.content, label, t.position.into())
+                .gap(t.gap)
+                .style(bubble_style)
+                .into();
+        };
+
+        // The bubble paints its own background so the caret shares its color.
         tooltip(
             t.content,
-            iced::widget::text(tooltip_text).size(12),
+            Element::new(Bubble {
+                content: label.into(),
+                caret,
+            }),
             t.position.into(),
         )
         .gap(t.gap)
-        .style(|theme: &iced::Theme| {
-            let palette = theme.extended_palette();
-            iced::widget::container::Style {
-                background: Some(iced::Background::Color(palette.background.strong.color)),
-                border: iced::Border {
-                    radius: 4.0.into(),
-                    ..Default::default()
-                },
-                text_color: Some(palette.background.strong.text),
-                ..Default::default()
+        .into()
+    }
+}
+
+/// Style of a tooltip bubble without a caret.
+pub(crate) fn bubble_style(theme: &Theme) -> iced::widget::container::Style {
+    let (background, text_color) = bubble_colors(theme);
+    iced::widget::container::Style {
+        background: Some(iced::Background::Color(background)),
+        border: iced::Border {
+            radius: 4.0.into(),
+            ..Default::default()
+        },
+        text_color: Some(text_color),
+        ..Default::default()
+    }
+}
+
+/// Tooltip background and text colors.
+fn bubble_colors(theme: &Theme) -> (Color, Color) {
+    let palette = theme.extended_palette();
+    (
+        palette.background.strong.color,
+        palette.background.strong.text,
+    )
+}
+
+/// Side of the tooltip bubble carrying the caret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaretSide {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl CaretSide {
+    /// Offset of the box within the bubble, making room for the caret.
+    const fn box_offset(self) -> Vector {
+        match self {
+            Self::Top => Vector::new(0.0, ARROW_SIZE),
+            Self::Left => Vector::new(ARROW_SIZE, 0.0),
+            Self::Bottom | Self::Right => Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Extra size the caret adds to the box.
+    const fn extra(self) -> Size {
+        match self {
+            Self::Top | Self::Bottom => Size::new(0.0, ARROW_SIZE),
+            Self::Left | Self::Right => Size::new(ARROW_SIZE, 0.0),
+        }
+    }
+
+    /// The caret's tip for a box with these bounds.
+    fn tip(self, bounds: Rectangle) -> Point {
+        let center = bounds.center();
+
+        match self {
+            Self::Top => Point::new(center.x, bounds.y - ARROW_SIZE),
+            Self::Bottom => Point::new(center.x, bounds.y + bounds.height + ARROW_SIZE),
+            Self::Left => Point::new(bounds.x - ARROW_SIZE, center.y),
+            Self::Right => Point::new(bounds.x + bounds.width + ARROW_SIZE, center.y),
+        }
+    }
+
+    /// The caret as 1px strips narrowing towards the tip; quads cannot be
+    /// rotated, so the triangle is built from them.
+    fn strips(self, bounds: Rectangle) -> impl Iterator<Item = Rectangle> {
+        let tip = self.tip(bounds);
+
+        (0..ARROW_ROWS).map(move |row| {
+            // Half-width of the strip `row` pixels in from the tip.
+            let near_tip = f32::from(row);
+            let half = near_tip + 1.0;
+
+            match self {
+                Self::Top => Rectangle::new(
+                    Point::new(tip.x - half, tip.y + near_tip),
+                    Size::new(half * 2.0, 1.0),
+                ),
+                Self::Bottom => Rectangle::new(
+                    Point::new(tip.x - half, tip.y - near_tip - 1.0),
+                    Size::new(half * 2.0, 1.0),
+                ),
+                Self::Left => Rectangle::new(
+                    Point::new(tip.x + near_tip, tip.y - half),
+                    Size::new(1.0, half * 2.0),
+                ),
+                Self::Right => Rectangle::new(
+                    Point::new(tip.x - near_tip - 1.0, tip.y - half),
+                    Size::new(1.0, half * 2.0),
+                ),
             }
         })
-        .into()
+    }
+}
+
+/// Tooltip content on a rounded box with a caret on one side.
+struct Bubble<'a, Message, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    caret: CaretSide,
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Bubble<'_, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let extra = self.caret.extra();
+        let content =
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &limits.shrink(extra));
+        let size = content.size();
+
+        Node::with_children(
+            Size::new(size.width + extra.width, size.height + extra.height),
+            vec![content.translate(self.caret.box_offset())],
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let (background, text_color) = bubble_colors(theme);
+        let Some(content) = layout.children().next() else {
+            return;
+        };
+        let box_bounds = content.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: box_bounds,
+                border: Border::default().rounded(4.0),
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+
+        for strip in self.caret.strips(box_bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: strip,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style { text_color },
+            content,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Recorder;
+    use iced::widget::Space;
+
+    #[test]
+    fn top_tooltip_caret_points_down_at_anchor_center() {
+        let anchor = Rectangle::new(Point::new(100.0, 200.0), Size::new(80.0, 30.0));
+        let bubble: Bubble<'_, (), Recorder> = Bubble {
+            content: Space::new(60.0, 20.0).into(),
+            caret: TooltipPosition::Top.caret_side().expect("caret side"),
+        };
+
+        let mut tree = Tree::new(&bubble as &dyn Widget<(), Theme, Recorder>);
+        let mut recorder = Recorder::default();
+        let node = bubble.layout(
+            &mut tree,
+            &recorder,
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+        assert_eq!(node.size(), Size::new(60.0, 26.0));
+
+        // Placed above the anchor and centered on it, as iced does.
+        let size = node.size();
+        let node = node.move_to(Point::new(
+            anchor.center_x() - size.width / 2.0,
+            anchor.y - size.height,
+        ));
+        bubble.draw(
+            &tree,
+            &mut recorder,
+            &Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(400.0, 400.0)),
+        );
+
+        let quads = recorder.quad_bounds();
+        let (body, caret) = quads.split_first().expect("bubble quads");
+        assert_eq!(caret.len(), usize::from(ARROW_ROWS));
+
+        for strip in caret {
+            assert!(strip.y >= body.y + body.height);
+            assert!(strip.y + strip.height <= anchor.y);
+            assert!((strip.center_x() - anchor.center_x()).abs() < f32::EPSILON);
+        }
+
+        // The widest strip sits against the box, the narrowest at the tip.
+        assert!(caret[0].width < caret[caret.len() - 1].width);
+        assert!((caret[0].y + 1.0 - anchor.y).abs() < f32::EPSILON);
     }
 }
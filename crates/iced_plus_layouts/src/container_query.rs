@@ -0,0 +1,337 @@
+//! Container queries: content built for the breakpoint tier of its own width.
+
+use std::cell::RefCell;
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::{event, mouse, Element, Event, Length, Rectangle, Size, Vector};
+
+use crate::responsive::{app_breakpoints, BreakpointTier, Breakpoints};
+
+/// Content rebuilt for the breakpoint tier of the width it is given.
+///
+/// Like iced's `responsive`, but the view function receives a
+/// [`BreakpointTier`] instead of a raw size, and it only runs again when the
+/// tier changes or the application view is rebuilt. The tier comes from the
+/// container's own width, not the window's, so the same component adapts in
+/// a sidebar and in the main area.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ResponsiveContainer::new(|tier| {
+///     if tier >= BreakpointTier::MD {
+///         HStack::new().spacing(16.0).push(filters()).push(results()).into()
+///     } else {
+///         VStack::new().spacing(8.0).push(filters()).push(results()).into()
+///     }
+/// })
+/// ```
+pub struct ResponsiveContainer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    view: Box<dyn Fn(BreakpointTier) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    breakpoints: Breakpoints,
+    content: RefCell<Option<Built<'a, Message, Theme, Renderer>>>,
+}
+
+/// The content built so far, and the tier it was built for.
+type Built<'a, Message, Theme, Renderer> = (BreakpointTier, Element<'a, Message, Theme, Renderer>);
+
+impl<'a, Message, Theme, Renderer> ResponsiveContainer<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Create a container building its content with `view`.
+    pub fn new(
+        view: impl Fn(BreakpointTier) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            view: Box::new(view),
+            breakpoints: app_breakpoints(),
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Use custom breakpoints instead of the app-wide ones.
+    #[must_use]
+    pub const fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// The tier the content is built for at `width`.
+    #[must_use]
+    pub fn tier_for(&self, width: f32) -> BreakpointTier {
+        self.breakpoints.tier(width)
+    }
+
+    /// Build the content for `tier`, unless it already is, and sync the
+    /// child tree with it.
+    fn rebuild(&self, tree: &mut Tree, tier: BreakpointTier) {
+        let mut content = self.content.borrow_mut();
+        if matches!(&*content, Some((built, _)) if *built == tier) {
+            return;
+        }
+
+        let element = (self.view)(tier);
+        match tree.children.first_mut() {
+            Some(child) => child.diff(&element),
+            None => tree.children.push(Tree::new(&element)),
+        }
+        *content = Some((tier, element));
+    }
+}
+
+/// The tier the content was last laid out for; `None` before the first
+/// layout.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    tier: Option<BreakpointTier>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ResponsiveContainer<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        let height = self
+            .content
+            .borrow()
+            .as_ref()
+            .map_or(Length::Shrink, |(_, element)| {
+                element.as_widget().size().height
+            });
+
+        Size::new(Length::Fill, height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // Nothing is built until the first layout knows the width.
+        Vec::new()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if let Some(tier) = tree.state.downcast_ref::<State>().tier {
+            self.rebuild(tree, tier);
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let tier = self.tier_for(limits.max().width);
+        tree.state.downcast_mut::<State>().tier = Some(tier);
+        self.rebuild(tree, tier);
+
+        let content = self.content.borrow();
+        let (_, element) = content.as_ref().expect("content built for the tier");
+        let node = element
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(node.size(), vec![node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let (Some((_, element)), Some(child)) =
+            (&*self.content.borrow(), layout.children().next())
+        {
+            element
+                .as_widget()
+                .operate(&mut tree.children[0], child, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        match (self.content.get_mut(), layout.children().next()) {
+            (Some((_, element)), Some(child)) => element.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                child,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (&*self.content.borrow(), layout.children().next()) {
+            (Some((_, element)), Some(child)) => element.as_widget().mouse_interaction(
+                &tree.children[0],
+                child,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let (Some((_, element)), Some(child)) =
+            (&*self.content.borrow(), layout.children().next())
+        {
+            element.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                child,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (_, element) = self.content.get_mut().as_mut()?;
+
+        element.as_widget_mut().overlay(
+            tree.children.first_mut()?,
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ResponsiveContainer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(container: ResponsiveContainer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(container)
+    }
+}
+
+/// Convenience function to create a responsive container.
+pub fn responsive_container<'a, Message, Theme, Renderer>(
+    view: impl Fn(BreakpointTier) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> ResponsiveContainer<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    ResponsiveContainer::new(view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::Space;
+
+    /// A container whose content grows 10px wider with each tier.
+    fn sized_by_tier() -> ResponsiveContainer<'static, (), (), ()> {
+        ResponsiveContainer::new(|tier| {
+            let width = match tier {
+                BreakpointTier::XS => 10.0,
+                BreakpointTier::SM => 20.0,
+                BreakpointTier::MD => 30.0,
+                BreakpointTier::LG => 40.0,
+                BreakpointTier::XL => 50.0,
+            };
+
+            Space::new(Length::Fixed(width), Length::Fixed(10.0)).into()
+        })
+        .breakpoints(Breakpoints::STANDARD)
+    }
+
+    fn layout(container: &ResponsiveContainer<'_, (), (), ()>, tree: &mut Tree, width: f32) -> f32 {
+        let node = container.layout(tree, &(), &Limits::new(Size::ZERO, Size::new(width, 600.0)));
+
+        node.size().width
+    }
+
+    #[test]
+    fn tier_comes_from_the_allocated_width() {
+        let container = sized_by_tier();
+
+        assert_eq!(container.tier_for(320.0), BreakpointTier::XS);
+        assert_eq!(container.tier_for(767.0), BreakpointTier::SM);
+        assert_eq!(container.tier_for(768.0), BreakpointTier::MD);
+        assert_eq!(container.tier_for(1400.0), BreakpointTier::XL);
+        assert_eq!(
+            container.breakpoints(Breakpoints::COMPACT).tier_for(900.0),
+            BreakpointTier::LG
+        );
+    }
+
+    #[test]
+    fn content_is_rebuilt_when_the_tier_changes() {
+        let container = sized_by_tier();
+        let mut tree = Tree::new(&container as &dyn Widget<(), (), ()>);
+        assert!(tree.children.is_empty());
+
+        assert!((layout(&container, &mut tree, 900.0) - 30.0).abs() < f32::EPSILON);
+        assert_eq!(
+            tree.state.downcast_ref::<State>().tier,
+            Some(BreakpointTier::MD)
+        );
+
+        assert!((layout(&container, &mut tree, 400.0) - 10.0).abs() < f32::EPSILON);
+        assert_eq!(
+            tree.state.downcast_ref::<State>().tier,
+            Some(BreakpointTier::XS)
+        );
+
+        // A new view picks up the stored tier before its first layout.
+        let rebuilt = sized_by_tier();
+        Widget::<(), (), ()>::diff(&rebuilt, &mut tree);
+        assert!(matches!(
+            &*rebuilt.content.borrow(),
+            Some((BreakpointTier::XS, _))
+        ));
+    }
+}
@@ -9,8 +9,14 @@
 //! - [`VStack`] - Vertical stack layout (top to bottom)
 //! - [`AppShell`] - Application shell with sidebar and header areas
 //! - [`Modal`] - Modal overlay with backdrop
+//! - [`layered`] - Stack overlays in named [`Layer`](iced_plus_tokens::Layer) order
 //! - [`Breakpoints`] - Responsive breakpoint definitions
 //! - [`ShowOn`] - Show content only at certain breakpoints
+//! - [`ResponsivePadding`] - Padding that changes with the breakpoint tier
+//! - [`ResponsiveContainer`] - Content rebuilt for the tier of its own width
+//! - [`Masonry`] - Gallery that packs items into the shortest column
+//! - `testing::measure` - Layout snapshots for regression tests (`testing`
+//!   feature)
 //!
 //! # Type Safety
 //!
@@ -49,7 +55,11 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 
+#[cfg(feature = "responsive")]
+mod container_query;
 mod direction;
+#[cfg(feature = "responsive")]
+mod masonry;
 #[cfg(feature = "overlay")]
 mod overlay;
 mod private;
@@ -59,16 +69,26 @@ mod responsive;
 mod shell;
 #[cfg(feature = "stacks")]
 mod stack;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
+#[cfg(feature = "responsive")]
+pub use container_query::{responsive_container, ResponsiveContainer};
 pub use direction::{Direction, Horizontal, Vertical};
+#[cfg(feature = "responsive")]
+pub use masonry::Masonry;
 #[cfg(feature = "overlay")]
-pub use overlay::{drawer, drawer_left, drawer_right, modal, modal_with_opacity, Modal};
+pub use overlay::{
+    close_task, drawer, drawer_left, drawer_right, drawer_with_opacity, drawer_with_safe_area,
+    layered, modal, modal_with_opacity, Insets, Modal,
+};
 #[cfg(feature = "responsive")]
 pub use responsive::{
-    hide_on, responsive_row, show_on, BreakpointTier, Breakpoints, Responsive, ResponsiveRow,
+    app_breakpoints, hide_on, responsive_padding, responsive_row, set_app_breakpoints, show_on,
+    with_breakpoints, BreakpointTier, Breakpoints, Responsive, ResponsivePadding, ResponsiveRow,
     ShowOn,
 };
 #[cfg(feature = "shell")]
-pub use shell::AppShell;
+pub use shell::{AppShell, SidebarPosition};
 #[cfg(feature = "stacks")]
-pub use stack::{HStack, Stack, VStack};
+pub use stack::{CrossAxis, HStack, Overflow, Stack, VStack};
@@ -0,0 +1,403 @@
+//! Masonry layout for galleries of items with varying heights.
+
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, Clipboard, Shell};
+use iced::{event, mouse, Element, Event, Length, Point, Rectangle, Size, Vector};
+
+use crate::responsive::{app_breakpoints, Breakpoints, Responsive};
+
+/// A Pinterest-style gallery that packs items into columns.
+///
+/// Unlike a uniform grid there are no rows: each item goes into the column
+/// that is currently shortest, so items of different heights interlock.
+/// Every column has the same width, and the column count follows the
+/// breakpoint tier of the available width.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Masonry::new()
+///     .columns(Responsive::new(1).sm(2).lg(4))
+///     .spacing(12.0)
+///     .extend(photos.iter().map(photo_card))
+/// ```
+pub struct Masonry<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    columns: Responsive<usize>,
+    spacing: f32,
+    breakpoints: Breakpoints,
+}
+
+impl<'a, Message, Theme, Renderer> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Create an empty masonry layout with 1, 2, 3 and 4 columns from the
+    /// XS, SM, MD and LG tiers up.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            columns: Responsive::new(1).sm(2).md(3).lg(4),
+            spacing: 8.0,
+            breakpoints: app_breakpoints(),
+        }
+    }
+
+    /// Add an item.
+    #[must_use]
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Add several items.
+    #[must_use]
+    pub fn extend(
+        mut self,
+        children: impl IntoIterator<Item = impl Into<Element<'a, Message, Theme, Renderer>>>,
+    ) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the column count per breakpoint. A count of zero is treated as one.
+    #[must_use]
+    pub const fn columns(mut self, columns: Responsive<usize>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the gap between columns and between items in a column.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Use custom breakpoints instead of the app-wide ones.
+    #[must_use]
+    pub const fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Number of columns at `width`.
+    #[must_use]
+    pub fn column_count(&self, width: f32) -> usize {
+        (*self.columns.get(self.breakpoints.tier(width))).max(1)
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Masonry<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Index of the shortest column, preferring the leftmost on ties.
+fn shortest(heights: &[f32]) -> usize {
+    heights.iter().enumerate().fold(
+        0,
+        |best, (index, &height)| {
+            if height < heights[best] {
+                index
+            } else {
+                best
+            }
+        },
+    )
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Masonry<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::None
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let width = limits.max().width;
+        let columns = self.column_count(width);
+        let column_width =
+            (self.spacing.mul_add(-((columns - 1) as f32), width) / columns as f32).max(0.0);
+        let child_limits = Limits::new(
+            Size::new(column_width, 0.0),
+            Size::new(column_width, f32::INFINITY),
+        );
+
+        // Running height of each column, including the gap below its last item.
+        let mut heights = vec![0.0_f32; columns];
+
+        let nodes = self
+            .children
+            .iter()
+            .zip(tree.children.iter_mut())
+            .map(|(child, child_tree)| {
+                let column = shortest(&heights);
+                let node = child
+                    .as_widget()
+                    .layout(child_tree, renderer, &child_limits)
+                    .move_to(Point::new(
+                        column as f32 * (column_width + self.spacing),
+                        heights[column],
+                    ));
+
+                heights[column] += node.size().height + self.spacing;
+                node
+            })
+            .collect::<Vec<_>>();
+
+        let height = if nodes.is_empty() {
+            0.0
+        } else {
+            heights.iter().copied().fold(0.0, f32::max) - self.spacing
+        };
+
+        Node::with_children(Size::new(width, height.max(0.0)), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        for ((child, child_tree), child_layout) in self
+            .children
+            .iter()
+            .zip(tree.children.iter_mut())
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .operate(child_tree, child_layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        for ((child, child_tree), child_layout) in self
+            .children
+            .iter_mut()
+            .zip(tree.children.iter_mut())
+            .zip(layout.children())
+        {
+            let status = child.as_widget_mut().on_event(
+                child_tree,
+                event.clone(),
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(tree.children.iter())
+            .zip(layout.children())
+            .map(|((child, child_tree), child_layout)| {
+                child.as_widget().mouse_interaction(
+                    child_tree,
+                    child_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default())
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, child_tree), child_layout) in self
+            .children
+            .iter()
+            .zip(tree.children.iter())
+            .zip(layout.children())
+        {
+            if child_layout.bounds().intersects(viewport) {
+                child.as_widget().draw(
+                    child_tree,
+                    renderer,
+                    theme,
+                    style,
+                    child_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Masonry<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(masonry: Masonry<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(masonry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::Space;
+
+    /// Lay out items of the given heights at `width` and return the node.
+    fn arrange(heights: &[f32], width: f32) -> Node {
+        let masonry: Masonry<'_, (), (), ()> = Masonry::new()
+            .columns(Responsive::new(1).md(3))
+            .spacing(10.0)
+            .extend(
+                heights
+                    .iter()
+                    .map(|&height| Space::new(Length::Fill, Length::Fixed(height))),
+            );
+
+        let mut tree = Tree::new(&masonry as &dyn Widget<(), (), ()>);
+        masonry.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(width, 10_000.0)),
+        )
+    }
+
+    #[test]
+    fn each_item_goes_into_the_shortest_column() {
+        // Three 300px columns with 10px gaps at x = 0, 310 and 620.
+        let node = arrange(&[100.0, 300.0, 50.0, 80.0, 120.0, 40.0], 920.0);
+        let positions: Vec<_> = node
+            .children()
+            .iter()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (0.0, 0.0),
+                (310.0, 0.0),
+                (620.0, 0.0),
+                // Column 3 (60) is shortest, then column 1 (110), then 3 again (150).
+                (620.0, 60.0),
+                (0.0, 110.0),
+                (620.0, 150.0),
+            ]
+        );
+        assert!(node
+            .children()
+            .iter()
+            .all(|child| (child.bounds().width - 300.0).abs() < f32::EPSILON));
+    }
+
+    #[test]
+    fn column_heights_stay_balanced() {
+        let heights = [120.0, 80.0, 200.0, 60.0, 90.0, 150.0, 70.0, 110.0, 40.0];
+        let node = arrange(&heights, 920.0);
+
+        let mut columns = [0.0_f32; 3];
+        for child in node.children() {
+            let bounds = child.bounds();
+            let column = [0.0, 310.0, 620.0]
+                .iter()
+                .position(|x| (x - bounds.x).abs() < f32::EPSILON)
+                .expect("item aligned to a column");
+            columns[column] = columns[column].max(bounds.y + bounds.height);
+        }
+
+        let tallest = columns.iter().copied().fold(0.0, f32::max);
+        let shortest = columns.iter().copied().fold(f32::INFINITY, f32::min);
+
+        // Greedy packing keeps the spread within the tallest single item.
+        assert!(tallest - shortest <= 200.0);
+        assert!((node.size().height - tallest).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn column_count_follows_the_breakpoint() {
+        let narrow = arrange(&[10.0, 20.0], 400.0);
+        assert!(narrow
+            .children()
+            .iter()
+            .all(|child| child.bounds().x.abs() < f32::EPSILON));
+        assert!((narrow.size().height - 40.0).abs() < f32::EPSILON);
+    }
+}
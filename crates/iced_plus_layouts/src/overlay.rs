@@ -1,12 +1,66 @@
 //! Overlay layouts for modals, dialogs, and floating elements.
 
-use iced::widget::{center, container, mouse_area, opaque, stack};
-use iced::{Color, Element, Length};
+use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::widget::operation::focusable;
+use iced::advanced::widget::{self, operate, tree, Operation, Tree, Widget};
+use iced::advanced::{overlay, renderer, Clipboard, Shell};
+use iced::keyboard::{self, key};
+use iced::time::Instant;
+use iced::widget::{center, container, mouse_area, opaque};
+use iced::{
+    event, mouse, window, Color, Element, Event, Length, Padding, Rectangle, Size, Task, Vector,
+};
+use iced_plus_tokens::{reduce_motion, Easing, Layer, Motion};
+
+/// Safe-area insets: regions at the window edges overlays keep clear of,
+/// such as a custom title bar or a notch.
+pub type Insets = Padding;
+
+/// Stacks `layers` over `base` in [`Layer`] order.
+///
+/// The layers passed to one call are drawn by z-index, not by the order
+/// they are given, and layers with the same [`Layer`] keep their relative
+/// order. The topmost layer receives events first.
+///
+/// Ordering only applies among the layers of the same call: everything in
+/// `base` stays below them, whatever its own layers. [`modal`],
+/// [`Modal`] and the toast and drawer helpers of `iced_plus_components`
+/// each call `layered` with their one layer, so nest them from the bottom
+/// layer out, e.g. the toast stack around the modal to keep toasts above
+/// the backdrop.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use iced_plus_layouts::layered;
+/// use iced_plus_tokens::Layer;
+///
+/// layered(page, [(Layer::Toast, toasts), (Layer::Modal, dialog)])
+/// ```
+pub fn layered<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    layers: impl IntoIterator<Item = (Layer, Element<'a, Message, Theme, Renderer>)>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    let mut layers: Vec<_> = layers.into_iter().collect();
+    layers.sort_by_key(|(layer, _)| layer.z_index());
+
+    iced::widget::Stack::with_children(
+        std::iter::once(base.into()).chain(layers.into_iter().map(|(_, element)| element)),
+    )
+    .into()
+}
 
 /// Creates a modal overlay that displays content over a backdrop.
 ///
-/// Uses iced's built-in `stack`, `opaque`, `mouse_area`, and `center` widgets
-/// for proper overlay rendering with a semi-transparent backdrop.
+/// Uses iced's built-in `opaque`, `mouse_area`, and `center` widgets for a
+/// semi-transparent backdrop, [`layered`] on the [`Layer::Modal`] layer.
+/// Overlays nested in `base` are covered by the backdrop; wrap the modal in
+/// them instead to keep them on top.
 ///
 /// # Example
 ///
@@ -50,25 +104,23 @@ where
 {
     let opacity = opacity.clamp(0.0, 1.0);
 
-    stack![
-        base.into(),
-        opaque(
-            mouse_area(center(opaque(content)).style(move |_theme| {
-                container::Style {
-                    background: Some(
-                        Color {
-                            a: opacity,
-                            ..Color::BLACK
-                        }
-                        .into(),
-                    ),
-                    ..container::Style::default()
-                }
-            }))
-            .on_press(on_blur)
-        )
-    ]
-    .into()
+    let overlay = opaque(
+        mouse_area(center(opaque(content)).style(move |_theme| {
+            container::Style {
+                background: Some(
+                    Color {
+                        a: opacity,
+                        ..Color::BLACK
+                    }
+                    .into(),
+                ),
+                ..container::Style::default()
+            }
+        }))
+        .on_press(on_blur),
+    );
+
+    layered(base, [(Layer::Modal, overlay)])
 }
 
 /// Modal builder for more control over the overlay.
@@ -81,11 +133,23 @@ where
 ///     .on_backdrop_press(Message::CloseModal)
 ///     .into()
 /// ```
+///
+/// To hand keyboard focus back to the control that opened the modal, set
+/// [`restore_focus_to`](Self::restore_focus_to) and return
+/// [`close_task`](Self::close_task) when handling the close message.
+///
+/// The backdrop fades in when the modal appears, over the duration and
+/// [`easing`](Self::easing) of its [`motion`](Self::motion).
 pub struct Modal<'a, Message> {
     base: Element<'a, Message>,
     content: Element<'a, Message>,
     backdrop_opacity: f32,
     on_backdrop_press: Option<Message>,
+    on_escape: Option<Message>,
+    safe_area: Insets,
+    motion: Motion,
+    reduce_motion: bool,
+    restore_focus: Option<widget::Id>,
 }
 
 impl<'a, Message> Modal<'a, Message>
@@ -102,6 +166,11 @@ where
             content: content.into(),
             backdrop_opacity: 0.8,
             on_backdrop_press: None,
+            on_escape: None,
+            safe_area: Insets::ZERO,
+            motion: Motion::default(),
+            reduce_motion: reduce_motion(),
+            restore_focus: None,
         }
     }
 
@@ -118,6 +187,94 @@ where
         self.on_backdrop_press = Some(message);
         self
     }
+
+    /// Set the message to emit when Escape is pressed.
+    ///
+    /// Only the topmost modal reacts, and not while its content handles the
+    /// key itself or has a dropdown or other overlay open, so Escape closes
+    /// one thing at a time.
+    #[must_use]
+    pub fn on_escape(mut self, message: Message) -> Self {
+        self.on_escape = Some(message);
+        self
+    }
+
+    /// Center the content within the window minus these insets.
+    #[must_use]
+    pub fn safe_area(mut self, insets: impl Into<Insets>) -> Self {
+        self.safe_area = insets.into();
+        self
+    }
+
+    /// Set the duration and easing of the backdrop fade-in.
+    ///
+    /// Use [`Motion::NONE`] to show the modal without animating. It also
+    /// appears instantly if built while [`reduce_motion`] is on.
+    #[must_use]
+    pub const fn motion(mut self, motion: Motion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Set the easing curve of the backdrop fade-in.
+    ///
+    /// Defaults to [`Easing::STANDARD`], the motion tokens' standard curve.
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.motion.easing = easing;
+        self
+    }
+
+    /// Return focus to the widget with this id when the modal closes.
+    #[must_use]
+    pub fn restore_focus_to(mut self, id: impl Into<widget::Id>) -> Self {
+        self.restore_focus = Some(id.into());
+        self
+    }
+
+    /// The task to run once the modal has closed.
+    ///
+    /// Focuses the [`restore_focus_to`](Self::restore_focus_to) target, as
+    /// [`close_task`] does, or does nothing if none was set.
+    pub fn close_task<T>(&self) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        self.restore_focus_operation()
+            .map_or_else(Task::none, operate)
+    }
+
+    fn restore_focus_operation<T: 'static>(&self) -> Option<Box<dyn Operation<T>>> {
+        let target = self.restore_focus.clone()?;
+
+        Some(Box::new(focus_operation(target)))
+    }
+}
+
+/// The task handing keyboard focus back to `opener` once a modal or drawer
+/// has closed.
+///
+/// Return it from `update` when handling the close message, with the id of
+/// the control that opened the overlay, so keyboard users keep their place:
+///
+/// ```rust,ignore
+/// Message::CloseSettings => {
+///     self.show_settings = false;
+///     close_task(SETTINGS_BUTTON.clone())
+/// }
+/// ```
+///
+/// [`Modal::close_task`] returns the same task for the id given to
+/// [`Modal::restore_focus_to`].
+pub fn close_task<T>(opener: impl Into<widget::Id>) -> Task<T>
+where
+    T: Send + 'static,
+{
+    operate(focus_operation(opener.into()))
+}
+
+fn focus_operation<T>(target: widget::Id) -> impl Operation<T> {
+    focusable::focus(target)
 }
 
 impl<'a, Message> From<Modal<'a, Message>> for Element<'a, Message>
@@ -125,21 +282,15 @@ where
     Message: Clone + 'a,
 {
     fn from(modal: Modal<'a, Message>) -> Self {
-        let opacity = modal.backdrop_opacity;
-
         // Create the backdrop with centered content
-        let backdrop_content: Element<'a, Message> = center(opaque(modal.content))
-            .style(move |_theme| container::Style {
-                background: Some(
-                    Color {
-                        a: opacity,
-                        ..Color::BLACK
-                    }
-                    .into(),
-                ),
-                ..container::Style::default()
-            })
-            .into();
+        let backdrop_content: Element<'a, Message> = Element::new(FadingBackdrop {
+            content: center(opaque(modal.content))
+                .padding(modal.safe_area)
+                .into(),
+            opacity: modal.backdrop_opacity,
+            motion: modal.motion.reduced(modal.reduce_motion),
+            on_escape: modal.on_escape,
+        });
 
         // Wrap with mouse_area if we have a blur handler
         let overlay: Element<'a, Message> = if let Some(on_blur) = modal.on_backdrop_press {
@@ -148,7 +299,7 @@ where
             opaque(backdrop_content).into()
         };
 
-        stack![modal.base, overlay].into()
+        layered(modal.base, [(Layer::Modal, overlay)])
     }
 }
 
@@ -176,6 +327,54 @@ where
     drawer_left(base, content, on_close, 280.0)
 }
 
+/// Creates a left drawer overlay with custom scrim opacity.
+///
+/// Clicking anywhere on the dimmed backdrop emits `on_close`; clicks inside
+/// the drawer panel go to its content.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use iced_plus_layouts::drawer_with_opacity;
+///
+/// drawer_with_opacity(base, drawer_content, Message::CloseDrawer, 0.3)
+/// ```
+pub fn drawer_with_opacity<'a, Message>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+    on_close: Message,
+    opacity: f32,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    side_drawer(base, content, on_close, 280.0, true, opacity, Insets::ZERO)
+}
+
+/// Creates a left drawer overlay kept clear of the safe-area `insets`.
+///
+/// The drawer and its backdrop are inset, so a custom title bar along the
+/// top edge stays visible and interactive.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use iced_plus_layouts::drawer_with_safe_area;
+///
+/// drawer_with_safe_area(base, drawer_content, Message::CloseDrawer, Insets::ZERO.top(32))
+/// ```
+pub fn drawer_with_safe_area<'a, Message>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+    on_close: Message,
+    insets: impl Into<Insets>,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    side_drawer(base, content, on_close, 280.0, true, 0.5, insets.into())
+}
+
 /// Creates a drawer overlay from the left with custom width.
 pub fn drawer_left<'a, Message>(
     base: impl Into<Element<'a, Message>>,
@@ -186,43 +385,7 @@ pub fn drawer_left<'a, Message>(
 where
     Message: Clone + 'a,
 {
-    use iced::widget::row;
-
-    let drawer_content: Element<'a, Message> = container(content)
-        .width(Length::Fixed(width))
-        .height(Length::Fill)
-        .style(|theme: &iced::Theme| {
-            let palette = theme.extended_palette();
-            container::Style {
-                background: Some(palette.background.base.color.into()),
-                ..container::Style::default()
-            }
-        })
-        .into();
-
-    let backdrop: Element<'a, Message> = mouse_area(
-        container(iced::widget::Space::new(Length::Fill, Length::Fill)).style(|_theme| {
-            container::Style {
-                background: Some(
-                    Color {
-                        a: 0.5,
-                        ..Color::BLACK
-                    }
-                    .into(),
-                ),
-                ..container::Style::default()
-            }
-        }),
-    )
-    .on_press(on_close)
-    .into();
-
-    let overlay: Element<'a, Message> = row![opaque(drawer_content), backdrop]
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .into();
-
-    stack![base.into(), overlay].into()
+    side_drawer(base, content, on_close, width, true, 0.5, Insets::ZERO)
 }
 
 /// Creates a drawer overlay from the right with custom width.
@@ -232,11 +395,28 @@ pub fn drawer_right<'a, Message>(
     on_close: Message,
     width: f32,
 ) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    side_drawer(base, content, on_close, width, false, 0.5, Insets::ZERO)
+}
+
+fn side_drawer<'a, Message>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+    on_close: Message,
+    width: f32,
+    from_left: bool,
+    opacity: f32,
+    insets: Insets,
+) -> Element<'a, Message>
 where
     Message: Clone + 'a,
 {
     use iced::widget::row;
 
+    let opacity = opacity.clamp(0.0, 1.0);
+
     let drawer_content: Element<'a, Message> = container(content)
         .width(Length::Fixed(width))
         .height(Length::Fill)
@@ -250,11 +430,11 @@ where
         .into();
 
     let backdrop: Element<'a, Message> = mouse_area(
-        container(iced::widget::Space::new(Length::Fill, Length::Fill)).style(|_theme| {
+        container(iced::widget::Space::new(Length::Fill, Length::Fill)).style(move |_theme| {
             container::Style {
                 background: Some(
                     Color {
-                        a: 0.5,
+                        a: opacity,
                         ..Color::BLACK
                     }
                     .into(),
@@ -266,10 +446,395 @@ where
     .on_press(on_close)
     .into();
 
-    let overlay: Element<'a, Message> = row![backdrop, opaque(drawer_content)]
-        .width(Length::Fill)
-        .height(Length::Fill)
+    let overlay: Element<'a, Message> = if from_left {
+        row![opaque(drawer_content), backdrop]
+    } else {
+        row![backdrop, opaque(drawer_content)]
+    }
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into();
+
+    layered(
+        base,
+        [(Layer::Overlay, container(overlay).padding(insets).into())],
+    )
+}
+
+/// Progress through the fade-in since the overlay appeared.
+#[derive(Debug, Default)]
+struct Fade {
+    started: Option<Instant>,
+    progress: f32,
+}
+
+impl Fade {
+    /// Advance to `now`, returning whether the fade is still running.
+    fn advance(&mut self, now: Instant, motion: Motion) -> bool {
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started).as_secs_f32();
+        let duration = motion.duration_seconds();
+
+        self.progress = if duration > 0.0 {
+            (elapsed / duration).min(1.0)
+        } else {
+            1.0
+        };
+
+        self.progress < 1.0
+    }
+}
+
+/// A black backdrop behind `content` that fades in to `opacity`.
+struct FadingBackdrop<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    opacity: f32,
+    motion: Motion,
+    /// Emitted on Escape when nothing in `content` handles it.
+    on_escape: Option<Message>,
+}
+
+impl<Message, Theme, Renderer> FadingBackdrop<'_, Message, Theme, Renderer> {
+    /// Backdrop opacity at `progress` through the fade-in.
+    fn alpha(&self, progress: f32) -> f32 {
+        if self.motion.duration_ms == 0 {
+            self.opacity
+        } else {
+            self.opacity * self.motion.easing.apply(progress)
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FadingBackdrop<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Fade>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Fade::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if tree.state.downcast_mut::<Fade>().advance(now, self.motion) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let is_escape = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            })
+        );
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let Some(on_escape) = self.on_escape.as_ref().filter(|_| is_escape) else {
+            return status;
+        };
+
+        // Escape goes to the content first, e.g. a nested modal or an open
+        // dropdown, which keeps this modal open.
+        let content_overlay_open = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, Vector::ZERO)
+            .is_some();
+
+        if status == event::Status::Captured || content_overlay_open {
+            return status;
+        }
+
+        shell.publish(on_escape.clone());
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            Color {
+                a: self.alpha(tree.state.downcast_ref::<Fade>().progress),
+                ..Color::BLACK
+            },
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::{stack, Space};
+
+    #[test]
+    fn close_task_focuses_the_opener() {
+        use iced::widget::{column, text_input};
+
+        let page: Element<'_, (), iced::Theme, ()> = column![
+            text_input("Search", "").id(text_input::Id::new("search")),
+            text_input("Name", "").id(text_input::Id::new("open-settings")),
+        ]
         .into();
+        let mut tree = Tree::new(&page);
+        let node = page.as_widget().layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+        let mut run = |operation: &mut dyn Operation| {
+            page.as_widget()
+                .operate(&mut tree, Layout::new(&node), &(), operation);
+            [0, 1].map(|index| {
+                tree.children[index]
+                    .state
+                    .downcast_ref::<text_input::State<()>>()
+                    .is_focused()
+            })
+        };
+
+        assert_eq!(
+            run(&mut focusable::focus(widget::Id::new("search"))),
+            [true, false]
+        );
+
+        let settings = Modal::new(Space::new(10, 10), Space::new(10, 10))
+            .on_backdrop_press(())
+            .restore_focus_to(widget::Id::new("open-settings"));
+        let mut restore = settings
+            .restore_focus_operation()
+            .expect("restore focus operation");
+        assert_eq!(run(&mut restore), [false, true]);
+
+        let plain = Modal::new(Space::new(10, 10), Space::new(10, 10)).on_backdrop_press(());
+        assert!(plain.restore_focus_operation::<()>().is_none());
+    }
+
+    /// Backdrop opacity after a backdrop has been shown for `elapsed`.
+    fn alpha_after(motion: Motion, elapsed: std::time::Duration) -> f32 {
+        let mut backdrop: FadingBackdrop<'_, (), (), ()> = FadingBackdrop {
+            content: Space::new(Length::Fill, Length::Fill).into(),
+            opacity: 0.8,
+            motion,
+            on_escape: None,
+        };
+        let mut tree = Tree::new(&backdrop as &dyn Widget<(), (), ()>);
+        let node = backdrop.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+        let opened = Instant::now();
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for now in [opened, opened + elapsed] {
+            let _ = backdrop.on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut iced::advanced::clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::new(800.0, 600.0)),
+            );
+        }
+
+        backdrop.alpha(tree.state.downcast_ref::<Fade>().progress)
+    }
+
+    fn backdrop(on_escape: Option<&'static str>) -> FadingBackdrop<'static, &'static str, (), ()> {
+        FadingBackdrop {
+            content: Space::new(Length::Fill, Length::Fill).into(),
+            opacity: 0.8,
+            motion: Motion::NONE,
+            on_escape,
+        }
+    }
 
-    stack![base.into(), overlay].into()
+    /// Press Escape on `element`, returning the published messages.
+    fn press_escape(mut element: Element<'_, &'static str, (), ()>) -> Vec<&'static str> {
+        let mut tree = Tree::new(&element);
+        let viewport = Size::new(800.0, 600.0);
+        let node = element
+            .as_widget()
+            .layout(&mut tree, &(), &Limits::new(Size::ZERO, viewport));
+
+        let mut messages = Vec::new();
+        let _ = element.as_widget_mut().on_event(
+            &mut tree,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                modified_key: keyboard::Key::Named(key::Named::Escape),
+                physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::empty(),
+                text: None,
+            }),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut iced::advanced::clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(viewport),
+        );
+
+        messages
+    }
+
+    #[test]
+    fn escape_closes_only_the_topmost_modal() {
+        assert_eq!(
+            press_escape(Element::new(backdrop(Some("close")))),
+            ["close"]
+        );
+        assert!(press_escape(Element::new(backdrop(None))).is_empty());
+
+        let stacked = stack![
+            Element::new(backdrop(Some("close settings"))),
+            Element::new(backdrop(Some("close confirm"))),
+        ];
+        assert_eq!(press_escape(stacked.into()), ["close confirm"]);
+    }
+
+    #[test]
+    fn layers_stack_by_z_index() {
+        let layer = |width: f32| -> Element<'_, &'static str, (), ()> {
+            Space::new(Length::Fixed(width), Length::Fill).into()
+        };
+        let stacked = layered(
+            Space::new(Length::Fill, Length::Fill),
+            [
+                (Layer::Toast, layer(4.0)),
+                (Layer::Modal, layer(3.0)),
+                (Layer::Dropdown, layer(2.0)),
+            ],
+        );
+
+        let widths: Vec<f32> = crate::testing::measure(stacked, Size::new(100.0, 100.0))
+            .child_bounds()
+            .iter()
+            .map(|bounds| bounds.width)
+            .collect();
+        assert_eq!(widths, [100.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn backdrop_fade_follows_configured_easing() {
+        let half = std::time::Duration::from_millis(100);
+
+        let linear = alpha_after(Motion::new(200, Easing::Linear), half);
+        assert!((linear - 0.4).abs() < 0.001);
+
+        let standard = alpha_after(Motion::default(), half);
+        assert!(standard > 0.4 && standard < 0.8);
+
+        assert!((alpha_after(Motion::NONE, std::time::Duration::ZERO) - 0.8).abs() < f32::EPSILON);
+    }
 }
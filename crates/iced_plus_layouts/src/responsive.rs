@@ -1,12 +1,17 @@
 //! Responsive layout helpers for adaptive UIs.
 //!
 //! Provides breakpoint-aware layouts that adapt based on container width.
+//!
+//! Responsive widgets use the breakpoints of the view they are built in,
+//! set with [`with_breakpoints`], falling back to the app-wide ones set with
+//! [`set_app_breakpoints`], unless given their own.
 
-use iced::advanced::layout::{Layout, Limits, Node};
+use iced::advanced::layout::{self, Layout, Limits, Node};
 use iced::advanced::renderer;
 use iced::advanced::widget::{tree, Operation, Tree, Widget};
 use iced::advanced::{Clipboard, Shell};
-use iced::{event, mouse, Element, Event, Length, Rectangle, Size};
+use iced::{event, mouse, Element, Event, Length, Padding, Rectangle, Size};
+use std::cell::Cell;
 
 /// Standard breakpoint values in pixels.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -71,6 +76,50 @@ impl Breakpoints {
     }
 }
 
+thread_local! {
+    static APP_BREAKPOINTS: Cell<Breakpoints> = const { Cell::new(Breakpoints::STANDARD) };
+    static SCOPED_BREAKPOINTS: Cell<Option<Breakpoints>> = const { Cell::new(None) };
+}
+
+/// Set the breakpoints responsive widgets built on this thread outside
+/// [`with_breakpoints`] use by default.
+///
+/// Call it once at startup (or when the setting changes); widgets given
+/// explicit breakpoints keep using those.
+pub fn set_app_breakpoints(breakpoints: Breakpoints) {
+    APP_BREAKPOINTS.with(|cell| cell.set(breakpoints));
+}
+
+/// Build `view` with responsive widgets defaulting to `breakpoints`,
+/// whatever the app-wide ones.
+///
+/// Scopes nest; the breakpoints apply to widgets built inside `view`, which
+/// keep them when laid out later, and are restored when it returns.
+pub fn with_breakpoints<T>(breakpoints: Breakpoints, view: impl FnOnce() -> T) -> T {
+    /// Restores the enclosing scope's breakpoints, even if `view` panics.
+    struct Restore(Option<Breakpoints>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_BREAKPOINTS.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED_BREAKPOINTS.with(|cell| cell.replace(Some(breakpoints))));
+
+    view()
+}
+
+/// The breakpoints responsive widgets are built with: those of the
+/// enclosing [`with_breakpoints`], or the app-wide ones
+/// ([`Breakpoints::STANDARD`] unless set).
+#[must_use]
+pub fn app_breakpoints() -> Breakpoints {
+    SCOPED_BREAKPOINTS
+        .with(Cell::get)
+        .unwrap_or_else(|| APP_BREAKPOINTS.with(Cell::get))
+}
+
 /// Breakpoint tier enum for pattern matching.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum BreakpointTier {
@@ -175,10 +224,14 @@ impl<T: Clone> Responsive<T> {
 }
 
 /// A wrapper that shows content only at certain breakpoints.
+///
+/// To build different content per tier, e.g. a row on desktops and a
+/// column on phones, use a [`ResponsiveContainer`](crate::ResponsiveContainer).
 pub struct ShowOn<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     content: Element<'a, Message, Theme, Renderer>,
     min_tier: Option<BreakpointTier>,
     max_tier: Option<BreakpointTier>,
+    excluded: Vec<BreakpointTier>,
     breakpoints: Breakpoints,
     current_width: f32,
 }
@@ -193,7 +246,8 @@ where
             content: content.into(),
             min_tier: None,
             max_tier: None,
-            breakpoints: Breakpoints::default(),
+            excluded: Vec::new(),
+            breakpoints: app_breakpoints(),
             current_width: 1024.0, // Default to desktop
         }
     }
@@ -212,7 +266,17 @@ where
         self
     }
 
-    /// Set custom breakpoints.
+    /// Never show at these breakpoint tiers, e.g. hide on tablets with
+    /// `except(&[BreakpointTier::MD])`.
+    ///
+    /// Combines with [`min`](Self::min) and [`max`](Self::max).
+    #[must_use]
+    pub fn except(mut self, tiers: &[BreakpointTier]) -> Self {
+        self.excluded.extend_from_slice(tiers);
+        self
+    }
+
+    /// Use custom breakpoints instead of the app-wide ones.
     #[must_use]
     pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
         self.breakpoints = breakpoints;
@@ -225,7 +289,7 @@ where
         let min_ok = self.min_tier.map_or(true, |min| tier >= min);
         let max_ok = self.max_tier.map_or(true, |max| tier <= max);
 
-        min_ok && max_ok
+        min_ok && max_ok && !self.excluded.contains(&tier)
     }
 }
 
@@ -359,7 +423,10 @@ where
     ShowOn::new(content)
 }
 
-/// Convenience function to hide content on certain breakpoints.
+/// Convenience function to hide content at one breakpoint tier and show it
+/// at all the others.
+///
+/// Use [`ShowOn::except`] to hide it at several tiers.
 pub fn hide_on<'a, Message, Theme, Renderer>(
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
     tier: BreakpointTier,
@@ -367,21 +434,218 @@ pub fn hide_on<'a, Message, Theme, Renderer>(
 where
     Renderer: renderer::Renderer,
 {
-    // Hide on this tier means show on everything else
-    // This is a simplification - for complex cases, use ShowOn directly
-    ShowOn::new(content).min(match tier {
-        BreakpointTier::XS => BreakpointTier::SM,
-        BreakpointTier::SM => BreakpointTier::MD,
-        BreakpointTier::MD => BreakpointTier::LG,
-        BreakpointTier::LG => BreakpointTier::XL,
-        BreakpointTier::XL => BreakpointTier::XL, // Can't hide XL only with this
-    })
+    ShowOn::new(content).except(&[tier])
+}
+
+/// A container whose padding changes with the breakpoint tier.
+///
+/// The tier is resolved from the width available to the container, so pages
+/// don't have to thread breakpoint logic through their own state.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use iced::Padding;
+/// use iced_plus_layouts::{Responsive, ResponsivePadding};
+///
+/// ResponsivePadding::new(
+///     page,
+///     Responsive::new(Padding::new(12.0)).md(Padding::new(24.0)).lg(Padding::new(48.0)),
+/// )
+/// ```
+pub struct ResponsivePadding<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    padding: Responsive<Padding>,
+    breakpoints: Breakpoints,
+}
+
+impl<'a, Message, Theme, Renderer> ResponsivePadding<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Create a container applying `padding` around `content`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        padding: Responsive<Padding>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            padding,
+            breakpoints: app_breakpoints(),
+        }
+    }
+
+    /// Use custom breakpoints instead of the app-wide ones.
+    #[must_use]
+    pub const fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Padding applied when the container is `width` pixels wide.
+    #[must_use]
+    pub fn padding_for(&self, width: f32) -> Padding {
+        *self.padding.get(self.breakpoints.tier(width))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ResponsivePadding<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = self.content.as_widget().size();
+        let padding = self.padding_for(limits.max().width);
+
+        layout::padded(limits, size.width, size.height, padding, |limits| {
+            self.content.as_widget().layout(tree, renderer, limits)
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(child_layout) = layout.children().next() {
+            self.content
+                .as_widget()
+                .operate(tree, child_layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        match layout.children().next() {
+            Some(child_layout) => self.content.as_widget_mut().on_event(
+                tree,
+                event,
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map_or_else(mouse::Interaction::default, |child_layout| {
+                self.content.as_widget().mouse_interaction(
+                    tree,
+                    child_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(child_layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                child_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ResponsivePadding<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(padding: ResponsivePadding<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(padding)
+    }
+}
+
+/// Convenience function to create a responsive padding container.
+pub fn responsive_padding<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    padding: Responsive<Padding>,
+) -> ResponsivePadding<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    ResponsivePadding::new(content, padding)
 }
 
 /// A responsive row that stacks vertically on small screens.
 ///
 /// On screens smaller than the `stack_below` tier, children are arranged
 /// vertically. On larger screens, they're arranged horizontally.
+/// Children that lay out to zero size, like a hidden [`ShowOn`], take no
+/// room and no spacing.
+///
+/// Side by side, children share the width equally unless added with
+/// [`push_weighted`](Self::push_weighted), e.g. for a 2:1 split between a
+/// main panel and a sidebar card.
 ///
 /// # Example
 ///
@@ -391,15 +655,20 @@ where
 /// ResponsiveRow::new()
 ///     .stack_below(BreakpointTier::MD)  // Stack on mobile/tablet
 ///     .spacing(16.0)
-///     .push(card1)
-///     .push(card2)
-///     .push(card3)
+///     .order(Responsive::new(vec![1, 0, 2]).md(vec![0, 1, 2]))  // Content first on mobile
+///     .push(sidebar)
+///     .push(content)
+///     .push(aside)
 /// ```
 pub struct ResponsiveRow<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    weights: Vec<f32>,
     spacing: f32,
     stack_below: BreakpointTier,
+    min_child_width: Option<f32>,
     breakpoints: Breakpoints,
+    order: Option<Responsive<Vec<usize>>>,
+    stretch_children: bool,
 }
 
 impl<'a, Message, Theme, Renderer> ResponsiveRow<'a, Message, Theme, Renderer>
@@ -407,19 +676,38 @@ where
     Renderer: renderer::Renderer,
 {
     /// Create a new responsive row.
+    #[must_use]
     pub fn new() -> Self {
         Self {
             children: Vec::new(),
+            weights: Vec::new(),
             spacing: 8.0,
             stack_below: BreakpointTier::MD,
-            breakpoints: Breakpoints::default(),
+            min_child_width: None,
+            breakpoints: app_breakpoints(),
+            order: None,
+            stretch_children: true,
         }
     }
 
     /// Add a child element.
     #[must_use]
-    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_weighted(child, 1.0)
+    }
+
+    /// Add a child that takes `weight` shares of the width when the row is
+    /// laid out side by side; [`push`](Self::push) adds a share of 1.
+    ///
+    /// Stacked, every child gets the full width.
+    #[must_use]
+    pub fn push_weighted(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        weight: f32,
+    ) -> Self {
         self.children.push(child.into());
+        self.weights.push(weight.max(0.0));
         self
     }
 
@@ -434,20 +722,109 @@ where
     #[must_use]
     pub fn stack_below(mut self, tier: BreakpointTier) -> Self {
         self.stack_below = tier;
+        self.min_child_width = None;
+        self
+    }
+
+    /// Stack vertically whenever the narrowest column would be narrower
+    /// than `min_child_width`, whatever the breakpoint tier.
+    ///
+    /// Replaces [`stack_below`](Self::stack_below); whichever is set last wins.
+    #[must_use]
+    pub const fn stack_when_below(mut self, min_child_width: f32) -> Self {
+        self.min_child_width = Some(min_child_width);
         self
     }
 
-    /// Use custom breakpoints.
+    /// Use custom breakpoints instead of the app-wide ones.
     #[must_use]
     pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
         self.breakpoints = breakpoints;
         self
     }
 
+    /// Stretch side-by-side children to the height of the tallest one, like
+    /// CSS `align-items: stretch` (default true).
+    ///
+    /// Each child is laid out again with the row height as its minimum, so
+    /// cards grow until their backgrounds line up and `Fill`-height children
+    /// fill the row. The tallest sibling is measured among the children that
+    /// don't fill. Stacked children are never stretched.
+    #[must_use]
+    pub const fn stretch_children(mut self, stretch: bool) -> Self {
+        self.stretch_children = stretch;
+        self
+    }
+
+    /// Set the visual order of the children per breakpoint, like CSS `order`.
+    ///
+    /// Each list holds child indices (in push order) in the order they should
+    /// appear. Children missing from the list follow in push order, and
+    /// out-of-range or repeated indices are ignored.
+    #[must_use]
+    pub fn order(mut self, order: Responsive<Vec<usize>>) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     fn should_stack(&self, width: f32) -> bool {
+        if let Some(min_child_width) = self.min_child_width {
+            return self
+                .column_widths(width, |_| true)
+                .into_iter()
+                .any(|column| column < min_child_width);
+        }
+
         let tier = self.breakpoints.tier(width);
         tier < self.stack_below
     }
+
+    /// Width of each child laid out side by side, split by weight among
+    /// the `visible` children; the others get none.
+    #[allow(clippy::cast_precision_loss)]
+    fn column_widths(&self, width: f32, visible: impl Fn(usize) -> bool) -> Vec<f32> {
+        let visible: Vec<bool> = (0..self.children.len()).map(visible).collect();
+        let count = visible.iter().filter(|&&visible| visible).count().max(1) as f32;
+        let total_weight: f32 = self
+            .weights
+            .iter()
+            .zip(&visible)
+            .filter_map(|(weight, &visible)| visible.then_some(*weight))
+            .sum();
+        let available = self.spacing.mul_add(-(count - 1.0), width);
+
+        self.weights
+            .iter()
+            .zip(visible)
+            .map(|(weight, visible)| match (visible, total_weight > 0.0) {
+                (false, _) => 0.0,
+                (true, true) => available * weight / total_weight,
+                (true, false) => available / count,
+            })
+            .collect()
+    }
+
+    /// Child indices in the order they are laid out at `width`.
+    fn visual_order(&self, width: f32) -> Vec<usize> {
+        let count = self.children.len();
+        let mut seen = vec![false; count];
+        let mut order = Vec::with_capacity(count);
+
+        let requested = self
+            .order
+            .as_ref()
+            .map(|order| order.get(self.breakpoints.tier(width)).as_slice())
+            .unwrap_or_default();
+
+        for index in requested.iter().copied().chain(0..count) {
+            if index < count && !seen[index] {
+                seen[index] = true;
+                order.push(index);
+            }
+        }
+
+        order
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Default for ResponsiveRow<'a, Message, Theme, Renderer>
@@ -496,57 +873,98 @@ where
             let mut max_w = 0.0_f32;
             let child_limits = Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY));
 
-            let nodes: Vec<Node> = self
-                .children
-                .iter()
-                .zip(tree.children.iter_mut())
-                .map(|(child, child_tree)| {
-                    let node = child
-                        .as_widget()
-                        .layout(child_tree, renderer, &child_limits);
-                    let size = node.size();
-                    let node = node.move_to(iced::Point::new(0.0, y));
+            // Nodes stay in push order; only their positions follow `order`.
+            let mut nodes = vec![Node::default(); self.children.len()];
+            let mut any_visible = false;
+
+            for index in self.visual_order(max_width) {
+                let node = self.children[index].as_widget().layout(
+                    &mut tree.children[index],
+                    renderer,
+                    &child_limits,
+                );
+                let size = node.size();
+                nodes[index] = node.move_to(iced::Point::new(0.0, y));
+
+                // Hidden children keep no gap around them.
+                if size.height > 0.0 {
                     y += size.height + self.spacing;
-                    max_w = max_w.max(size.width);
-                    node
-                })
-                .collect();
+                    any_visible = true;
+                }
+                max_w = max_w.max(size.width);
+            }
 
             // Remove last spacing
-            if !nodes.is_empty() {
+            if any_visible {
                 y -= self.spacing;
             }
 
             Node::with_children(Size::new(max_w.max(max_width), y), nodes)
         } else {
-            // Horizontal layout - equal width for all children
+            // Horizontal layout - width split by weight
             let child_count = self.children.len();
             if child_count == 0 {
                 return Node::new(Size::new(max_width, 0.0));
             }
 
-            let total_spacing = self.spacing * (child_count - 1) as f32;
-            let child_width = (max_width - total_spacing) / child_count as f32;
-            let child_limits = Limits::new(Size::ZERO, Size::new(child_width, f32::INFINITY));
+            let layout_child = |tree: &mut Tree, index: usize, width: f32| {
+                self.children[index].as_widget().layout(
+                    &mut tree.children[index],
+                    renderer,
+                    &Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+                )
+            };
+
+            let mut widths = self.column_widths(max_width, |_| true);
+            let mut nodes: Vec<Node> = (0..child_count)
+                .map(|index| layout_child(tree, index, widths[index]))
+                .collect();
 
-            let mut x = 0.0;
-            let mut max_h = 0.0_f32;
+            // Children laying out to zero width are hidden: they get no
+            // column and no gap, and the others share their room.
+            let visible = nodes.iter().filter(|node| node.size().width > 0.0).count();
+            if visible > 0 && visible < child_count {
+                widths = self.column_widths(max_width, |index| nodes[index].size().width > 0.0);
+                for (index, node) in nodes.iter_mut().enumerate() {
+                    if node.size().width > 0.0 {
+                        *node = layout_child(tree, index, widths[index]);
+                    }
+                }
+            }
 
-            let nodes: Vec<Node> = self
-                .children
+            let fills_height =
+                |index: usize| self.children[index].as_widget().size().height.is_fill();
+            let max_h = nodes
                 .iter()
-                .zip(tree.children.iter_mut())
-                .map(|(child, child_tree)| {
-                    let node = child
-                        .as_widget()
-                        .layout(child_tree, renderer, &child_limits);
-                    let size = node.size();
-                    let node = node.move_to(iced::Point::new(x, 0.0));
-                    x += child_width + self.spacing;
-                    max_h = max_h.max(size.height);
-                    node
-                })
-                .collect();
+                .enumerate()
+                .filter(|&(index, _)| !(self.stretch_children && fills_height(index)))
+                .fold(0.0_f32, |max_h, (_, node)| max_h.max(node.size().height));
+
+            // Lay the visible children out again at the row height, so those
+            // filling it match their tallest sibling.
+            if self.stretch_children {
+                for (index, node) in nodes.iter_mut().enumerate() {
+                    if node.size().width > 0.0 {
+                        *node = self.children[index].as_widget().layout(
+                            &mut tree.children[index],
+                            renderer,
+                            &Limits::new(Size::new(0.0, max_h), Size::new(widths[index], max_h)),
+                        );
+                    }
+                }
+            }
+
+            let mut x = 0.0;
+
+            for index in self.visual_order(max_width) {
+                let node = &mut nodes[index];
+                let size = node.size();
+                node.move_to_mut(iced::Point::new(x, 0.0));
+
+                if size.width > 0.0 {
+                    x += widths[index] + self.spacing;
+                }
+            }
 
             Node::with_children(Size::new(max_width, max_h), nodes)
         }
@@ -679,9 +1097,307 @@ where
 }
 
 /// Convenience function to create a responsive row.
+#[must_use]
 pub fn responsive_row<'a, Message, Theme, Renderer>() -> ResponsiveRow<'a, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer,
 {
     ResponsiveRow::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::with_width;
+    use iced::widget::Space;
+    use iced::Point;
+
+    fn padded_offset(width: f32) -> f32 {
+        let padding = Responsive::new(Padding::new(8.0)).lg(Padding::new(32.0));
+        let container: ResponsivePadding<'_, (), (), ()> =
+            ResponsivePadding::new(Space::new(Length::Fill, Length::Fixed(10.0)), padding);
+
+        let mut tree = Tree::new(&container as &dyn Widget<(), (), ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(width, 600.0));
+        let node = container.layout(&mut tree, &(), &limits);
+
+        node.children()[0].bounds().x
+    }
+
+    #[test]
+    fn responsive_padding_resolves_tier_from_width() {
+        assert!((padded_offset(400.0) - 8.0).abs() < f32::EPSILON);
+        assert!((padded_offset(1200.0) - 32.0).abs() < f32::EPSILON);
+    }
+
+    fn row_offsets(width: f32) -> Vec<Point> {
+        let row: ResponsiveRow<'_, (), (), ()> = ResponsiveRow::new()
+            .order(Responsive::new(vec![1, 0]).lg(vec![0, 1]))
+            .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+            .push(Space::new(Length::Fill, Length::Fixed(20.0)));
+
+        let mut tree = Tree::new(&row as &dyn Widget<(), (), ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(width, 600.0));
+        let node = row.layout(&mut tree, &(), &limits);
+
+        node.children()
+            .iter()
+            .map(|child| child.bounds().position())
+            .collect()
+    }
+
+    #[test]
+    fn responsive_row_reorders_children_per_tier() {
+        // XS stacks with the second child on top.
+        let xs = row_offsets(400.0);
+        assert!(xs[1].y < xs[0].y);
+
+        // LG lays out side by side in push order.
+        let lg = row_offsets(1200.0);
+        assert!(lg[0].x < lg[1].x);
+        assert!((lg[0].y - lg[1].y).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn hidden_child_leaves_a_single_gap() {
+        let row = |width: f32| {
+            let row: ResponsiveRow<'_, (), (), ()> = ResponsiveRow::new()
+                .spacing(16.0)
+                .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+                .push(Space::new(Length::Fixed(0.0), Length::Fixed(0.0)))
+                .push(Space::new(Length::Fill, Length::Fixed(10.0)));
+            crate::testing::measure(row, Size::new(width, 600.0)).child_bounds()
+        };
+
+        // Side by side, the two visible children split the width around one gap.
+        let wide = row(1200.0);
+        assert!((wide[0].width - 592.0).abs() < f32::EPSILON);
+        assert!((wide[2].x - (wide[0].x + wide[0].width) - 16.0).abs() < f32::EPSILON);
+
+        // Stacked, the same holds vertically.
+        let narrow = row(400.0);
+        assert!((narrow[2].y - (narrow[0].y + narrow[0].height) - 16.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn weighted_children_split_the_width() {
+        let row: ResponsiveRow<'_, (), (), ()> = ResponsiveRow::new()
+            .spacing(12.0)
+            .push_weighted(Space::new(Length::Fill, Length::Fixed(10.0)), 2.0)
+            .push(Space::new(Length::Fill, Length::Fixed(10.0)));
+        let children = crate::testing::measure(row, Size::new(900.0, 600.0)).child_bounds();
+
+        // 888px after the gap, split 2:1.
+        assert!((children[0].width - 592.0).abs() < f32::EPSILON);
+        assert!((children[1].width - 296.0).abs() < f32::EPSILON);
+        assert!((children[1].x - 604.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn stretched_children_match_the_tallest() {
+        let card = |content_height: f32| {
+            iced::widget::container(Space::new(Length::Fill, Length::Fixed(content_height)))
+                .padding(8)
+        };
+        let row = |stretch: bool| -> ResponsiveRow<'_, (), iced::Theme, ()> {
+            ResponsiveRow::new()
+                .stretch_children(stretch)
+                .push(card(40.0))
+                .push(card(120.0))
+                .push(Space::new(Length::Fill, Length::Fill))
+        };
+
+        let stretched = crate::testing::measure(row(true), Size::new(1200.0, 800.0));
+        for child in stretched.child_bounds() {
+            assert!((child.height - 136.0).abs() < f32::EPSILON, "{child:?}");
+        }
+        assert!((stretched.bounds.height - 136.0).abs() < f32::EPSILON);
+
+        let packed = crate::testing::measure(row(false), Size::new(1200.0, 800.0)).child_bounds();
+        assert!((packed[0].height - 56.0).abs() < f32::EPSILON);
+        assert!((packed[1].height - 136.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn app_breakpoints_apply_unless_overridden() {
+        let offset = |breakpoints: Option<Breakpoints>| {
+            let padding = Responsive::new(Padding::new(8.0)).lg(Padding::new(32.0));
+            let mut container: ResponsivePadding<'_, (), (), ()> =
+                ResponsivePadding::new(Space::new(Length::Fill, Length::Fixed(10.0)), padding);
+            if let Some(breakpoints) = breakpoints {
+                container = container.breakpoints(breakpoints);
+            }
+
+            container.padding_for(900.0).left
+        };
+
+        // 900px is MD with the standard breakpoints but LG with the compact ones.
+        assert!((offset(None) - 8.0).abs() < f32::EPSILON);
+
+        set_app_breakpoints(Breakpoints::COMPACT);
+        let app_wide = offset(None);
+        let overridden = offset(Some(Breakpoints::STANDARD));
+        set_app_breakpoints(Breakpoints::STANDARD);
+
+        assert!((app_wide - 32.0).abs() < f32::EPSILON);
+        assert!((overridden - 8.0).abs() < f32::EPSILON);
+
+        // Breakpoints scoped to a view stay with the widgets built in it.
+        let scoped = with_breakpoints(Breakpoints::COMPACT, || {
+            ResponsivePadding::<'_, (), (), ()>::new(
+                Space::new(Length::Fill, Length::Fixed(10.0)),
+                Responsive::new(Padding::new(8.0)).lg(Padding::new(32.0)),
+            )
+        });
+        assert!((scoped.padding_for(900.0).left - 32.0).abs() < f32::EPSILON);
+        assert!((offset(None) - 8.0).abs() < f32::EPSILON);
+    }
+
+    /// A width inside each tier of the standard breakpoints.
+    const TIER_WIDTHS: [(BreakpointTier, f32); 5] = [
+        (BreakpointTier::XS, 320.0),
+        (BreakpointTier::SM, 600.0),
+        (BreakpointTier::MD, 900.0),
+        (BreakpointTier::LG, 1100.0),
+        (BreakpointTier::XL, 1400.0),
+    ];
+
+    /// Whether `show_on` is visible at `width`.
+    fn is_shown(show_on: ShowOn<'_, (), (), ()>, width: f32) -> bool {
+        with_width(show_on.breakpoints(Breakpoints::STANDARD), width)
+            .bounds
+            .width
+            > 0.0
+    }
+
+    fn content() -> Space {
+        Space::new(Length::Fill, Length::Fixed(10.0))
+    }
+
+    #[test]
+    fn show_on_follows_the_tier_of_its_width() {
+        type MakeShowOn = fn() -> ShowOn<'static, (), (), ()>;
+
+        let cases: [(MakeShowOn, [bool; 5]); 6] = [
+            (
+                || ShowOn::new(content()).min(BreakpointTier::MD),
+                [false, false, true, true, true],
+            ),
+            (
+                || ShowOn::new(content()).max(BreakpointTier::SM),
+                [true, true, false, false, false],
+            ),
+            (
+                || {
+                    ShowOn::new(content())
+                        .min(BreakpointTier::SM)
+                        .max(BreakpointTier::LG)
+                },
+                [false, true, true, true, false],
+            ),
+            (
+                || hide_on(content(), BreakpointTier::XS),
+                [false, true, true, true, true],
+            ),
+            (
+                || hide_on(content(), BreakpointTier::XL),
+                [true, true, true, true, false],
+            ),
+            (
+                || {
+                    ShowOn::new(content())
+                        .min(BreakpointTier::SM)
+                        .except(&[BreakpointTier::MD, BreakpointTier::XL])
+                },
+                [false, true, false, true, false],
+            ),
+        ];
+
+        for (case, (show_on, expected)) in cases.into_iter().enumerate() {
+            for ((tier, width), shown) in TIER_WIDTHS.into_iter().zip(expected) {
+                assert_eq!(
+                    is_shown(show_on(), width),
+                    shown,
+                    "case {case} at {tier:?} ({width}px)"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hide_on_an_interior_tier_shows_around_it() {
+        let hidden_on_md = || hide_on(content(), BreakpointTier::MD);
+
+        assert!(is_shown(hidden_on_md(), 600.0));
+        assert!(!is_shown(hidden_on_md(), 900.0));
+        assert!(is_shown(hidden_on_md(), 1100.0));
+    }
+
+    #[test]
+    fn responsive_row_stacks_below_its_tier() {
+        let stacked = |stack_below: BreakpointTier, width: f32| {
+            let row: ResponsiveRow<'_, (), (), ()> = ResponsiveRow::new()
+                .breakpoints(Breakpoints::STANDARD)
+                .stack_below(stack_below)
+                .push(content())
+                .push(content());
+            let children = with_width(row, width).child_bounds();
+
+            children[1].y > children[0].y
+        };
+
+        for stack_below in [BreakpointTier::SM, BreakpointTier::MD, BreakpointTier::XL] {
+            for (tier, width) in TIER_WIDTHS {
+                assert_eq!(
+                    stacked(stack_below, width),
+                    tier < stack_below,
+                    "stack below {stack_below:?} at {tier:?} ({width}px)"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn responsive_get_falls_through_to_the_nearest_smaller_tier() {
+        let sparse = Responsive::new("xs").md("md");
+        let full = Responsive::new("xs").sm("sm").md("md").lg("lg").xl("xl");
+        let cases = [
+            (&sparse, ["xs", "xs", "md", "md", "md"]),
+            (&full, ["xs", "sm", "md", "lg", "xl"]),
+            (
+                &Responsive::new("xs").lg("lg"),
+                ["xs", "xs", "xs", "lg", "lg"],
+            ),
+        ];
+
+        for (responsive, expected) in cases {
+            for ((tier, _), value) in TIER_WIDTHS.into_iter().zip(expected) {
+                assert_eq!(*responsive.get(tier), value, "{tier:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn stack_when_below_uses_minimum_child_width() {
+        let stacks = |min_child_width: f32, width: f32| {
+            let row: ResponsiveRow<'_, (), (), ()> = ResponsiveRow::new()
+                .stack_when_below(min_child_width)
+                .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+                .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+                .push(Space::new(Length::Fill, Length::Fixed(10.0)));
+
+            let mut tree = Tree::new(&row as &dyn Widget<(), (), ()>);
+            let limits = Limits::new(Size::ZERO, Size::new(width, 600.0));
+            let node = row.layout(&mut tree, &(), &limits);
+
+            node.children()[1].bounds().y > 0.0
+        };
+
+        // Columns would be ~228px at 700px and ~328px at 1000px.
+        assert!(stacks(300.0, 700.0));
+        assert!(!stacks(300.0, 1000.0));
+
+        // The tier no longer matters: LG still stacks if columns are too narrow.
+        assert!(stacks(400.0, 1100.0));
+    }
+}
@@ -4,15 +4,48 @@ use iced::advanced::layout::{Layout, Limits, Node};
 use iced::advanced::renderer;
 use iced::advanced::widget::{tree, Operation, Tree, Widget};
 use iced::advanced::{Clipboard, Shell};
-use iced::{event, mouse, Element, Event, Length, Point, Rectangle, Size};
+use iced::{event, mouse, touch, Color, Element, Event, Length, Point, Rectangle, Size};
+
+/// Opacity of the scrim behind the sidebar when it is shown as a drawer.
+const SCRIM_OPACITY: f32 = 0.5;
+
+/// Which side of the content an [`AppShell`] sidebar is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SidebarPosition {
+    /// Left of the content, e.g. for navigation.
+    #[default]
+    Left,
+    /// Right of the content, e.g. for an inspector panel.
+    Right,
+}
+
+/// How the sidebar is placed at the current width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SidebarMode {
+    /// Beside the content.
+    Inline,
+    /// Over the content, as an open drawer.
+    Drawer,
+    /// Collapsed with the drawer closed.
+    Hidden,
+}
 
 /// Application shell with sidebar and main content areas.
 ///
 /// The shell provides a common desktop application layout with:
-/// - Optional left sidebar
+/// - Optional sidebar, on the left or
+///   [right](Self::sidebar_position) of the content
 /// - Main content area
 /// - Optional header
 ///
+/// With [`collapse_below`](Self::collapse_below), the sidebar leaves the
+/// layout on narrow windows and is shown as an overlay drawer instead,
+/// opened with [`drawer_open`](Self::drawer_open). Clicking the scrim next to
+/// the drawer emits the [`on_toggle`](Self::on_toggle) message.
+///
+/// A [`collapsed`](Self::collapsed) sidebar shrinks to a narrow rail, e.g.
+/// of icons, and the content widens to take the freed space.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -22,13 +55,27 @@ use iced::{event, mouse, Element, Event, Length, Point, Rectangle, Size};
 ///     .sidebar(sidebar_content)
 ///     .sidebar_width(250.0)
 ///     .header(header_content);
+///
+/// // Collapse to a hamburger drawer below 768px
+/// let shell = AppShell::new(main_content)
+///     .sidebar(side_nav)
+///     .header(app_bar)
+///     .collapse_below(768.0)
+///     .drawer_open(self.show_drawer)
+///     .on_toggle(Message::ToggleDrawer);
 /// ```
 pub struct AppShell<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     content: Element<'a, Message, Theme, Renderer>,
     sidebar: Option<Element<'a, Message, Theme, Renderer>>,
     header: Option<Element<'a, Message, Theme, Renderer>>,
     sidebar_width: f32,
+    collapsed: bool,
+    collapsed_width: f32,
+    sidebar_position: SidebarPosition,
     header_height: f32,
+    collapse_below: Option<f32>,
+    drawer_open: bool,
+    on_toggle: Option<Message>,
 }
 
 impl<'a, Message, Theme, Renderer> AppShell<'a, Message, Theme, Renderer>
@@ -42,7 +89,13 @@ where
             sidebar: None,
             header: None,
             sidebar_width: 240.0,
+            collapsed: false,
+            collapsed_width: 64.0,
+            sidebar_position: SidebarPosition::default(),
             header_height: 48.0,
+            collapse_below: None,
+            drawer_open: false,
+            on_toggle: None,
         }
     }
 
@@ -67,17 +120,112 @@ where
         self
     }
 
+    /// Set whether the sidebar is collapsed to its
+    /// [`collapsed_width`](Self::collapsed_width).
+    ///
+    /// The collapsed sidebar still receives events, so a rail of icons stays
+    /// clickable. The drawer shown on narrow windows always has the full
+    /// width.
+    #[must_use]
+    pub const fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Set the width of the collapsed sidebar in pixels (default 64).
+    #[must_use]
+    pub const fn collapsed_width(mut self, width: f32) -> Self {
+        self.collapsed_width = width;
+        self
+    }
+
+    /// Width of the inline sidebar: the
+    /// [`collapsed_width`](Self::collapsed_width) while collapsed, the
+    /// [`sidebar_width`](Self::sidebar_width) otherwise.
+    #[must_use]
+    pub const fn effective_sidebar_width(&self) -> f32 {
+        if self.collapsed {
+            self.collapsed_width
+        } else {
+            self.sidebar_width
+        }
+    }
+
+    /// Set which side of the content the sidebar is on (default left).
+    ///
+    /// The collapsed drawer opens from the same side.
+    #[must_use]
+    pub const fn sidebar_position(mut self, position: SidebarPosition) -> Self {
+        self.sidebar_position = position;
+        self
+    }
+
     /// Set the header height in pixels.
     #[must_use]
     pub fn header_height(mut self, height: f32) -> Self {
         self.header_height = height;
         self
     }
+
+    /// Collapse the sidebar into a drawer when the shell is narrower than
+    /// `width` pixels.
+    #[must_use]
+    pub const fn collapse_below(mut self, width: f32) -> Self {
+        self.collapse_below = Some(width);
+        self
+    }
+
+    /// Set whether the collapsed sidebar is shown as a drawer.
+    ///
+    /// Has no effect while the shell is wide enough for the inline sidebar.
+    #[must_use]
+    pub const fn drawer_open(mut self, open: bool) -> Self {
+        self.drawer_open = open;
+        self
+    }
+
+    /// Set the message emitted when the scrim beside the open drawer is
+    /// pressed, usually the same message as the header's menu button.
+    #[must_use]
+    pub fn on_toggle(mut self, message: Message) -> Self {
+        self.on_toggle = Some(message);
+        self
+    }
+
+    /// Placement of the sidebar when the shell is `width` pixels wide.
+    fn sidebar_mode(&self, width: f32) -> SidebarMode {
+        match self.collapse_below {
+            Some(breakpoint) if width < breakpoint => {
+                if self.drawer_open {
+                    SidebarMode::Drawer
+                } else {
+                    SidebarMode::Hidden
+                }
+            }
+            _ => SidebarMode::Inline,
+        }
+    }
+
+    /// The area below the header, covered by the scrim when the drawer is open.
+    fn body_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let header_height = if self.header.is_some() {
+            self.header_height.min(bounds.height)
+        } else {
+            0.0
+        };
+
+        Rectangle {
+            y: bounds.y + header_height,
+            height: bounds.height - header_height,
+            ..bounds
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for AppShell<'a, Message, Theme, Renderer>
 where
+    Message: Clone,
     Renderer: renderer::Renderer,
 {
     fn size(&self) -> Size<Length> {
@@ -128,9 +276,10 @@ where
 
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         let bounds = limits.max();
+        let sidebar_mode = self.sidebar_mode(bounds.width);
 
-        let sidebar_width = if self.sidebar.is_some() {
-            self.sidebar_width
+        let sidebar_width = if self.sidebar.is_some() && sidebar_mode == SidebarMode::Inline {
+            self.effective_sidebar_width()
         } else {
             0.0
         };
@@ -144,8 +293,11 @@ where
         let mut children = Vec::new();
         let mut child_index = 0;
 
-        // Content area
-        let content_x = sidebar_width;
+        // Content area, beside the inline sidebar
+        let content_x = match self.sidebar_position {
+            SidebarPosition::Left => sidebar_width,
+            SidebarPosition::Right => 0.0,
+        };
         let content_y = header_height;
         let content_width = (bounds.width - sidebar_width).max(0.0);
         let content_height = (bounds.height - header_height).max(0.0);
@@ -161,22 +313,31 @@ where
         children.push(content_node);
         child_index += 1;
 
-        // Sidebar
-        if self.sidebar.is_some() {
-            let sidebar_height = bounds.height - header_height;
-            let sidebar_limits = Limits::new(
-                Size::ZERO,
-                Size::new(self.sidebar_width, sidebar_height.max(0.0)),
-            );
-
-            let sidebar_node = self
-                .sidebar
-                .as_ref()
-                .unwrap()
-                .as_widget()
-                .layout(&mut tree.children[child_index], renderer, &sidebar_limits)
-                .move_to(Point::new(0.0, header_height));
-            children.push(sidebar_node);
+        // Sidebar, either inline or over the content as a drawer
+        if let Some(ref sidebar) = self.sidebar {
+            let panel_width = match sidebar_mode {
+                SidebarMode::Inline => self.effective_sidebar_width(),
+                SidebarMode::Drawer | SidebarMode::Hidden => self.sidebar_width,
+            }
+            .min(bounds.width);
+            let panel = if sidebar_mode == SidebarMode::Hidden {
+                Node::new(Size::ZERO)
+            } else {
+                let sidebar_height = bounds.height - header_height;
+                let sidebar_limits =
+                    Limits::new(Size::ZERO, Size::new(panel_width, sidebar_height.max(0.0)));
+
+                sidebar.as_widget().layout(
+                    &mut tree.children[child_index],
+                    renderer,
+                    &sidebar_limits,
+                )
+            };
+            let panel_x = match self.sidebar_position {
+                SidebarPosition::Left => 0.0,
+                SidebarPosition::Right => bounds.width - panel_width,
+            };
+            children.push(panel.move_to(Point::new(panel_x, header_height)));
             child_index += 1;
         }
 
@@ -205,6 +366,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        let sidebar_mode = self.sidebar_mode(layout.bounds().width);
         let mut children_iter = layout.children();
         let mut tree_iter = tree.children.iter_mut();
 
@@ -221,9 +383,11 @@ where
             if let (Some(sidebar_layout), Some(sidebar_tree)) =
                 (children_iter.next(), tree_iter.next())
             {
-                sidebar
-                    .as_widget()
-                    .operate(sidebar_tree, sidebar_layout, renderer, operation);
+                if sidebar_mode != SidebarMode::Hidden {
+                    sidebar
+                        .as_widget()
+                        .operate(sidebar_tree, sidebar_layout, renderer, operation);
+                }
             }
         }
 
@@ -250,19 +414,27 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let bounds = layout.bounds();
+        let sidebar_mode = self.sidebar_mode(bounds.width);
+        let drawer = self.sidebar.is_some() && sidebar_mode == SidebarMode::Drawer;
         let mut children_iter = layout.children();
         let mut tree_iter = tree.children.iter_mut();
 
         let mut status = event::Status::Ignored;
+        let mut drawer_bounds = None;
 
-        // Content
+        // Content, unreachable by the pointer behind an open drawer
         if let (Some(content_layout), Some(content_tree)) = (children_iter.next(), tree_iter.next())
         {
             status = status.merge(self.content.as_widget_mut().on_event(
                 content_tree,
                 event.clone(),
                 content_layout,
-                cursor,
+                if drawer {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                },
                 renderer,
                 clipboard,
                 shell,
@@ -275,16 +447,42 @@ where
             if let (Some(sidebar_layout), Some(sidebar_tree)) =
                 (children_iter.next(), tree_iter.next())
             {
-                status = status.merge(sidebar.as_widget_mut().on_event(
-                    sidebar_tree,
-                    event.clone(),
-                    sidebar_layout,
-                    cursor,
-                    renderer,
-                    clipboard,
-                    shell,
-                    viewport,
-                ));
+                if sidebar_mode != SidebarMode::Hidden {
+                    status = status.merge(sidebar.as_widget_mut().on_event(
+                        sidebar_tree,
+                        event.clone(),
+                        sidebar_layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    ));
+                }
+
+                if drawer {
+                    drawer_bounds = Some(sidebar_layout.bounds());
+                }
+            }
+        }
+
+        // Scrim beside the open drawer
+        if let Some(drawer_bounds) = drawer_bounds {
+            let pressed = matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            );
+
+            if pressed
+                && status == event::Status::Ignored
+                && cursor.is_over(self.body_bounds(bounds))
+                && !cursor.is_over(drawer_bounds)
+            {
+                if let Some(on_toggle) = self.on_toggle.clone() {
+                    shell.publish(on_toggle);
+                }
+                status = event::Status::Captured;
             }
         }
 
@@ -317,6 +515,8 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        let sidebar_mode = self.sidebar_mode(layout.bounds().width);
+        let drawer = self.sidebar.is_some() && sidebar_mode == SidebarMode::Drawer;
         let mut children_iter = layout.children();
         let mut tree_iter = tree.children.iter();
 
@@ -328,7 +528,11 @@ where
             interaction = self.content.as_widget().mouse_interaction(
                 content_tree,
                 content_layout,
-                cursor,
+                if drawer {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                },
                 viewport,
                 renderer,
             );
@@ -338,13 +542,17 @@ where
             if let (Some(sidebar_layout), Some(sidebar_tree)) =
                 (children_iter.next(), tree_iter.next())
             {
-                let sidebar_interaction = sidebar.as_widget().mouse_interaction(
-                    sidebar_tree,
-                    sidebar_layout,
-                    cursor,
-                    viewport,
-                    renderer,
-                );
+                let sidebar_interaction = if sidebar_mode == SidebarMode::Hidden {
+                    mouse::Interaction::default()
+                } else {
+                    sidebar.as_widget().mouse_interaction(
+                        sidebar_tree,
+                        sidebar_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+                };
                 if sidebar_interaction != mouse::Interaction::default() {
                     interaction = sidebar_interaction;
                 }
@@ -381,15 +589,14 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let bounds = layout.bounds();
+        let sidebar_mode = self.sidebar_mode(bounds.width);
+        let drawer = self.sidebar.is_some() && sidebar_mode == SidebarMode::Drawer;
         let mut children_iter = layout.children();
         let mut tree_iter = tree.children.iter();
 
-        // Draw header first (top layer)
-        // Then sidebar
-        // Then content (behind)
-
-        // We need to draw in reverse order to get proper layering
-        // But for this shell, the order doesn't matter as they don't overlap
+        // Content, sidebar and header don't overlap, except for the drawer,
+        // which is drawn over the content in its own layer.
 
         // Content
         if let (Some(content_layout), Some(content_tree)) = (children_iter.next(), tree_iter.next())
@@ -400,7 +607,11 @@ where
                 theme,
                 style,
                 content_layout,
-                cursor,
+                if drawer {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                },
                 viewport,
             );
         }
@@ -410,15 +621,43 @@ where
             if let (Some(sidebar_layout), Some(sidebar_tree)) =
                 (children_iter.next(), tree_iter.next())
             {
-                sidebar.as_widget().draw(
-                    sidebar_tree,
-                    renderer,
-                    theme,
-                    style,
-                    sidebar_layout,
-                    cursor,
-                    viewport,
-                );
+                match sidebar_mode {
+                    SidebarMode::Inline => sidebar.as_widget().draw(
+                        sidebar_tree,
+                        renderer,
+                        theme,
+                        style,
+                        sidebar_layout,
+                        cursor,
+                        viewport,
+                    ),
+                    SidebarMode::Drawer => {
+                        let body = self.body_bounds(bounds);
+
+                        renderer.with_layer(body, |renderer| {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: body,
+                                    ..renderer::Quad::default()
+                                },
+                                Color {
+                                    a: SCRIM_OPACITY,
+                                    ..Color::BLACK
+                                },
+                            );
+                            sidebar.as_widget().draw(
+                                sidebar_tree,
+                                renderer,
+                                theme,
+                                style,
+                                sidebar_layout,
+                                cursor,
+                                viewport,
+                            );
+                        });
+                    }
+                    SidebarMode::Hidden => {}
+                }
             }
         }
 
@@ -444,7 +683,7 @@ where
 impl<'a, Message, Theme, Renderer> From<AppShell<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'a,
+    Message: Clone + 'a,
     Theme: 'a,
     Renderer: renderer::Renderer + 'a,
 {
@@ -452,3 +691,113 @@ where
         Element::new(shell)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        ToggleDrawer,
+    }
+
+    fn shell(drawer_open: bool) -> AppShell<'static, Message, (), ()> {
+        AppShell::new(Space::new(Length::Fill, Length::Fill))
+            .sidebar(Space::new(Length::Fill, Length::Fill))
+            .header(Space::new(Length::Fill, Length::Fill))
+            .collapse_below(768.0)
+            .drawer_open(drawer_open)
+            .on_toggle(Message::ToggleDrawer)
+    }
+
+    fn layout(shell: &AppShell<'static, Message, (), ()>, width: f32) -> (Tree, Node) {
+        let mut tree = Tree::new(shell as &dyn Widget<Message, (), ()>);
+        let limits = Limits::new(Size::ZERO, Size::new(width, 600.0));
+        let node = shell.layout(&mut tree, &(), &limits);
+
+        (tree, node)
+    }
+
+    #[test]
+    fn sidebar_collapses_below_breakpoint() {
+        let (_, wide) = layout(&shell(false), 1024.0);
+        let content = wide.children()[0].bounds();
+        assert!((content.x - 240.0).abs() < f32::EPSILON);
+        assert!((content.width - 784.0).abs() < f32::EPSILON);
+        assert!((wide.children()[1].bounds().width - 240.0).abs() < f32::EPSILON);
+
+        let (_, narrow) = layout(&shell(false), 600.0);
+        let content = narrow.children()[0].bounds();
+        assert!(content.x.abs() < f32::EPSILON);
+        assert!((content.width - 600.0).abs() < f32::EPSILON);
+        assert_eq!(narrow.children()[1].bounds().size(), Size::ZERO);
+
+        // The open drawer overlaps the content instead of pushing it aside.
+        let (_, open) = layout(&shell(true), 600.0);
+        assert!((open.children()[0].bounds().width - 600.0).abs() < f32::EPSILON);
+        assert!((open.children()[1].bounds().width - 240.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn pressing_scrim_toggles_drawer() {
+        let press = |position: Point| {
+            let mut shell = shell(true);
+            let (mut tree, node) = layout(&shell, 600.0);
+            let mut messages = Vec::new();
+
+            let _ = shell.on_event(
+                &mut tree,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(600.0, 600.0)),
+            );
+
+            messages
+        };
+
+        assert_eq!(press(Point::new(400.0, 300.0)), vec![Message::ToggleDrawer]);
+        assert!(press(Point::new(100.0, 300.0)).is_empty(), "inside drawer");
+        assert!(press(Point::new(400.0, 20.0)).is_empty(), "on header");
+    }
+
+    #[test]
+    fn collapsed_sidebar_narrows_to_a_rail() {
+        let expanded = shell(false);
+        assert!((expanded.effective_sidebar_width() - 240.0).abs() < f32::EPSILON);
+
+        let collapsed = shell(false).collapsed(true);
+        assert!((collapsed.effective_sidebar_width() - 64.0).abs() < f32::EPSILON);
+
+        let (_, node) = layout(&collapsed, 1024.0);
+        let content = node.children()[0].bounds();
+        assert!((content.x - 64.0).abs() < f32::EPSILON);
+        assert!((content.width - 960.0).abs() < f32::EPSILON);
+        assert!((node.children()[1].bounds().width - 64.0).abs() < f32::EPSILON);
+
+        let (_, node) = layout(&collapsed.collapsed_width(80.0), 1024.0);
+        assert!((node.children()[0].bounds().x - 80.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn sidebar_sits_on_the_chosen_side() {
+        let (_, left) = layout(&shell(false), 1024.0);
+        assert!(left.children()[1].bounds().x.abs() < f32::EPSILON);
+
+        let right = shell(true).sidebar_position(SidebarPosition::Right);
+        let (_, wide) = layout(&right, 1024.0);
+        let content = wide.children()[0].bounds();
+        assert!(content.x.abs() < f32::EPSILON);
+        assert!((content.width - 784.0).abs() < f32::EPSILON);
+        assert!((wide.children()[1].bounds().x - 784.0).abs() < f32::EPSILON);
+
+        // The collapsed drawer opens from the right edge too.
+        let (_, narrow) = layout(&right, 600.0);
+        assert!((narrow.children()[1].bounds().x - 360.0).abs() < f32::EPSILON);
+    }
+}
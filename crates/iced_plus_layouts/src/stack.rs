@@ -5,15 +5,93 @@
 
 use std::marker::PhantomData;
 
+use iced::advanced::graphics::text::Paragraph;
 use iced::advanced::layout::{self, Layout};
 use iced::advanced::renderer;
+use iced::advanced::widget::text;
 use iced::advanced::widget::{self, Widget};
 use iced::advanced::{Clipboard, Shell};
 use iced::event;
-use iced::{Element, Event, Length, Point, Rectangle, Size};
+use iced::{Alignment, Element, Event, Length, Point, Rectangle, Size};
 use smallvec::SmallVec;
 
 use crate::direction::{Direction, Horizontal, Vertical};
+use crate::responsive::{app_breakpoints, Breakpoints, Responsive};
+
+/// How children are placed on the cross axis of a stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrossAxis {
+    /// Align to the start (top for `HStack`, left for `VStack`).
+    Start,
+    /// Center on the cross axis.
+    #[default]
+    Center,
+    /// Align to the end (bottom for `HStack`, right for `VStack`).
+    End,
+    /// Align `HStack` children on their text baseline.
+    ///
+    /// Each child's baseline is the baseline of the first line of the first
+    /// text inside it, as shaped by the renderer, so a 14px label and a 24px
+    /// value line up on the same baseline rather than on their centers.
+    /// Children without text sit on the baseline with their bottom edge.
+    Baseline,
+}
+
+impl From<Alignment> for CrossAxis {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Start => Self::Start,
+            Alignment::Center => Self::Center,
+            Alignment::End => Self::End,
+        }
+    }
+}
+
+/// What a stack does with children that extend past its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Draw overflowing children in full.
+    #[default]
+    Visible,
+    /// Clip children to the stack's bounds.
+    Clip,
+}
+
+/// Distance from the top of a laid out child to its baseline: that of its
+/// first text, or its bottom edge if it has none.
+fn baseline_offset(tree: &widget::Tree, node: &layout::Node) -> f32 {
+    first_baseline(tree, node).unwrap_or_else(|| node.size().height)
+}
+
+/// Baseline of the first line of the first text widget in `tree`, measured
+/// from the top of `node`.
+///
+/// Only text shaped by iced's own renderers carries line metrics; other
+/// renderers report no baseline.
+fn first_baseline(tree: &widget::Tree, node: &layout::Node) -> Option<f32> {
+    if tree.tag == widget::tree::Tag::of::<text::State<Paragraph>>() {
+        let state = tree.state.downcast_ref::<text::State<Paragraph>>();
+
+        return state
+            .0
+            .raw()
+            .buffer()
+            .layout_runs()
+            .next()
+            .map(|line| line.line_y);
+    }
+
+    tree.children
+        .iter()
+        .zip(node.children())
+        .find_map(|(tree, child)| first_baseline(tree, child).map(|y| child.bounds().y + y))
+}
+
+/// Whether a child laid out to `size` takes no room on the main axis, e.g.
+/// a hidden `ShowOn`, so no spacing is kept around it.
+fn is_collapsed<D: Direction>(size: Size) -> bool {
+    D::main_axis(size.width, size.height) <= 0.0
+}
 
 /// A stack layout that arranges children in a single direction.
 ///
@@ -28,11 +106,19 @@ where
     D: Direction,
 {
     children: SmallVec<[Element<'a, Message, Theme, Renderer>; 8]>,
-    spacing: f32,
+    /// Per-child cross-axis overrides, parallel to `children`.
+    alignments: SmallVec<[Option<CrossAxis>; 8]>,
+    spacing: Responsive<f32>,
+    breakpoints: Breakpoints,
     padding: f32,
     width: Length,
     height: Length,
-    align: D::CrossAlign,
+    min_main: f32,
+    max_main: f32,
+    max_cross: f32,
+    align: CrossAxis,
+    overflow: Overflow,
+    equal_children: bool,
     _direction: PhantomData<D>,
 }
 
@@ -53,11 +139,18 @@ where
     pub fn new() -> Self {
         Self {
             children: SmallVec::new(),
-            spacing: 0.0,
+            alignments: SmallVec::new(),
+            spacing: Responsive::new(0.0),
+            breakpoints: app_breakpoints(),
             padding: 0.0,
             width: Length::Shrink,
             height: Length::Shrink,
-            align: D::default_align(),
+            min_main: 0.0,
+            max_main: f32::INFINITY,
+            max_cross: f32::INFINITY,
+            align: CrossAxis::from(D::default_align().into()),
+            overflow: Overflow::Visible,
+            equal_children: false,
             _direction: PhantomData,
         }
     }
@@ -65,7 +158,25 @@ where
     /// Set the spacing between children.
     #[must_use]
     pub fn spacing(mut self, spacing: impl Into<f32>) -> Self {
-        self.spacing = spacing.into();
+        self.spacing = Responsive::new(spacing.into());
+        self
+    }
+
+    /// Set spacing between children that changes with the breakpoint tier,
+    /// e.g. tighter on small screens.
+    ///
+    /// The tier is resolved from the width available to the stack.
+    #[must_use]
+    pub const fn spacing_responsive(mut self, spacing: Responsive<f32>) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Use custom breakpoints for [`spacing_responsive`](Self::spacing_responsive)
+    /// instead of the app-wide ones.
+    #[must_use]
+    pub const fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
         self
     }
 
@@ -90,13 +201,72 @@ where
         self
     }
 
+    /// Set the minimum main-axis extent.
+    ///
+    /// For `HStack` this is the minimum width, for `VStack` the minimum height.
+    #[must_use]
+    pub const fn min_main(mut self, min: f32) -> Self {
+        self.min_main = min;
+        self
+    }
+
+    /// Set the maximum main-axis extent.
+    ///
+    /// For `HStack` this is the maximum width, for `VStack` the maximum height.
+    /// A filling stack stops growing at this size. To cap the width of a
+    /// `VStack`, use [`max_cross`](Self::max_cross).
+    #[must_use]
+    pub const fn max_main(mut self, max: f32) -> Self {
+        self.max_main = max;
+        self
+    }
+
+    /// Set the maximum cross-axis extent.
+    ///
+    /// For `HStack` this is the maximum height, for `VStack` the maximum
+    /// width, e.g. a content column that fills the window up to 600px and
+    /// is centered by its parent. Children are laid out within the cap.
+    #[must_use]
+    pub const fn max_cross(mut self, max: f32) -> Self {
+        self.max_cross = max;
+        self
+    }
+
     /// Set the cross-axis alignment.
     ///
     /// For `HStack`, this sets vertical alignment (top, center, bottom).
     /// For `VStack`, this sets horizontal alignment (left, center, right).
     #[must_use]
     pub fn align(mut self, align: D::CrossAlign) -> Self {
-        self.align = align;
+        self.align = CrossAxis::from(align.into());
+        self
+    }
+
+    /// Set how children extending past the stack are drawn.
+    #[must_use]
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Clip children to the stack's bounds, e.g. in fixed-size panels.
+    #[must_use]
+    pub const fn clip(self, clip: bool) -> Self {
+        self.overflow(if clip {
+            Overflow::Clip
+        } else {
+            Overflow::Visible
+        })
+    }
+
+    /// Lay every child out at the size of the largest one on both axes,
+    /// e.g. for a row of equal cards, without wrapping each child in a
+    /// filling container.
+    ///
+    /// Hidden children stay hidden.
+    #[must_use]
+    pub const fn equal_children(mut self, equal: bool) -> Self {
+        self.equal_children = equal;
         self
     }
 
@@ -104,6 +274,23 @@ where
     #[must_use]
     pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
         self.children.push(child.into());
+        self.alignments.push(None);
+        self
+    }
+
+    /// Add a child element with its own cross-axis alignment, overriding
+    /// the stack's [`align`](Self::align) for this child only.
+    ///
+    /// Like `align`, `HStack` takes a `Vertical` alignment and `VStack` a
+    /// `Horizontal` one.
+    #[must_use]
+    pub fn push_aligned(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        align: D::CrossAlign,
+    ) -> Self {
+        self.children.push(child.into());
+        self.alignments.push(Some(CrossAxis::from(align.into())));
         self
     }
 
@@ -141,6 +328,18 @@ where
         children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
     ) -> Self {
         self.children.extend(children);
+        self.alignments.resize(self.children.len(), None);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> HStack<'_, Message, Theme, Renderer> {
+    /// Set the cross-axis placement, including [`CrossAxis::Baseline`].
+    ///
+    /// Use baseline alignment for label + value rows mixing font sizes.
+    #[must_use]
+    pub const fn cross_axis(mut self, cross_axis: CrossAxis) -> Self {
+        self.align = cross_axis;
         self
     }
 }
@@ -154,6 +353,63 @@ where
     }
 }
 
+impl<D, Message, Theme, Renderer> Stack<'_, D, Message, Theme, Renderer>
+where
+    D: Direction,
+    Renderer: renderer::Renderer,
+{
+    /// `limits` narrowed to the stack's size and main- and cross-axis caps.
+    fn constrain(&self, limits: &layout::Limits) -> layout::Limits {
+        let limits = limits.width(self.width).height(self.height);
+
+        if D::is_horizontal() {
+            limits
+                .min_width(self.min_main)
+                .max_width(self.max_main)
+                .max_height(self.max_cross)
+        } else {
+            limits
+                .min_height(self.min_main)
+                .max_height(self.max_main)
+                .max_width(self.max_cross)
+        }
+    }
+
+    /// Lay the visible children out again at the size of the largest one,
+    /// returning their total main-axis extent and the shared cross extent.
+    fn equalize(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        nodes: &mut [layout::Node],
+    ) -> (f32, f32) {
+        let largest = nodes
+            .iter()
+            .map(layout::Node::size)
+            .filter(|&size| !is_collapsed::<D>(size))
+            .fold(Size::ZERO, |largest, size| {
+                Size::new(
+                    largest.width.max(size.width),
+                    largest.height.max(size.height),
+                )
+            });
+
+        let mut main = 0.0;
+        for (i, (child, node)) in self.children.iter().zip(nodes).enumerate() {
+            if !is_collapsed::<D>(node.size()) {
+                *node = child.as_widget().layout(
+                    &mut tree.children[i],
+                    renderer,
+                    &layout::Limits::new(largest, largest),
+                );
+                main += D::main_axis(node.size().width, node.size().height);
+            }
+        }
+
+        (main, D::cross_axis(largest.width, largest.height))
+    }
+}
+
 impl<'a, D, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Stack<'a, D, Message, Theme, Renderer>
 where
@@ -164,26 +420,30 @@ where
         Size::new(self.width, self.height)
     }
 
+    // Child counts stay far below where `f32` loses integer precision.
+    #[allow(clippy::cast_precision_loss)]
     fn layout(
         &self,
         tree: &mut widget::Tree,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits.width(self.width).height(self.height);
+        // For horizontal: main axis is width, cross axis is height
+        // For vertical: main axis is height, cross axis is width
+        let is_horizontal = D::is_horizontal();
+
+        let limits = self.constrain(limits);
         let padding = self.padding;
+        let spacing = *self.spacing.get(self.breakpoints.tier(limits.max().width));
 
         if self.children.is_empty() {
             return layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO));
         }
 
-        // For horizontal: main axis is width, cross axis is height
-        // For vertical: main axis is height, cross axis is width
-        let is_horizontal = D::is_horizontal();
-
         let mut nodes = Vec::with_capacity(self.children.len());
         let mut main_axis_used = padding * 2.0;
         let mut cross_axis_max: f32 = 0.0;
+        let mut visible = 0_usize;
 
         for (i, child) in self.children.iter().enumerate() {
             let child_limits = if is_horizontal {
@@ -205,11 +465,43 @@ where
                 cross_axis_max = cross_axis_max.max(size.width);
             }
 
+            if !is_collapsed::<D>(size) {
+                visible += 1;
+            }
+
             nodes.push(node);
         }
 
-        // Add spacing between children
-        main_axis_used += self.spacing * (self.children.len().saturating_sub(1)) as f32;
+        if self.equal_children {
+            let (main, cross) = self.equalize(tree, renderer, &mut nodes);
+            main_axis_used = padding.mul_add(2.0, main);
+            cross_axis_max = cross;
+        }
+
+        // With baseline alignment the cross extent is the tallest ascent plus
+        // the deepest descent, which can exceed the tallest child.
+        let baselines: Vec<f32> = if is_horizontal && self.align == CrossAxis::Baseline {
+            tree.children
+                .iter()
+                .zip(&nodes)
+                .map(|(tree, node)| baseline_offset(tree, node))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let baseline =
+            (!baselines.is_empty()).then(|| baselines.iter().copied().fold(0.0_f32, f32::max));
+
+        if let Some(baseline) = baseline {
+            cross_axis_max = nodes
+                .iter()
+                .zip(&baselines)
+                .map(|(node, offset)| baseline - offset + node.size().height)
+                .fold(0.0_f32, f32::max);
+        }
+
+        // Add spacing between children, skipping hidden ones
+        main_axis_used += spacing * visible.saturating_sub(1) as f32;
 
         // Calculate total size
         let total_size = if is_horizontal {
@@ -228,7 +520,7 @@ where
             total_size.width - padding * 2.0
         };
 
-        for node in &mut nodes {
+        for (i, (node, align)) in nodes.iter_mut().zip(&self.alignments).enumerate() {
             let size = node.size();
             let cross_size = if is_horizontal {
                 size.height
@@ -237,12 +529,11 @@ where
             };
 
             // Calculate cross-axis offset based on alignment
-            let cross_offset = match self.align.into() {
-                iced::alignment::Alignment::Start => padding,
-                iced::alignment::Alignment::Center => {
-                    padding + (cross_available - cross_size) / 2.0
-                }
-                iced::alignment::Alignment::End => padding + cross_available - cross_size,
+            let cross_offset = match (align.unwrap_or(self.align), baseline) {
+                (CrossAxis::Baseline, Some(baseline)) => padding + baseline - baselines[i],
+                (CrossAxis::Start | CrossAxis::Baseline, _) => padding,
+                (CrossAxis::Center, _) => padding + (cross_available - cross_size) / 2.0,
+                (CrossAxis::End, _) => padding + cross_available - cross_size,
             };
 
             let position = if is_horizontal {
@@ -253,11 +544,9 @@ where
 
             node.move_to_mut(position);
 
-            main_offset += if is_horizontal {
-                size.width
-            } else {
-                size.height
-            } + self.spacing;
+            if !is_collapsed::<D>(size) {
+                main_offset += D::main_axis(size.width, size.height) + spacing;
+            }
         }
 
         layout::Node::with_children(total_size, nodes)
@@ -364,15 +653,27 @@ where
         cursor: iced::mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((child, state), layout) in self
-            .children
-            .iter()
-            .zip(&tree.children)
-            .zip(layout.children())
-        {
-            child
-                .as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        let draw_children = |renderer: &mut Renderer, viewport: &Rectangle| {
+            for ((child, state), layout) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                child
+                    .as_widget()
+                    .draw(state, renderer, theme, style, layout, cursor, viewport);
+            }
+        };
+
+        match self.overflow {
+            Overflow::Visible => draw_children(renderer, viewport),
+            Overflow::Clip => {
+                let bounds = layout.bounds();
+                if let Some(clipped) = bounds.intersection(viewport) {
+                    renderer.with_layer(bounds, |renderer| draw_children(renderer, &clipped));
+                }
+            }
         }
     }
 }
@@ -389,3 +690,274 @@ where
         Self::new(stack)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Recorder;
+    use iced::advanced::renderer::Quad;
+    use iced::advanced::Renderer as _;
+    use iced::widget::Space;
+
+    fn resolve<D: Direction>(stack: &Stack<'_, D, (), (), ()>, available: Size) -> Size {
+        let mut tree = widget::Tree::new(stack as &dyn Widget<(), (), ()>);
+        let limits = layout::Limits::new(Size::ZERO, available);
+
+        stack.layout(&mut tree, &(), &limits).size()
+    }
+
+    #[test]
+    fn push_aligned_overrides_the_stack_alignment() {
+        use iced::alignment::Horizontal;
+
+        let column: VStack<'_, (), (), ()> = VStack::new()
+            .width(200.0)
+            .push_aligned(
+                Space::new(Length::Fixed(80.0), Length::Fixed(20.0)),
+                Horizontal::Left,
+            )
+            .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)))
+            .push(Space::new(Length::Fixed(60.0), Length::Fixed(20.0)));
+        let snapshot = crate::testing::measure(column, Size::new(400.0, 400.0));
+
+        // The header hugs the left edge; the rest stay centered.
+        let x: Vec<f32> = snapshot.child_bounds().iter().map(|b| b.x).collect();
+        assert_eq!(x, [0.0, 80.0, 70.0]);
+    }
+
+    #[test]
+    fn zero_size_children_take_no_spacing() {
+        let row: HStack<'_, (), (), ()> = HStack::new()
+            .spacing(10.0)
+            .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)))
+            .push(Space::new(Length::Fixed(0.0), Length::Fixed(0.0)))
+            .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)));
+        let snapshot = crate::testing::measure(row, Size::new(400.0, 400.0));
+
+        assert!((snapshot.bounds.width - 90.0).abs() < f32::EPSILON);
+        assert!((snapshot.children[2].bounds.x - 50.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn responsive_spacing_follows_the_available_width() {
+        let gap = |width: f32| {
+            let column: VStack<'_, (), (), ()> = VStack::new()
+                .spacing_responsive(Responsive::new(4.0).md(16.0))
+                .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)))
+                .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)));
+            let children = crate::testing::measure(column, Size::new(width, 600.0)).child_bounds();
+
+            children[1].y - children[0].y - children[0].height
+        };
+
+        assert!((gap(800.0) - 16.0).abs() < f32::EPSILON);
+        assert!((gap(400.0) - 4.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn equal_children_match_the_largest_card() {
+        let card = |content_height: f32| {
+            iced::widget::container(Space::new(
+                Length::Fixed(80.0),
+                Length::Fixed(content_height),
+            ))
+            .padding(8)
+        };
+        let row = |equal: bool| -> HStack<'_, (), iced::Theme, ()> {
+            HStack::new()
+                .spacing(12.0)
+                .equal_children(equal)
+                .push(card(40.0))
+                .push(card(120.0))
+                .push(card(80.0))
+        };
+
+        let equal = crate::testing::measure(row(true), Size::new(800.0, 600.0));
+        for card in equal.child_bounds() {
+            assert_eq!(card.size(), Size::new(96.0, 136.0));
+        }
+        assert_eq!(equal.bounds.size(), Size::new(312.0, 136.0));
+
+        let packed = crate::testing::measure(row(false), Size::new(800.0, 600.0)).child_bounds();
+        assert!((packed[0].height - 56.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn max_main_caps_filling_stack() {
+        let row: HStack<'_, (), (), ()> = HStack::new()
+            .width(Length::Fill)
+            .max_main(600.0)
+            .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)));
+        let size = resolve(&row, Size::new(1000.0, 400.0));
+        assert!((size.width - 600.0).abs() < f32::EPSILON);
+
+        let column: VStack<'_, (), (), ()> = VStack::new()
+            .height(Length::Fill)
+            .max_main(600.0)
+            .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)));
+        let size = resolve(&column, Size::new(400.0, 1000.0));
+        assert!((size.height - 600.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn max_cross_caps_filling_column_width() {
+        let column: VStack<'_, (), (), ()> = VStack::new()
+            .width(Length::Fill)
+            .max_cross(600.0)
+            .push(Space::new(Length::Fill, Length::Fixed(20.0)));
+        let mut tree = widget::Tree::new(&column as &dyn Widget<(), (), ()>);
+        let node = column.layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 400.0)),
+        );
+
+        assert!((node.size().width - 600.0).abs() < f32::EPSILON);
+        assert!((node.children()[0].size().width - 600.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn min_main_grows_shrinking_stack() {
+        let row: HStack<'_, (), (), ()> = HStack::new()
+            .min_main(300.0)
+            .push(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)));
+        let size = resolve(&row, Size::new(1000.0, 400.0));
+        assert!((size.width - 300.0).abs() < f32::EPSILON);
+    }
+
+    /// A headless renderer that shapes text with real fonts.
+    fn shaping_renderer() -> iced::Renderer {
+        iced::Renderer::Secondary(iced_tiny_skia::Renderer::new(
+            iced::Font::default(),
+            iced::Pixels(16.0),
+        ))
+    }
+
+    /// Lay out `row` and return its node with the tree used to lay it out.
+    fn lay_out_row(
+        row: &HStack<'_, (), iced::Theme, iced::Renderer>,
+    ) -> (widget::Tree, layout::Node) {
+        let mut tree = widget::Tree::new(row as &dyn Widget<(), iced::Theme, iced::Renderer>);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = row.layout(&mut tree, &shaping_renderer(), &limits);
+
+        (tree, node)
+    }
+
+    #[test]
+    fn baseline_aligns_text_of_different_sizes() {
+        let row: HStack<'_, (), iced::Theme, iced::Renderer> = HStack::new()
+            .cross_axis(CrossAxis::Baseline)
+            .push(iced::widget::text("Total").size(14))
+            .push(iced::widget::text("42").size(40));
+        let (tree, node) = lay_out_row(&row);
+
+        // Baselines read from the shaped first lines, placed in the row.
+        let baselines: Vec<f32> = tree
+            .children
+            .iter()
+            .zip(node.children())
+            .map(|(tree, child)| {
+                child.bounds().y + first_baseline(tree, child).expect("shaped text")
+            })
+            .collect();
+
+        assert!((baselines[0] - baselines[1]).abs() < 1e-3);
+        // The small label moves down to the large value's baseline.
+        assert!(node.children()[0].bounds().y > node.children()[1].bounds().y);
+    }
+
+    #[test]
+    fn baseline_finds_text_nested_in_children() {
+        let row: HStack<'_, (), iced::Theme, iced::Renderer> = HStack::new()
+            .cross_axis(CrossAxis::Baseline)
+            .push(iced::widget::container(iced::widget::text("Label").size(14)).padding(10))
+            .push(iced::widget::text("Value").size(24))
+            .push(Space::new(Length::Fixed(16.0), Length::Fixed(16.0)));
+        let (tree, node) = lay_out_row(&row);
+        let children = node.children();
+
+        let label = children[0].bounds().y
+            + first_baseline(&tree.children[0], &children[0]).expect("nested text");
+        let value = children[1].bounds().y
+            + first_baseline(&tree.children[1], &children[1]).expect("shaped text");
+        let icon = children[2].bounds();
+
+        assert!((label - value).abs() < 1e-3);
+        // Children without text sit on the baseline.
+        assert!((icon.y + icon.height - value).abs() < 1e-3);
+    }
+
+    /// A 200x50 block that ignores its layout limits.
+    struct Oversized;
+
+    impl Widget<(), (), Recorder> for Oversized {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Shrink, Length::Shrink)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut widget::Tree,
+            _renderer: &Recorder,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::new(200.0, 50.0))
+        }
+
+        fn draw(
+            &self,
+            _tree: &widget::Tree,
+            renderer: &mut Recorder,
+            _theme: &(),
+            _style: &renderer::Style,
+            layout: Layout<'_>,
+            _cursor: iced::mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+            renderer.fill_quad(
+                Quad {
+                    bounds: layout.bounds(),
+                    ..Quad::default()
+                },
+                iced::Color::BLACK,
+            );
+        }
+    }
+
+    fn draw_oversized(clip: bool) -> Vec<Rectangle> {
+        let row: HStack<'_, (), (), Recorder> = HStack::new()
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(50.0))
+            .clip(clip)
+            .push(Element::new(Oversized));
+
+        let mut recorder = Recorder::default();
+        let mut tree = widget::Tree::new(&row as &dyn Widget<(), (), Recorder>);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = row.layout(&mut tree, &recorder, &limits);
+
+        row.draw(
+            &tree,
+            &mut recorder,
+            &(),
+            &renderer::Style::default(),
+            Layout::new(&node),
+            iced::mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(400.0, 400.0)),
+        );
+
+        recorder.visible()
+    }
+
+    #[test]
+    fn clip_confines_children_to_stack_bounds() {
+        let stack = Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0));
+
+        assert_eq!(draw_oversized(true), vec![stack]);
+        assert_eq!(
+            draw_oversized(false),
+            vec![Rectangle::new(Point::ORIGIN, Size::new(200.0, 50.0))]
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Layout snapshots for tests.
+//!
+//! Enabled by the `testing` feature; add it to the `dev-dependencies` of a
+//! crate whose tests use it.
+//!
+//! [`measure`] lays out an element headlessly, with iced's `()` renderer,
+//! and records the bounds of every layout node. Compare the result with
+//! `assert_eq!`, or its [`Display`](fmt::Display) text with a stored
+//! snapshot, to catch layout regressions:
+//!
+//! ```rust,ignore
+//! use iced_plus_layouts::testing::measure;
+//!
+//! let snapshot = measure(VStack::new().push(header).push(body), Size::new(800.0, 600.0));
+//! assert_eq!(snapshot.to_string(), include_str!("snapshots/page.txt"));
+//! ```
+//!
+//! Responsive widgets pick their layout from the width alone; [`with_width`]
+//! lays one out at a given window width, so a test can sweep the breakpoint
+//! tiers:
+//!
+//! ```rust,ignore
+//! for (width, stacked) in [(320.0, true), (900.0, false), (1400.0, false)] {
+//!     let children = with_width(row(), width).child_bounds();
+//!     assert_eq!(children[1].y > 0.0, stacked, "at {width}px");
+//! }
+//! ```
+//!
+//! [`Recorder`] is a renderer that keeps what widgets draw, for tests of
+//! colors, clipping and text.
+
+use std::fmt;
+
+use iced::advanced::graphics::geometry;
+use iced::advanced::layout::{Layout, Limits};
+use iced::advanced::renderer::{self, Quad};
+use iced::advanced::text;
+use iced::advanced::widget::Tree;
+use iced::border::Radius;
+use iced::{Background, Color, Element, Font, Pixels, Point, Rectangle, Size, Transformation};
+
+/// The bounds of a laid out node and of all its descendants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutSnapshot {
+    /// Absolute bounds of the node.
+    pub bounds: Rectangle,
+    /// Snapshots of the node's children, in layout order.
+    pub children: Vec<Self>,
+}
+
+impl LayoutSnapshot {
+    /// Capture `layout` and its descendants.
+    #[must_use]
+    pub fn capture(layout: Layout<'_>) -> Self {
+        Self {
+            bounds: layout.bounds(),
+            children: layout.children().map(Self::capture).collect(),
+        }
+    }
+
+    /// The bounds of the children, in layout order.
+    #[must_use]
+    pub fn child_bounds(&self) -> Vec<Rectangle> {
+        self.children.iter().map(|child| child.bounds).collect()
+    }
+
+    /// The bounds of this node and all its descendants, depth first.
+    #[must_use]
+    pub fn flatten(&self) -> Vec<Rectangle> {
+        let mut bounds = vec![self.bounds];
+        for child in &self.children {
+            bounds.extend(child.flatten());
+        }
+        bounds
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = self.bounds;
+        writeln!(
+            f,
+            "{:indent$}{x},{y} {width}x{height}",
+            "",
+            indent = depth * 2
+        )?;
+
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One line per node, `x,y widthxheight`, children indented under their
+/// parent.
+impl fmt::Display for LayoutSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// Lay out `element` within `size` and snapshot the resulting bounds.
+///
+/// The `()` renderer measures all text as empty, so snapshots of text
+/// reflect paddings and fixed sizes only.
+pub fn measure<'a, Message, Theme>(
+    element: impl Into<Element<'a, Message, Theme, ()>>,
+    size: Size,
+) -> LayoutSnapshot
+where
+    Message: 'a,
+    Theme: 'a,
+{
+    let element = element.into();
+    let mut tree = Tree::new(&element);
+    let node = element
+        .as_widget()
+        .layout(&mut tree, &(), &Limits::new(Size::ZERO, size));
+
+    LayoutSnapshot::capture(Layout::new(&node))
+}
+
+/// Lay out `element` at `width` with unbounded height, as in a vertical
+/// scrollable, and snapshot the resulting bounds.
+///
+/// Children that fill the height lay out infinitely tall; give them a fixed
+/// height, or use [`measure`] with a finite size.
+pub fn with_width<'a, Message, Theme>(
+    element: impl Into<Element<'a, Message, Theme, ()>>,
+    width: f32,
+) -> LayoutSnapshot
+where
+    Message: 'a,
+    Theme: 'a,
+{
+    measure(element, Size::new(width, f32::INFINITY))
+}
+
+/// A renderer recording the quads, text and layers drawn with it.
+///
+/// Layers clip what is drawn inside them, as in a real renderer: the
+/// [`visible`](DrawnQuad::visible) part of a quad is its intersection with
+/// every open layer.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    /// Every quad filled, in draw order.
+    pub quads: Vec<DrawnQuad>,
+    /// The content and color of every text drawn, in draw order.
+    pub texts: Vec<(String, Color)>,
+    /// The color of every paragraph drawn, e.g. by iced's `text`, in draw
+    /// order; paragraphs are measured empty, so their content is lost.
+    pub paragraphs: Vec<Color>,
+    /// The bounds of every layer started, in draw order.
+    pub layers: Vec<Rectangle>,
+    open_layers: Vec<Rectangle>,
+}
+
+/// A quad drawn on a [`Recorder`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawnQuad {
+    /// The bounds of the quad.
+    pub bounds: Rectangle,
+    /// The part of the quad inside all open layers, if any.
+    pub visible: Option<Rectangle>,
+    /// The fill color, if the background is a solid color.
+    pub color: Option<Color>,
+    /// The corner radii of the quad's border.
+    pub radius: Radius,
+}
+
+impl Recorder {
+    /// The colors of the solid quads, in draw order.
+    #[must_use]
+    pub fn colors(&self) -> Vec<Color> {
+        self.quads.iter().filter_map(|quad| quad.color).collect()
+    }
+
+    /// The bounds of all quads, in draw order.
+    #[must_use]
+    pub fn quad_bounds(&self) -> Vec<Rectangle> {
+        self.quads.iter().map(|quad| quad.bounds).collect()
+    }
+
+    /// The visible parts of all quads, in draw order; fully clipped quads
+    /// are left out.
+    #[must_use]
+    pub fn visible(&self) -> Vec<Rectangle> {
+        self.quads.iter().filter_map(|quad| quad.visible).collect()
+    }
+
+    /// The content of every text drawn, in draw order.
+    #[must_use]
+    pub fn text_contents(&self) -> Vec<&str> {
+        self.texts
+            .iter()
+            .map(|(content, _)| content.as_str())
+            .collect()
+    }
+}
+
+impl renderer::Renderer for Recorder {
+    fn start_layer(&mut self, bounds: Rectangle) {
+        self.layers.push(bounds);
+        self.open_layers.push(bounds);
+    }
+
+    fn end_layer(&mut self) {
+        self.open_layers.pop();
+    }
+
+    fn start_transformation(&mut self, _transformation: Transformation) {}
+
+    fn end_transformation(&mut self) {}
+
+    fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>) {
+        let visible = self
+            .open_layers
+            .iter()
+            .try_fold(quad.bounds, |visible, layer| visible.intersection(layer));
+        let color = match background.into() {
+            Background::Color(color) => Some(color),
+            Background::Gradient(_) => None,
+        };
+
+        self.quads.push(DrawnQuad {
+            bounds: quad.bounds,
+            visible,
+            color,
+            radius: quad.border.radius,
+        });
+    }
+
+    fn clear(&mut self) {
+        self.quads.clear();
+        self.texts.clear();
+        self.paragraphs.clear();
+        self.layers.clear();
+    }
+}
+
+/// Measures all text as empty, like the `()` renderer.
+impl text::Renderer for Recorder {
+    type Font = Font;
+    type Paragraph = ();
+    type Editor = ();
+
+    const ICON_FONT: Font = Font::DEFAULT;
+    const CHECKMARK_ICON: char = '0';
+    const ARROW_DOWN_ICON: char = '0';
+
+    fn default_font(&self) -> Font {
+        Font::default()
+    }
+
+    fn default_size(&self) -> Pixels {
+        Pixels(16.0)
+    }
+
+    fn fill_paragraph(&mut self, _paragraph: &(), _: Point, color: Color, _: Rectangle) {
+        self.paragraphs.push(color);
+    }
+
+    fn fill_editor(&mut self, _editor: &(), _: Point, _: Color, _: Rectangle) {}
+
+    fn fill_text(&mut self, text: text::Text, _: Point, color: Color, _: Rectangle) {
+        self.texts.push((text.content, color));
+    }
+}
+
+/// Draws no geometry, so canvas-based widgets can be drawn too.
+impl geometry::Renderer for Recorder {
+    type Geometry = ();
+    type Frame = ();
+
+    fn new_frame(&self, _size: Size) {}
+
+    fn draw_geometry(&mut self, _geometry: ()) {}
+}
+
+#[cfg(all(test, feature = "stacks"))]
+mod tests {
+    use super::*;
+    use crate::VStack;
+    use iced::widget::Space;
+    use iced::Length;
+
+    #[test]
+    fn vstack_of_two_fixed_children() {
+        let stack: VStack<'_, (), (), ()> = VStack::new()
+            .spacing(10.0)
+            .push(Space::new(Length::Fixed(100.0), Length::Fixed(40.0)))
+            .push(Space::new(Length::Fixed(60.0), Length::Fixed(20.0)));
+        let snapshot = measure(stack, Size::new(400.0, 300.0));
+
+        // Children are centered across the stack by default.
+        assert_eq!(
+            snapshot.child_bounds(),
+            [
+                Rectangle::new(iced::Point::ORIGIN, Size::new(100.0, 40.0)),
+                Rectangle::new(iced::Point::new(20.0, 50.0), Size::new(60.0, 20.0)),
+            ]
+        );
+        assert_eq!(
+            snapshot.to_string(),
+            "0,0 100x70\n  0,0 100x40\n  20,50 60x20\n"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Accessibility preferences of the operating system.
+//!
+//! iced doesn't report them, so [`detect_reduce_motion`] asks the OS
+//! directly. Apps read it once for their initial state and build their view
+//! inside `iced_plus_tokens::with_reduce_motion`, so components skip their
+//! animations:
+//!
+//! ```rust,ignore
+//! use iced_plus_platform::accessibility::detect_reduce_motion;
+//! use iced_plus_tokens::with_reduce_motion;
+//!
+//! fn new() -> Self {
+//!     Self {
+//!         reduce_motion: detect_reduce_motion().unwrap_or(false),
+//!         ..Self::default()
+//!     }
+//! }
+//!
+//! fn view(&self) -> Element<'_, Message> {
+//!     with_reduce_motion(self.reduce_motion, || self.page())
+//! }
+//! ```
+
+use std::process::Command;
+
+/// Whether the OS asks apps to reduce motion, if it reports it.
+///
+/// Reads "Reduce motion" on macOS, "Show animations in Windows" on Windows
+/// and the GNOME `enable-animations` setting elsewhere. This spawns a
+/// process, so call it at startup rather than from `view`.
+#[must_use]
+pub fn detect_reduce_motion() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    let reduce = run(
+        "defaults",
+        &["read", "com.apple.universalaccess", "reduceMotion"],
+    )
+    .and_then(|output| parse_macos(&output));
+
+    #[cfg(target_os = "windows")]
+    let reduce = run(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Control Panel\Desktop\WindowMetrics",
+            "/v",
+            "MinAnimate",
+        ],
+    )
+    .and_then(|output| parse_windows(&output));
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let reduce = run(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "enable-animations"],
+    )
+    .and_then(|output| parse_gnome(&output));
+
+    reduce
+}
+
+/// Standard output of `program`, if it ran and succeeded.
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `defaults read` prints `1` while reduce motion is on.
+#[cfg(any(target_os = "macos", test))]
+fn parse_macos(output: &str) -> Option<bool> {
+    match output.trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// `reg query` prints the value last on its line; `0` turns animations off.
+#[cfg(any(target_os = "windows", test))]
+fn parse_windows(output: &str) -> Option<bool> {
+    let line = output.lines().find(|line| line.contains("MinAnimate"))?;
+
+    match line.split_whitespace().last()? {
+        "0" => Some(true),
+        "1" => Some(false),
+        _ => None,
+    }
+}
+
+/// `gsettings get` prints whether animations are enabled.
+#[cfg(any(not(any(target_os = "macos", target_os = "windows")), test))]
+fn parse_gnome(output: &str) -> Option<bool> {
+    match output.trim() {
+        "false" => Some(true),
+        "true" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_platform_setting() {
+        assert_eq!(parse_macos("1\n"), Some(true));
+        assert_eq!(parse_macos("0\n"), Some(false));
+
+        let windows = |value| {
+            format!(
+                "\r\nHKEY_CURRENT_USER\\Control Panel\\Desktop\\WindowMetrics\r\n    MinAnimate    REG_SZ    {value}\r\n"
+            )
+        };
+        assert_eq!(parse_windows(&windows("0")), Some(true));
+        assert_eq!(parse_windows(&windows("1")), Some(false));
+
+        assert_eq!(parse_gnome("false\n"), Some(true));
+        assert_eq!(parse_gnome("true\n"), Some(false));
+
+        // Missing keys report nothing rather than a guess.
+        assert_eq!(parse_macos(""), None);
+        assert_eq!(parse_windows("ERROR: not found"), None);
+        assert_eq!(parse_gnome("No such schema"), None);
+    }
+}
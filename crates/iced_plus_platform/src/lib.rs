@@ -5,10 +5,12 @@
 //! - **Tray icons** - System tray/notification area integration
 //! - **Notifications** - Desktop notifications
 //! - **Hotkeys** - Global keyboard shortcuts
+//! - **Shortcuts** - In-app keyboard shortcuts via iced subscriptions
 //! - **Window** - Extended window management
 //! - **Audio** - Audio playback APIs
 //! - **Recording** - Audio and video recording APIs
 //! - **WebView** - Embedded web browser integration
+//! - **Accessibility** - OS accessibility preferences such as reduced motion
 //!
 //! # Platform Support
 //!
@@ -40,6 +42,9 @@ pub mod notifications;
 #[cfg(feature = "hotkeys")]
 pub mod hotkeys;
 
+#[cfg(feature = "shortcuts")]
+pub mod shortcuts;
+
 #[cfg(feature = "window")]
 pub mod window;
 
@@ -51,3 +56,6 @@ pub mod recording;
 
 #[cfg(feature = "webview")]
 pub mod webview;
+
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
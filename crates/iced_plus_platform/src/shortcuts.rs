@@ -0,0 +1,276 @@
+//! In-app keyboard shortcuts.
+//!
+//! Unlike global hotkeys, these only fire while the application
+//! window has focus. They are delivered through iced's event loop as a
+//! [`Subscription`], so key presses already handled by a widget (e.g. typing
+//! into a text input) are ignored.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use iced::keyboard::{key::Named, Modifiers};
+//! use iced_plus_platform::shortcuts::{Shortcut, Shortcuts};
+//!
+//! fn subscription(&self) -> Subscription<Message> {
+//!     Shortcuts::new()
+//!         .bind(Shortcut::char(Modifiers::COMMAND, 's'), Message::Save)
+//!         .bind(Shortcut::char(Modifiers::COMMAND | Modifiers::SHIFT, 'z'), Message::Redo)
+//!         .bind(Shortcut::named(Modifiers::empty(), Named::Escape), Message::Cancel)
+//!         .subscription()
+//! }
+//! ```
+
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::advanced::subscription::{self, EventStream, Hasher, Recipe};
+use iced::event;
+use iced::futures::stream::BoxStream;
+use iced::keyboard::{self, key, Key, Modifiers};
+use iced::Subscription;
+
+/// A single key combination.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// Modifier keys that must be held.
+    pub modifiers: Modifiers,
+    /// The main key.
+    pub key: Key,
+}
+
+impl Shortcut {
+    /// Create a shortcut from modifiers and a key.
+    #[must_use]
+    pub const fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Create a shortcut for a character key.
+    ///
+    /// Characters are matched case-insensitively, so `'s'` also matches
+    /// the `S` reported while Shift is held.
+    #[must_use]
+    pub fn char(modifiers: Modifiers, c: char) -> Self {
+        Self::new(
+            modifiers,
+            Key::Character(c.to_lowercase().collect::<String>().into()),
+        )
+    }
+
+    /// Create a shortcut for a named key (Escape, Enter, arrows, ...).
+    #[must_use]
+    pub const fn named(modifiers: Modifiers, named: key::Named) -> Self {
+        Self::new(modifiers, Key::Named(named))
+    }
+
+    /// Check whether a key press matches this shortcut.
+    #[must_use]
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        if !same_modifiers(self.modifiers, modifiers) {
+            return false;
+        }
+
+        match (&self.key, key) {
+            (Key::Character(expected), Key::Character(pressed)) => {
+                expected.to_lowercase() == pressed.to_lowercase()
+            }
+            (expected, pressed) => expected == pressed,
+        }
+    }
+}
+
+/// Compare the modifiers that matter for shortcuts, ignoring lock keys.
+fn same_modifiers(a: Modifiers, b: Modifiers) -> bool {
+    a.shift() == b.shift()
+        && a.control() == b.control()
+        && a.alt() == b.alt()
+        && a.logo() == b.logo()
+}
+
+/// A set of in-app keyboard shortcuts mapped to messages.
+#[derive(Debug, Clone)]
+pub struct Shortcuts<Message> {
+    bindings: Vec<(Shortcut, Message)>,
+}
+
+impl<Message> Shortcuts<Message> {
+    /// Create an empty set of shortcuts.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Bind a shortcut to a message.
+    ///
+    /// Binding the same shortcut twice keeps the first binding.
+    #[must_use]
+    pub fn bind(mut self, shortcut: Shortcut, message: Message) -> Self {
+        self.bindings.push((shortcut, message));
+        self
+    }
+
+    /// Number of bound shortcuts.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Whether no shortcuts are bound.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Get all bound shortcuts.
+    pub fn iter(&self) -> impl Iterator<Item = (&Shortcut, &Message)> {
+        self.bindings
+            .iter()
+            .map(|(shortcut, message)| (shortcut, message))
+    }
+}
+
+impl<Message: Clone> Shortcuts<Message> {
+    /// Get the message bound to a key press, if any.
+    #[must_use]
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> Option<Message> {
+        self.bindings
+            .iter()
+            .find(|(shortcut, _)| shortcut.matches(key, modifiers))
+            .map(|(_, message)| message.clone())
+    }
+}
+
+impl<Message> Shortcuts<Message>
+where
+    Message: Clone + Hash + Send + 'static,
+{
+    /// Listen for the bound shortcuts.
+    ///
+    /// The subscription is keyed on the bindings, both key combinations and
+    /// messages, so changing either restarts it.
+    pub fn subscription(self) -> Subscription<Message> {
+        subscription::from_recipe(ShortcutRecipe { shortcuts: self })
+    }
+}
+
+impl<Message> Default for Shortcuts<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ShortcutRecipe<Message> {
+    shortcuts: Shortcuts<Message>,
+}
+
+impl<Message> Recipe for ShortcutRecipe<Message>
+where
+    Message: Clone + Hash + Send + 'static,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.shortcuts.bindings.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<'static, Message> {
+        use iced::futures::{future, StreamExt};
+
+        input
+            .filter_map(move |event| {
+                future::ready(match event {
+                    subscription::Event::Interaction {
+                        event:
+                            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                                key, modifiers, ..
+                            }),
+                        status: event::Status::Ignored,
+                        ..
+                    } => self.shortcuts.matches(&key, modifiers),
+                    subscription::Event::Interaction { .. } => None,
+                })
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Hash)]
+    enum Message {
+        Save,
+        Cancel,
+    }
+
+    fn shortcuts() -> Shortcuts<Message> {
+        Shortcuts::new()
+            .bind(Shortcut::char(Modifiers::CTRL, 's'), Message::Save)
+            .bind(
+                Shortcut::named(Modifiers::empty(), key::Named::Escape),
+                Message::Cancel,
+            )
+    }
+
+    #[test]
+    fn emits_mapped_message() {
+        let shortcuts = shortcuts();
+
+        assert_eq!(
+            shortcuts.matches(&Key::Character("s".into()), Modifiers::CTRL),
+            Some(Message::Save)
+        );
+        assert_eq!(
+            shortcuts.matches(&Key::Named(key::Named::Escape), Modifiers::empty()),
+            Some(Message::Cancel)
+        );
+    }
+
+    #[test]
+    fn ignores_other_combos() {
+        let shortcuts = shortcuts();
+
+        assert_eq!(
+            shortcuts.matches(&Key::Character("s".into()), Modifiers::empty()),
+            None
+        );
+        assert_eq!(
+            shortcuts.matches(
+                &Key::Character("s".into()),
+                Modifiers::CTRL | Modifiers::SHIFT
+            ),
+            None
+        );
+        assert_eq!(
+            shortcuts.matches(&Key::Character("d".into()), Modifiers::CTRL),
+            None
+        );
+    }
+
+    fn recipe_hash(shortcuts: Shortcuts<Message>) -> u64 {
+        use std::hash::Hasher as _;
+
+        let mut hasher = Hasher::default();
+        ShortcutRecipe { shortcuts }.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn rebinding_a_message_restarts_the_subscription() {
+        let save = Shortcut::char(Modifiers::CTRL, 's');
+        let bound_to = |message| Shortcuts::new().bind(save.clone(), message);
+
+        assert_eq!(
+            recipe_hash(bound_to(Message::Save)),
+            recipe_hash(bound_to(Message::Save))
+        );
+        assert_ne!(
+            recipe_hash(bound_to(Message::Save)),
+            recipe_hash(bound_to(Message::Cancel))
+        );
+    }
+}
@@ -15,6 +15,26 @@
 //! // Navigate to a URL
 //! webview.send(WebViewCommand::Navigate("https://example.com".into()));
 //! ```
+//!
+//! # Embedded surfaces
+//!
+//! A [`WebViewSurface`] is a webview embedded in a region of an application
+//! window, e.g. one built with `wry`. Its events drive a [`WebViewState`]:
+//!
+//! ```rust,ignore
+//! fn update(&mut self, message: Message) {
+//!     match message {
+//!         Message::Go(url) => self.surface.navigate(&url)?,
+//!         Message::Back => self.surface.back()?,
+//!         // On every frame, or from a subscription woken by the backend.
+//!         Message::Tick => {
+//!             for event in self.state.sync(&mut self.surface) {
+//!                 // Handle downloads, page messages, new windows...
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
 
 use std::collections::HashMap;
 
@@ -150,6 +170,55 @@ impl WebViewState {
             .map(|u| u.starts_with("https://"))
             .unwrap_or(false)
     }
+
+    /// Update the state from an event of its webview.
+    ///
+    /// Events that carry no state, like [`WebViewEvent::MessageReceived`],
+    /// are left for the application to handle.
+    pub fn apply(&mut self, event: &WebViewEvent) {
+        match event {
+            WebViewEvent::NavigationStarted(url) => {
+                self.url = Some(url.clone());
+                self.loading = true;
+                self.progress = 0.0;
+                self.error = None;
+            }
+            WebViewEvent::NavigationCompleted(url) => {
+                self.url = Some(url.clone());
+                self.loading = false;
+                self.progress = 1.0;
+                self.security.secure = self.is_secure();
+            }
+            WebViewEvent::NavigationFailed { url, error } => {
+                self.url = Some(url.clone());
+                self.loading = false;
+                self.error = Some(error.clone());
+            }
+            WebViewEvent::TitleChanged(title) => self.title = Some(title.clone()),
+            WebViewEvent::ProgressChanged(progress) => self.progress = progress.clamp(0.0, 1.0),
+            WebViewEvent::HistoryChanged {
+                can_go_back,
+                can_go_forward,
+            } => {
+                self.can_go_back = *can_go_back;
+                self.can_go_forward = *can_go_forward;
+            }
+            WebViewEvent::FocusChanged(focused) => self.focused = *focused,
+            WebViewEvent::Error(error) => self.error = Some(error.clone()),
+            _ => {}
+        }
+    }
+
+    /// Apply every pending event of `surface`, returning them so the
+    /// application can handle the rest.
+    pub fn sync<S>(&mut self, surface: &mut S) -> Vec<WebViewEvent>
+    where
+        S: WebViewSurface + ?Sized,
+    {
+        std::iter::from_fn(|| surface.poll_event())
+            .inspect(|event| self.apply(event))
+            .collect()
+    }
 }
 
 /// Security information for the current page.
@@ -211,6 +280,13 @@ pub enum WebViewEvent {
     Ready,
     /// Focus changed.
     FocusChanged(bool),
+    /// The back/forward history changed.
+    HistoryChanged {
+        /// Whether there is a page to go back to.
+        can_go_back: bool,
+        /// Whether there is a page to go forward to.
+        can_go_forward: bool,
+    },
     /// Error occurred.
     Error(String),
 }
@@ -325,6 +401,77 @@ pub trait WebViewBackend: Send {
     fn handle_permission(&self, permission: Permission, allow: bool);
 }
 
+/// A webview embedded in a region of an application window.
+///
+/// Unlike [`WebViewBackend`], a surface is driven from the application
+/// thread, like the window it lives in: commands are methods, and what the
+/// page does comes back as [`WebViewEvent`]s to feed a [`WebViewState`]
+/// through [`WebViewState::sync`]. Commands report only whether they were
+/// accepted; the state changes once the matching events arrive.
+pub trait WebViewSurface {
+    /// Start loading `url`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`WebViewError::InvalidUrl`] if the surface cannot load
+    /// `url`.
+    fn navigate(&mut self, url: &str) -> Result<(), WebViewError>;
+
+    /// Go back in history.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is no page to go back to.
+    fn back(&mut self) -> Result<(), WebViewError>;
+
+    /// Go forward in history.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is no page to go forward to.
+    fn forward(&mut self) -> Result<(), WebViewError>;
+
+    /// Reload the current page.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the surface is gone, e.g. after its window closed.
+    fn reload(&mut self) -> Result<(), WebViewError>;
+
+    /// Move the surface to `bounds`, in logical pixels of its window.
+    fn set_bounds(&mut self, bounds: iced::Rectangle);
+
+    /// Take the next pending event, if any.
+    fn poll_event(&mut self) -> Option<WebViewEvent>;
+
+    /// The pending events, in the order they happened.
+    fn events(&mut self) -> SurfaceEvents<'_, Self>
+    where
+        Self: Sized,
+    {
+        SurfaceEvents { surface: self }
+    }
+}
+
+/// Iterator over the pending events of a [`WebViewSurface`].
+///
+/// Created by [`WebViewSurface::events`].
+#[derive(Debug)]
+pub struct SurfaceEvents<'a, S: ?Sized> {
+    surface: &'a mut S,
+}
+
+impl<S> Iterator for SurfaceEvents<'_, S>
+where
+    S: WebViewSurface + ?Sized,
+{
+    type Item = WebViewEvent;
+
+    fn next(&mut self) -> Option<WebViewEvent> {
+        self.surface.poll_event()
+    }
+}
+
 /// WebView errors.
 #[derive(Debug, Clone)]
 pub enum WebViewError {
@@ -419,3 +566,161 @@ pub mod urls {
         url.split('/').next()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// A surface that loads every page instantly, titled after its URL.
+    #[derive(Default)]
+    struct MockSurface {
+        history: Vec<String>,
+        current: usize,
+        pending: VecDeque<WebViewEvent>,
+    }
+
+    impl MockSurface {
+        fn load(&mut self, url: String) {
+            self.pending.extend([
+                WebViewEvent::NavigationStarted(url.clone()),
+                WebViewEvent::ProgressChanged(0.5),
+                WebViewEvent::TitleChanged(format!("Title of {url}")),
+                WebViewEvent::NavigationCompleted(url),
+                WebViewEvent::HistoryChanged {
+                    can_go_back: self.current > 0,
+                    can_go_forward: self.current + 1 < self.history.len(),
+                },
+            ]);
+        }
+
+        fn go_to(&mut self, index: usize) -> Result<(), WebViewError> {
+            let url = self
+                .history
+                .get(index)
+                .cloned()
+                .ok_or_else(|| WebViewError::NavigationFailed("no such page".into()))?;
+            self.current = index;
+            self.load(url);
+            Ok(())
+        }
+    }
+
+    impl WebViewSurface for MockSurface {
+        fn navigate(&mut self, url: &str) -> Result<(), WebViewError> {
+            if !urls::is_http(url) {
+                return Err(WebViewError::InvalidUrl(url.into()));
+            }
+            if !self.history.is_empty() {
+                self.history.truncate(self.current + 1);
+                self.current += 1;
+            }
+            self.history.push(url.into());
+            self.load(url.into());
+            Ok(())
+        }
+
+        fn back(&mut self) -> Result<(), WebViewError> {
+            let index = self
+                .current
+                .checked_sub(1)
+                .ok_or_else(|| WebViewError::NavigationFailed("no page to go back to".into()))?;
+            self.go_to(index)
+        }
+
+        fn forward(&mut self) -> Result<(), WebViewError> {
+            self.go_to(self.current + 1)
+        }
+
+        fn reload(&mut self) -> Result<(), WebViewError> {
+            self.go_to(self.current)
+        }
+
+        fn set_bounds(&mut self, _bounds: iced::Rectangle) {}
+
+        fn poll_event(&mut self) -> Option<WebViewEvent> {
+            self.pending.pop_front()
+        }
+    }
+
+    #[test]
+    fn navigation_updates_url_title_and_loading() {
+        let mut surface = MockSurface::default();
+        let mut state = WebViewState::new();
+
+        surface.navigate("https://example.com").unwrap();
+
+        // Halfway through, the page is still loading.
+        for event in surface.events().take(2) {
+            state.apply(&event);
+        }
+        assert_eq!(state.url.as_deref(), Some("https://example.com"));
+        assert!(state.loading);
+        assert!((state.progress - 0.5).abs() < f32::EPSILON);
+
+        state.sync(&mut surface);
+        assert!(!state.loading);
+        assert_eq!(state.title.as_deref(), Some("Title of https://example.com"));
+        assert!(state.security.secure);
+        assert!(surface.poll_event().is_none());
+    }
+
+    #[test]
+    fn history_follows_back_and_forward() {
+        let mut surface = MockSurface::default();
+        let mut state = WebViewState::new();
+
+        surface.navigate("https://a.example").unwrap();
+        surface.navigate("http://b.example").unwrap();
+        state.sync(&mut surface);
+        assert!(state.can_go_back);
+        assert!(!state.can_go_forward);
+        assert!(!state.security.secure);
+
+        surface.back().unwrap();
+        state.sync(&mut surface);
+        assert_eq!(state.url.as_deref(), Some("https://a.example"));
+        assert!(!state.can_go_back);
+        assert!(state.can_go_forward);
+
+        surface.forward().unwrap();
+        state.sync(&mut surface);
+        assert_eq!(state.url.as_deref(), Some("http://b.example"));
+        assert!(state.can_go_back);
+        assert!(!state.can_go_forward);
+
+        surface.reload().unwrap();
+        assert_eq!(state.sync(&mut surface).len(), 5);
+        assert_eq!(state.url.as_deref(), Some("http://b.example"));
+        assert!(state.can_go_back);
+    }
+
+    #[test]
+    fn failures_end_loading_with_an_error() {
+        let mut surface = MockSurface::default();
+        let mut state = WebViewState::new();
+
+        assert!(matches!(
+            surface.navigate("ftp://example.com"),
+            Err(WebViewError::InvalidUrl(_))
+        ));
+        assert!(surface.back().is_err());
+        assert!(state.sync(&mut surface).is_empty());
+
+        state.apply(&WebViewEvent::NavigationStarted(
+            "https://down.example".into(),
+        ));
+        state.apply(&WebViewEvent::NavigationFailed {
+            url: "https://down.example".into(),
+            error: "connection refused".into(),
+        });
+        assert!(!state.loading);
+        assert_eq!(state.error.as_deref(), Some("connection refused"));
+
+        // A new navigation clears the error.
+        state.apply(&WebViewEvent::NavigationStarted(
+            "https://up.example".into(),
+        ));
+        assert!(state.error.is_none());
+    }
+}
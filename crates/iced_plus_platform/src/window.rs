@@ -188,3 +188,42 @@ impl WindowSettings {
         self.state(WindowState::Fullscreen)
     }
 }
+
+/// A window action requested by in-app window chrome, such as the controls
+/// of a custom title bar in a borderless window.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // In update:
+/// Message::Window(action) => action.task(self.window_id),
+///
+/// // In view:
+/// TitleBar::new("Notes")
+///     .on_drag(Message::Window(WindowAction::Drag))
+///     .on_close(Message::Window(WindowAction::Close))
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowAction {
+    /// Start moving the window with the pressed mouse button.
+    Drag,
+    /// Minimize the window.
+    Minimize,
+    /// Maximize the window, or restore it if it is maximized.
+    ToggleMaximize,
+    /// Close the window.
+    Close,
+}
+
+impl WindowAction {
+    /// The task performing this action on window `id`.
+    #[must_use]
+    pub fn task<T>(self, id: iced::window::Id) -> iced::Task<T> {
+        match self {
+            Self::Drag => iced::window::drag(id),
+            Self::Minimize => iced::window::minimize(id, true),
+            Self::ToggleMaximize => iced::window::toggle_maximize(id),
+            Self::Close => iced::window::close(id),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Button styling for iced.
 
+use iced::border::Radius;
 use iced::widget::button;
 use iced::{Background, Border, Color};
 use iced_plus_tokens::Shade;
@@ -20,6 +21,11 @@ pub enum ButtonClass {
     Destructive,
     /// Outline button - bordered with transparent background.
     Outline,
+    /// Tonal button - low-emphasis primary container fill.
+    Tonal,
+    /// Primary button with its own corner radii, for buttons joined flush
+    /// to neighbouring controls.
+    Segment(Radius),
 }
 
 impl<'a> button::Catalog for AppTheme<'a> {
@@ -38,23 +44,46 @@ impl<'a> button::Catalog for AppTheme<'a> {
             ButtonClass::Ghost => ghost_style(self, status, is_dark),
             ButtonClass::Destructive => destructive_style(self, status, is_dark),
             ButtonClass::Outline => outline_style(self, status, is_dark),
+            ButtonClass::Tonal => tonal_style(self, status, is_dark),
+            ButtonClass::Segment(radius) => {
+                let mut style = primary_style(self, status, is_dark);
+                style.border.radius = *radius;
+                style
+            }
         }
     }
 }
 
+/// Overlay marking an interaction state on a transparent button: the
+/// content color (white on dark themes, black on light) at `opacity`.
+const fn state_layer(is_dark: bool, opacity: f32) -> Color {
+    let content = if is_dark { Color::WHITE } else { Color::BLACK };
+
+    Color {
+        a: opacity,
+        ..content
+    }
+}
+
 fn primary_style(theme: &AppTheme, status: button::Status, _is_dark: bool) -> button::Style {
     let (bg_shade, text_color) = match status {
         button::Status::Active => (Shade::S500, Color::WHITE),
         button::Status::Hovered => (Shade::S600, Color::WHITE),
         button::Status::Pressed => (Shade::S700, Color::WHITE),
-        button::Status::Disabled => (Shade::S300, Color::from_rgba(1.0, 1.0, 1.0, 0.5)),
+        button::Status::Disabled => (
+            Shade::S300,
+            Color {
+                a: theme.opacity().disabled,
+                ..Color::WHITE
+            },
+        ),
     };
 
     button::Style {
         background: Some(Background::Color(theme.primary(bg_shade))),
         text_color,
         border: Border {
-            radius: theme.radius().md().into(),
+            radius: theme.tokens().button_radius().into(),
             ..Border::default()
         },
         shadow: iced::Shadow::default(),
@@ -91,7 +120,7 @@ fn secondary_style(theme: &AppTheme, status: button::Status, is_dark: bool) -> b
         background: Some(Background::Color(theme.neutral(bg_shade))),
         text_color: theme.neutral(text_shade),
         border: Border {
-            radius: theme.radius().md().into(),
+            radius: theme.tokens().button_radius().into(),
             ..Border::default()
         },
         shadow: iced::Shadow::default(),
@@ -104,22 +133,14 @@ fn ghost_style(theme: &AppTheme, status: button::Status, is_dark: bool) -> butto
             Color::TRANSPARENT,
             if is_dark { Shade::S100 } else { Shade::S700 },
         ),
-        button::Status::Hovered => {
-            let bg = if is_dark {
-                Color::from_rgba(1.0, 1.0, 1.0, 0.1)
-            } else {
-                Color::from_rgba(0.0, 0.0, 0.0, 0.05)
-            };
-            (bg, if is_dark { Shade::S50 } else { Shade::S900 })
-        }
-        button::Status::Pressed => {
-            let bg = if is_dark {
-                Color::from_rgba(1.0, 1.0, 1.0, 0.15)
-            } else {
-                Color::from_rgba(0.0, 0.0, 0.0, 0.1)
-            };
-            (bg, if is_dark { Shade::S50 } else { Shade::S900 })
-        }
+        button::Status::Hovered => (
+            state_layer(is_dark, theme.opacity().hover),
+            if is_dark { Shade::S50 } else { Shade::S900 },
+        ),
+        button::Status::Pressed => (
+            state_layer(is_dark, theme.opacity().pressed),
+            if is_dark { Shade::S50 } else { Shade::S900 },
+        ),
         button::Status::Disabled => (Color::TRANSPARENT, Shade::S400),
     };
 
@@ -127,7 +148,7 @@ fn ghost_style(theme: &AppTheme, status: button::Status, is_dark: bool) -> butto
         background: Some(Background::Color(bg)),
         text_color: theme.neutral(text_shade),
         border: Border {
-            radius: theme.radius().md().into(),
+            radius: theme.tokens().button_radius().into(),
             ..Border::default()
         },
         shadow: iced::Shadow::default(),
@@ -139,14 +160,20 @@ fn destructive_style(theme: &AppTheme, status: button::Status, _is_dark: bool) -
         button::Status::Active => (Shade::S500, Color::WHITE),
         button::Status::Hovered => (Shade::S600, Color::WHITE),
         button::Status::Pressed => (Shade::S700, Color::WHITE),
-        button::Status::Disabled => (Shade::S300, Color::from_rgba(1.0, 1.0, 1.0, 0.5)),
+        button::Status::Disabled => (
+            Shade::S300,
+            Color {
+                a: theme.opacity().disabled,
+                ..Color::WHITE
+            },
+        ),
     };
 
     button::Style {
         background: Some(Background::Color(theme.destructive(bg_shade))),
         text_color,
         border: Border {
-            radius: theme.radius().md().into(),
+            radius: theme.tokens().button_radius().into(),
             ..Border::default()
         },
         shadow: iced::Shadow::default(),
@@ -160,41 +187,177 @@ fn outline_style(theme: &AppTheme, status: button::Status, is_dark: bool) -> but
             if is_dark { Shade::S600 } else { Shade::S300 },
             if is_dark { Shade::S100 } else { Shade::S700 },
         ),
+        button::Status::Hovered => (
+            state_layer(is_dark, theme.opacity().hover),
+            if is_dark { Shade::S500 } else { Shade::S400 },
+            if is_dark { Shade::S50 } else { Shade::S900 },
+        ),
+        button::Status::Pressed => (
+            state_layer(is_dark, theme.opacity().pressed),
+            if is_dark { Shade::S400 } else { Shade::S500 },
+            if is_dark { Shade::S50 } else { Shade::S900 },
+        ),
+        button::Status::Disabled => (Color::TRANSPARENT, Shade::S300, Shade::S400),
+    };
+
+    button::Style {
+        background: Some(Background::Color(bg)),
+        text_color: theme.neutral(text_shade),
+        border: Border {
+            radius: theme.tokens().button_radius().into(),
+            width: 1.0,
+            color: theme.neutral(border_shade),
+        },
+        shadow: iced::Shadow::default(),
+    }
+}
+
+fn tonal_style(theme: &AppTheme, status: button::Status, is_dark: bool) -> button::Style {
+    let (bg, text_color) = match status {
+        button::Status::Active => {
+            if is_dark {
+                (Shade::S800, Shade::S100)
+            } else {
+                (Shade::S100, Shade::S700)
+            }
+        }
         button::Status::Hovered => {
-            let bg = if is_dark {
-                Color::from_rgba(1.0, 1.0, 1.0, 0.05)
+            if is_dark {
+                (Shade::S700, Shade::S50)
             } else {
-                Color::from_rgba(0.0, 0.0, 0.0, 0.02)
-            };
-            (
-                bg,
-                if is_dark { Shade::S500 } else { Shade::S400 },
-                if is_dark { Shade::S50 } else { Shade::S900 },
-            )
+                (Shade::S200, Shade::S800)
+            }
         }
         button::Status::Pressed => {
-            let bg = if is_dark {
-                Color::from_rgba(1.0, 1.0, 1.0, 0.1)
+            if is_dark {
+                (Shade::S600, Shade::S50)
             } else {
-                Color::from_rgba(0.0, 0.0, 0.0, 0.05)
+                (Shade::S300, Shade::S900)
+            }
+        }
+        button::Status::Disabled => {
+            return button::Style {
+                background: Some(Background::Color(theme.neutral(Shade::S100))),
+                text_color: theme.neutral(Shade::S400),
+                border: Border {
+                    radius: theme.tokens().button_radius().into(),
+                    ..Border::default()
+                },
+                shadow: iced::Shadow::default(),
             };
-            (
-                bg,
-                if is_dark { Shade::S400 } else { Shade::S500 },
-                if is_dark { Shade::S50 } else { Shade::S900 },
-            )
         }
-        button::Status::Disabled => (Color::TRANSPARENT, Shade::S300, Shade::S400),
     };
 
     button::Style {
-        background: Some(Background::Color(bg)),
-        text_color: theme.neutral(text_shade),
+        background: Some(Background::Color(theme.primary(bg))),
+        text_color: theme.primary(text_color),
         border: Border {
-            radius: theme.radius().md().into(),
-            width: 1.0,
-            color: theme.neutral(border_shade),
+            radius: theme.tokens().button_radius().into(),
+            ..Border::default()
         },
         shadow: iced::Shadow::default(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::button::Catalog;
+
+    #[test]
+    fn tonal_uses_primary_container_colors() {
+        let light = AppTheme::light();
+        let style = light.style(&ButtonClass::Tonal, button::Status::Active);
+
+        assert_eq!(
+            style.background,
+            Some(Background::Color(light.primary(Shade::S100)))
+        );
+        assert_eq!(style.text_color, light.primary(Shade::S700));
+
+        let dark = AppTheme::dark();
+        let style = dark.style(&ButtonClass::Tonal, button::Status::Active);
+
+        assert_eq!(
+            style.background,
+            Some(Background::Color(dark.primary(Shade::S800)))
+        );
+        assert_eq!(style.text_color, dark.primary(Shade::S100));
+    }
+    #[test]
+    fn state_overlays_use_opacity_tokens() {
+        let light = AppTheme::light();
+        let opacity = *light.opacity();
+
+        for class in [ButtonClass::Ghost, ButtonClass::Outline] {
+            let hovered = light.style(&class, button::Status::Hovered);
+            let pressed = light.style(&class, button::Status::Pressed);
+
+            assert_eq!(
+                hovered.background,
+                Some(Background::Color(Color {
+                    a: opacity.hover,
+                    ..Color::BLACK
+                }))
+            );
+            assert_eq!(
+                pressed.background,
+                Some(Background::Color(Color {
+                    a: opacity.pressed,
+                    ..Color::BLACK
+                }))
+            );
+        }
+
+        // Custom tokens change the overlays.
+        let mut tokens = light.tokens().clone();
+        tokens.opacity.hover = 0.2;
+        let custom = AppTheme::custom(tokens, "dark");
+        let hovered = custom.style(&ButtonClass::Ghost, button::Status::Hovered);
+        assert_eq!(
+            hovered.background,
+            Some(Background::Color(Color {
+                a: 0.2,
+                ..Color::WHITE
+            }))
+        );
+
+        let disabled = light.style(&ButtonClass::Primary, button::Status::Disabled);
+        assert!((disabled.text_color.a - opacity.disabled).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn button_radius_override_leaves_cards_alone() {
+        use crate::catalog::ContainerClass;
+        use iced::widget::container;
+        use iced_plus_tokens::RadiusSize;
+
+        let light = AppTheme::light();
+        let tokens = light.tokens().clone().with_button_radius(RadiusSize::Full);
+        let pill = AppTheme::custom(tokens, "light");
+        let button_radius = |theme: &AppTheme, class| {
+            Catalog::style(theme, &class, button::Status::Active)
+                .border
+                .radius
+        };
+        let card_radius = |theme: &AppTheme| {
+            container::Catalog::style(theme, &ContainerClass::Card)
+                .border
+                .radius
+        };
+
+        assert_eq!(
+            button_radius(&pill, ButtonClass::Primary),
+            light.radius().full().into()
+        );
+        assert_eq!(
+            button_radius(&pill, ButtonClass::Outline),
+            light.radius().full().into()
+        );
+        assert_eq!(
+            button_radius(&light, ButtonClass::Primary),
+            light.radius().md().into()
+        );
+        assert_eq!(card_radius(&pill), card_radius(&light));
+    }
+}
@@ -55,7 +55,7 @@ fn card_style(theme: &AppTheme, is_dark: bool) -> container::Style {
     container::Style {
         background: Some(Background::Color(bg)),
         border: Border {
-            radius: theme.radius().lg().into(),
+            radius: theme.tokens().card_radius().into(),
             width: 1.0,
             color: border_color,
         },
@@ -1,5 +1,6 @@
 //! Text input styling for iced.
 
+use iced::border::Radius;
 use iced::widget::text_input;
 use iced::{Background, Border, Color};
 use iced_plus_tokens::Shade;
@@ -14,6 +15,14 @@ pub enum TextInputClass {
     Default,
     /// Filled input with background.
     Filled,
+    /// Default or filled input with its own corner radii, for inputs joined
+    /// flush to neighbouring controls.
+    Segment {
+        /// Whether to use the filled style.
+        filled: bool,
+        /// The corner radii.
+        radius: Radius,
+    },
 }
 
 impl<'a> text_input::Catalog for AppTheme<'a> {
@@ -29,6 +38,15 @@ impl<'a> text_input::Catalog for AppTheme<'a> {
         match class {
             TextInputClass::Default => default_style(self, status, is_dark),
             TextInputClass::Filled => filled_style(self, status, is_dark),
+            TextInputClass::Segment { filled, radius } => {
+                let mut style = if *filled {
+                    filled_style(self, status, is_dark)
+                } else {
+                    default_style(self, status, is_dark)
+                };
+                style.border.radius = *radius;
+                style
+            }
         }
     }
 }
@@ -98,7 +116,7 @@ fn default_style(theme: &AppTheme, status: text_input::Status, is_dark: bool) ->
     text_input::Style {
         background: Background::Color(bg),
         border: Border {
-            radius: theme.radius().md().into(),
+            radius: theme.tokens().input_radius().into(),
             width: border_width,
             color: border_color,
         },
@@ -174,7 +192,7 @@ fn filled_style(theme: &AppTheme, status: text_input::Status, is_dark: bool) ->
     text_input::Style {
         background: Background::Color(bg),
         border: Border {
-            radius: theme.radius().md().into(),
+            radius: theme.tokens().input_radius().into(),
             width: border_width,
             color: border_color,
         },
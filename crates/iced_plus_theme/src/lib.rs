@@ -9,6 +9,9 @@
 //! - [`ButtonClass`] - Button styling variants (Primary, Secondary, Ghost, etc.)
 //! - [`ContainerClass`] - Container styling variants (Card, Surface, Bordered)
 //! - [`TextInputClass`] - Text input styling variants (Default, Filled)
+//! - [`with_ui_scale`] - Factor for font sizes and component metrics, per view
+//!   or app-wide with [`set_ui_scale`]
+//! - [`ThemeMode`] - Light/dark mode, detected from the OS with the `dark-light` feature
 //!
 //! # Example
 //!
@@ -30,14 +33,20 @@
 
 mod catalog;
 mod private;
+pub mod scale;
 pub mod size;
 pub mod status;
+pub mod system;
 pub mod theme;
 pub mod variant;
 
 // Re-export main types
 pub use catalog::*;
+pub use scale::{scaled, set_ui_scale, ui_scale, with_ui_scale};
 pub use size::{ComponentSize, ExtraLarge, ExtraSmall, Large, Medium, Small};
 pub use status::Status;
+pub use system::ThemeMode;
+#[cfg(feature = "dark-light")]
+pub use system::{detect_mode, theme_mode_subscription};
 pub use theme::{token_to_iced, AppTheme};
-pub use variant::{ButtonVariant, Destructive, Ghost, Outline, Primary, Secondary};
+pub use variant::{ButtonVariant, Destructive, Ghost, Outline, Primary, Secondary, Tonal};
@@ -0,0 +1,105 @@
+//! UI scale applied to component metrics.
+//!
+//! Apps offering a "UI scale" setting keep the factor in their state and
+//! build their view inside [`with_ui_scale`]:
+//!
+//! ```rust,ignore
+//! fn view(&self) -> Element<'_, Message> {
+//!     with_ui_scale(self.settings.ui_scale, || self.page())
+//! }
+//! ```
+//!
+//! Components multiply their font sizes, paddings and heights by the factor
+//! when they are built, so the view alone decides how it is scaled. Outside
+//! [`with_ui_scale`], components fall back to the app-wide factor set with
+//! [`set_ui_scale`], which lives on the UI thread, where iced builds views.
+
+use std::cell::Cell;
+
+thread_local! {
+    static UI_SCALE: Cell<f32> = const { Cell::new(1.0) };
+    static SCOPED_UI_SCALE: Cell<Option<f32>> = const { Cell::new(None) };
+}
+
+/// Non-finite or non-positive factors mean no scaling.
+fn sanitize(scale: f32) -> f32 {
+    if scale.is_finite() && scale > 0.0 {
+        scale
+    } else {
+        1.0
+    }
+}
+
+/// Set the app-wide UI scale factor for components built on this thread
+/// outside [`with_ui_scale`].
+///
+/// Non-finite or non-positive factors reset the scale to `1.0`.
+pub fn set_ui_scale(scale: f32) {
+    UI_SCALE.with(|cell| cell.set(sanitize(scale)));
+}
+
+/// Build `view` with components scaled by `scale`, whatever the app-wide
+/// factor.
+///
+/// Scopes nest; the factor applies to components built inside `view` and
+/// is restored when it returns. Non-finite or non-positive factors mean no
+/// scaling.
+pub fn with_ui_scale<T>(scale: f32, view: impl FnOnce() -> T) -> T {
+    /// Restores the enclosing scope's factor, even if `view` panics.
+    struct Restore(Option<f32>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_UI_SCALE.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED_UI_SCALE.with(|cell| cell.replace(Some(sanitize(scale)))));
+
+    view()
+}
+
+/// The UI scale factor components are built with: that of the enclosing
+/// [`with_ui_scale`], or the app-wide one (`1.0` by default).
+#[must_use]
+pub fn ui_scale() -> f32 {
+    SCOPED_UI_SCALE
+        .with(Cell::get)
+        .unwrap_or_else(|| UI_SCALE.with(Cell::get))
+}
+
+/// Scale a size in pixels by the current UI scale factor.
+#[must_use]
+pub fn scaled(pixels: f32) -> f32 {
+    pixels * ui_scale()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_scale_resets_to_one() {
+        set_ui_scale(1.5);
+        assert!((scaled(10.0) - 15.0).abs() < f32::EPSILON);
+
+        set_ui_scale(0.0);
+        assert!((ui_scale() - 1.0).abs() < f32::EPSILON);
+        set_ui_scale(f32::NAN);
+        assert!((ui_scale() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn scoped_scale_overrides_the_app_wide_one() {
+        set_ui_scale(1.5);
+
+        let (outer, inner) =
+            with_ui_scale(2.0, || (scaled(10.0), with_ui_scale(0.5, || scaled(10.0))));
+        assert!((outer - 20.0).abs() < f32::EPSILON);
+        assert!((inner - 5.0).abs() < f32::EPSILON);
+
+        // The app-wide factor applies again once the scope ends.
+        assert!((scaled(10.0) - 15.0).abs() < f32::EPSILON);
+        set_ui_scale(1.0);
+    }
+}
@@ -0,0 +1,124 @@
+//! Following the operating system's light/dark preference.
+//!
+//! Detection needs the `dark-light` feature; without it [`ThemeMode`] and
+//! [`AppTheme::from_mode`] are still available for apps that track the
+//! mode themselves.
+
+use crate::AppTheme;
+
+/// A light or dark color scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ThemeMode {
+    /// Light color scheme.
+    #[default]
+    Light,
+    /// Dark color scheme.
+    Dark,
+}
+
+impl ThemeMode {
+    /// The opposite mode, for a manual toggle.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Light => Self::Dark,
+            Self::Dark => Self::Light,
+        }
+    }
+}
+
+impl AppTheme<'static> {
+    /// Create the preset theme for a mode.
+    #[must_use]
+    pub fn from_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Light => Self::light(),
+            ThemeMode::Dark => Self::dark(),
+        }
+    }
+
+    /// Create the preset theme matching the OS color scheme.
+    ///
+    /// Falls back to light when the OS does not report a preference.
+    #[cfg(feature = "dark-light")]
+    #[must_use]
+    pub fn system() -> Self {
+        system_theme(detect_mode)
+    }
+}
+
+/// The theme for whatever `detect` reports, light if it reports nothing.
+#[cfg(any(feature = "dark-light", test))]
+fn system_theme(detect: impl FnOnce() -> Option<ThemeMode>) -> AppTheme<'static> {
+    AppTheme::from_mode(detect().unwrap_or_default())
+}
+
+/// The OS color scheme, if it reports one.
+#[cfg(feature = "dark-light")]
+#[must_use]
+pub fn detect_mode() -> Option<ThemeMode> {
+    match dark_light::detect() {
+        dark_light::Mode::Dark => Some(ThemeMode::Dark),
+        dark_light::Mode::Light => Some(ThemeMode::Light),
+        dark_light::Mode::Default => None,
+    }
+}
+
+/// How often [`theme_mode_subscription`] checks the OS color scheme.
+#[cfg(feature = "dark-light")]
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Emits the new mode whenever the OS color scheme changes.
+///
+/// Nothing is emitted at startup; use [`AppTheme::system`] for the initial
+/// theme.
+///
+/// ```rust,ignore
+/// fn subscription(&self) -> Subscription<Message> {
+///     theme_mode_subscription().map(Message::SystemThemeChanged)
+/// }
+/// ```
+#[cfg(feature = "dark-light")]
+pub fn theme_mode_subscription() -> iced::Subscription<ThemeMode> {
+    iced::Subscription::run(mode_changes)
+}
+
+#[cfg(feature = "dark-light")]
+fn mode_changes() -> impl iced::futures::Stream<Item = ThemeMode> {
+    let (sender, receiver) = iced::futures::channel::mpsc::unbounded();
+
+    // Detection is blocking on some platforms, so poll off the executor.
+    std::thread::spawn(move || {
+        let mut last = detect_mode().unwrap_or_default();
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let mode = detect_mode().unwrap_or_default();
+            if mode != last {
+                last = mode;
+
+                // The subscription was dropped; stop polling.
+                if sender.unbounded_send(mode).is_err() {
+                    break;
+                }
+            } else if sender.is_closed() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_theme_maps_detected_scheme_to_preset() {
+        assert_eq!(system_theme(|| Some(ThemeMode::Dark)).name(), "dark");
+        assert_eq!(system_theme(|| Some(ThemeMode::Light)).name(), "light");
+        assert_eq!(system_theme(|| None).name(), "light");
+    }
+}
@@ -77,10 +77,11 @@ impl<'a> AppTheme<'a> {
         &self.tokens
     }
 
-    /// Get the spacing scale.
+    /// Get the spacing scale, multiplied by the current
+    /// [UI scale](crate::set_ui_scale).
     #[must_use]
-    pub fn spacing(&self) -> &iced_plus_tokens::SpacingScale {
-        &self.tokens.spacing
+    pub fn spacing(&self) -> iced_plus_tokens::SpacingScale {
+        iced_plus_tokens::SpacingScale::new(self.tokens.spacing.values().map(crate::scaled))
     }
 
     /// Get the color palette.
@@ -113,6 +114,12 @@ impl<'a> AppTheme<'a> {
         &self.tokens.motion
     }
 
+    /// Get the opacity scale.
+    #[must_use]
+    pub fn opacity(&self) -> &iced_plus_tokens::OpacityScale {
+        &self.tokens.opacity
+    }
+
     // Color helpers
 
     /// Get the primary color at a specific shade.
@@ -208,6 +215,44 @@ impl<'a> AppTheme<'a> {
     }
 }
 
+impl AppTheme<'_> {
+    /// Build an iced palette from the tokens.
+    ///
+    /// Primary, success and danger use shade 500 of their scales; background
+    /// and text follow [`AppTheme::background`] and [`AppTheme::text`].
+    #[must_use]
+    pub fn palette(&self) -> iced::theme::Palette {
+        iced::theme::Palette {
+            background: self.background(),
+            text: self.text(),
+            primary: self.primary(Shade::S500),
+            success: self.success(Shade::S500),
+            danger: self.destructive(Shade::S500),
+        }
+    }
+
+    /// Build an iced extended palette from the tokens.
+    #[must_use]
+    pub fn extended_palette(&self) -> iced::theme::palette::Extended {
+        iced::theme::palette::Extended::generate(self.palette())
+    }
+
+    /// Build an iced theme sharing this theme's palette.
+    ///
+    /// Use this to style raw iced widgets and the `iced::Theme` based
+    /// components with the same colors as the tokens.
+    #[must_use]
+    pub fn to_iced_theme(&self) -> iced::Theme {
+        iced::Theme::custom(self.name.to_string(), self.palette())
+    }
+}
+
+impl From<&AppTheme<'_>> for iced::Theme {
+    fn from(theme: &AppTheme<'_>) -> Self {
+        theme.to_iced_theme()
+    }
+}
+
 impl Default for AppTheme<'static> {
     fn default() -> Self {
         Self::light()
@@ -219,3 +264,24 @@ impl Default for AppTheme<'static> {
 pub fn token_to_iced(color: TokenColor) -> iced::Color {
     iced::Color::from_rgba(color.r, color.g, color.b, color.a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extended_palette_follows_custom_tokens() {
+        let mut tokens = ThemeTokens::default();
+        tokens.colors.primary.s500 = TokenColor::from_rgb8(0x12, 0x34, 0x56);
+
+        let theme = AppTheme::custom(tokens, "custom");
+        let expected = token_to_iced(theme.tokens().colors.primary.s500);
+
+        assert_eq!(theme.palette().primary, expected);
+        assert_eq!(theme.extended_palette().primary.base.color, expected);
+        assert_eq!(
+            theme.to_iced_theme().extended_palette().primary.base.color,
+            expected
+        );
+    }
+}
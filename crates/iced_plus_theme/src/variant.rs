@@ -30,6 +30,11 @@ impl Sealed for Destructive {}
 pub struct Outline;
 impl Sealed for Outline {}
 
+/// Tonal variant - low-emphasis primary container fill.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tonal;
+impl Sealed for Tonal {}
+
 /// Trait for button variants with compile-time style resolution.
 pub trait ButtonVariant: Sealed + Copy + Default {
     /// Human-readable name of this variant.
@@ -56,6 +61,10 @@ impl ButtonVariant for Outline {
     const NAME: &'static str = "outline";
 }
 
+impl ButtonVariant for Tonal {
+    const NAME: &'static str = "tonal";
+}
+
 /// Trait for input variants.
 pub trait InputVariant: Sealed + Copy + Default {
     /// Human-readable name of this variant.
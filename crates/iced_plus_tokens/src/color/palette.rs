@@ -39,6 +39,19 @@ impl ColorPalette {
     }
 }
 
+impl ColorPalette {
+    /// Mix every scale of `a` with the same scale of `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            primary: ColorScale::lerp(&a.primary, &b.primary, t),
+            secondary: ColorScale::lerp(&a.secondary, &b.secondary, t),
+            neutral: ColorScale::lerp(&a.neutral, &b.neutral, t),
+            semantic: SemanticColors::lerp(&a.semantic, &b.semantic, t),
+        }
+    }
+}
+
 impl Default for ColorPalette {
     fn default() -> Self {
         Self {
@@ -82,6 +95,19 @@ impl SemanticColors {
     }
 }
 
+impl SemanticColors {
+    /// Mix every scale of `a` with the same scale of `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            success: ColorScale::lerp(&a.success, &b.success, t),
+            warning: ColorScale::lerp(&a.warning, &b.warning, t),
+            destructive: ColorScale::lerp(&a.destructive, &b.destructive, t),
+            info: ColorScale::lerp(&a.info, &b.info, t),
+        }
+    }
+}
+
 impl Default for SemanticColors {
     fn default() -> Self {
         Self {
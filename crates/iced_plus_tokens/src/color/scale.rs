@@ -3,6 +3,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::interpolate::lerp;
+
 /// An RGBA color with f32 components in the range 0.0..=1.0.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -46,6 +48,18 @@ impl Color {
         )
     }
 
+    /// Mix this color with `other`: `0.0` gives this color, `1.0` gives
+    /// `other`, and values in between blend each channel linearly.
+    #[must_use]
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        Self {
+            r: lerp(self.r, other.r, t),
+            g: lerp(self.g, other.g, t),
+            b: lerp(self.b, other.b, t),
+            a: lerp(self.a, other.a, t),
+        }
+    }
+
     /// Return this color with a different alpha value.
     #[must_use]
     pub const fn with_alpha(self, a: f32) -> Self {
@@ -246,6 +260,13 @@ impl ColorScale {
         self.s700
     }
 
+    /// Mix every shade of `a` with the same shade of `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let (a, b) = (a.to_array(), b.to_array());
+        Self::from_array(std::array::from_fn(|i| a[i].mix(b[i], t)))
+    }
+
     /// Convert to an array of colors.
     #[must_use]
     pub const fn to_array(&self) -> [Color; 10] {
@@ -293,6 +314,14 @@ mod tests {
         assert_eq!(Shade::S900.value(), 900);
     }
 
+    #[test]
+    fn colors_mix_per_channel() {
+        let gray = Color::BLACK.mix(Color::WHITE, 0.5);
+        assert!((gray.r - 0.5).abs() < f32::EPSILON);
+        assert!((gray.a - 1.0).abs() < f32::EPSILON);
+        assert_eq!(Color::BLACK.mix(Color::WHITE, 2.0), Color::WHITE);
+    }
+
     #[test]
     fn color_scale_get() {
         let scale = ColorScale::default();
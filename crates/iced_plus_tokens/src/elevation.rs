@@ -1,6 +1,7 @@
 //! Elevation and shadow tokens.
 
 use crate::color::Color;
+use crate::interpolate::lerp;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -34,6 +35,18 @@ impl Shadow {
         }
     }
 
+    /// Interpolate every field from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            offset_x: lerp(a.offset_x, b.offset_x, t),
+            offset_y: lerp(a.offset_y, b.offset_y, t),
+            blur: lerp(a.blur, b.blur, t),
+            spread: lerp(a.spread, b.spread, t),
+            color: a.color.mix(b.color, t),
+        }
+    }
+
     /// No shadow.
     pub const NONE: Self = Self::new(0.0, 0.0, 0.0, 0.0, Color::TRANSPARENT);
 }
@@ -118,6 +131,30 @@ impl Elevation {
         }
     }
 
+    /// Interpolate every field from `a` to `b`.
+    ///
+    /// A secondary shadow present on only one side fades in or out.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let faded = |shadow: Shadow| Shadow {
+            color: shadow.color.with_alpha(0.0),
+            ..shadow
+        };
+        let shadow_secondary = match (a.shadow_secondary, b.shadow_secondary) {
+            (Some(a), Some(b)) => Some(Shadow::lerp(&a, &b, t)),
+            (Some(a), None) => Some(Shadow::lerp(&a, &faded(a), t)),
+            (None, Some(b)) => Some(Shadow::lerp(&faded(b), &b, t)),
+            (None, None) => None,
+        };
+
+        Self {
+            shadow: Shadow::lerp(&a.shadow, &b.shadow, t),
+            shadow_secondary,
+            border_width: lerp(a.border_width, b.border_width, t),
+            border_color: a.border_color.mix(b.border_color, t),
+        }
+    }
+
     /// Flat elevation (no visual elevation).
     pub const FLAT: Self = Self::new(Shadow::NONE);
 }
@@ -158,6 +195,20 @@ impl ElevationScale {
     }
 }
 
+impl ElevationScale {
+    /// Interpolate every level from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            flat: Elevation::lerp(&a.flat, &b.flat, t),
+            raised: Elevation::lerp(&a.raised, &b.raised, t),
+            overlay: Elevation::lerp(&a.overlay, &b.overlay, t),
+            floating: Elevation::lerp(&a.floating, &b.floating, t),
+            modal: Elevation::lerp(&a.modal, &b.modal, t),
+        }
+    }
+}
+
 impl Default for ElevationScale {
     fn default() -> Self {
         let shadow_color = Color::new(0.0, 0.0, 0.0, 0.1);
@@ -0,0 +1,242 @@
+//! Export tokens for other platforms.
+//!
+//! [`ThemeTokens::to_css_variables`] and
+//! [`ThemeTokens::to_style_dictionary_json`] render the same flat list of
+//! tokens, so the Rust tokens can stay the single source of truth for web
+//! and native front-ends alike.
+
+use std::fmt::Write as _;
+
+use crate::color::{Color, ColorScale, Shade};
+use crate::radius::RadiusSize;
+use crate::typography::TextStyleName;
+use crate::ThemeTokens;
+
+/// Spacing names in [`SpacingSize`](crate::SpacingSize) order.
+const SPACING_NAMES: [&str; 10] = [
+    "xxs", "xs", "sm", "md", "lg", "xl", "2xl", "3xl", "4xl", "5xl",
+];
+
+/// Radius sizes and their exported names.
+const RADIUS_NAMES: [(RadiusSize, &str); 8] = [
+    (RadiusSize::None, "none"),
+    (RadiusSize::Xs, "xs"),
+    (RadiusSize::Sm, "sm"),
+    (RadiusSize::Md, "md"),
+    (RadiusSize::Lg, "lg"),
+    (RadiusSize::Xl, "xl"),
+    (RadiusSize::Xl2, "2xl"),
+    (RadiusSize::Full, "full"),
+];
+
+/// Text style names in [`TextStyleName`] order.
+const TEXT_STYLES: [(TextStyleName, &str); 11] = [
+    (TextStyleName::DisplayXl, "display-xl"),
+    (TextStyleName::DisplayLg, "display-lg"),
+    (TextStyleName::HeadingLg, "heading-lg"),
+    (TextStyleName::HeadingMd, "heading-md"),
+    (TextStyleName::HeadingSm, "heading-sm"),
+    (TextStyleName::BodyLg, "body-lg"),
+    (TextStyleName::BodyMd, "body-md"),
+    (TextStyleName::BodySm, "body-sm"),
+    (TextStyleName::Code, "code"),
+    (TextStyleName::Label, "label"),
+    (TextStyleName::Micro, "micro"),
+];
+
+/// A single exported token: its path (e.g. `["color", "primary", "500"]`)
+/// and CSS value.
+type Token = (Vec<String>, String);
+
+impl Color {
+    /// Format as `#rrggbb`, or `#rrggbbaa` when not fully opaque.
+    #[must_use]
+    pub fn to_hex(self) -> String {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        let mut hex = format!(
+            "#{:02x}{:02x}{:02x}",
+            channel(self.r),
+            channel(self.g),
+            channel(self.b)
+        );
+        if self.a < 1.0 {
+            let _ = write!(hex, "{:02x}", channel(self.a));
+        }
+        hex
+    }
+}
+
+impl ThemeTokens {
+    /// Render the tokens as CSS custom properties on `:root`.
+    ///
+    /// Names follow the token paths, e.g. `--color-primary-500`,
+    /// `--spacing-lg`, `--radius-md` and `--font-body-md-size`.
+    #[must_use]
+    pub fn to_css_variables(&self) -> String {
+        let mut css = String::from(":root {\n");
+        for (path, value) in self.export_tokens() {
+            let _ = writeln!(css, "  --{}: {value};", path.join("-"));
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// Render the tokens in the [Style Dictionary] JSON format.
+    ///
+    /// Each token is a leaf object with a `value`, nested by its path.
+    ///
+    /// [Style Dictionary]: https://amzn.github.io/style-dictionary/
+    #[must_use]
+    pub fn to_style_dictionary_json(&self) -> String {
+        let mut json = String::from("{");
+        // Keys of the currently open objects, and whether each open object
+        // (including the root) already has a member.
+        let mut open: Vec<String> = Vec::new();
+        let mut has_members = vec![false];
+
+        let close = |json: &mut String, open: &mut Vec<String>, has_members: &mut Vec<bool>| {
+            open.pop();
+            has_members.pop();
+            let _ = write!(json, "\n{}}}", "  ".repeat(open.len() + 1));
+        };
+
+        for (path, value) in self.export_tokens() {
+            let shared = open
+                .iter()
+                .zip(&path)
+                .take_while(|(open, next)| open == next)
+                .count();
+            while open.len() > shared {
+                close(&mut json, &mut open, &mut has_members);
+            }
+
+            for segment in &path[shared..] {
+                if let Some(has_members) = has_members.last_mut() {
+                    if std::mem::replace(has_members, true) {
+                        json.push(',');
+                    }
+                }
+                let indent = "  ".repeat(open.len() + 1);
+                let _ = write!(json, "\n{indent}\"{}\": {{", escape(segment));
+                open.push(segment.clone());
+                has_members.push(false);
+            }
+
+            let indent = "  ".repeat(open.len() + 1);
+            let _ = write!(json, "\n{indent}\"value\": \"{}\"", escape(&value));
+            close(&mut json, &mut open, &mut has_members);
+        }
+
+        while !open.is_empty() {
+            close(&mut json, &mut open, &mut has_members);
+        }
+        json.push_str("\n}\n");
+        json
+    }
+
+    /// All exported tokens in a stable order, grouped by path prefix.
+    fn export_tokens(&self) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut push = |path: &[&str], value: String| {
+            tokens.push((path.iter().map(|s| (*s).to_string()).collect(), value));
+        };
+
+        let colors = &self.colors;
+        let scales: [(&str, &ColorScale); 7] = [
+            ("primary", &colors.primary),
+            ("secondary", &colors.secondary),
+            ("neutral", &colors.neutral),
+            ("success", &colors.semantic.success),
+            ("warning", &colors.semantic.warning),
+            ("destructive", &colors.semantic.destructive),
+            ("info", &colors.semantic.info),
+        ];
+        for (name, scale) in scales {
+            for shade in Shade::ALL {
+                push(
+                    &["color", name, &shade.value().to_string()],
+                    scale.get(shade).to_hex(),
+                );
+            }
+        }
+
+        for (name, value) in SPACING_NAMES.iter().zip(self.spacing.values()) {
+            push(&["spacing", name], format!("{value}px"));
+        }
+
+        for (size, name) in RADIUS_NAMES {
+            push(&["radius", name], format!("{}px", self.radius.get(size)));
+        }
+
+        for (style, name) in TEXT_STYLES {
+            let style = self.typography.get(style);
+            push(&["font", name, "family"], style.font_family.to_string());
+            push(&["font", name, "size"], format!("{}px", style.size));
+            push(&["font", name, "weight"], style.weight.value().to_string());
+            push(
+                &["font", name, "line-height"],
+                style.line_height.to_string(),
+            );
+        }
+
+        let motion = &self.motion;
+        for (name, motion) in [
+            ("instant", motion.instant),
+            ("fast", motion.fast),
+            ("normal", motion.normal),
+            ("slow", motion.slow),
+            ("slower", motion.slower),
+        ] {
+            push(&["duration", name], format!("{}ms", motion.duration_ms));
+        }
+
+        let opacity = &self.opacity;
+        for (name, value) in [
+            ("hover", opacity.hover),
+            ("focus", opacity.focus),
+            ("pressed", opacity.pressed),
+            ("dragged", opacity.dragged),
+            ("disabled", opacity.disabled),
+        ] {
+            push(&["opacity", name], value.to_string());
+        }
+
+        tokens
+    }
+}
+
+/// Escape a string for a JSON string literal.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(all(test, feature = "preset-light"))]
+mod tests {
+    use crate::presets;
+
+    #[test]
+    fn css_contains_named_hex_variables() {
+        let css = presets::light_tokens().to_css_variables();
+
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.contains("  --color-primary-500: #3b82f6;\n"));
+        assert!(css.contains("  --color-primary-50: #eff6ff;\n"));
+        assert!(css.contains("  --spacing-lg: 16px;\n"));
+        assert!(css.contains("  --radius-full: 9999px;\n"));
+        assert!(css.contains("  --font-body-md-size: 16px;\n"));
+        assert!(css.contains("  --duration-normal: 200ms;\n"));
+        assert!(css.contains("  --opacity-hover: 0.08;\n"));
+    }
+
+    #[test]
+    fn style_dictionary_nests_tokens_by_path() {
+        let json = presets::light_tokens().to_style_dictionary_json();
+
+        assert!(json.starts_with("{\n  \"color\": {\n    \"primary\": {\n      \"50\": {"));
+        assert!(json.contains("\"500\": {\n        \"value\": \"#3b82f6\"\n      }"));
+        assert!(json.contains("\"lg\": {\n      \"value\": \"16px\"\n    }"));
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+    }
+}
@@ -0,0 +1,18 @@
+//! Helpers for interpolating tokens between two themes.
+
+/// Linear interpolation from `a` to `b`, with `t` clamped to `0.0..=1.0`.
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    // Weighting both ends returns exactly `a` at 0.0 and `b` at 1.0.
+    let t = t.clamp(0.0, 1.0);
+    a.mul_add(1.0 - t, b * t)
+}
+
+/// `a` for the first half of the transition and `b` for the second, for
+/// values that can't be blended, such as font families.
+pub fn snap<T>(a: T, b: T, t: f32) -> T {
+    if t < 0.5 {
+        a
+    } else {
+        b
+    }
+}
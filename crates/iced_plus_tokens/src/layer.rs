@@ -0,0 +1,120 @@
+//! Layer tokens that order overlays along the z-axis.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Named layers, from the bottom of the window to the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Layer {
+    /// Page content
+    Base,
+    /// Dropdown menus and select lists
+    Dropdown,
+    /// Sticky headers and toolbars that scroll content passes under
+    Sticky,
+    /// Backdrops and drawers
+    Overlay,
+    /// Modals and dialogs
+    Modal,
+    /// Toast notifications, shown above modals
+    Toast,
+    /// Tooltips, always on top
+    Tooltip,
+}
+
+impl Layer {
+    /// All layers, bottom to top.
+    pub const ALL: [Self; 7] = [
+        Self::Base,
+        Self::Dropdown,
+        Self::Sticky,
+        Self::Overlay,
+        Self::Modal,
+        Self::Toast,
+        Self::Tooltip,
+    ];
+
+    /// The z-index of this layer in the [default scale](LayerScale::DEFAULT).
+    #[must_use]
+    pub const fn z_index(self) -> u32 {
+        LayerScale::DEFAULT.get(self)
+    }
+}
+
+/// Z-indices of the named [`Layer`]s.
+///
+/// Overlays passed to the same `layered` call of `iced_plus_layouts` are
+/// stacked in z-index order, so a toast layered with a modal shows on top of
+/// it. An overlay nested inside another's base stays below it, whatever its
+/// z-index. The values are spaced apart to leave room for layers in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LayerScale {
+    /// Page content (0)
+    pub base: u32,
+    /// Dropdowns (1000)
+    pub dropdown: u32,
+    /// Sticky elements (1100)
+    pub sticky: u32,
+    /// Backdrops and drawers (1200)
+    pub overlay: u32,
+    /// Modals (1300)
+    pub modal: u32,
+    /// Toasts (1400)
+    pub toast: u32,
+    /// Tooltips (1500)
+    pub tooltip: u32,
+}
+
+impl LayerScale {
+    /// Default layer scale values.
+    pub const DEFAULT: Self = Self {
+        base: 0,
+        dropdown: 1000,
+        sticky: 1100,
+        overlay: 1200,
+        modal: 1300,
+        toast: 1400,
+        tooltip: 1500,
+    };
+
+    /// Get a z-index by layer.
+    #[must_use]
+    pub const fn get(&self, layer: Layer) -> u32 {
+        match layer {
+            Layer::Base => self.base,
+            Layer::Dropdown => self.dropdown,
+            Layer::Sticky => self.sticky,
+            Layer::Overlay => self.overlay,
+            Layer::Modal => self.modal,
+            Layer::Toast => self.toast,
+            Layer::Tooltip => self.tooltip,
+        }
+    }
+}
+
+impl Default for LayerScale {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers_are_strictly_increasing() {
+        for pair in Layer::ALL.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].z_index() < pair[1].z_index());
+        }
+    }
+
+    #[test]
+    fn toasts_show_above_modals_above_dropdowns() {
+        assert!(Layer::Toast.z_index() > Layer::Modal.z_index());
+        assert!(Layer::Modal.z_index() > Layer::Dropdown.z_index());
+    }
+}
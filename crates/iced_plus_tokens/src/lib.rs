@@ -10,7 +10,13 @@
 //! - **Spacing**: Modular spacing scale for margins, padding, and gaps
 //! - **Radius**: Border radius presets
 //! - **Elevation**: Shadow and depth definitions
-//! - **Motion**: Animation duration and easing presets
+//! - **Layers**: Named z-indices that order overlays
+//! - **Motion**: Animation duration and easing presets, and a global
+//!   reduced-motion switch
+//! - **Opacity**: Interaction state overlay opacities
+//!
+//! The default spacing, radius and type sizes are also available as `const`
+//! tables in [`tables`] for tooling that only needs the raw values.
 //!
 //! # Example
 //!
@@ -38,19 +44,29 @@
 
 pub mod color;
 pub mod elevation;
+pub mod export;
+mod interpolate;
+pub mod layer;
 pub mod motion;
+pub mod opacity;
 pub mod presets;
 mod private;
 pub mod radius;
 pub mod spacing;
+pub mod tables;
 pub mod tokens;
 pub mod typography;
 
 // Re-export main types at crate root for convenience
 pub use color::{Color, ColorPalette, ColorScale, SemanticColors, Shade};
 pub use elevation::{Elevation, ElevationLevel, ElevationScale, Shadow};
-pub use motion::{DurationPreset, Easing, Motion, MotionScale};
-pub use radius::{RadiusScale, RadiusSize};
+pub use layer::{Layer, LayerScale};
+pub use motion::{
+    reduce_motion, set_reduce_motion, with_reduce_motion, DurationPreset, Easing, Motion,
+    MotionScale,
+};
+pub use opacity::{OpacityLevel, OpacityScale};
+pub use radius::{ComponentRadii, RadiusScale, RadiusSize};
 pub use spacing::{SpacingScale, SpacingSize};
 pub use tokens::{ThemePreset, ThemeTokens};
-pub use typography::{FontWeight, TextStyle, TextStyleName, TypographyScale};
+pub use typography::{FontWeight, TextStyle, TextStyleName, TextTransform, TypographyScale};
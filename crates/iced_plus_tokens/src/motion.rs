@@ -1,8 +1,70 @@
 //! Motion and animation timing tokens.
+//!
+//! Apps honoring the OS "reduce motion" accessibility setting keep it in
+//! their state and build their view inside [`with_reduce_motion`]:
+//!
+//! ```rust,ignore
+//! fn view(&self) -> Element<'_, Message> {
+//!     with_reduce_motion(self.settings.reduce_motion, || self.page())
+//! }
+//! ```
+//!
+//! Components capture the setting when they are built, then skip slides and
+//! fades, and spinners hold still. Outside [`with_reduce_motion`], they fall
+//! back to the app-wide setting from [`set_reduce_motion`]. iced doesn't
+//! report the OS setting; with its `accessibility` feature,
+//! `iced_plus_platform::accessibility::detect_reduce_motion` reads it for
+//! the initial state.
+
+use std::cell::Cell;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+thread_local! {
+    static REDUCE_MOTION: Cell<bool> = const { Cell::new(false) };
+    static SCOPED_REDUCE_MOTION: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Set whether components built on this thread outside
+/// [`with_reduce_motion`] should avoid animating.
+///
+/// iced doesn't report the OS setting, so apps read it from the platform
+/// (or their own preferences) and pass it here, typically at startup.
+pub fn set_reduce_motion(reduce: bool) {
+    REDUCE_MOTION.with(|cell| cell.set(reduce));
+}
+
+/// Build `view` with components avoiding animation if `reduce`, whatever
+/// the app-wide setting.
+///
+/// Scopes nest; the setting applies to components built inside `view`,
+/// which keep it while they animate, and is restored when it returns.
+pub fn with_reduce_motion<T>(reduce: bool, view: impl FnOnce() -> T) -> T {
+    /// Restores the enclosing scope's setting, even if `view` panics.
+    struct Restore(Option<bool>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_REDUCE_MOTION.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED_REDUCE_MOTION.with(|cell| cell.replace(Some(reduce))));
+
+    view()
+}
+
+/// Whether components are built with reduced motion: the setting of the
+/// enclosing [`with_reduce_motion`], or the app-wide one (`false` by
+/// default).
+#[must_use]
+pub fn reduce_motion() -> bool {
+    SCOPED_REDUCE_MOTION
+        .with(Cell::get)
+        .unwrap_or_else(|| REDUCE_MOTION.with(Cell::get))
+}
+
 /// Named duration presets.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -67,6 +129,45 @@ impl Easing {
 
     /// Sharp ease for elements that need to feel snappy.
     pub const SHARP: Self = Self::CubicBezier(0.4, 0.0, 0.6, 1.0);
+
+    /// Map linear progress `t` in `0.0..=1.0` through the curve.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    (-2.0_f32).mul_add(t, 4.0).mul_add(t, -1.0)
+                }
+            }
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                // Find the curve parameter whose x is `t`, then take its y.
+                let bezier = |p1: f32, p2: f32, s: f32| {
+                    let inv = 1.0 - s;
+                    (3.0 * inv * inv * s).mul_add(p1, (3.0 * inv * s * s).mul_add(p2, s * s * s))
+                };
+
+                let (mut low, mut high) = (0.0_f32, 1.0_f32);
+                let mut s = t;
+                for _ in 0..20 {
+                    if bezier(x1, x2, s) < t {
+                        low = s;
+                    } else {
+                        high = s;
+                    }
+                    s = (low + high) / 2.0;
+                }
+
+                bezier(y1, y2, s)
+            }
+        }
+    }
 }
 
 impl Default for Easing {
@@ -107,8 +208,44 @@ impl Motion {
         self.duration_ms as f32 / 1000.0
     }
 
+    /// Interpolate the duration from `a` to `b`; the easing switches
+    /// halfway.
+    #[must_use]
+    // Durations are far below where `f32` loses integer precision, and the
+    // result lies between two `u32`s.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            duration_ms: crate::interpolate::lerp(a.duration_ms as f32, b.duration_ms as f32, t)
+                .round() as u32,
+            easing: crate::interpolate::snap(a.easing, b.easing, t),
+        }
+    }
+
     /// No motion (instant).
     pub const NONE: Self = Self::new(0, Easing::Linear);
+
+    /// This motion, or [`Motion::NONE`] while [`reduce_motion`] is on.
+    #[must_use]
+    pub fn effective(self) -> Self {
+        self.reduced(reduce_motion())
+    }
+
+    /// This motion, or [`Motion::NONE`] if `reduce`.
+    ///
+    /// Components pass the [`reduce_motion`] setting they were built with.
+    #[must_use]
+    pub const fn reduced(self, reduce: bool) -> Self {
+        if reduce {
+            Self::NONE
+        } else {
+            self
+        }
+    }
 }
 
 impl Default for Motion {
@@ -134,6 +271,18 @@ pub struct MotionScale {
 }
 
 impl MotionScale {
+    /// Interpolate every preset from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            instant: Motion::lerp(&a.instant, &b.instant, t),
+            fast: Motion::lerp(&a.fast, &b.fast, t),
+            normal: Motion::lerp(&a.normal, &b.normal, t),
+            slow: Motion::lerp(&a.slow, &b.slow, t),
+            slower: Motion::lerp(&a.slower, &b.slower, t),
+        }
+    }
+
     /// Get motion by duration preset.
     #[must_use]
     pub const fn get(&self, preset: DurationPreset) -> Motion {
@@ -158,3 +307,50 @@ impl Default for MotionScale {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_keeps_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::STANDARD,
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-3);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-3);
+        }
+
+        // Standard decelerates: well past halfway at the midpoint.
+        assert!(Easing::STANDARD.apply(0.5) > 0.7);
+    }
+
+    #[test]
+    fn reduce_motion_makes_motion_instant() {
+        let motion = Motion::default();
+        assert_eq!(motion.effective(), motion);
+
+        set_reduce_motion(true);
+        assert_eq!(motion.effective(), Motion::NONE);
+        set_reduce_motion(false);
+    }
+
+    #[test]
+    fn scoped_reduce_motion_overrides_the_app_wide_one() {
+        set_reduce_motion(true);
+
+        let (outer, inner) = with_reduce_motion(false, || {
+            (reduce_motion(), with_reduce_motion(true, reduce_motion))
+        });
+        assert!(!outer);
+        assert!(inner);
+
+        // The app-wide setting applies again once the scope ends.
+        assert!(reduce_motion());
+        set_reduce_motion(false);
+    }
+}
@@ -0,0 +1,83 @@
+//! Opacity tokens for interaction state overlays.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Named opacity levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OpacityLevel {
+    /// Pointer over an interactive element (8%)
+    Hover,
+    /// Keyboard focus on an interactive element (12%)
+    Focus,
+    /// Element being pressed (12%)
+    Pressed,
+    /// Element being dragged (16%)
+    Dragged,
+    /// Content of a disabled element (38%)
+    Disabled,
+}
+
+/// Opacities of the overlays and content that mark interaction states.
+///
+/// State overlays are drawn in the content color over a surface, so a
+/// hovered ghost button on a light theme gets black at [`hover`](Self::hover)
+/// opacity. The defaults follow Material's state layers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpacityScale {
+    /// Hover overlay (0.08)
+    pub hover: f32,
+    /// Focus overlay (0.12)
+    pub focus: f32,
+    /// Pressed overlay (0.12)
+    pub pressed: f32,
+    /// Dragged overlay (0.16)
+    pub dragged: f32,
+    /// Disabled content (0.38)
+    pub disabled: f32,
+}
+
+impl OpacityScale {
+    /// Default opacity scale values.
+    pub const DEFAULT: Self = Self {
+        hover: 0.08,
+        focus: 0.12,
+        pressed: 0.12,
+        dragged: 0.16,
+        disabled: 0.38,
+    };
+
+    /// Interpolate every opacity from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        use crate::interpolate::lerp;
+
+        Self {
+            hover: lerp(a.hover, b.hover, t),
+            focus: lerp(a.focus, b.focus, t),
+            pressed: lerp(a.pressed, b.pressed, t),
+            dragged: lerp(a.dragged, b.dragged, t),
+            disabled: lerp(a.disabled, b.disabled, t),
+        }
+    }
+
+    /// Get an opacity by level.
+    #[must_use]
+    pub const fn get(&self, level: OpacityLevel) -> f32 {
+        match level {
+            OpacityLevel::Hover => self.hover,
+            OpacityLevel::Focus => self.focus,
+            OpacityLevel::Pressed => self.pressed,
+            OpacityLevel::Dragged => self.dragged,
+            OpacityLevel::Disabled => self.disabled,
+        }
+    }
+}
+
+impl Default for OpacityScale {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
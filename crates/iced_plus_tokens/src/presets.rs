@@ -4,7 +4,8 @@ use crate::{
     color::{Color, ColorPalette, ColorScale, SemanticColors},
     elevation::{Elevation, ElevationScale, Shadow},
     motion::MotionScale,
-    radius::RadiusScale,
+    opacity::OpacityScale,
+    radius::{ComponentRadii, RadiusScale},
     spacing::SpacingScale,
     tokens::{ThemePreset, ThemeTokens},
     typography::TypographyScale,
@@ -32,6 +33,8 @@ pub fn light_tokens() -> ThemeTokens {
         radius: RadiusScale::DEFAULT,
         elevation: light_elevation(),
         motion: MotionScale::default(),
+        opacity: OpacityScale::DEFAULT,
+        component_radii: ComponentRadii::DEFAULT,
     }
 }
 
@@ -45,6 +48,8 @@ pub fn dark_tokens() -> ThemeTokens {
         radius: RadiusScale::DEFAULT,
         elevation: dark_elevation(),
         motion: MotionScale::default(),
+        opacity: OpacityScale::DEFAULT,
+        component_radii: ComponentRadii::DEFAULT,
     }
 }
 
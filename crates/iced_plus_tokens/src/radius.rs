@@ -44,7 +44,7 @@ pub struct RadiusScale {
 impl RadiusScale {
     /// Default radius scale values.
     pub const DEFAULT: Self = Self {
-        values: [0.0, 2.0, 4.0, 6.0, 8.0, 12.0, 16.0, 9999.0],
+        values: crate::tables::RADIUS,
     };
 
     /// Create a custom radius scale.
@@ -53,6 +53,14 @@ impl RadiusScale {
         Self { values }
     }
 
+    /// Interpolate every value from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self::new(std::array::from_fn(|i| {
+            crate::interpolate::lerp(a.values[i], b.values[i], t)
+        }))
+    }
+
     /// Get a radius value by size.
     #[inline(always)]
     #[must_use]
@@ -122,3 +130,36 @@ impl Default for RadiusScale {
         Self::DEFAULT
     }
 }
+
+/// Per-component radius overrides.
+///
+/// A component without an override uses its default size on the
+/// [`RadiusScale`]: medium for buttons and inputs, large for cards. An
+/// override picks a different size on the same scale, e.g. pill-shaped
+/// buttons with [`RadiusSize::Full`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComponentRadii {
+    /// Radius of buttons.
+    pub button: Option<RadiusSize>,
+    /// Radius of cards.
+    pub card: Option<RadiusSize>,
+    /// Radius of text inputs.
+    pub input: Option<RadiusSize>,
+}
+
+impl ComponentRadii {
+    /// No overrides.
+    pub const DEFAULT: Self = Self {
+        button: None,
+        card: None,
+        input: None,
+    };
+
+    /// The overrides of `a` for the first half of the transition and those
+    /// of `b` for the second; sizes on the scale can't be blended.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        crate::interpolate::snap(*a, *b, t)
+    }
+}
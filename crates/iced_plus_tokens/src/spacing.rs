@@ -76,7 +76,7 @@ pub struct SpacingScale {
 impl SpacingScale {
     /// Default spacing scale values.
     pub const DEFAULT: Self = Self {
-        values: [2.0, 4.0, 8.0, 12.0, 16.0, 24.0, 32.0, 48.0, 64.0, 96.0],
+        values: crate::tables::SPACING,
     };
 
     /// Create a custom spacing scale from an array.
@@ -85,6 +85,14 @@ impl SpacingScale {
         Self { values }
     }
 
+    /// Interpolate every value from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self::new(std::array::from_fn(|i| {
+            crate::interpolate::lerp(a.values[i], b.values[i], t)
+        }))
+    }
+
     /// Get a spacing value by size.
     #[inline(always)]
     #[must_use]
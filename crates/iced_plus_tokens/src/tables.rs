@@ -0,0 +1,68 @@
+//! Raw token values as `const` tables.
+//!
+//! These are the numbers behind the default scales, usable in `const`
+//! contexts and by tooling (CSS or docs generators, headless renderers)
+//! that wants plain values. Like the rest of this crate they have no
+//! dependency on iced; colors are the plain RGBA [`Color`](crate::Color).
+
+use crate::typography::TextStyleName;
+
+/// Default spacing values in pixels, indexed by
+/// [`SpacingSize::index`](crate::SpacingSize::index).
+pub const SPACING: [f32; 10] = [2.0, 4.0, 8.0, 12.0, 16.0, 24.0, 32.0, 48.0, 64.0, 96.0];
+
+/// Default border radii in pixels, indexed by
+/// [`RadiusSize::index`](crate::RadiusSize::index).
+pub const RADIUS: [f32; 8] = [0.0, 2.0, 4.0, 6.0, 8.0, 12.0, 16.0, 9999.0];
+
+/// Default font size and relative line height of each text style.
+pub const TYPOGRAPHY: [(TextStyleName, f32, f32); 11] = [
+    (TextStyleName::DisplayXl, 60.0, 1.1),
+    (TextStyleName::DisplayLg, 48.0, 1.1),
+    (TextStyleName::HeadingLg, 32.0, 1.2),
+    (TextStyleName::HeadingMd, 24.0, 1.3),
+    (TextStyleName::HeadingSm, 20.0, 1.4),
+    (TextStyleName::BodyLg, 18.0, 1.6),
+    (TextStyleName::BodyMd, 16.0, 1.5),
+    (TextStyleName::BodySm, 14.0, 1.5),
+    (TextStyleName::Code, 14.0, 1.6),
+    (TextStyleName::Label, 12.0, 1.4),
+    (TextStyleName::Micro, 10.0, 1.4),
+];
+
+/// Default font size of a text style, in pixels.
+#[must_use]
+pub const fn font_size(name: TextStyleName) -> f32 {
+    TYPOGRAPHY[name as usize].1
+}
+
+/// Default line height of a text style, relative to its font size.
+#[must_use]
+pub const fn line_height(name: TextStyleName) -> f32 {
+    TYPOGRAPHY[name as usize].2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RadiusScale, RadiusSize, SpacingScale, SpacingSize, TypographyScale};
+
+    #[test]
+    fn tables_match_runtime_scales() {
+        let spacing = SpacingScale::default();
+        for size in SpacingSize::ALL {
+            assert!((SPACING[size.index()] - spacing.get(size)).abs() < f32::EPSILON);
+        }
+
+        let radius = RadiusScale::default();
+        for size in [RadiusSize::None, RadiusSize::Md, RadiusSize::Full] {
+            assert!((RADIUS[size.index()] - radius.get(size)).abs() < f32::EPSILON);
+        }
+
+        let typography = TypographyScale::default();
+        for (name, size, line_height) in TYPOGRAPHY {
+            assert!((typography.get(name).size - size).abs() < f32::EPSILON);
+            assert!((typography.get(name).line_height - line_height).abs() < f32::EPSILON);
+        }
+    }
+}
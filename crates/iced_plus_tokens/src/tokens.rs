@@ -1,8 +1,13 @@
 //! Aggregate theme tokens structure.
 
 use crate::{
-    color::ColorPalette, elevation::ElevationScale, motion::MotionScale, radius::RadiusScale,
-    spacing::SpacingScale, typography::TypographyScale,
+    color::ColorPalette,
+    elevation::ElevationScale,
+    motion::MotionScale,
+    opacity::OpacityScale,
+    radius::{ComponentRadii, RadiusScale, RadiusSize},
+    spacing::SpacingScale,
+    typography::TypographyScale,
 };
 
 #[cfg(feature = "serde")]
@@ -27,10 +32,19 @@ pub struct ThemeTokens {
     pub elevation: ElevationScale,
     /// Motion/animation scale
     pub motion: MotionScale,
+    /// Interaction state opacity scale
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub opacity: OpacityScale,
+    /// Per-component radius overrides
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub component_radii: ComponentRadii,
 }
 
 impl ThemeTokens {
     /// Create a new theme tokens set.
+    ///
+    /// The opacity scale starts at [`OpacityScale::DEFAULT`] and no component
+    /// radius is overridden.
     #[must_use]
     pub fn new(
         colors: ColorPalette,
@@ -47,9 +61,78 @@ impl ThemeTokens {
             radius,
             elevation,
             motion,
+            opacity: OpacityScale::DEFAULT,
+            component_radii: ComponentRadii::DEFAULT,
         }
     }
 
+    /// Interpolate every token from `a` to `b`, e.g. to crossfade from a
+    /// light to a dark theme.
+    ///
+    /// `t` runs from `0.0`, giving `a`, to `1.0`, giving `b`. Colors,
+    /// shadows and sizes blend linearly; values that can't be blended, such
+    /// as font families, easings and radius overrides, switch halfway.
+    ///
+    /// ```rust,ignore
+    /// let t = Motion::default().easing.apply(elapsed / duration);
+    /// let tokens = ThemeTokens::lerp(&light.tokens, &dark.tokens, t);
+    /// ```
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            colors: ColorPalette::lerp(&a.colors, &b.colors, t),
+            typography: TypographyScale::lerp(&a.typography, &b.typography, t),
+            spacing: SpacingScale::lerp(&a.spacing, &b.spacing, t),
+            radius: RadiusScale::lerp(&a.radius, &b.radius, t),
+            elevation: ElevationScale::lerp(&a.elevation, &b.elevation, t),
+            motion: MotionScale::lerp(&a.motion, &b.motion, t),
+            opacity: OpacityScale::lerp(&a.opacity, &b.opacity, t),
+            component_radii: ComponentRadii::lerp(&a.component_radii, &b.component_radii, t),
+        }
+    }
+
+    /// Round buttons with `size` from the radius scale instead of medium.
+    #[must_use]
+    pub const fn with_button_radius(mut self, size: RadiusSize) -> Self {
+        self.component_radii.button = Some(size);
+        self
+    }
+
+    /// Round cards with `size` from the radius scale instead of large.
+    #[must_use]
+    pub const fn with_card_radius(mut self, size: RadiusSize) -> Self {
+        self.component_radii.card = Some(size);
+        self
+    }
+
+    /// Round text inputs with `size` from the radius scale instead of medium.
+    #[must_use]
+    pub const fn with_input_radius(mut self, size: RadiusSize) -> Self {
+        self.component_radii.input = Some(size);
+        self
+    }
+
+    /// Corner radius of buttons, taking overrides into account.
+    #[must_use]
+    pub fn button_radius(&self) -> f32 {
+        self.radius
+            .get(self.component_radii.button.unwrap_or(RadiusSize::Md))
+    }
+
+    /// Corner radius of cards, taking overrides into account.
+    #[must_use]
+    pub fn card_radius(&self) -> f32 {
+        self.radius
+            .get(self.component_radii.card.unwrap_or(RadiusSize::Lg))
+    }
+
+    /// Corner radius of text inputs, taking overrides into account.
+    #[must_use]
+    pub fn input_radius(&self) -> f32 {
+        self.radius
+            .get(self.component_radii.input.unwrap_or(RadiusSize::Md))
+    }
+
     /// Get the color palette.
     #[must_use]
     pub fn colors(&self) -> &ColorPalette {
@@ -85,6 +168,12 @@ impl ThemeTokens {
     pub fn motion(&self) -> &MotionScale {
         &self.motion
     }
+
+    /// Get the opacity scale.
+    #[must_use]
+    pub const fn opacity(&self) -> &OpacityScale {
+        &self.opacity
+    }
 }
 
 impl Default for ThemeTokens {
@@ -96,6 +185,8 @@ impl Default for ThemeTokens {
             radius: RadiusScale::default(),
             elevation: ElevationScale::default(),
             motion: MotionScale::default(),
+            opacity: OpacityScale::default(),
+            component_radii: ComponentRadii::default(),
         }
     }
 }
@@ -119,3 +210,36 @@ impl ThemePreset {
         Self { id, name, tokens }
     }
 }
+
+#[cfg(all(test, feature = "preset-light", feature = "preset-dark"))]
+mod tests {
+    use super::*;
+    use crate::presets::{dark_tokens, light_tokens};
+
+    #[test]
+    fn lerp_crossfades_between_themes() {
+        let (light, dark) = (light_tokens(), dark_tokens());
+
+        assert_eq!(ThemeTokens::lerp(&light, &dark, 0.0), light);
+        assert_eq!(ThemeTokens::lerp(&light, &dark, 1.0), dark);
+
+        let halfway = ThemeTokens::lerp(&light, &dark, 0.5);
+        for ((mid, from), to) in halfway
+            .colors
+            .primary
+            .to_array()
+            .into_iter()
+            .zip(light.colors.primary.to_array())
+            .zip(dark.colors.primary.to_array())
+        {
+            for (mid, from, to) in [
+                (mid.r, from.r, to.r),
+                (mid.g, from.g, to.g),
+                (mid.b, from.b, to.b),
+            ] {
+                assert!((mid - (from + to) / 2.0).abs() < 1e-6);
+                assert!(mid >= from.min(to) && mid <= from.max(to));
+            }
+        }
+    }
+}
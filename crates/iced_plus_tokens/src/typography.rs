@@ -42,6 +42,48 @@ impl Default for FontWeight {
     }
 }
 
+/// Case transform applied to text when rendering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextTransform {
+    /// Text is rendered as written.
+    #[default]
+    None,
+    /// ALL CAPS.
+    Uppercase,
+    /// all lowercase.
+    Lowercase,
+    /// First Letter Of Each Word capitalized; the rest is left as written.
+    Capitalize,
+}
+
+impl TextTransform {
+    /// Apply the transform to a string.
+    #[must_use]
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::None => text.to_owned(),
+            Self::Uppercase => text.to_uppercase(),
+            Self::Lowercase => text.to_lowercase(),
+            Self::Capitalize => {
+                let mut result = String::with_capacity(text.len());
+                let mut word_start = true;
+
+                for c in text.chars() {
+                    if word_start {
+                        result.extend(c.to_uppercase());
+                    } else {
+                        result.push(c);
+                    }
+                    word_start = c.is_whitespace();
+                }
+
+                result
+            }
+        }
+    }
+}
+
 /// A complete text style definition.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -54,8 +96,11 @@ pub struct TextStyle {
     pub weight: FontWeight,
     /// Line height as a multiplier (e.g., 1.5 = 150%)
     pub line_height: f32,
-    /// Letter spacing in pixels (can be negative)
+    /// Letter spacing in pixels (can be negative). Spaced text is laid out
+    /// on a single line and never wraps, so it suits short labels only.
     pub letter_spacing: f32,
+    /// Case transform
+    pub text_transform: TextTransform,
 }
 
 impl TextStyle {
@@ -73,6 +118,7 @@ impl TextStyle {
             weight,
             line_height,
             letter_spacing: 0.0,
+            text_transform: TextTransform::None,
         }
     }
 
@@ -83,6 +129,29 @@ impl TextStyle {
         self
     }
 
+    /// Create a text style with a case transform.
+    #[must_use]
+    pub const fn with_text_transform(mut self, text_transform: TextTransform) -> Self {
+        self.text_transform = text_transform;
+        self
+    }
+
+    /// Interpolate the size, line height and letter spacing from `a` to
+    /// `b`; the family, weight and transform switch halfway.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        use crate::interpolate::{lerp, snap};
+
+        Self {
+            font_family: snap(a.font_family, b.font_family, t),
+            size: lerp(a.size, b.size, t),
+            weight: snap(a.weight, b.weight, t),
+            line_height: lerp(a.line_height, b.line_height, t),
+            letter_spacing: lerp(a.letter_spacing, b.letter_spacing, t),
+            text_transform: snap(a.text_transform, b.text_transform, t),
+        }
+    }
+
     /// Get the computed line height in pixels.
     #[must_use]
     pub const fn line_height_px(&self) -> f32 {
@@ -97,6 +166,9 @@ impl Default for TextStyle {
 }
 
 /// Named text style categories.
+///
+/// Variants are ordered from largest to smallest, matching
+/// [`tables::TYPOGRAPHY`](crate::tables::TYPOGRAPHY).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TextStyleName {
@@ -172,23 +244,68 @@ impl TypographyScale {
     }
 }
 
+impl TypographyScale {
+    /// Interpolate every text style from `a` to `b`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            display_xl: TextStyle::lerp(&a.display_xl, &b.display_xl, t),
+            display_lg: TextStyle::lerp(&a.display_lg, &b.display_lg, t),
+            heading_lg: TextStyle::lerp(&a.heading_lg, &b.heading_lg, t),
+            heading_md: TextStyle::lerp(&a.heading_md, &b.heading_md, t),
+            heading_sm: TextStyle::lerp(&a.heading_sm, &b.heading_sm, t),
+            body_lg: TextStyle::lerp(&a.body_lg, &b.body_lg, t),
+            body_md: TextStyle::lerp(&a.body_md, &b.body_md, t),
+            body_sm: TextStyle::lerp(&a.body_sm, &b.body_sm, t),
+            code: TextStyle::lerp(&a.code, &b.code, t),
+            label: TextStyle::lerp(&a.label, &b.label, t),
+            micro: TextStyle::lerp(&a.micro, &b.micro, t),
+        }
+    }
+}
+
 impl Default for TypographyScale {
     fn default() -> Self {
         const FONT: &str = "Inter";
         const MONO: &str = "JetBrains Mono";
 
+        const fn style(family: &'static str, name: TextStyleName, weight: FontWeight) -> TextStyle {
+            TextStyle::new(
+                family,
+                crate::tables::font_size(name),
+                weight,
+                crate::tables::line_height(name),
+            )
+        }
+
         Self {
-            display_xl: TextStyle::new(FONT, 60.0, FontWeight::Bold, 1.1),
-            display_lg: TextStyle::new(FONT, 48.0, FontWeight::Bold, 1.1),
-            heading_lg: TextStyle::new(FONT, 32.0, FontWeight::SemiBold, 1.2),
-            heading_md: TextStyle::new(FONT, 24.0, FontWeight::SemiBold, 1.3),
-            heading_sm: TextStyle::new(FONT, 20.0, FontWeight::SemiBold, 1.4),
-            body_lg: TextStyle::new(FONT, 18.0, FontWeight::Regular, 1.6),
-            body_md: TextStyle::new(FONT, 16.0, FontWeight::Regular, 1.5),
-            body_sm: TextStyle::new(FONT, 14.0, FontWeight::Regular, 1.5),
-            code: TextStyle::new(MONO, 14.0, FontWeight::Regular, 1.6),
-            label: TextStyle::new(FONT, 12.0, FontWeight::Medium, 1.4),
-            micro: TextStyle::new(FONT, 10.0, FontWeight::Medium, 1.4),
+            display_xl: style(FONT, TextStyleName::DisplayXl, FontWeight::Bold),
+            display_lg: style(FONT, TextStyleName::DisplayLg, FontWeight::Bold),
+            heading_lg: style(FONT, TextStyleName::HeadingLg, FontWeight::SemiBold),
+            heading_md: style(FONT, TextStyleName::HeadingMd, FontWeight::SemiBold),
+            heading_sm: style(FONT, TextStyleName::HeadingSm, FontWeight::SemiBold),
+            body_lg: style(FONT, TextStyleName::BodyLg, FontWeight::Regular),
+            body_md: style(FONT, TextStyleName::BodyMd, FontWeight::Regular),
+            body_sm: style(FONT, TextStyleName::BodySm, FontWeight::Regular),
+            code: style(MONO, TextStyleName::Code, FontWeight::Regular),
+            label: style(FONT, TextStyleName::Label, FontWeight::Medium),
+            micro: style(FONT, TextStyleName::Micro, FontWeight::Medium),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_transform_changes_case() {
+        assert_eq!(TextTransform::Uppercase.apply("Navigation"), "NAVIGATION");
+        assert_eq!(TextTransform::Lowercase.apply("Navigation"), "navigation");
+        assert_eq!(
+            TextTransform::Capitalize.apply("hello  wide world"),
+            "Hello  Wide World"
+        );
+        assert_eq!(TextTransform::None.apply("as is"), "as is");
+    }
+}
@@ -39,10 +39,10 @@ use iced_plus_components::tooltip::{Tooltip, TooltipPosition};
 use iced_plus_components::webview::{BrowserBar, WebViewState};
 use iced_plus_components::{
     Alert, Avatar, AvatarShape, AvatarSize, Badge, Card, Elevation, Heading, Image,
-    ImagePlaceholder, Progress, Skeleton, Switch, Text, TextInput,
+    ImagePlaceholder, InputGroup, Progress, Skeleton, Switch, Text, TextInput,
 };
 use iced_plus_components::{Icon, IconName};
-use iced_plus_layouts::{drawer, BreakpointTier, HStack, Modal, ResponsiveRow, VStack};
+use iced_plus_layouts::{AppShell, BreakpointTier, HStack, Modal, ResponsiveRow, VStack};
 
 #[cfg(feature = "webview")]
 use raw_window_handle::{HandleError, HasWindowHandle, RawWindowHandle, WindowHandle};
@@ -161,15 +161,11 @@ enum DropdownOption {
     Option3,
 }
 
-impl std::fmt::Display for DropdownOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Option1 => write!(f, "Option 1"),
-            Self::Option2 => write!(f, "Option 2"),
-            Self::Option3 => write!(f, "Option 3"),
-        }
-    }
-}
+iced_plus_components::selectable_enum!(DropdownOption {
+    Option1 => "Option 1",
+    Option2 => "Option 2",
+    Option3 => "Option 3",
+});
 
 struct App {
     dark_mode: bool,
@@ -955,23 +951,24 @@ impl App {
         .width(Length::Fill)
         .height(Length::Fill);
 
-        let main_content: Element<'_, Message> = column![app_bar, scrollable_content,].into();
-
-        // Wrap with drawer if open
-        let with_drawer: Element<'_, Message> = if self.show_drawer {
-            let side_nav = self.side_nav();
-            drawer(main_content, side_nav, Message::ToggleDrawer)
-        } else {
-            main_content
-        };
+        // Side navigation beside the page, collapsing to a drawer on narrow windows
+        let shell: Element<'_, Message> = AppShell::new(scrollable_content)
+            .header(app_bar)
+            .header_height(56.0)
+            .sidebar(self.side_nav())
+            .sidebar_width(260.0)
+            .collapse_below(900.0)
+            .drawer_open(self.show_drawer)
+            .on_toggle(Message::ToggleDrawer)
+            .into();
 
         // Wrap with modal if open
         let with_modal: Element<'_, Message> = if self.show_modal {
-            Modal::new(with_drawer, self.modal_content())
+            Modal::new(shell, self.modal_content())
                 .on_backdrop_press(Message::CloseModal)
                 .into()
         } else {
-            with_drawer
+            shell
         };
 
         // Wrap with toast container
@@ -1320,7 +1317,8 @@ impl App {
                     Button::primary("Top").small().on_press(Message::Increment),
                     "Tooltip on top",
                 )
-                .position(TooltipPosition::Top),
+                .position(TooltipPosition::Top)
+                .arrow(true),
             )
             .push(
                 Tooltip::new(
@@ -1471,18 +1469,11 @@ impl App {
     }
 
     fn select_section(&self) -> Element<'_, Message> {
-        let select: Element<'_, Message> = Select::new(
-            &[
-                DropdownOption::Option1,
-                DropdownOption::Option2,
-                DropdownOption::Option3,
-            ][..],
-            self.selected_option,
-            Message::DropdownSelected,
-        )
-        .placeholder("Choose an option...")
-        .width(Length::Fill)
-        .into();
+        let select: Element<'_, Message> =
+            Select::from_enum(self.selected_option, Message::DropdownSelected)
+                .placeholder("Choose an option...")
+                .width(Length::Fill)
+                .into();
 
         VStack::new()
             .spacing(12.0)
@@ -2182,8 +2173,6 @@ impl App {
     }
 
     fn browser_section(&self) -> Element<'_, Message> {
-        use iced::widget::text_input;
-
         // URL input bar
         let url_bar: Element<'_, Message> = row![
             Button::secondary("Back")
@@ -2195,13 +2184,12 @@ impl App {
             Button::secondary("Reload")
                 .small()
                 .on_press(Message::WebViewReload),
-            text_input("Enter URL...", &self.url_input)
-                .on_input(Message::UrlInputChanged)
-                .on_submit(Message::WebViewNavigate)
-                .width(Length::Fill),
-            Button::primary("Go")
-                .small()
-                .on_press(Message::WebViewNavigate),
+            InputGroup::new(
+                TextInput::new("Enter URL...", &self.url_input)
+                    .on_input(Message::UrlInputChanged)
+                    .on_submit(Message::WebViewNavigate),
+            )
+            .button("Go", Message::WebViewNavigate),
         ]
         .spacing(8)
         .align_y(alignment::Vertical::Center)